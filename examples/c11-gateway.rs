@@ -0,0 +1,35 @@
+//! This example demonstrates how to route all the requests through an AI gateway with the `Gateway` presets
+//! (Helicone, LiteLLM, Portkey).
+//!
+//! Set `GATEWAY` to `helicone`, `litellm`, or `portkey` (default `litellm`) with the matching
+//! `HELICONE_API_KEY`, `LITELLM_API_KEY`, or `PORTKEY_API_KEY` (and `PORTKEY_VIRTUAL_KEY`) environment variables.
+
+use genai::chat::printer::print_chat_stream;
+use genai::chat::{ChatMessage, ChatRequest};
+use genai::{Client, Gateway};
+
+const MODEL: &str = "gpt-4o-mini";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+	tracing_subscriber::fmt().with_max_level(tracing::Level::DEBUG).init();
+
+	// -- Build the gateway preset
+	let gateway = match std::env::var("GATEWAY").as_deref() {
+		Ok("helicone") => Gateway::helicone().with_header("Helicone-Property-App", "genai-example"),
+		Ok("portkey") => Gateway::portkey().with_virtual_key(std::env::var("PORTKEY_VIRTUAL_KEY")?),
+		_ => Gateway::litellm("http://localhost:4000"),
+	};
+	println!("\n--- Gateway: {:?} ({})", gateway.kind(), gateway.endpoint().base_url());
+
+	// -- Build the client with the gateway
+	let client = Client::builder().with_gateway(gateway).build();
+
+	let chat_req = ChatRequest::new(vec![ChatMessage::user("Why is the sky blue? (answer in one sentence)")]);
+
+	println!("\n--- Answer: (streaming)");
+	let chat_res = client.exec_chat_stream(MODEL, chat_req, None).await?;
+	print_chat_stream(chat_res, None).await?;
+
+	Ok(())
+}
@@ -114,6 +114,17 @@ impl AdapterKind {
 			AdapterKind::Ollama => None,
 		}
 	}
+
+	/// Get the max number of inputs of an embed batch request (used to split the `Client::embed_many(..)` inputs).
+	pub fn embed_max_batch_size(&self) -> usize {
+		match self {
+			AdapterKind::OpenAI => 2048,
+			AdapterKind::Cohere => 96,
+			AdapterKind::Gemini => 100,
+			// no documented limit, but keep the requests reasonably sized
+			_ => 512,
+		}
+	}
 }
 
 /// From Model implementations
@@ -123,10 +134,10 @@ impl AdapterKind {
 	/// When more control is needed, the `ServiceTypeResolver` can be used
 	/// to map a model name to any adapter and endpoint.
 	///
-	///  - OpenAI     - starts_with "gpt", "o3", "o1", "chatgpt"
+	///  - OpenAI     - starts_with "gpt", "o3", "o1", "chatgpt", "ft:" (fine-tuned models), "sora"
 	///  - Anthropic  - starts_with "claude"
 	///  - Cohere     - starts_with "command"
-	///  - Gemini     - starts_with "gemini"
+	///  - Gemini     - starts_with "gemini", "veo"
 	///  - Groq       - model in Groq models
 	///  - DeepSeek   - model in DeepSeek models (deepseek.com)
 	///  - Zhipu      - starts_with "glm"
@@ -153,13 +164,15 @@ impl AdapterKind {
 			|| model.starts_with("chatgpt")
 			|| model.starts_with("codex")
 			|| model.starts_with("text-embedding")
+			|| model.starts_with("ft:")
+			|| model.starts_with("sora")
 		{
 			Ok(Self::OpenAI)
 		} else if model.starts_with("claude") {
 			Ok(Self::Anthropic)
 		} else if model.starts_with("command") || model.starts_with("embed-") {
 			Ok(Self::Cohere)
-		} else if model.starts_with("gemini") {
+		} else if model.starts_with("gemini") || model.starts_with("veo") {
 			Ok(Self::Gemini)
 		} else if model.starts_with("grok") {
 			Ok(Self::Xai)
@@ -1,17 +1,20 @@
-use crate::adapter::adapters::support::get_api_key;
+use super::files::FILES_API_BETA;
+use crate::adapter::adapters::support::{check_logit_bias, get_api_key};
 use crate::adapter::anthropic::AnthropicStreamer;
 use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
-	ChatOptionsSet, ChatRequest, ChatResponse, ChatRole, ChatStream, ChatStreamResponse, ContentBlock, ContentPart,
-	ImageSource, MessageContent, PromptTokensDetails, ReasoningEffort, ToolCall, Usage,
+	BuiltInTool, CacheTtl, ChatMessage, ChatOptionsSet, ChatRequest, ChatResponse, ChatResponseFormat, ChatRole,
+	ChatStream, ChatStreamResponse, Citation, CitationSource, ContentBlock, ContentFilterInfo, ContentPart,
+	FinishReason, ImageSource, MessageContent, PromptTokensDetails, ReasoningEffort, ToolCall, ToolChoice, Usage,
 };
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::WebResponse;
+use crate::{Error, Result, ServiceTarget};
 use crate::{Headers, ModelIden};
-use crate::{Result, ServiceTarget};
 use reqwest::RequestBuilder;
 use reqwest_eventsource::EventSource;
 use serde_json::{Value, json};
+use std::ops::Range;
 use tracing::warn;
 use value_ext::JsonValueExt;
 
@@ -34,6 +37,12 @@ const MAX_TOKENS_8K: u32 = 8192; // claude-3-5-sonnet, claude-3-5-haiku
 const MAX_TOKENS_4K: u32 = 4096; // claude-3-opus, claude-3-haiku
 
 const ANTHROPIC_VERSION: &str = "2023-06-01";
+const COMPUTER_USE_BETA: &str = "computer-use-2025-01-24";
+const CODE_EXECUTION_BETA: &str = "code-execution-2025-05-22";
+const INTERLEAVED_THINKING_BETA: &str = "interleaved-thinking-2025-05-14";
+
+/// The description of the structured output tool, when the `JsonSpec` has none.
+const STRUCTURED_OUTPUT_TOOL_DESCRIPTION: &str = "Respond with the structured output, as the input of this tool.";
 const MODELS: &[&str] = &[
 	"claude-opus-4-1-20250805",
 	"claude-opus-4-20250514",
@@ -80,6 +89,7 @@ impl Adapter for AnthropicAdapter {
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
 		let ServiceTarget { endpoint, auth, model } = target;
+		check_logit_bias(&model, &options_set)?;
 
 		// -- api_key
 		let api_key = get_api_key(auth, &model)?;
@@ -90,22 +100,6 @@ impl Adapter for AnthropicAdapter {
 		// -- Detect OAuth by checking if api_key starts with "Bearer "
 		let is_oauth = api_key.starts_with("Bearer ");
 
-		// -- headers
-		let headers = if is_oauth {
-			// OAuth uses Authorization header and requires anthropic-beta header
-			Headers::from(vec![
-				("Authorization".to_string(), api_key),
-				("anthropic-version".to_string(), ANTHROPIC_VERSION.to_string()),
-				("anthropic-beta".to_string(), "oauth-2025-04-20".to_string()),
-			])
-		} else {
-			// Regular API key uses x-api-key header
-			Headers::from(vec![
-				("x-api-key".to_string(), api_key),
-				("anthropic-version".to_string(), ANTHROPIC_VERSION.to_string()),
-			])
-		};
-
 		// -- Calculate thinking_enabled early to pass to message formatting
 		let (model_name, _) = model.model_name.as_model_name_and_namespace();
 		let supports_thinking = model_name.contains("claude-opus-4")
@@ -113,24 +107,44 @@ impl Adapter for AnthropicAdapter {
 			|| model_name.contains("claude-3-7-sonnet")
 			|| model_name.contains("claude-haiku-4-5");
 
-		let thinking_enabled = if supports_thinking {
-			match options_set.reasoning_effort() {
-				Some(ReasoningEffort::Low) => true,
-				Some(ReasoningEffort::Medium) => true,
-				Some(ReasoningEffort::High) => true,
-				Some(ReasoningEffort::Budget(b)) => *b > 0,
-				None => false,
-			}
-		} else {
-			false
-		};
+		// NOTE: The budget tokens come from the `ReasoningEffort` provider mapping table
+		let thinking_budget = options_set.reasoning_effort().and_then(ReasoningEffort::anthropic_budget);
+		// NOTE: Anthropic rejects the forced tool use with extended thinking, so the thinking is disabled.
+		let forced_tool_choice = options_set.tool_choice().is_some_and(ToolChoice::is_forced);
+		if supports_thinking && thinking_budget.is_some() && forced_tool_choice {
+			tracing::warn!(
+				"Anthropic does not support the forced tool choice with extended thinking, disabling the thinking for model '{model}'."
+			);
+		}
+		let thinking_enabled = supports_thinking && thinking_budget.is_some() && !forced_tool_choice;
+		let interleaved_thinking = thinking_enabled && options_set.interleaved_thinking() == Some(true);
+
+		// -- Check the thinking blocks of the history (Anthropic rejects them with opaque 400s)
+		validate_thinking_history(&model, &chat_req, thinking_enabled)?;
+
+		// -- headers
+		// NOTE: File references, the computer use tool, and the interleaved thinking require their beta header
+		let mut betas = request_betas(&chat_req);
+		if interleaved_thinking {
+			betas.push(INTERLEAVED_THINKING_BETA);
+		}
+		let headers = Self::util_auth_headers(api_key, &betas);
 
 		// -- Parts
+		let default_cache_ttl = options_set.cache_ttl().unwrap_or_default();
+		let tools_cache_ttl = if options_set.tools_cache_control().unwrap_or(true) {
+			Some(options_set.tools_cache_ttl().unwrap_or(default_cache_ttl))
+		} else {
+			None
+		};
 		let AnthropicRequestParts {
 			system,
-			messages,
+			mut messages,
 			tools,
-		} = Self::into_anthropic_request_parts(chat_req, is_oauth, thinking_enabled)?;
+		} = Self::into_anthropic_request_parts(chat_req, is_oauth, thinking_enabled, default_cache_ttl, tools_cache_ttl)?;
+		if options_set.citations() == Some(true) {
+			enable_document_citations(&mut messages);
+		}
 
 		// -- Build the basic payload
 		let stream = matches!(service_type, ServiceType::ChatStream);
@@ -147,6 +161,57 @@ impl Adapter for AnthropicAdapter {
 		if let Some(tools) = tools {
 			payload.x_insert("/tools", tools)?;
 		}
+		if let Some(tool_choice) = options_set.tool_choice() {
+			let tool_choice = match tool_choice {
+				ToolChoice::Auto => json!({"type": "auto"}),
+				ToolChoice::None => json!({"type": "none"}),
+				ToolChoice::Required => json!({"type": "any"}),
+				ToolChoice::Named(name) => json!({"type": "tool", "name": name}),
+			};
+			payload.x_insert("tool_choice", tool_choice)?;
+		}
+
+		// -- Structured output (Anthropic has no response format, so the JsonSpec is a forced tool)
+		// NOTE: The tool use is turned back into the text content of the response (see `to_chat_response`).
+		if let Some(ChatResponseFormat::JsonSpec(json_spec)) = options_set.response_format() {
+			let description = json_spec.description.as_deref().unwrap_or(STRUCTURED_OUTPUT_TOOL_DESCRIPTION);
+			let tool = json!({
+				"name": json_spec.name,
+				"description": description,
+				"input_schema": json_spec.schema,
+			});
+			match payload.get_mut("tools").and_then(Value::as_array_mut) {
+				Some(tools) => tools.push(tool),
+				None => payload.x_insert("tools", vec![tool])?,
+			}
+
+			// NOTE: Anthropic does not allow forcing a tool with extended thinking, so the model is only instructed to use it.
+			// NOTE: An explicit `ToolChoice` takes precedence.
+			if options_set.tool_choice().is_none() {
+				let tool_choice = if thinking_enabled {
+					json!({"type": "auto"})
+				} else {
+					json!({"type": "tool", "name": json_spec.name})
+				};
+				payload.x_insert("tool_choice", tool_choice)?;
+			}
+		}
+
+		// -- Parallel tool use (a flag of the `tool_choice`, which defaults to `auto`)
+		if options_set.parallel_tool_calls() == Some(false) && payload.get("tools").is_some() {
+			let tool_choice = payload.get_mut("tool_choice").and_then(Value::as_object_mut);
+			match tool_choice {
+				// NOTE: The `none` tool choice has no such flag (no tool use at all).
+				Some(tool_choice) if tool_choice.get("type").and_then(Value::as_str) == Some("none") => (),
+				Some(tool_choice) => {
+					tool_choice.insert("disable_parallel_tool_use".to_string(), true.into());
+				}
+				None => payload.x_insert(
+					"tool_choice",
+					json!({"type": "auto", "disable_parallel_tool_use": true}),
+				)?,
+			}
+		}
 
 		// -- Calculate max_tokens first (required for Anthropic)
 		let max_tokens = options_set.max_tokens().unwrap_or_else(|| {
@@ -172,20 +237,20 @@ impl Adapter for AnthropicAdapter {
 
 		// -- Add thinking configuration if enabled
 		if thinking_enabled {
-			// Convert reasoning effort to budget tokens
-			let budget_tokens = match options_set.reasoning_effort() {
-				Some(ReasoningEffort::Low) => 4096,     // 4k tokens
-				Some(ReasoningEffort::Medium) => 16384, // 16k tokens (recommended starting point)
-				Some(ReasoningEffort::High) => 32768,   // 32k tokens
-				Some(ReasoningEffort::Budget(b)) => *b as u32,
-				None => 16384, // Default to medium if thinking is enabled
-			};
+			// Default to medium if thinking is enabled
+			let budget_tokens = thinking_budget.unwrap_or(16384);
 
 			// Ensure budget is at least 1024 (Anthropic minimum)
 			let budget_tokens = budget_tokens.max(1024);
 
 			// Ensure budget is less than max_tokens
-			let budget_tokens = budget_tokens.min(max_tokens.saturating_sub(100));
+			// NOTE: With the interleaved thinking, the budget is for all the thinking blocks of the turn,
+			//       so it can exceed the max_tokens.
+			let budget_tokens = if interleaved_thinking {
+				budget_tokens
+			} else {
+				budget_tokens.min(max_tokens.saturating_sub(100))
+			};
 
 			let thinking = json!({
 				"type": "enabled",
@@ -234,6 +299,22 @@ impl Adapter for AnthropicAdapter {
 			}
 		}
 
+		// NOTE: top_k cannot be set when thinking is enabled
+		if let Some(top_k) = options_set.top_k()
+			&& !thinking_enabled
+		{
+			payload.x_insert("top_k", top_k)?;
+		}
+
+		if options_set.frequency_penalty().is_some() || options_set.presence_penalty().is_some() {
+			warn!("Anthropic does not support frequency_penalty/presence_penalty, ignoring them.");
+		}
+
+		// NOTE: Anthropic only supports the `user_id` metadata
+		if let Some(user) = options_set.user() {
+			payload.x_insert("metadata", json!({"user_id": user}))?;
+		}
+
 		Ok(WebRequestData { url, headers, payload })
 	}
 
@@ -254,33 +335,68 @@ impl Adapter for AnthropicAdapter {
 		let usage = body.x_take::<Value>("usage");
 		let usage = usage.map(Self::into_usage).unwrap_or_default();
 
+		// -- Capture the eventual finish reason
+		let finish_reason = body.x_get_as::<&str>("stop_reason").ok().map(FinishReason::from_provider);
+		let stop_sequence: Option<String> = body.x_get("stop_sequence").ok();
+
+		// -- Capture the eventual refusal (Anthropic does not return the filter categories)
+		let content_filter = match body.x_get_as::<&str>("stop_reason") {
+			Ok("refusal") => Some(ContentFilterInfo::refusal(None)),
+			_ => None,
+		};
+
 		// -- Capture the content
 		let mut content: Vec<MessageContent> = Vec::new();
 
 		// -- Process content items
 		let json_content_items: Vec<Value> = body.x_take("content")?;
 
-		// Check if we have thinking blocks mixed with other content
+		// -- Turn the eventual structured output tool use into text (see `to_web_request_data`)
+		let json_content_items: Vec<Value> = match options_set.response_format() {
+			Some(ChatResponseFormat::JsonSpec(json_spec)) => json_content_items
+				.into_iter()
+				.map(|item| structured_output_to_text(item, &json_spec.name))
+				.collect(),
+			_ => json_content_items,
+		};
+
+		// Check if we have thinking blocks (or cited text, or code execution blocks) mixed with other content
 		let has_thinking_blocks = json_content_items.iter().any(|item| {
 			matches!(
 				item.get("type").and_then(|v| v.as_str()),
 				Some("thinking" | "redacted_thinking")
 			)
 		});
+		let has_citations = json_content_items
+			.iter()
+			.any(|item| item.get("citations").is_some_and(Value::is_array));
+
+		let has_code_execution = json_content_items
+			.iter()
+			.any(|item| item.get("type").and_then(Value::as_str) == Some("code_execution_tool_result"));
 
-		if has_thinking_blocks {
-			// When thinking blocks are present, preserve exact block sequence
+		if has_thinking_blocks || has_citations || has_code_execution {
+			// When thinking blocks (or citations, or code executions) are present, preserve exact block sequence
 			let mut blocks: Vec<ContentBlock> = Vec::new();
 			let mut reasoning_content = String::new();
+			// The char offset of the text block in the response text (the text blocks concatenated), for the citation spans
+			let mut text_offset = 0;
 
 			for mut item in json_content_items {
 				let typ: &str = item.x_get_as("type")?;
 				match typ {
 					"text" => {
-						let text = item.x_take("text")?;
+						let text: String = item.x_take("text")?;
+						let text_len = text.chars().count();
+						let citations = item
+							.x_take::<Vec<Value>>("citations")
+							.ok()
+							.map(|citations| into_citations(citations, text_offset..text_offset + text_len));
+						text_offset += text_len;
 						blocks.push(ContentBlock::Text {
 							text,
 							thought_signature: None,
+							citations,
 						});
 					}
 					"thinking" => {
@@ -306,6 +422,32 @@ impl Adapter for AnthropicAdapter {
 							thought_signature: None,
 						});
 					}
+					"server_tool_use" if item.x_get_as::<&str>("name").ok() == Some("code_execution") => {
+						let code = item.x_take("/input/code").unwrap_or_default();
+						blocks.push(ContentBlock::ExecutableCode {
+							language: Some("python".to_string()),
+							code,
+						});
+					}
+					"code_execution_tool_result" => {
+						let mut result: Value = item.x_take("content").unwrap_or_default();
+						let stdout: String = result.x_take("stdout").unwrap_or_default();
+						let stderr: String = result.x_take("stderr").unwrap_or_default();
+						let error_code: Option<String> = result.x_take("error_code").ok();
+						let return_code: i64 = result.x_take("return_code").unwrap_or_default();
+						let output = match (stderr.is_empty(), error_code) {
+							(_, Some(error_code)) => error_code,
+							(true, None) => stdout,
+							(false, None) => format!("{stdout}{stderr}"),
+						};
+						blocks.push(ContentBlock::CodeExecutionResult {
+							output,
+							is_error: return_code != 0
+								|| result.x_get_as::<&str>("type").ok() != Some("code_execution_result"),
+						});
+					}
+					// The other server tool blocks (e.g., web search) are executed by Anthropic
+					"server_tool_use" | "web_search_tool_result" => (),
 					_ => {
 						// Skip unknown block types
 						warn!("Unknown content block type in Anthropic response: {}", typ);
@@ -324,8 +466,22 @@ impl Adapter for AnthropicAdapter {
 				},
 				model_iden,
 				provider_model_iden,
+				system_fingerprint: None,
 				usage,
 				captured_raw_body,
+				response_digest: None,
+				response_headers: None,
+				prompt_leaks: None,
+				file_citations: None,
+				web_citations: None,
+				output_repair: None,
+				ai_disclosure: None,
+				provider_exts: Default::default(),
+				finish_reason,
+				stop_sequence,
+				choices: Vec::new(),
+				logprobs: None,
+				content_filter,
 			})
 		} else {
 			// No thinking blocks - use traditional parsing for backward compatibility
@@ -362,8 +518,22 @@ impl Adapter for AnthropicAdapter {
 				reasoning_content: None,
 				model_iden,
 				provider_model_iden,
+				system_fingerprint: None,
 				usage,
 				captured_raw_body,
+				response_digest: None,
+				response_headers: None,
+				prompt_leaks: None,
+				file_citations: None,
+				web_citations: None,
+				output_repair: None,
+				ai_disclosure: None,
+				provider_exts: Default::default(),
+				finish_reason,
+				stop_sequence,
+				choices: Vec::new(),
+				logprobs: None,
+				content_filter,
 			})
 		}
 	}
@@ -408,6 +578,34 @@ impl Adapter for AnthropicAdapter {
 // region:    --- Support
 
 impl AnthropicAdapter {
+	/// Build the auth headers (x-api-key or OAuth) with the eventual `anthropic-beta` values.
+	pub(super) fn util_auth_headers(api_key: String, betas: &[&str]) -> Headers {
+		// -- Detect OAuth by checking if api_key starts with "Bearer "
+		let is_oauth = api_key.starts_with("Bearer ");
+
+		let mut betas: Vec<&str> = betas.to_vec();
+		let mut headers = if is_oauth {
+			// OAuth uses Authorization header and requires anthropic-beta header
+			betas.insert(0, "oauth-2025-04-20");
+			Headers::from(vec![
+				("Authorization".to_string(), api_key),
+				("anthropic-version".to_string(), ANTHROPIC_VERSION.to_string()),
+			])
+		} else {
+			// Regular API key uses x-api-key header
+			Headers::from(vec![
+				("x-api-key".to_string(), api_key),
+				("anthropic-version".to_string(), ANTHROPIC_VERSION.to_string()),
+			])
+		};
+
+		if !betas.is_empty() {
+			headers.merge(("anthropic-beta", betas.join(",")));
+		}
+
+		headers
+	}
+
 	/// Check if the model is Claude 4.5, which requires temperature/top_p exclusivity.
 	/// Claude 4.5 models cannot use both temperature and top_p together.
 	fn is_claude_4_5(model_name: &str) -> bool {
@@ -456,36 +654,45 @@ impl AnthropicAdapter {
 	/// - Will push the `ChatRequest.system` and system message to `AnthropicRequestParts.system`
 	/// - When is_oauth is true, forces array format for system prompts
 	/// - When thinking_enabled is true, adds thinking blocks to assistant messages before tool calls
-	fn into_anthropic_request_parts(
+	/// - The `default_cache_ttl` is the TTL of the `CacheControl::Ephemeral` messages
+	/// - The `tools_cache_ttl` is the TTL of the cache breakpoint of the last tool (None for no breakpoint)
+	pub(super) fn into_anthropic_request_parts(
 		chat_req: ChatRequest,
 		is_oauth: bool,
 		_thinking_enabled: bool,
+		default_cache_ttl: CacheTtl,
+		tools_cache_ttl: Option<CacheTtl>,
 	) -> Result<AnthropicRequestParts> {
 		let mut messages: Vec<Value> = Vec::new();
-		// (content, is_cache_control)
-		let mut systems: Vec<(String, bool)> = Vec::new();
+		// (content, cache_ttl)
+		let mut systems: Vec<(String, Option<CacheTtl>)> = Vec::new();
 
-		// NOTE: For now, this means the first System cannot have a cache control
-		//       so that we do not change too much.
+		// NOTE: The first System is cached only with an explicit `ChatRequest::with_system_cache_point()`.
 		if let Some(system) = chat_req.system {
-			systems.push((system, false));
+			let cache_ttl = chat_req
+				.system_cache_control
+				.map(|cache_control| cache_control.ttl_or(default_cache_ttl));
+			systems.push((system, cache_ttl));
 		}
 
 		// -- Process the messages
 		for msg in chat_req.messages {
-			let is_cache_control = msg.options.map(|o| o.cache_control.is_some()).unwrap_or(false);
+			let cache_ttl = msg
+				.options
+				.and_then(|o| o.cache_control)
+				.map(|cache_control| cache_control.ttl_or(default_cache_ttl));
 
 			match msg.role {
 				// for now, system and tool messages go to the system
-				ChatRole::System => {
+				ChatRole::System | ChatRole::Developer => {
 					if let MessageContent::Text(content) = msg.content {
-						systems.push((content, is_cache_control))
+						systems.push((content, cache_ttl))
 					}
 					// TODO: Needs to trace/warn that other types are not supported
 				}
 				ChatRole::User => {
 					let content = match msg.content {
-						MessageContent::Text(content) => apply_cache_control_to_text(is_cache_control, content),
+						MessageContent::Text(content) => apply_cache_control_to_text(cache_ttl, content),
 						MessageContent::Parts(parts) => {
 							let values = parts
 								.iter()
@@ -511,10 +718,48 @@ impl AnthropicAdapter {
 											},
 										})),
 									},
+									ContentPart::File(file_ref) => {
+										// Images must be referenced as image blocks, everything else as document
+										let is_image =
+											file_ref.content_type.as_deref().is_some_and(|ct| ct.starts_with("image/"));
+										let block_type = if is_image { "image" } else { "document" };
+										Some(json!({
+											"type": block_type,
+											"source": {
+												"type": "file",
+												"file_id": file_ref.id,
+											}
+										}))
+									}
+									ContentPart::Document { content_type, source } => match source {
+										ImageSource::Url(url) => Some(json!({
+											"type": "document",
+											"source": {
+												"type": "url",
+												"url": url,
+											}
+										})),
+										ImageSource::Base64(content) => Some(json!({
+											"type": "document",
+											"source": {
+												"type": "base64",
+												"media_type": content_type,
+												"data": content,
+											},
+										})),
+									},
+									ContentPart::Audio { .. } => {
+										warn!("Anthropic does not support audio inputs, skipping the audio part");
+										None
+									}
+									ContentPart::Video { .. } => {
+										warn!("Anthropic does not support video inputs, skipping the video part");
+										None
+									}
 								})
 								.collect::<Vec<Value>>();
 
-							let values = apply_cache_control_to_parts(is_cache_control, values);
+							let values = apply_cache_control_to_parts(cache_ttl, values);
 
 							json!(values)
 						}
@@ -524,6 +769,11 @@ impl AnthropicAdapter {
 								.into_iter()
 								.map(|block| match block {
 									ContentBlock::Text { text, .. } => json!({"type": "text", "text": text}),
+									// NOTE: The code execution blocks are sent as text (the server tool blocks need their ids)
+									ContentBlock::ExecutableCode { code: text, .. }
+									| ContentBlock::CodeExecutionResult { output: text, .. } => {
+										json!({"type": "text", "text": text})
+									}
 									ContentBlock::Thinking { text, signature } => {
 										let mut obj = json!({"type": "thinking", "thinking": text});
 										if let Some(sig) = signature {
@@ -548,10 +798,24 @@ impl AnthropicAdapter {
 										"tool_use_id": tool_use_id,
 										"content": content,
 									}),
+									ContentBlock::Image { content_type, source } => match source {
+										ImageSource::Url(url) => json!({
+											"type": "image",
+											"source": {"type": "url", "url": url},
+										}),
+										ImageSource::Base64(content) => json!({
+											"type": "image",
+											"source": {
+												"type": "base64",
+												"media_type": content_type,
+												"data": content,
+											},
+										}),
+									},
 								})
 								.collect::<Vec<Value>>();
 
-							let values = apply_cache_control_to_parts(is_cache_control, values);
+							let values = apply_cache_control_to_parts(cache_ttl, values);
 							json!(values)
 						}
 						// Use `match` instead of `if let`. This will allow to future-proof this
@@ -568,7 +832,7 @@ impl AnthropicAdapter {
 					//
 					match msg.content {
 						MessageContent::Text(content) => {
-							let content = apply_cache_control_to_text(is_cache_control, content);
+							let content = apply_cache_control_to_text(cache_ttl, content);
 							messages.push(json! ({"role": "assistant", "content": content}))
 						}
 						MessageContent::ToolCalls(tool_calls) => {
@@ -584,7 +848,7 @@ impl AnthropicAdapter {
 									})
 								})
 								.collect::<Vec<Value>>();
-							let tool_calls = apply_cache_control_to_parts(is_cache_control, tool_calls);
+							let tool_calls = apply_cache_control_to_parts(cache_ttl, tool_calls);
 							messages.push(json! ({
 								"role": "assistant",
 								"content": tool_calls
@@ -594,36 +858,50 @@ impl AnthropicAdapter {
 							// For assistant messages with blocks, convert directly
 							let values = blocks
 								.into_iter()
-								.map(|block| match block {
-									ContentBlock::Text { text, .. } => json!({"type": "text", "text": text}),
+								.filter_map(|block| match block {
+									ContentBlock::Text { text, .. } => Some(json!({"type": "text", "text": text})),
+									// NOTE: The code execution blocks are sent as text (the server tool blocks need their ids)
+									ContentBlock::ExecutableCode { code: text, .. }
+									| ContentBlock::CodeExecutionResult { output: text, .. } => Some(json!({"type": "text", "text": text})),
+									// NOTE: The unsigned thinking blocks (only without thinking, see `validate_thinking_history`)
+									//       are rejected by Anthropic, so they are dropped.
+									ContentBlock::Thinking { signature, .. }
+										if signature.as_deref().is_none_or(str::is_empty) =>
+									{
+										None
+									}
 									ContentBlock::Thinking { text, signature } => {
 										let mut obj = json!({"type": "thinking", "thinking": text});
 										if let Some(sig) = signature {
 											obj["signature"] = json!(sig);
 										}
-										obj
+										Some(obj)
 									}
-									ContentBlock::RedactedThinking { data } => json!({
+									ContentBlock::RedactedThinking { data } => Some(json!({
 										"type": "redacted_thinking",
 										"data": data,
-									}),
-									ContentBlock::ToolUse { id, name, input, .. } => json!({
+									})),
+									ContentBlock::ToolUse { id, name, input, .. } => Some(json!({
 										"type": "tool_use",
 										"id": id,
 										"name": name,
 										"input": input,
-									}),
+									})),
 									ContentBlock::ToolResult {
 										tool_use_id, content, ..
-									} => json!({
+									} => Some(json!({
 										"type": "tool_result",
 										"tool_use_id": tool_use_id,
 										"content": content,
-									}),
+									})),
+									ContentBlock::Image { .. } => {
+										warn!("Anthropic does not support assistant images, skipping the image block");
+										None
+									}
 								})
 								.collect::<Vec<Value>>();
 
-							let values = apply_cache_control_to_parts(is_cache_control, values);
+							let values = apply_cache_control_to_parts(cache_ttl, values);
 							messages.push(json! ({
 								"role": "assistant",
 								"content": values
@@ -639,14 +917,25 @@ impl AnthropicAdapter {
 						let tool_responses = tool_responses
 							.into_iter()
 							.map(|tool_response| {
+								// NOTE: The tool response parts (e.g., the computer use screenshots) make a content array.
+								let content = if tool_response.parts.is_empty() {
+									json!(tool_response.content)
+								} else {
+									let mut values: Vec<Value> = Vec::new();
+									if !tool_response.content.is_empty() {
+										values.push(json!({"type": "text", "text": tool_response.content}));
+									}
+									values.extend(tool_response.parts.iter().filter_map(into_tool_result_part));
+									json!(values)
+								};
 								json!({
 									"type": "tool_result",
-									"content": tool_response.content,
+									"content": content,
 									"tool_use_id": tool_response.call_id,
 								})
 							})
 							.collect::<Vec<Value>>();
-						let tool_responses = apply_cache_control_to_parts(is_cache_control, tool_responses);
+						let tool_responses = apply_cache_control_to_parts(cache_ttl, tool_responses);
 						// FIXME: MessageContent::ToolResponse should be MessageContent::ToolResponses (even if OpenAI does require multi Tool message)
 						messages.push(json!({
 							"role": "user",
@@ -673,7 +962,7 @@ impl AnthropicAdapter {
 				}));
 
 				// Add user's system prompts, clarifying they override Claude Code identity
-				for (idx, (content, is_cache_control)) in systems.iter().enumerate() {
+				for (idx, (content, cache_ttl)) in systems.iter().enumerate() {
 					let text = if idx == 0 {
 						// Prepend clarification to first user system prompt
 						format!("You are NOT Claude Code. {}", content)
@@ -683,8 +972,13 @@ impl AnthropicAdapter {
 
 					let mut part = json!({"type": "text", "text": text});
 					// Apply cache control if specified or on the last system message
-					if *is_cache_control || (idx == systems.len() - 1) {
-						part["cache_control"] = json!({"type": "ephemeral", "ttl": "1h"});
+					let cache_ttl = if idx == systems.len() - 1 {
+						Some(cache_ttl.unwrap_or(default_cache_ttl))
+					} else {
+						*cache_ttl
+					};
+					if let Some(cache_ttl) = cache_ttl {
+						part["cache_control"] = cache_control_value(cache_ttl);
 					}
 					parts.push(part);
 				}
@@ -692,19 +986,21 @@ impl AnthropicAdapter {
 			} else {
 				// Non-OAuth uses existing logic
 				let mut last_cache_idx = -1;
+				let mut last_cache_ttl = default_cache_ttl;
 				// first determine the last cache control index
-				for (idx, (_, is_cache_control)) in systems.iter().enumerate() {
-					if *is_cache_control {
+				for (idx, (_, cache_ttl)) in systems.iter().enumerate() {
+					if let Some(cache_ttl) = cache_ttl {
 						last_cache_idx = idx as i32;
+						last_cache_ttl = *cache_ttl;
 					}
 				}
 				// Now build the system multi part
-				let system: Value = if last_cache_idx > 0 {
+				let system: Value = if last_cache_idx >= 0 {
 					let mut parts: Vec<Value> = Vec::new();
 					for (idx, (content, _)) in systems.iter().enumerate() {
 						let idx = idx as i32;
 						if idx == last_cache_idx {
-							let part = json!({"type": "text", "text": content, "cache_control": {"type": "ephemeral", "ttl": "1h"}});
+							let part = json!({"type": "text", "text": content, "cache_control": cache_control_value(last_cache_ttl)});
 							parts.push(part);
 						} else {
 							let part = json!({"type": "text", "text": content});
@@ -729,6 +1025,9 @@ impl AnthropicAdapter {
 			tools
 				.into_iter()
 				.map(|tool| {
+					if let Some(builtin) = &tool.builtin {
+						return into_builtin_tool_value(builtin);
+					}
 					// TODO: Need to handle the error correctly
 					// TODO: Needs to have a custom serializer (tool should not have to match to a provider)
 					// NOTE: Right now, low probability, so we just return null if cannot convert to value.
@@ -746,8 +1045,9 @@ impl AnthropicAdapter {
 				.collect::<Vec<Value>>()
 		});
 
-		if let Some(tool) = tools.as_mut().and_then(|t| t.last_mut()).and_then(|t| t.as_object_mut()) {
-			tool.insert("cache_control".to_string(), json!({"type": "ephemeral", "ttl": "1h"}));
+		let last_tool = tools.as_mut().and_then(|t| t.last_mut()).and_then(|t| t.as_object_mut());
+		if let (Some(tool), Some(tools_cache_ttl)) = (last_tool, tools_cache_ttl) {
+			tool.insert("cache_control".to_string(), cache_control_value(tools_cache_ttl));
 		}
 
 		Ok(AnthropicRequestParts {
@@ -758,10 +1058,228 @@ impl AnthropicAdapter {
 	}
 }
 
+/// Returns the structured output tool use content item as a text content item (other items are unchanged).
+fn structured_output_to_text(mut item: Value, tool_name: &str) -> Value {
+	let is_structured_output =
+		item.x_get_str("type").ok() == Some("tool_use") && item.x_get_str("name").ok() == Some(tool_name);
+	if !is_structured_output {
+		return item;
+	}
+	let input = item.x_take::<Value>("input").unwrap_or_default();
+	json!({"type": "text", "text": input.to_string()})
+}
+
+/// The Anthropic server tool of a genai built-in tool.
+fn into_builtin_tool_value(builtin: &BuiltInTool) -> Value {
+	match builtin {
+		BuiltInTool::WebSearch(config) => {
+			let mut tool_value = json!({"type": "web_search_20250305", "name": "web_search"});
+			if let Some(max_uses) = config.max_uses {
+				tool_value["max_uses"] = json!(max_uses);
+			}
+			if let Some(allowed_domains) = &config.allowed_domains {
+				tool_value["allowed_domains"] = json!(allowed_domains);
+			}
+			tool_value
+		}
+		BuiltInTool::CodeExecution => json!({"type": "code_execution_20250522", "name": "code_execution"}),
+		BuiltInTool::TextEditor(version) => json!({"type": version.tool_type(), "name": builtin.name()}),
+		BuiltInTool::Bash => json!({"type": "bash_20250124", "name": "bash"}),
+		BuiltInTool::Computer(config) => {
+			let mut tool_value = json!({
+				"type": "computer_20250124",
+				"name": "computer",
+				"display_width_px": config.display_width_px,
+				"display_height_px": config.display_height_px,
+			});
+			if let Some(display_number) = config.display_number {
+				tool_value["display_number"] = json!(display_number);
+			}
+			tool_value
+		}
+	}
+}
+
+/// Enable the citations on all the document blocks of the messages (see `ChatOptions::with_citations(..)`).
+fn enable_document_citations(messages: &mut [Value]) {
+	for message in messages {
+		let Some(blocks) = message.get_mut("content").and_then(Value::as_array_mut) else {
+			continue;
+		};
+		for block in blocks {
+			if block.get("type").and_then(Value::as_str) == Some("document") {
+				block["citations"] = json!({"enabled": true});
+			}
+		}
+	}
+}
+
+/// Turn the `citations` of a text block into `Citation`s, with the span of the text block in the response text.
+/// The document citations (`char_location`, `page_location`, `content_block_location`) have the document index as id,
+/// and the web search citations have the url.
+fn into_citations(citations: Vec<Value>, span: Range<usize>) -> Vec<Citation> {
+	citations
+		.into_iter()
+		.map(|mut citation| {
+			let cited_text = citation.x_take("cited_text").ok();
+			let (source, id, title) = match citation.x_get_as::<&str>("type") {
+				Ok("web_search_result_location") => (
+					CitationSource::Web,
+					citation.x_take("url").unwrap_or_default(),
+					citation.x_take("title").ok(),
+				),
+				_ => (
+					CitationSource::Document,
+					citation.x_get::<u64>("document_index").unwrap_or_default().to_string(),
+					citation.x_take("document_title").ok(),
+				),
+			};
+			Citation {
+				source,
+				id,
+				title,
+				span: Some(span.clone()),
+				cited_text,
+			}
+		})
+		.collect()
+}
+
+/// The Anthropic block of a tool response part (only the text and image parts are supported in the tool results).
+fn into_tool_result_part(part: &ContentPart) -> Option<Value> {
+	match part {
+		ContentPart::Text(text) => Some(json!({"type": "text", "text": text})),
+		ContentPart::Image { content_type, source } => Some(match source {
+			ImageSource::Url(url) => json!({
+				"type": "image",
+				"source": {"type": "url", "url": url},
+			}),
+			ImageSource::Base64(content) => json!({
+				"type": "image",
+				"source": {
+					"type": "base64",
+					"media_type": content_type,
+					"data": content,
+				},
+			}),
+		}),
+		_ => {
+			warn!("Anthropic tool results only support text and image parts, skipping the part");
+			None
+		}
+	}
+}
+
+/// The beta headers required by the chat request (the files api for the file references, the computer use
+/// and the code execution tools).
+pub(super) fn request_betas(chat_req: &ChatRequest) -> Vec<&'static str> {
+	let mut betas = Vec::new();
+	if has_file_parts(chat_req) {
+		betas.push(FILES_API_BETA);
+	}
+	let has_computer_tool = chat_req
+		.tools
+		.iter()
+		.flatten()
+		.any(|tool| matches!(tool.builtin, Some(BuiltInTool::Computer(_))));
+	if has_computer_tool {
+		betas.push(COMPUTER_USE_BETA);
+	}
+	let has_code_execution_tool = chat_req
+		.tools
+		.iter()
+		.flatten()
+		.any(|tool| matches!(tool.builtin, Some(BuiltInTool::CodeExecution)));
+	if has_code_execution_tool {
+		betas.push(CODE_EXECUTION_BETA);
+	}
+	betas
+}
+
+/// Validate the round-trip of the assistant thinking blocks before sending them to Anthropic, when thinking is enabled:
+/// - Each `ContentBlock::Thinking` must keep its signature (the thinking blocks must be sent back unmodified).
+/// - The assistant tool use message of the current turn (only followed by the tool responses)
+///   must start with its thinking block.
+///
+/// NOTE: Without thinking, the history is not validated, and the unsigned thinking blocks
+///       (e.g., of a transcript from another provider) are dropped when the messages are built.
+fn validate_thinking_history(model_iden: &ModelIden, chat_req: &ChatRequest, thinking_enabled: bool) -> Result<()> {
+	if !thinking_enabled {
+		return Ok(());
+	}
+
+	let invalid = |message_index: usize, cause: &'static str| Error::ThinkingHistoryInvalid {
+		model_iden: model_iden.clone(),
+		message_index,
+		cause,
+	};
+
+	// -- Check the signatures
+	for (idx, msg) in chat_req.messages.iter().enumerate() {
+		let MessageContent::Blocks(blocks) = &msg.content else {
+			continue;
+		};
+		let has_unsigned_thinking = blocks.iter().any(
+			|block| matches!(block, ContentBlock::Thinking { signature, .. } if signature.as_deref().is_none_or(str::is_empty)),
+		);
+		if has_unsigned_thinking {
+			return Err(invalid(
+				idx,
+				"A thinking block has no signature (the thinking blocks must be sent back unmodified)",
+			));
+		}
+	}
+
+	// -- Check the tool use message of the current turn
+	let last_assistant = chat_req
+		.messages
+		.iter()
+		.enumerate()
+		.rev()
+		.take_while(|(_, msg)| matches!(msg.role, ChatRole::Assistant | ChatRole::Tool))
+		.find(|(_, msg)| matches!(msg.role, ChatRole::Assistant));
+	let starts_with_thinking = match last_assistant {
+		Some((_, ChatMessage { content, .. })) => match content {
+			MessageContent::Blocks(blocks)
+				if blocks.iter().any(|block| matches!(block, ContentBlock::ToolUse { .. })) =>
+			{
+				matches!(
+					blocks.first(),
+					Some(ContentBlock::Thinking { .. } | ContentBlock::RedactedThinking { .. })
+				)
+			}
+			MessageContent::ToolCalls(_) => false,
+			_ => true,
+		},
+		None => true,
+	};
+	if let (false, Some((idx, _))) = (starts_with_thinking, last_assistant) {
+		return Err(invalid(
+			idx,
+			"With thinking enabled, the assistant tool use message must start with its thinking block \
+			 (send back the response content blocks)",
+		));
+	}
+
+	Ok(())
+}
+
+/// Returns true if one of the messages references a provider file (requires the files api beta header)
+fn has_file_parts(chat_req: &ChatRequest) -> bool {
+	chat_req.messages.iter().any(|msg| match &msg.content {
+		MessageContent::Parts(parts) => parts.iter().any(|part| matches!(part, ContentPart::File(_))),
+		_ => false,
+	})
+}
+
+fn cache_control_value(cache_ttl: CacheTtl) -> Value {
+	json!({"type": "ephemeral", "ttl": cache_ttl.as_str()})
+}
+
 /// Apply the cache control logic to a text content
-fn apply_cache_control_to_text(is_cache_control: bool, content: String) -> Value {
-	if is_cache_control {
-		let value = json!({"type": "text", "text": content, "cache_control": {"type": "ephemeral", "ttl": "1h"}});
+fn apply_cache_control_to_text(cache_ttl: Option<CacheTtl>, content: String) -> Value {
+	if let Some(cache_ttl) = cache_ttl {
+		let value = json!({"type": "text", "text": content, "cache_control": cache_control_value(cache_ttl)});
 		json!(vec![value])
 	}
 	// simple return
@@ -771,23 +1289,23 @@ fn apply_cache_control_to_text(is_cache_control: bool, content: String) -> Value
 }
 
 /// Apply the cache control logic to a text content
-fn apply_cache_control_to_parts(is_cache_control: bool, parts: Vec<Value>) -> Vec<Value> {
+fn apply_cache_control_to_parts(cache_ttl: Option<CacheTtl>, parts: Vec<Value>) -> Vec<Value> {
 	let mut parts = parts;
-	if is_cache_control && !parts.is_empty() {
+	if let Some(cache_ttl) = cache_ttl.filter(|_| !parts.is_empty()) {
 		let len = parts.len();
 		if let Some(last_value) = parts.get_mut(len - 1) {
 			// NOTE: For now, if it fails, then, no cache
-			let _ = last_value.x_insert("cache_control", json!( {"type": "ephemeral", "ttl": "1h"}));
+			let _ = last_value.x_insert("cache_control", cache_control_value(cache_ttl));
 			// TODO: Should warn
 		}
 	}
 	parts
 }
 
-struct AnthropicRequestParts {
-	system: Option<Value>,
-	messages: Vec<Value>,
-	tools: Option<Vec<Value>>,
+pub(super) struct AnthropicRequestParts {
+	pub system: Option<Value>,
+	pub messages: Vec<Value>,
+	pub tools: Option<Vec<Value>>,
 }
 
 // endregion: --- Support
@@ -0,0 +1,69 @@
+//! Anthropic Count Tokens API implementation
+//! API Documentation: https://docs.anthropic.com/en/api/messages-count-tokens
+
+use super::adapter_impl::{AnthropicRequestParts, request_betas};
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::anthropic::AnthropicAdapter;
+use crate::chat::{CacheTtl, ChatRequest, TokenCount};
+use crate::webc::WebClient;
+use crate::{Error, Result, ServiceTarget};
+use serde_json::json;
+use value_ext::JsonValueExt;
+
+impl AnthropicAdapter {
+	/// Count the input tokens of a chat request with the `messages/count_tokens` endpoint.
+	///
+	/// NOTE: The request parts (system, messages, tools) are built with the same conversion as the chat request.
+	pub(in crate::adapter) async fn count_tokens(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		chat_req: ChatRequest,
+	) -> Result<TokenCount> {
+		let ServiceTarget { endpoint, auth, model } = target;
+
+		// -- api_key & headers
+		let api_key = get_api_key(auth, &model)?;
+		let is_oauth = api_key.starts_with("Bearer ");
+		let betas = request_betas(&chat_req);
+		let headers = Self::util_auth_headers(api_key, &betas);
+
+		// -- url
+		let url = format!("{}messages/count_tokens", endpoint.base_url());
+
+		// -- payload
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+		let AnthropicRequestParts {
+			system,
+			messages,
+			tools,
+		} = Self::into_anthropic_request_parts(chat_req, is_oauth, false, CacheTtl::default(), None)?;
+
+		let mut payload = json!({
+			"model": model_name.to_string(),
+			"messages": messages,
+		});
+		if let Some(system) = system {
+			payload.x_insert("system", system)?;
+		}
+		if let Some(tools) = tools {
+			payload.x_insert("tools", tools)?;
+		}
+
+		// -- Exec
+		let mut web_res =
+			web_client
+				.do_post(&url, &headers, payload)
+				.await
+				.map_err(|webc_error| Error::WebModelCall {
+					model_iden: model.clone(),
+					webc_error,
+				})?;
+
+		let input_tokens: i32 = web_res.body.x_take("input_tokens")?;
+
+		Ok(TokenCount {
+			model_iden: model,
+			input_tokens,
+		})
+	}
+}
@@ -0,0 +1,123 @@
+//! Anthropic Files API implementation (beta)
+//! API Documentation: https://docs.anthropic.com/en/docs/build-with-claude/files
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::anthropic::AnthropicAdapter;
+use crate::files::{FileRef, FileUpload};
+use crate::webc::{self, WebClient};
+use crate::{Error, Headers, Result, ServiceTarget};
+use reqwest::multipart::{Form, Part};
+use serde::Deserialize;
+use value_ext::JsonValueExt;
+
+/// The beta header value required for the Files API (and to reference files in messages).
+pub(super) const FILES_API_BETA: &str = "files-api-2025-04-14";
+
+// region:    --- Anthropic File Response
+
+#[derive(Debug, Deserialize)]
+struct AnthropicFile {
+	id: String,
+	filename: Option<String>,
+	mime_type: Option<String>,
+	size_bytes: Option<u64>,
+}
+
+// endregion: --- Anthropic File Response
+
+impl AnthropicAdapter {
+	pub(in crate::adapter) async fn upload_file(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		file: FileUpload,
+	) -> Result<FileRef> {
+		let (base_url, headers) = files_base_url_and_headers(target)?;
+		let url = format!("{base_url}files");
+
+		let FileUpload {
+			file_name,
+			content_type,
+			content,
+			// NOTE: Anthropic does not have a purpose
+			purpose: _,
+		} = file;
+
+		let part = Part::bytes(content.to_vec())
+			.file_name(file_name)
+			.mime_str(&content_type)
+			.map_err(|err| map_webc_error(err.into()))?;
+		let form = Form::new().part("file", part);
+
+		let web_res = web_client
+			.do_post_multipart(&url, &headers, form)
+			.await
+			.map_err(map_webc_error)?;
+
+		let file: AnthropicFile = serde_json::from_value(web_res.body)?;
+		Ok(into_file_ref(file))
+	}
+
+	pub(in crate::adapter) async fn list_files(web_client: &WebClient, target: ServiceTarget) -> Result<Vec<FileRef>> {
+		let (base_url, headers) = files_base_url_and_headers(target)?;
+		let url = format!("{base_url}files");
+
+		let mut web_res = web_client.do_get(&url, &headers).await.map_err(map_webc_error)?;
+
+		let files: Vec<AnthropicFile> = web_res.body.x_take("data")?;
+		Ok(files.into_iter().map(into_file_ref).collect())
+	}
+
+	pub(in crate::adapter) async fn get_file(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		file_id: &str,
+	) -> Result<FileRef> {
+		let (base_url, headers) = files_base_url_and_headers(target)?;
+		let url = format!("{base_url}files/{file_id}");
+
+		let web_res = web_client.do_get(&url, &headers).await.map_err(map_webc_error)?;
+
+		let file: AnthropicFile = serde_json::from_value(web_res.body)?;
+		Ok(into_file_ref(file))
+	}
+
+	pub(in crate::adapter) async fn delete_file(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		file_id: &str,
+	) -> Result<()> {
+		let (base_url, headers) = files_base_url_and_headers(target)?;
+		let url = format!("{base_url}files/{file_id}");
+
+		web_client.do_delete(&url, &headers).await.map_err(map_webc_error)?;
+
+		Ok(())
+	}
+}
+
+// region:    --- Support
+
+fn files_base_url_and_headers(target: ServiceTarget) -> Result<(String, Headers)> {
+	let ServiceTarget { endpoint, auth, model } = target;
+	let api_key = get_api_key(auth, &model)?;
+	let headers = AnthropicAdapter::util_auth_headers(api_key, &[FILES_API_BETA]);
+	Ok((endpoint.base_url().to_string(), headers))
+}
+
+fn map_webc_error(webc_error: webc::Error) -> Error {
+	Error::WebAdapterCall {
+		adapter_kind: AdapterKind::Anthropic,
+		webc_error,
+	}
+}
+
+fn into_file_ref(file: AnthropicFile) -> FileRef {
+	let mut file_ref = FileRef::new(AdapterKind::Anthropic, file.id);
+	file_ref.file_name = file.filename;
+	file_ref.content_type = file.mime_type;
+	file_ref.size = file.size_bytes;
+	file_ref
+}
+
+// endregion: --- Support
@@ -6,7 +6,12 @@
 // region:    --- Modules
 
 mod adapter_impl;
+mod count_tokens;
+mod files;
+mod model_info;
+mod ping;
 mod streamer;
+mod usage_report;
 
 pub use adapter_impl::*;
 pub use streamer::*;
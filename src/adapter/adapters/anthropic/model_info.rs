@@ -0,0 +1,37 @@
+//! Model metadata of the Anthropic API.
+//! API Documentation: https://docs.anthropic.com/en/api/models
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::anthropic::AnthropicAdapter;
+use crate::webc::WebClient;
+use crate::{Error, ModelInfo, Result, ServiceTarget};
+use value_ext::JsonValueExt;
+
+impl AnthropicAdapter {
+	/// NOTE: The Anthropic model endpoint returns the display name, and the token limits when available.
+	pub(in crate::adapter) async fn model_info(web_client: &WebClient, target: ServiceTarget) -> Result<ModelInfo> {
+		let ServiceTarget { endpoint, auth, model } = target;
+		let api_key = get_api_key(auth, &model)?;
+		let headers = AnthropicAdapter::util_auth_headers(api_key, &[]);
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+		let url = format!("{}models/{model_name}", endpoint.base_url());
+
+		let web_res = web_client
+			.do_get(&url, &headers)
+			.await
+			.map_err(|webc_error| Error::WebAdapterCall {
+				adapter_kind: AdapterKind::Anthropic,
+				webc_error,
+			})?;
+		let body = web_res.body;
+
+		let mut info = ModelInfo::new(model.clone(), Default::default());
+		info.display_name = body.x_get("display_name").ok();
+		info.context_window = body.x_get("max_input_tokens").ok();
+		info.max_output_tokens = body.x_get("max_tokens").ok();
+		info.captured_raw_body = body;
+
+		Ok(info)
+	}
+}
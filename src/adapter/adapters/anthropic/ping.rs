@@ -0,0 +1,27 @@
+//! Health check of the Anthropic API with the models listing.
+//! API Documentation: https://docs.anthropic.com/en/api/models-list
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::anthropic::AnthropicAdapter;
+use crate::webc::WebClient;
+use crate::{Error, Result, ServiceTarget};
+
+impl AnthropicAdapter {
+	pub(in crate::adapter) async fn ping(web_client: &WebClient, target: ServiceTarget) -> Result<u16> {
+		let ServiceTarget { endpoint, auth, model } = target;
+		let api_key = get_api_key(auth, &model)?;
+		let headers = AnthropicAdapter::util_auth_headers(api_key, &[]);
+		let url = format!("{}models?limit=1", endpoint.base_url());
+
+		let web_res = web_client
+			.do_get(&url, &headers)
+			.await
+			.map_err(|webc_error| Error::WebAdapterCall {
+				adapter_kind: AdapterKind::Anthropic,
+				webc_error,
+			})?;
+
+		Ok(web_res.status.as_u16())
+	}
+}
@@ -1,6 +1,6 @@
 use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions};
 use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
-use crate::chat::{ChatOptionsSet, ToolCall, Usage};
+use crate::chat::{ChatOptionsSet, ChatResponseFormat, FinishReason, ToolCall, Usage};
 use crate::{Error, ModelIden, Result};
 use reqwest_eventsource::{Event, EventSource};
 use serde_json::Value;
@@ -18,23 +18,42 @@ pub struct AnthropicStreamer {
 
 	captured_data: StreamerCapturedData,
 	in_progress_block: InProgressBlock,
+
+	/// The name of the eventual structured output tool, which input is streamed as text.
+	structured_output_tool: Option<String>,
 }
 
 enum InProgressBlock {
 	Text,
-	ToolUse { id: String, name: String, input: String },
+	ToolUse {
+		id: String,
+		name: String,
+		input: String,
+	},
+	/// The structured output tool use (see `AnthropicAdapter::to_web_request_data`).
+	StructuredOutput,
 	Thinking,
-	RedactedThinking { _data: String },
+	RedactedThinking {
+		_data: String,
+	},
+	/// The server tool blocks (`server_tool_use`, `web_search_tool_result`, and `code_execution_tool_result`),
+	/// run by Anthropic, which deltas (the server tool input) are not streamed.
+	ServerTool,
 }
 
 impl AnthropicStreamer {
 	pub fn new(inner: EventSource, model_iden: ModelIden, options_set: ChatOptionsSet<'_, '_>) -> Self {
+		let structured_output_tool = match options_set.response_format() {
+			Some(ChatResponseFormat::JsonSpec(json_spec)) => Some(json_spec.name.clone()),
+			_ => None,
+		};
 		Self {
 			inner,
 			done: false,
 			options: StreamerOptions::new(model_iden, options_set),
 			captured_data: Default::default(),
 			in_progress_block: InProgressBlock::Text,
+			structured_output_tool,
 		}
 	}
 }
@@ -61,6 +80,7 @@ impl futures::Stream for AnthropicStreamer {
 						}
 						"message_delta" => {
 							self.capture_usage(message_type, &message.data)?;
+							self.capture_finish_reason(&message.data);
 							continue;
 						}
 						"content_block_start" => {
@@ -81,11 +101,19 @@ impl futures::Stream for AnthropicStreamer {
 										tracing::warn!("redacted_thinking block missing data field");
 									}
 								}
+								Ok("server_tool_use" | "web_search_tool_result" | "code_execution_tool_result") => {
+									self.in_progress_block = InProgressBlock::ServerTool
+								}
 								Ok("tool_use") => {
-									self.in_progress_block = InProgressBlock::ToolUse {
-										id: data.x_take("/content_block/id")?,
-										name: data.x_take("/content_block/name")?,
-										input: String::new(),
+									let name: String = data.x_take("/content_block/name")?;
+									self.in_progress_block = if self.structured_output_tool.as_ref() == Some(&name) {
+										InProgressBlock::StructuredOutput
+									} else {
+										InProgressBlock::ToolUse {
+											id: data.x_take("/content_block/id")?,
+											name,
+											input: String::new(),
+										}
 									};
 								}
 								Ok(txt) => {
@@ -106,8 +134,16 @@ impl futures::Stream for AnthropicStreamer {
 								})?;
 
 							match &mut self.in_progress_block {
-								InProgressBlock::Text => {
-									let content: String = data.x_take("/delta/text")?;
+								// NOTE: The text blocks also get the `citations_delta` (with `ChatOptions::with_citations(true)`),
+								//       which are not captured in the stream for now, so only the `text_delta` are streamed.
+								InProgressBlock::Text if data.x_get_str("/delta/type").ok() != Some("text_delta") => {
+									continue;
+								}
+								InProgressBlock::Text | InProgressBlock::StructuredOutput => {
+									let content: String = match self.in_progress_block {
+										InProgressBlock::StructuredOutput => data.x_take("/delta/partial_json")?,
+										_ => data.x_take("/delta/text")?,
+									};
 
 									// Add to the captured_content if chat options say so
 									if self.options.capture_content {
@@ -140,6 +176,10 @@ impl futures::Stream for AnthropicStreamer {
 									// Redacted thinking blocks don't have delta content, just skip
 									continue;
 								}
+								InProgressBlock::ServerTool => {
+									// The server tool input (`input_json_delta`) is not part of the response content, just skip
+									continue;
+								}
 							}
 						}
 						"content_block_stop" => {
@@ -195,6 +235,8 @@ impl futures::Stream for AnthropicStreamer {
 								captured_text_content: self.captured_data.content.take(),
 								captured_reasoning_content: self.captured_data.reasoning_content.take(),
 								captured_tool_calls: self.captured_data.tool_calls.take(),
+								finish_reason: self.captured_data.finish_reason.take(),
+								stop_sequence: self.captured_data.stop_sequence.take(),
 							};
 
 							// TODO: Need to capture the data as needed
@@ -218,6 +260,19 @@ impl futures::Stream for AnthropicStreamer {
 
 // Support
 impl AnthropicStreamer {
+	/// Capture the eventual `stop_reason` (and `stop_sequence`) of the `message_delta` event.
+	fn capture_finish_reason(&mut self, message_data: &str) {
+		let Ok(data) = self.parse_message_data(message_data) else {
+			return;
+		};
+		if let Ok(stop_reason) = data.x_get_as::<&str>("/delta/stop_reason") {
+			self.captured_data.finish_reason = Some(FinishReason::from_provider(stop_reason));
+		}
+		if let Ok(stop_sequence) = data.x_get::<String>("/delta/stop_sequence") {
+			self.captured_data.stop_sequence = Some(stop_sequence);
+		}
+	}
+
 	fn capture_usage(&mut self, message_type: &str, message_data: &str) -> Result<()> {
 		if self.options.capture_usage {
 			let data = self.parse_message_data(message_data)?;
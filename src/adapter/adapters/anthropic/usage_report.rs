@@ -0,0 +1,238 @@
+//! Anthropic Admin API usage and cost report implementation (read-only)
+//! API Documentation: https://docs.anthropic.com/en/api/usage-cost-api
+//!
+//! NOTE: Those endpoints require an Admin API key (`sk-ant-admin...`), which is taken from the
+//!       `ANTHROPIC_ADMIN_KEY` environment variable when set, or from the resolved auth otherwise.
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::anthropic::AnthropicAdapter;
+use crate::usage_report::{
+	CostReport, CostReportBucket, CostReportEntry, UsageReport, UsageReportBucket, UsageReportEntry, UsageReportRequest,
+};
+use crate::webc::{self, WebClient};
+use crate::{Error, Headers, Result, ServiceTarget};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+/// The environment variable of the Admin API key used for the usage and cost reports.
+const ADMIN_KEY_ENV_NAME: &str = "ANTHROPIC_ADMIN_KEY";
+
+/// Safety limit on the number of pages fetched for one report.
+const MAX_PAGES: usize = 100;
+
+// region:    --- Anthropic Report Responses
+
+#[derive(Debug, Deserialize)]
+struct AnthropicReportPage<T> {
+	data: Vec<AnthropicReportBucket<T>>,
+	#[serde(default)]
+	has_more: bool,
+	next_page: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicReportBucket<T> {
+	starting_at: String,
+	ending_at: String,
+	results: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicUsageResult {
+	#[serde(default)]
+	uncached_input_tokens: i32,
+	#[serde(default)]
+	cache_creation: Option<AnthropicCacheCreation>,
+	#[serde(default)]
+	cache_read_input_tokens: i32,
+	#[serde(default)]
+	output_tokens: i32,
+	model: Option<String>,
+	workspace_id: Option<String>,
+	api_key_id: Option<String>,
+	service_tier: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicCacheCreation {
+	#[serde(default)]
+	ephemeral_1h_input_tokens: i32,
+	#[serde(default)]
+	ephemeral_5m_input_tokens: i32,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicCostResult {
+	/// The amount in the lowest currency unit (cents), as a decimal string.
+	amount: String,
+	currency: String,
+	description: Option<String>,
+	cost_type: Option<String>,
+	token_type: Option<String>,
+	model: Option<String>,
+	workspace_id: Option<String>,
+}
+
+// endregion: --- Anthropic Report Responses
+
+impl AnthropicAdapter {
+	pub(in crate::adapter) async fn usage_report(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		req: UsageReportRequest,
+	) -> Result<UsageReport> {
+		let (base_url, headers) = admin_base_url_and_headers(target)?;
+		let mut url = format!("{base_url}organizations/usage_report/messages?{}", report_query(&req));
+		for model in req.models.iter() {
+			url.push_str(&format!("&models[]={model}"));
+		}
+
+		let buckets = fetch_all_pages::<AnthropicUsageResult>(web_client, &url, &headers).await?;
+
+		let buckets = buckets
+			.into_iter()
+			.map(|bucket| UsageReportBucket {
+				starting_at: bucket.starting_at,
+				ending_at: bucket.ending_at,
+				results: bucket.results.into_iter().map(into_usage_report_entry).collect(),
+			})
+			.collect();
+
+		Ok(UsageReport {
+			adapter_kind: AdapterKind::Anthropic,
+			buckets,
+		})
+	}
+
+	pub(in crate::adapter) async fn cost_report(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		req: UsageReportRequest,
+	) -> Result<CostReport> {
+		let (base_url, headers) = admin_base_url_and_headers(target)?;
+		let url = format!("{base_url}organizations/cost_report?{}", report_query(&req));
+
+		let buckets = fetch_all_pages::<AnthropicCostResult>(web_client, &url, &headers).await?;
+
+		let buckets = buckets
+			.into_iter()
+			.map(|bucket| {
+				let results = bucket
+					.results
+					.into_iter()
+					.map(into_cost_report_entry)
+					.collect::<Result<Vec<_>>>()?;
+				Ok(CostReportBucket {
+					starting_at: bucket.starting_at,
+					ending_at: bucket.ending_at,
+					results,
+				})
+			})
+			.collect::<Result<Vec<_>>>()?;
+
+		Ok(CostReport {
+			adapter_kind: AdapterKind::Anthropic,
+			buckets,
+		})
+	}
+}
+
+// region:    --- Support
+
+fn admin_base_url_and_headers(target: ServiceTarget) -> Result<(String, Headers)> {
+	let ServiceTarget { endpoint, auth, model } = target;
+	let api_key = match std::env::var(ADMIN_KEY_ENV_NAME) {
+		Ok(admin_key) => admin_key,
+		Err(_) => get_api_key(auth, &model)?,
+	};
+	let headers = AnthropicAdapter::util_auth_headers(api_key, &[]);
+	Ok((endpoint.base_url().to_string(), headers))
+}
+
+/// Build the query parameters shared by the usage and cost reports.
+fn report_query(req: &UsageReportRequest) -> String {
+	let mut params = vec![format!("starting_at={}", req.starting_at)];
+	if let Some(ending_at) = &req.ending_at {
+		params.push(format!("ending_at={ending_at}"));
+	}
+	if let Some(bucket_width) = &req.bucket_width {
+		params.push(format!("bucket_width={bucket_width}"));
+	}
+	for group_by in req.group_by.iter() {
+		params.push(format!("group_by[]={group_by}"));
+	}
+	params.join("&")
+}
+
+/// Fetch all the report pages (following `next_page`) and return the merged buckets.
+async fn fetch_all_pages<T: DeserializeOwned>(
+	web_client: &WebClient,
+	url: &str,
+	headers: &Headers,
+) -> Result<Vec<AnthropicReportBucket<T>>> {
+	let mut buckets = Vec::new();
+	let mut page_url = url.to_string();
+
+	for _ in 0..MAX_PAGES {
+		let web_res = web_client.do_get(&page_url, headers).await.map_err(map_webc_error)?;
+		let page: AnthropicReportPage<T> = serde_json::from_value(web_res.body)?;
+		buckets.extend(page.data);
+
+		match page.next_page.filter(|_| page.has_more) {
+			Some(next_page) => page_url = format!("{url}&page={next_page}"),
+			None => break,
+		}
+	}
+
+	Ok(buckets)
+}
+
+fn into_usage_report_entry(result: AnthropicUsageResult) -> UsageReportEntry {
+	let cache_creation_input_tokens = result
+		.cache_creation
+		.map(|cc| cc.ephemeral_1h_input_tokens + cc.ephemeral_5m_input_tokens)
+		.unwrap_or(0);
+
+	// Same normalization as the chat response usage
+	let usage = AnthropicAdapter::into_usage(json!({
+		"input_tokens": result.uncached_input_tokens,
+		"cache_creation_input_tokens": cache_creation_input_tokens,
+		"cache_read_input_tokens": result.cache_read_input_tokens,
+		"output_tokens": result.output_tokens,
+	}));
+
+	UsageReportEntry {
+		model: result.model,
+		workspace_id: result.workspace_id,
+		api_key_id: result.api_key_id,
+		service_tier: result.service_tier,
+		usage,
+	}
+}
+
+fn into_cost_report_entry(result: AnthropicCostResult) -> Result<CostReportEntry> {
+	let amount_cents: f64 = result.amount.parse().map_err(|_| Error::InvalidJsonResponseElement {
+		info: "Anthropic cost report amount is not a number",
+	})?;
+
+	Ok(CostReportEntry {
+		amount: amount_cents / 100.,
+		currency: result.currency,
+		description: result.description,
+		cost_type: result.cost_type,
+		token_type: result.token_type,
+		model: result.model,
+		workspace_id: result.workspace_id,
+	})
+}
+
+fn map_webc_error(webc_error: webc::Error) -> Error {
+	Error::WebAdapterCall {
+		adapter_kind: AdapterKind::Anthropic,
+		webc_error,
+	}
+}
+
+// endregion: --- Support
@@ -1,8 +1,9 @@
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{check_logit_bias, get_api_key};
 use crate::adapter::cohere::CohereStreamer;
 use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
-	ChatOptionsSet, ChatRequest, ChatResponse, ChatRole, ChatStream, ChatStreamResponse, MessageContent, Usage,
+	ChatOptionsSet, ChatRequest, ChatResponse, ChatRole, ChatStream, ChatStreamResponse, FinishReason, MessageContent,
+	Usage,
 };
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::{WebResponse, WebStream};
@@ -61,6 +62,7 @@ impl Adapter for CohereAdapter {
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
 		let ServiceTarget { endpoint, auth, model } = target;
+		check_logit_bias(&model, &options_set)?;
 
 		// -- api_key (this Adapter requires it)
 		let api_key = get_api_key(auth, &model)?;
@@ -110,6 +112,18 @@ impl Adapter for CohereAdapter {
 		if let Some(top_p) = options_set.top_p() {
 			payload.x_insert("p", top_p)?;
 		}
+		if let Some(top_k) = options_set.top_k() {
+			payload.x_insert("k", top_k)?;
+		}
+		if let Some(frequency_penalty) = options_set.frequency_penalty() {
+			payload.x_insert("frequency_penalty", frequency_penalty)?;
+		}
+		if let Some(presence_penalty) = options_set.presence_penalty() {
+			payload.x_insert("presence_penalty", presence_penalty)?;
+		}
+		if let Some(seed) = options_set.seed() {
+			payload.x_insert("seed", seed)?;
+		}
 
 		Ok(WebRequestData { url, headers, payload })
 	}
@@ -131,6 +145,9 @@ impl Adapter for CohereAdapter {
 		// -- Get usage
 		let usage = body.x_take("/meta/tokens").map(Self::into_usage).unwrap_or_default();
 
+		// -- Get the eventual finish reason
+		let finish_reason = body.x_get_as::<&str>("finish_reason").ok().map(FinishReason::from_provider);
+
 		// -- Get response
 		let Some(mut last_chat_history_item) = body.x_take::<Vec<Value>>("chat_history")?.pop() else {
 			return Err(Error::NoChatResponse { model_iden });
@@ -146,8 +163,22 @@ impl Adapter for CohereAdapter {
 			reasoning_content: None,
 			model_iden,
 			provider_model_iden,
+			system_fingerprint: None,
 			usage,
 			captured_raw_body,
+			response_digest: None,
+			response_headers: None,
+			prompt_leaks: None,
+			stop_sequence: None,
+			file_citations: None,
+			web_citations: None,
+			output_repair: None,
+			ai_disclosure: None,
+			provider_exts: Default::default(),
+			finish_reason,
+			choices: Vec::new(),
+			logprobs: None,
+			content_filter: None,
 		})
 	}
 
@@ -266,7 +297,7 @@ impl CohereAdapter {
 
 			match msg.role {
 				// For now, system and tool messages go to the system
-				ChatRole::System => systems.push(content),
+				ChatRole::System | ChatRole::Developer => systems.push(content),
 				ChatRole::User => chat_history.push(json! ({"role": "USER", "content": content})),
 				ChatRole::Assistant => chat_history.push(json! ({"role": "CHATBOT", "content": content})),
 				ChatRole::Tool => {
@@ -1,10 +1,12 @@
 //! Cohere Embeddings API implementation
 //! API Documentation: https://docs.cohere.com/reference/embed
 
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{check_embed_dimensions, embed_usage, get_api_key};
 use crate::adapter::{Adapter, ServiceType, WebRequestData};
-use crate::chat::Usage;
-use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse, Embedding};
+use crate::chat::{ContentPart, ImageSource};
+use crate::embed::{
+	EmbedBilledUnits, EmbedOptionsSet, EmbedRequest, EmbedResponse, Embedding, EmbeddingTypedVectors, EmbeddingVector,
+};
 use crate::webc::WebResponse;
 use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
 use serde::{Deserialize, Serialize};
@@ -33,10 +35,15 @@ struct CohereInput {
 }
 
 #[derive(Debug, Serialize)]
-struct CohereContent {
-	#[serde(rename = "type")]
-	content_type: String,
-	text: String,
+#[serde(tag = "type", rename_all = "snake_case")]
+enum CohereContent {
+	Text { text: String },
+	ImageUrl { image_url: CohereImageUrl },
+}
+
+#[derive(Debug, Serialize)]
+struct CohereImageUrl {
+	url: String,
 }
 
 // endregion: --- Cohere Embed Request
@@ -68,6 +75,7 @@ struct CohereMeta {
 #[derive(Debug, Deserialize)]
 struct CohereBilledUnits {
 	input_tokens: Option<u32>,
+	images: Option<u32>,
 }
 
 // endregion: --- Cohere Embed Response
@@ -85,6 +93,9 @@ pub fn to_embed_request_data(
 	// Extract the actual model name (without namespace)
 	let (model_name, _) = model.model_name.as_model_name_and_namespace();
 
+	// Only the embed-v4 models support the output_dimension (the v3 models have fixed dimensions)
+	check_embed_dimensions(&model, options_set.dimensions(), model_name.starts_with("embed-v4"))?;
+
 	// Build headers
 	let mut headers = Headers::from(vec![
 		("Authorization".to_string(), format!("Bearer {api_key}")),
@@ -106,17 +117,24 @@ pub fn to_embed_request_data(
 			// For batch, use the texts array format
 			(Some(texts), None)
 		}
+		crate::embed::EmbedInput::Parts(inputs) => {
+			// For multimodal, use the inputs array format (one embedding per input)
+			let inputs = inputs
+				.into_iter()
+				.map(|parts| {
+					let content = parts
+						.into_iter()
+						.map(|part| into_cohere_content(part, &model))
+						.collect::<Result<Vec<_>>>()?;
+					Ok(CohereInput { content })
+				})
+				.collect::<Result<Vec<_>>>()?;
+			(None, Some(inputs))
+		}
 	};
 
 	// Determine embedding types - default to float
-	let embedding_types = {
-		let format = options_set.encoding_format().unwrap_or("float");
-		let embedding_type = match format {
-			"float" | "int8" | "uint8" | "binary" | "ubinary" => format,
-			_ => "float",
-		};
-		Some(vec![embedding_type.to_string()])
-	};
+	let embedding_types = Some(requested_embedding_types(&options_set));
 
 	let cohere_req = CohereEmbedRequest {
 		texts,
@@ -160,45 +178,59 @@ pub fn to_embed_response(
 			serde_error,
 		})?;
 
-	// Extract embedding vectors, converting all types to f32
-	let embedding_vectors = {
-		let embeddings = &cohere_res.embeddings;
-
-		if let Some(float_embeddings) = &embeddings.float_embeddings {
-			float_embeddings.clone()
-		} else if let Some(int8_embeddings) = &embeddings.int8 {
-			int8_embeddings
-				.iter()
-				.map(|vec| vec.iter().map(|&v| v as f32).collect())
-				.collect()
-		} else if let Some(uint8_embeddings) = &embeddings.uint8 {
-			uint8_embeddings
-				.iter()
-				.map(|vec| vec.iter().map(|&v| v as f32).collect())
-				.collect()
-		} else if let Some(binary_embeddings) = &embeddings.binary {
-			binary_embeddings
-				.iter()
-				.map(|vec| vec.iter().map(|&v| v as f32).collect())
-				.collect()
-		} else if let Some(ubinary_embeddings) = &embeddings.ubinary {
-			ubinary_embeddings
-				.iter()
-				.map(|vec| vec.iter().map(|&v| v as f32).collect())
-				.collect()
-		} else {
-			return Err(Error::StreamParse {
-				model_iden: model_iden.clone(),
-				serde_error: serde_json::from_str::<()>("No embedding data found in response").unwrap_err(),
-			});
-		}
-	};
+	// Transpose the embeddings per type into the typed vectors per input
+	let CohereEmbeddings {
+		float_embeddings,
+		int8,
+		uint8,
+		binary,
+		ubinary,
+	} = cohere_res.embeddings;
+	let count = [
+		float_embeddings.as_ref().map(Vec::len),
+		int8.as_ref().map(Vec::len),
+		uint8.as_ref().map(Vec::len),
+		binary.as_ref().map(Vec::len),
+		ubinary.as_ref().map(Vec::len),
+	]
+	.into_iter()
+	.flatten()
+	.max()
+	.ok_or_else(|| Error::StreamParse {
+		model_iden: model_iden.clone(),
+		serde_error: serde_json::from_str::<()>("No embedding data found in response").unwrap_err(),
+	})?;
 
-	// Convert to our format
-	let embeddings: Vec<Embedding> = embedding_vectors
+	let mut typed_vectors = vec![EmbeddingTypedVectors::default(); count];
+	set_typed_vectors(&mut typed_vectors, float_embeddings, |t, v| t.float = Some(v));
+	set_typed_vectors(&mut typed_vectors, int8, |t, v| t.int8 = Some(v));
+	set_typed_vectors(&mut typed_vectors, uint8, |t, v| t.uint8 = Some(v));
+	set_typed_vectors(&mut typed_vectors, binary, |t, v| t.binary = Some(v));
+	set_typed_vectors(&mut typed_vectors, ubinary, |t, v| t.ubinary = Some(v));
+
+	// The main vector is the first requested type (then float, int8, ...), converted to f32
+	let requested_types = requested_embedding_types(&options_set);
+	let with_typed_vectors = options_set.embedding_types().is_some();
+	let embeddings: Vec<Embedding> = typed_vectors
 		.into_iter()
 		.enumerate()
-		.map(|(index, vector)| Embedding::new(vector, index))
+		.map(|(index, typed)| {
+			let main_vector = requested_types
+				.iter()
+				.map(String::as_str)
+				.chain(EMBEDDING_TYPES)
+				.find_map(|embedding_type| typed.vector(embedding_type));
+			let vector = main_vector.as_ref().map(EmbeddingVector::to_f32_vec).unwrap_or_default();
+			let mut embedding = Embedding::new(vector, index);
+			if let Some(native_vector) = main_vector.filter(|v| !matches!(v, EmbeddingVector::F32(_))) {
+				embedding = embedding.with_native_vector(native_vector);
+			}
+			if with_typed_vectors {
+				embedding.with_typed_vectors(typed)
+			} else {
+				embedding
+			}
+		})
 		.collect();
 
 	// Log any API warnings and debug info
@@ -210,24 +242,13 @@ pub fn to_embed_response(
 		}
 	}
 
-	// Create usage information
-	let usage = Usage {
-		prompt_tokens: cohere_res
-			.meta
-			.as_ref()
-			.and_then(|m| m.billed_units.as_ref())
-			.and_then(|b| b.input_tokens)
-			.map(|t| t as i32),
-		completion_tokens: None, // Embeddings don't have output tokens
-		total_tokens: cohere_res
-			.meta
-			.as_ref()
-			.and_then(|m| m.billed_units.as_ref())
-			.and_then(|b| b.input_tokens)
-			.map(|t| t as i32),
-		prompt_tokens_details: None,
-		completion_tokens_details: None,
-	};
+	// Create usage information (from the billed units)
+	let billed_units = cohere_res.meta.and_then(|m| m.billed_units).map(|b| EmbedBilledUnits {
+		input_tokens: b.input_tokens.map(|t| t as i32),
+		input_characters: None,
+		images: b.images.map(|i| i as i32),
+	});
+	let usage = embed_usage(billed_units.as_ref().and_then(|b| b.input_tokens));
 
 	// Create provider model identifier
 	let provider_model_iden = ModelIden {
@@ -236,6 +257,9 @@ pub fn to_embed_response(
 	};
 
 	let mut response = EmbedResponse::new(embeddings, model_iden, provider_model_iden, usage);
+	if let Some(billed_units) = billed_units {
+		response = response.with_billed_units(billed_units);
+	}
 
 	// Capture raw body if requested
 	if options_set.capture_raw_body() {
@@ -246,3 +270,62 @@ pub fn to_embed_response(
 }
 
 // endregion: --- Public Functions
+
+// region:    --- Support
+
+/// The Cohere embedding types, in the default order of preference for the main `Embedding.vector`.
+const EMBEDDING_TYPES: [&str; 5] = ["float", "int8", "uint8", "binary", "ubinary"];
+
+/// Returns the valid `embedding_types` (or the single `encoding_format`), defaulting to float.
+fn requested_embedding_types(options_set: &EmbedOptionsSet<'_, '_>) -> Vec<String> {
+	let types: Vec<String> = match options_set.embedding_types() {
+		Some(types) => types
+			.iter()
+			.filter(|t| EMBEDDING_TYPES.contains(&t.as_str()))
+			.cloned()
+			.collect(),
+		None => options_set
+			.encoding_format()
+			.filter(|t| EMBEDDING_TYPES.contains(t))
+			.map(|t| vec![t.to_string()])
+			.unwrap_or_default(),
+	};
+
+	if types.is_empty() {
+		vec!["float".to_string()]
+	} else {
+		types
+	}
+}
+
+/// Convert a content part to the Cohere content (text or image data URI).
+fn into_cohere_content(part: ContentPart, model: &ModelIden) -> Result<CohereContent> {
+	match part {
+		ContentPart::Text(text) => Ok(CohereContent::Text { text }),
+		ContentPart::Image { content_type, source } => {
+			let url = match source {
+				ImageSource::Url(url) => url,
+				ImageSource::Base64(content) => format!("data:{content_type};base64,{content}"),
+			};
+			Ok(CohereContent::ImageUrl {
+				image_url: CohereImageUrl { url },
+			})
+		}
+		_ => Err(Error::MessageContentTypeNotSupported {
+			model_iden: model.clone(),
+			cause: "Only text and image parts are supported for the embeddings",
+		}),
+	}
+}
+
+fn set_typed_vectors<T>(
+	typed_vectors: &mut [EmbeddingTypedVectors],
+	vectors: Option<Vec<Vec<T>>>,
+	set: impl Fn(&mut EmbeddingTypedVectors, Vec<T>),
+) {
+	for (typed, vector) in typed_vectors.iter_mut().zip(vectors.into_iter().flatten()) {
+		set(typed, vector);
+	}
+}
+
+// endregion: --- Support
@@ -1,16 +1,18 @@
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{check_logit_bias, get_api_key};
 use crate::adapter::gemini::GeminiStreamer;
 use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
-	ChatOptionsSet, ChatRequest, ChatResponse, ChatResponseFormat, ChatRole, ChatStream, ChatStreamResponse,
-	CompletionTokensDetails, ContentBlock, ContentPart, ImageSource, MessageContent, PromptTokensDetails,
-	ReasoningEffort, ToolCall, Usage,
+	BuiltInTool, ChatChoice, ChatOptionsSet, ChatRequest, ChatResponse, ChatResponseFormat, ChatRole, ChatStream,
+	ChatStreamResponse, CompletionTokensDetails, ContentBlock, ContentFilterCategory, ContentFilterInfo, ContentPart,
+	FinishReason, GeminiGroundingChunk, GeminiGroundingMetadata, GeminiGroundingSupport, ImageSource, MessageContent,
+	PromptTokensDetails, ProviderExts, ReasoningEffort, ToolCall, ToolChoice, Usage,
 };
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::{WebResponse, WebStream};
 use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
 use reqwest::RequestBuilder;
 use serde_json::{Value, json};
+use std::time::Duration;
 use value_ext::JsonValueExt;
 
 pub struct GeminiAdapter;
@@ -23,12 +25,6 @@ const MODELS: &[&str] = &[
 	"gemini-2.0-flash-lite",
 ];
 
-// Per gemini doc (https://x.com/jeremychone/status/1916501987371438372)
-const REASONING_ZERO: u32 = 0;
-const REASONING_LOW: u32 = 1000;
-const REASONING_MEDIUM: u32 = 8000;
-const REASONING_HIGH: u32 = 24000;
-
 // curl \
 //   -H 'Content-Type: application/json' \
 //   -d '{"contents":[{"parts":[{"text":"Explain how AI works"}]}]}' \
@@ -72,6 +68,7 @@ impl Adapter for GeminiAdapter {
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
 		let ServiceTarget { endpoint, auth, model } = target;
+		check_logit_bias(&model, &options_set)?;
 		let (model_name, _) = model.model_name.as_model_name_and_namespace();
 
 		// -- api_key
@@ -86,11 +83,12 @@ impl Adapter for GeminiAdapter {
 			(model, None) => {
 				// let model_name: &str = &model.model_name;
 				if let Some((prefix, last)) = model_name.rsplit_once('-') {
+					// NOTE: Only the supported suffixes, not all the keywords (a model name can end with `-none` or `-minimal`)
 					let reasoning = match last {
-						"zero" => Some(ReasoningEffort::Budget(REASONING_ZERO)),
-						"low" => Some(ReasoningEffort::Budget(REASONING_LOW)),
-						"medium" => Some(ReasoningEffort::Budget(REASONING_MEDIUM)),
-						"high" => Some(ReasoningEffort::Budget(REASONING_HIGH)),
+						"zero" => Some(ReasoningEffort::Disabled),
+						"low" => Some(ReasoningEffort::Low),
+						"medium" => Some(ReasoningEffort::Medium),
+						"high" => Some(ReasoningEffort::High),
 						_ => None,
 					};
 					// create the model name if there was a `-..` reasoning suffix
@@ -101,16 +99,7 @@ impl Adapter for GeminiAdapter {
 					(model, None)
 				}
 			}
-			// If reasoning effort, turn the low, medium, budget ones into Budget
-			(model, Some(effort)) => {
-				let effort = match effort {
-					ReasoningEffort::Low => ReasoningEffort::Budget(REASONING_LOW),
-					ReasoningEffort::Medium => ReasoningEffort::Budget(REASONING_MEDIUM),
-					ReasoningEffort::High => ReasoningEffort::Budget(REASONING_HIGH),
-					ReasoningEffort::Budget(budget) => ReasoningEffort::Budget(*budget),
-				};
-				(model, Some(effort))
-			}
+			(model, Some(effort)) => (model, Some(effort.clone())),
 		};
 
 		// -- parts
@@ -125,11 +114,12 @@ impl Adapter for GeminiAdapter {
 			"contents": contents,
 		});
 
-		// -- Set the reasoning effort
-		if let Some(ReasoningEffort::Budget(budget)) = reasoning_effort {
-			payload.x_insert("/generationConfig/thinkingConfig/thinkingBudget", budget)?;
-			// Include thoughts in the response when thinking is enabled
-			payload.x_insert("/generationConfig/thinkingConfig/includeThoughts", true)?;
+		// -- Set the reasoning effort (the budget comes from the `ReasoningEffort` provider mapping table)
+		if let Some(reasoning_effort) = reasoning_effort {
+			let thinking_budget = reasoning_effort.gemini_budget();
+			payload.x_insert("/generationConfig/thinkingConfig/thinkingBudget", thinking_budget)?;
+			// Include the thoughts (the `thought: true` parts, returned as `reasoning_content`) when thinking is enabled
+			payload.x_insert("/generationConfig/thinkingConfig/includeThoughts", thinking_budget > 0)?;
 		}
 
 		// Note: It's unclear from the spec if the content of systemInstruction should have a role.
@@ -148,6 +138,32 @@ impl Adapter for GeminiAdapter {
 		if let Some(tools) = tools {
 			payload.x_insert("tools", tools)?;
 		}
+		if let Some(tool_choice) = options_set.tool_choice() {
+			let function_calling_config = match tool_choice {
+				ToolChoice::Auto => json!({"mode": "AUTO"}),
+				ToolChoice::None => json!({"mode": "NONE"}),
+				ToolChoice::Required => json!({"mode": "ANY"}),
+				ToolChoice::Named(name) => json!({"mode": "ANY", "allowedFunctionNames": [name]}),
+			};
+			payload.x_insert("/toolConfig/functionCallingConfig", function_calling_config)?;
+		}
+
+		// -- Explicit context cache
+		if let Some(cached_content) = options_set.cached_content() {
+			payload.x_insert(
+				"cachedContent",
+				super::cached_content::cached_content_name_path(cached_content),
+			)?;
+		}
+
+		// -- Safety settings
+		if let Some(safety_settings) = options_set.safety_settings() {
+			let safety_settings: Vec<Value> = safety_settings
+				.iter()
+				.map(|setting| json!({"category": setting.category.as_str(), "threshold": setting.threshold.as_str()}))
+				.collect();
+			payload.x_insert("safetySettings", safety_settings)?;
+		}
 
 		// -- Response Format
 		if let Some(ChatResponseFormat::JsonSpec(st_json)) = options_set.response_format() {
@@ -160,6 +176,17 @@ impl Adapter for GeminiAdapter {
 				if name == "additionalProperties" {
 					parent_map.remove("additionalProperties");
 				}
+				// Gemini has no type unions, so `["string", "null"]` is `"string"` with `nullable`
+				if name == "type"
+					&& let Some(Value::Array(types)) = parent_map.get("type")
+					&& types.iter().any(|typ| typ == "null")
+				{
+					let non_null: Vec<Value> = types.iter().filter(|typ| *typ != "null").cloned().collect();
+					if let [typ] = non_null.as_slice() {
+						parent_map.insert("type".to_string(), typ.clone());
+						parent_map.insert("nullable".to_string(), true.into());
+					}
+				}
 				true
 			});
 			payload.x_insert("/generationConfig/responseSchema", schema)?;
@@ -180,6 +207,24 @@ impl Adapter for GeminiAdapter {
 		if let Some(top_p) = options_set.top_p() {
 			payload.x_insert("/generationConfig/topP", top_p)?;
 		}
+		if let Some(top_k) = options_set.top_k() {
+			payload.x_insert("/generationConfig/topK", top_k)?;
+		}
+		if let Some(candidate_count) = options_set.candidate_count() {
+			payload.x_insert("/generationConfig/candidateCount", candidate_count)?;
+		}
+		if let Some(logprobs) = options_set.logprobs() {
+			payload.x_insert("/generationConfig/responseLogprobs", logprobs)?;
+		}
+		if let Some(frequency_penalty) = options_set.frequency_penalty() {
+			payload.x_insert("/generationConfig/frequencyPenalty", frequency_penalty)?;
+		}
+		if let Some(presence_penalty) = options_set.presence_penalty() {
+			payload.x_insert("/generationConfig/presencePenalty", presence_penalty)?;
+		}
+		if let Some(seed) = options_set.seed() {
+			payload.x_insert("/generationConfig/seed", seed)?;
+		}
 
 		// -- url
 		let provider_model = model.from_name(provider_model_name);
@@ -202,7 +247,36 @@ impl Adapter for GeminiAdapter {
 		// TODO: Need to be implemented (if available), for now, just clone model_iden
 		let provider_model_name: Option<String> = body.x_remove("modelVersion").ok();
 		let provider_model_iden = model_iden.from_optional_name(provider_model_name);
-		let gemini_response = Self::body_to_gemini_chat_response(&model_iden.clone(), body)?;
+
+		// -- Capture the eventual content filter info (safety ratings and block reasons)
+		let content_filter = Self::into_content_filter_info(&body);
+
+		// -- Capture the eventual provider extras (grounding metadata)
+		let provider_exts = Self::into_provider_exts(&body);
+
+		// -- Capture the eventual multiple candidates (see `ChatOptions::with_candidate_count(..)`)
+		let choices = Self::into_chat_choices(&body);
+		let logprobs = body.pointer("/candidates/0/logprobsResult").cloned();
+
+		// -- Capture the eventual finish reason (or the prompt block reason, when blocked without candidates)
+		let finish_reason = body
+			.x_get_as::<&str>("/candidates/0/finishReason")
+			.or_else(|_| body.x_get_as::<&str>("/promptFeedback/blockReason"))
+			.ok()
+			.map(FinishReason::from_provider);
+
+		// When blocked without any content (e.g., prompt blocked), return an empty response with the filter info
+		let gemini_response = if content_filter.as_ref().is_some_and(|info| info.blocked)
+			&& body.pointer("/candidates/0/content/parts").is_none()
+		{
+			let usage = body.x_take::<Value>("usageMetadata").map(Self::into_usage).unwrap_or_default();
+			GeminiChatResponse {
+				content: Vec::new(),
+				usage,
+			}
+		} else {
+			Self::body_to_gemini_chat_response(&model_iden.clone(), body)?
+		};
 		let GeminiChatResponse {
 			content: gemini_content,
 			usage,
@@ -224,13 +298,28 @@ impl Adapter for GeminiAdapter {
 						blocks.push(ContentBlock::Text {
 							text,
 							thought_signature: None,
+							citations: None,
 						});
 					}
 					GeminiChatContent::Thinking { text, signature } => {
+						if !reasoning_content.is_empty() {
+							reasoning_content.push('\n');
+						}
 						reasoning_content.push_str(&text);
-						reasoning_content.push('\n');
 						blocks.push(ContentBlock::Thinking { text, signature });
 					}
+					GeminiChatContent::Image { content_type, data } => {
+						blocks.push(ContentBlock::Image {
+							content_type,
+							source: ImageSource::Base64(data.into()),
+						});
+					}
+					GeminiChatContent::ExecutableCode { language, code } => {
+						blocks.push(ContentBlock::ExecutableCode { language, code });
+					}
+					GeminiChatContent::CodeExecutionResult { output, is_error } => {
+						blocks.push(ContentBlock::CodeExecutionResult { output, is_error });
+					}
 					GeminiChatContent::ToolCall(tool_call) => {
 						blocks.push(ContentBlock::ToolUse {
 							id: tool_call.call_id.clone(),
@@ -251,8 +340,22 @@ impl Adapter for GeminiAdapter {
 				},
 				model_iden,
 				provider_model_iden,
+				system_fingerprint: None,
 				usage,
 				captured_raw_body,
+				response_digest: None,
+				response_headers: None,
+				prompt_leaks: None,
+				stop_sequence: None,
+				file_citations: None,
+				web_citations: None,
+				output_repair: None,
+				ai_disclosure: None,
+				provider_exts,
+				finish_reason,
+				choices,
+				logprobs,
+				content_filter,
 			})
 		} else {
 			// No thinking blocks, use simple format
@@ -263,6 +366,24 @@ impl Adapter for GeminiAdapter {
 				match g_item {
 					GeminiChatContent::Text(text) => content.push(MessageContent::from_text(text)),
 					GeminiChatContent::ToolCall(tool_call) => tool_calls.push(tool_call),
+					GeminiChatContent::Image { content_type, data } => {
+						content.push(MessageContent::Blocks(vec![ContentBlock::Image {
+							content_type,
+							source: ImageSource::Base64(data.into()),
+						}]))
+					}
+					GeminiChatContent::ExecutableCode { language, code } => {
+						content.push(MessageContent::Blocks(vec![ContentBlock::ExecutableCode {
+							language,
+							code,
+						}]))
+					}
+					GeminiChatContent::CodeExecutionResult { output, is_error } => {
+						content.push(MessageContent::Blocks(vec![ContentBlock::CodeExecutionResult {
+							output,
+							is_error,
+						}]))
+					}
 					GeminiChatContent::Thinking { .. } => {
 						// Should not happen if has_thinking is false
 					}
@@ -277,8 +398,22 @@ impl Adapter for GeminiAdapter {
 				reasoning_content: None,
 				model_iden,
 				provider_model_iden,
+				system_fingerprint: None,
 				usage,
 				captured_raw_body,
+				response_digest: None,
+				response_headers: None,
+				prompt_leaks: None,
+				stop_sequence: None,
+				file_citations: None,
+				web_citations: None,
+				output_repair: None,
+				ai_disclosure: None,
+				provider_exts,
+				finish_reason,
+				choices,
+				logprobs,
+				content_filter,
 			})
 		}
 	}
@@ -383,6 +518,29 @@ impl GeminiAdapter {
 				content.push(GeminiChatContent::ToolCall(tool_call))
 			}
 
+			// -- Capture eventual code execution (see `BuiltInTool::CodeExecution`)
+			if let Ok(mut executable_code) = part.x_take::<Value>("executableCode") {
+				content.push(GeminiChatContent::ExecutableCode {
+					language: executable_code.x_take::<String>("language").ok().map(|l| l.to_lowercase()),
+					code: executable_code.x_take("code").unwrap_or_default(),
+				})
+			}
+			if let Ok(mut result) = part.x_take::<Value>("codeExecutionResult") {
+				let outcome: String = result.x_take("outcome").unwrap_or_default();
+				content.push(GeminiChatContent::CodeExecutionResult {
+					output: result.x_take("output").unwrap_or_default(),
+					is_error: outcome != "OUTCOME_OK",
+				})
+			}
+
+			// -- Capture eventual image output (e.g., image generation models)
+			if let Ok(mut inline_data) = part.x_take::<Value>("inlineData") {
+				let content_type: String = inline_data.x_take("mimeType").unwrap_or_else(|_| "image/png".to_string());
+				if let Ok(data) = inline_data.x_take::<String>("data") {
+					content.push(GeminiChatContent::Image { content_type, data })
+				}
+			}
+
 			// -- Capture eventual text (including thoughts)
 			if let Some(text) = part
 				.x_take::<Value>("text")
@@ -404,6 +562,132 @@ impl GeminiAdapter {
 		Ok(GeminiChatResponse { content, usage })
 	}
 
+	/// The eventual content filter info from the Gemini response body
+	/// (`promptFeedback`, `candidates[0].safetyRatings`, and the safety `finishReason`s).
+	/// See gemini doc: https://ai.google.dev/api/generate-content#v1beta.SafetyRating
+	fn into_content_filter_info(body: &Value) -> Option<ContentFilterInfo> {
+		const SAFETY_FINISH_REASONS: &[&str] = &["SAFETY", "PROHIBITED_CONTENT", "BLOCKLIST", "SPII", "IMAGE_SAFETY"];
+
+		let block_reason = body.x_get_as::<&str>("/promptFeedback/blockReason").ok();
+		let finish_reason = body
+			.x_get_as::<&str>("/candidates/0/finishReason")
+			.ok()
+			.filter(|reason| SAFETY_FINISH_REASONS.contains(reason));
+
+		let mut categories = Vec::new();
+		for ratings_path in ["/promptFeedback/safetyRatings", "/candidates/0/safetyRatings"] {
+			let Some(ratings) = body.pointer(ratings_path).and_then(Value::as_array) else {
+				continue;
+			};
+			for rating in ratings {
+				let Ok(name) = rating.x_get::<String>("category") else {
+					continue;
+				};
+				categories.push(ContentFilterCategory {
+					name,
+					severity: rating.x_get("probability").ok(),
+					filtered: rating.x_get("blocked").unwrap_or(false),
+				});
+			}
+		}
+
+		let reason = block_reason.or(finish_reason);
+		if categories.is_empty() && reason.is_none() {
+			return None;
+		}
+
+		Some(ContentFilterInfo {
+			blocked: reason.is_some() || categories.iter().any(|category| category.filtered),
+			reason: reason.map(String::from),
+			categories,
+			message: body.x_get("/promptFeedback/blockReasonMessage").ok(),
+		})
+	}
+
+	/// The response choices, when the body has more than one candidate (empty otherwise).
+	/// NOTE: The thought signatures are not kept in the choices (only in the first candidate response content).
+	fn into_chat_choices(body: &Value) -> Vec<ChatChoice> {
+		let Some(candidates) = body.pointer("/candidates").and_then(Value::as_array) else {
+			return Vec::new();
+		};
+		if candidates.len() < 2 {
+			return Vec::new();
+		}
+
+		candidates
+			.iter()
+			.enumerate()
+			.map(|(idx, candidate)| {
+				let mut content: Vec<MessageContent> = Vec::new();
+				let mut tool_calls: Vec<ToolCall> = Vec::new();
+				let mut reasoning_content: Option<String> = None;
+				let parts = candidate.pointer("/content/parts").and_then(Value::as_array);
+				for part in parts.into_iter().flatten() {
+					if let Some(fn_call_value) = part.get("functionCall") {
+						let fn_name: String = fn_call_value.x_get("name").unwrap_or_default();
+						tool_calls.push(ToolCall {
+							call_id: fn_name.clone(),
+							fn_name,
+							fn_arguments: fn_call_value.x_get("args").unwrap_or(Value::Null),
+						});
+					}
+					if let Ok(text) = part.x_get::<String>("text") {
+						if part.x_get::<bool>("thought").unwrap_or(false) {
+							reasoning_content.get_or_insert_with(String::new).push_str(&text);
+						} else {
+							content.push(MessageContent::from_text(text));
+						}
+					}
+				}
+				if !tool_calls.is_empty() {
+					content.push(MessageContent::ToolCalls(tool_calls));
+				}
+
+				ChatChoice {
+					index: candidate.x_get("index").unwrap_or(idx),
+					content,
+					reasoning_content,
+					finish_reason: candidate.x_get_as::<&str>("finishReason").ok().map(FinishReason::from_provider),
+					logprobs: candidate.get("logprobsResult").cloned(),
+				}
+			})
+			.collect()
+	}
+
+	/// The eventual typed provider extras of the response body (the `candidates[0].groundingMetadata`).
+	fn into_provider_exts(body: &Value) -> ProviderExts {
+		let mut provider_exts = ProviderExts::default();
+		let Some(grounding) = body.pointer("/candidates/0/groundingMetadata") else {
+			return provider_exts;
+		};
+
+		let items = |path: &str| grounding.pointer(path).and_then(Value::as_array).cloned().unwrap_or_default();
+		provider_exts.insert(GeminiGroundingMetadata {
+			web_search_queries: grounding.x_get("webSearchQueries").unwrap_or_default(),
+			grounding_chunks: items("/groundingChunks")
+				.iter()
+				.map(|chunk| GeminiGroundingChunk {
+					uri: chunk.x_get("/web/uri").ok(),
+					title: chunk.x_get("/web/title").ok(),
+				})
+				.collect(),
+			grounding_supports: items("/groundingSupports")
+				.iter()
+				.map(|support| GeminiGroundingSupport {
+					text: support.x_get("/segment/text").ok(),
+					// NOTE: The `startIndex` (and `partIndex`) are omitted when 0.
+					start_index: support.x_get("/segment/startIndex").ok().or(Some(0)),
+					end_index: support.x_get("/segment/endIndex").ok(),
+					part_index: support.x_get("/segment/partIndex").ok().or(Some(0)),
+					grounding_chunk_indices: support.x_get("groundingChunkIndices").unwrap_or_default(),
+					confidence_scores: support.x_get("confidenceScores").unwrap_or_default(),
+				})
+				.collect(),
+			search_entry_point: grounding.x_get("/searchEntryPoint/renderedContent").ok(),
+		});
+		provider_exts
+	}
+
 	/// See gemini doc: https://ai.google.dev/api/generate-content#UsageMetadata
 	pub(super) fn into_usage(mut usage_value: Value) -> Usage {
 		let total_tokens: Option<i32> = usage_value.x_take("totalTokenCount").ok();
@@ -480,7 +764,7 @@ impl GeminiAdapter {
 	/// - `ChatRole::System` is concatenated (with an empty line) into a single `system` for the system instruction.
 	///   - This adapter uses version v1beta, which supports `systemInstruction`
 	/// - The eventual `chat_req.system` is pushed first into the "systemInstruction"
-	fn into_gemini_request_parts(
+	pub(super) fn into_gemini_request_parts(
 		model_iden: &ModelIden, // use for error reporting
 		chat_req: ChatRequest,
 	) -> Result<GeminiChatRequestParts> {
@@ -495,7 +779,7 @@ impl GeminiAdapter {
 		for msg in chat_req.messages {
 			match msg.role {
 				// For now, system goes as "user" (later, we might have adapter_config.system_to_user_impl)
-				ChatRole::System => {
+				ChatRole::System | ChatRole::Developer => {
 					let MessageContent::Text(content) = msg.content else {
 						return Err(Error::MessageContentTypeNotSupported {
 							model_iden: model_iden.clone(),
@@ -530,6 +814,68 @@ impl GeminiAdapter {
 												})),
 											}
 										}
+										ContentPart::File(file_ref) => match (&file_ref.uri, &file_ref.content_type) {
+											(Some(uri), Some(content_type)) => Some(json!({
+												"file_data": {
+													"mime_type": content_type,
+													"file_uri": uri
+												}
+											})),
+											_ => {
+												tracing::warn!(
+													"Gemini file reference '{}' requires a uri and content_type (use the FileRef from upload_file/get_file)",
+													file_ref.id
+												);
+												None
+											}
+										},
+										ContentPart::Document { content_type, source }
+										| ContentPart::Audio { content_type, source } => match source {
+											ImageSource::Url(url) => Some(json!({
+												"file_data": {
+													"mime_type": content_type,
+													"file_uri": url
+												}
+											})),
+											ImageSource::Base64(content) => Some(json!({
+												"inline_data": {
+													"mime_type": content_type,
+													"data": content
+												}
+											})),
+										},
+										ContentPart::Video {
+											content_type,
+											source,
+											start_offset,
+											end_offset,
+										} => {
+											let mut part = match source {
+												ImageSource::Url(url) => json!({
+													"file_data": {
+														"mime_type": content_type,
+														"file_uri": url
+													}
+												}),
+												ImageSource::Base64(content) => json!({
+													"inline_data": {
+														"mime_type": content_type,
+														"data": content
+													}
+												}),
+											};
+											if start_offset.is_some() || end_offset.is_some() {
+												let mut video_metadata = json!({});
+												if let Some(start_offset) = start_offset {
+													video_metadata["startOffset"] = json!(format_offset(start_offset));
+												}
+												if let Some(end_offset) = end_offset {
+													video_metadata["endOffset"] = json!(format_offset(end_offset));
+												}
+												part["videoMetadata"] = video_metadata;
+											}
+											Some(part)
+										}
 									})
 									.collect::<Vec<Value>>()
 							)
@@ -576,6 +922,7 @@ impl GeminiAdapter {
 										ContentBlock::Text {
 											text,
 											thought_signature,
+											..
 										} => {
 											let mut part = json!({"text": text});
 											// Only include gemini signatures
@@ -619,6 +966,13 @@ impl GeminiAdapter {
 											}
 											Some(part)
 										}
+										ContentBlock::Image { content_type, source } => {
+											Some(image_source_to_part(&content_type, &source))
+										}
+										block @ (ContentBlock::ExecutableCode { .. }
+										| ContentBlock::CodeExecutionResult { .. }) => {
+											code_execution_to_part(&block)
+										}
 										ContentBlock::ToolResult {
 											tool_use_id,
 											content,
@@ -683,6 +1037,7 @@ impl GeminiAdapter {
 									ContentBlock::Text {
 										text,
 										thought_signature,
+										..
 									} => {
 										let mut part = json!({"text": text});
 										// Only include gemini signatures
@@ -726,6 +1081,11 @@ impl GeminiAdapter {
 										}
 										Some(part)
 									}
+									ContentBlock::Image { content_type, source } => {
+										Some(image_source_to_part(&content_type, &source))
+									}
+									block @ (ContentBlock::ExecutableCode { .. }
+									| ContentBlock::CodeExecutionResult { .. }) => code_execution_to_part(&block),
 									_ => None, // Skip other block types
 								})
 								.collect::<Vec<Value>>();
@@ -803,8 +1163,21 @@ impl GeminiAdapter {
 			//       The rest are builtins
 			let mut function_declarations: Vec<Value> = Vec::new();
 			for req_tool in req_tools {
+				// -- if it is a genai built-in tool
+				if let Some(builtin) = &req_tool.builtin {
+					match builtin {
+						BuiltInTool::WebSearch(_) => tools.push(json!({"googleSearch": {}})),
+						BuiltInTool::CodeExecution => tools.push(json!({"codeExecution": {}})),
+						BuiltInTool::Computer(_) | BuiltInTool::TextEditor(_) | BuiltInTool::Bash => {
+							return Err(Error::AdapterNotSupported {
+								adapter_kind: model_iden.adapter_kind,
+								feature: format!("built-in tool {}", builtin.name()),
+							});
+						}
+					}
+				}
 				// -- if it is a builtin tool
-				if matches!(
+				else if matches!(
 					req_tool.name.as_str(),
 					"googleSearch" | "googleSearchRetrieval" | "codeExecution" | "urlContext"
 				) {
@@ -837,6 +1210,48 @@ impl GeminiAdapter {
 	}
 }
 
+/// The Gemini part of an image content (e.g., an image output sent back in the history).
+fn image_source_to_part(content_type: &str, source: &ImageSource) -> Value {
+	match source {
+		ImageSource::Url(url) => json!({
+			"file_data": {
+				"mime_type": content_type,
+				"file_uri": url
+			}
+		}),
+		ImageSource::Base64(content) => json!({
+			"inline_data": {
+				"mime_type": content_type,
+				"data": content
+			}
+		}),
+	}
+}
+
+/// The Gemini parts of the code execution blocks (e.g., sent back in the history).
+fn code_execution_to_part(block: &ContentBlock) -> Option<Value> {
+	match block {
+		ContentBlock::ExecutableCode { language, code } => Some(json!({
+			"executableCode": {
+				"language": language.as_deref().unwrap_or("python").to_uppercase(),
+				"code": code,
+			}
+		})),
+		ContentBlock::CodeExecutionResult { output, is_error } => Some(json!({
+			"codeExecutionResult": {
+				"outcome": if *is_error { "OUTCOME_FAILED" } else { "OUTCOME_OK" },
+				"output": output,
+			}
+		})),
+		_ => None,
+	}
+}
+
+/// Format a video offset as a Gemini duration string (e.g., `10s`, `1.5s`).
+fn format_offset(offset: &Duration) -> String {
+	format!("{}s", offset.as_secs_f64())
+}
+
 // struct Gemini
 
 /// FIXME: need to be Vec<GeminiChatContent>
@@ -849,15 +1264,18 @@ pub(super) enum GeminiChatContent {
 	Text(String),
 	Thinking { text: String, signature: Option<String> },
 	ToolCall(ToolCall),
+	Image { content_type: String, data: String },
+	ExecutableCode { language: Option<String>, code: String },
+	CodeExecutionResult { output: String, is_error: bool },
 }
 
-struct GeminiChatRequestParts {
-	system: Option<String>,
+pub(super) struct GeminiChatRequestParts {
+	pub system: Option<String>,
 	/// The chat history (user and assistant, except for the last user message which is a message)
-	contents: Vec<Value>,
+	pub contents: Vec<Value>,
 
 	/// The tools to use
-	tools: Option<Vec<Value>>,
+	pub tools: Option<Vec<Value>>,
 }
 
 // endregion: --- Support
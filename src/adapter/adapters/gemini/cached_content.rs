@@ -0,0 +1,118 @@
+//! Gemini Context Caching API implementation
+//! API Documentation: https://ai.google.dev/api/caching
+
+use super::adapter_impl::GeminiChatRequestParts;
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::gemini::GeminiAdapter;
+use crate::chat::{CachedContent, ChatRequest};
+use crate::webc::{self, WebClient};
+use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use serde_json::{Value, json};
+use std::time::Duration;
+use value_ext::JsonValueExt;
+
+impl GeminiAdapter {
+	/// Create a `cachedContents` with the system, contents, and tools of the chat request
+	/// (same conversion as the chat request).
+	pub(in crate::adapter) async fn create_cached_content(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		model_name: &str,
+		chat_req: ChatRequest,
+		ttl: Option<Duration>,
+	) -> Result<CachedContent> {
+		let (base_url, headers) = caching_base_url_and_headers(&target)?;
+		let url = format!("{base_url}cachedContents");
+
+		let GeminiChatRequestParts {
+			system,
+			contents,
+			tools,
+		} = Self::into_gemini_request_parts(&target.model, chat_req)?;
+
+		let mut payload = json!({
+			"model": format!("models/{model_name}"),
+			"contents": contents,
+		});
+		if let Some(system) = system {
+			payload.x_insert("systemInstruction", json!({"parts": [{"text": system}]}))?;
+		}
+		if let Some(tools) = tools {
+			payload.x_insert("tools", tools)?;
+		}
+		if let Some(ttl) = ttl {
+			payload.x_insert("ttl", format!("{}s", ttl.as_secs()))?;
+		}
+
+		let web_res = web_client.do_post(&url, &headers, payload).await.map_err(map_webc_error)?;
+
+		into_cached_content(web_res.body)
+	}
+
+	pub(in crate::adapter) async fn get_cached_content(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		name: &str,
+	) -> Result<CachedContent> {
+		let (base_url, headers) = caching_base_url_and_headers(&target)?;
+		let url = format!("{base_url}{}", cached_content_name_path(name));
+
+		let web_res = web_client.do_get(&url, &headers).await.map_err(map_webc_error)?;
+
+		into_cached_content(web_res.body)
+	}
+
+	pub(in crate::adapter) async fn delete_cached_content(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		name: &str,
+	) -> Result<()> {
+		let (base_url, headers) = caching_base_url_and_headers(&target)?;
+		let url = format!("{base_url}{}", cached_content_name_path(name));
+
+		web_client.do_delete(&url, &headers).await.map_err(map_webc_error)?;
+
+		Ok(())
+	}
+}
+
+// region:    --- Support
+
+fn caching_base_url_and_headers(target: &ServiceTarget) -> Result<(String, Headers)> {
+	let api_key = get_api_key(target.auth.clone(), &target.model)?;
+	let headers = Headers::from(("x-goog-api-key".to_string(), api_key));
+	Ok((target.endpoint.base_url().to_string(), headers))
+}
+
+/// Allow the cached content name with or without the `cachedContents/` prefix.
+pub(super) fn cached_content_name_path(name: &str) -> String {
+	if name.starts_with("cachedContents/") {
+		name.to_string()
+	} else {
+		format!("cachedContents/{name}")
+	}
+}
+
+fn map_webc_error(webc_error: webc::Error) -> Error {
+	Error::WebAdapterCall {
+		adapter_kind: AdapterKind::Gemini,
+		webc_error,
+	}
+}
+
+fn into_cached_content(mut body: Value) -> Result<CachedContent> {
+	let name: String = body.x_take("name")?;
+	// e.g., `models/gemini-2.5-flash`
+	let model: String = body.x_take("model")?;
+	let model_name = model.strip_prefix("models/").unwrap_or(&model);
+
+	Ok(CachedContent {
+		model_iden: ModelIden::new(AdapterKind::Gemini, model_name),
+		name,
+		expire_time: body.x_take("expireTime").ok(),
+		token_count: body.x_take("/usageMetadata/totalTokenCount").ok(),
+	})
+}
+
+// endregion: --- Support
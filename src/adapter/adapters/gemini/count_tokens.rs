@@ -0,0 +1,74 @@
+//! Gemini Count Tokens API implementation
+//! API Documentation: https://ai.google.dev/api/tokens
+
+use super::adapter_impl::GeminiChatRequestParts;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::gemini::GeminiAdapter;
+use crate::chat::{ChatRequest, TokenCount};
+use crate::webc::WebClient;
+use crate::{Error, Headers, Result, ServiceTarget};
+use serde_json::json;
+use value_ext::JsonValueExt;
+
+impl GeminiAdapter {
+	/// Count the input tokens of a chat request with the `models/{model}:countTokens` endpoint.
+	///
+	/// NOTE: The request parts (system, contents, tools) are built with the same conversion as the chat request,
+	///       and sent as a `generateContentRequest` (so that the system instruction and tools are counted as well).
+	pub(in crate::adapter) async fn count_tokens(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		chat_req: ChatRequest,
+	) -> Result<TokenCount> {
+		let ServiceTarget { endpoint, auth, model } = target;
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+
+		// -- api_key & headers
+		let api_key = get_api_key(auth, &model)?;
+		let headers = Headers::from(("x-goog-api-key".to_string(), api_key));
+
+		// -- url
+		let url = format!("{}models/{model_name}:countTokens", endpoint.base_url());
+
+		// -- payload
+		let GeminiChatRequestParts {
+			system,
+			contents,
+			tools,
+		} = Self::into_gemini_request_parts(&model, chat_req)?;
+
+		let mut generate_content_request = json!({
+			"model": format!("models/{model_name}"),
+			"contents": contents,
+		});
+		if let Some(system) = system {
+			generate_content_request.x_insert(
+				"systemInstruction",
+				json!({
+					"parts": [ { "text": system }]
+				}),
+			)?;
+		}
+		if let Some(tools) = tools {
+			generate_content_request.x_insert("tools", tools)?;
+		}
+		let payload = json!({ "generateContentRequest": generate_content_request });
+
+		// -- Exec
+		let mut web_res =
+			web_client
+				.do_post(&url, &headers, payload)
+				.await
+				.map_err(|webc_error| Error::WebModelCall {
+					model_iden: model.clone(),
+					webc_error,
+				})?;
+
+		let input_tokens: i32 = web_res.body.x_take("totalTokens")?;
+
+		Ok(TokenCount {
+			model_iden: model,
+			input_tokens,
+		})
+	}
+}
@@ -1,9 +1,8 @@
 //! Gemini Embeddings API implementation
 //! API Documentation: https://ai.google.dev/gemini-api/docs/embeddings
 
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{check_embed_dimensions, embed_usage, get_api_key};
 use crate::adapter::{Adapter, ServiceType, WebRequestData};
-use crate::chat::Usage;
 use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse, Embedding};
 use crate::webc::WebResponse;
 use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
@@ -101,6 +100,9 @@ pub fn to_embed_request_data(
 	let (model_name, _) = model.model_name.as_model_name_and_namespace();
 	let full_model_name = format!("models/{model_name}",);
 
+	// The legacy embedding-001 model has fixed dimensions
+	check_embed_dimensions(&model, options_set.dimensions(), model_name != "embedding-001")?;
+
 	// Convert EmbedRequest to Gemini format and determine URL
 	let (payload, is_batch) = match embed_req.input {
 		crate::embed::EmbedInput::Single(text) => {
@@ -166,6 +168,12 @@ pub fn to_embed_request_data(
 
 			(payload, true)
 		}
+		crate::embed::EmbedInput::Parts(_) => {
+			return Err(Error::AdapterNotSupported {
+				adapter_kind: model.adapter_kind,
+				feature: "multimodal embeddings".to_string(),
+			});
+		}
 	};
 
 	// Get the service URL and modify it for batch requests
@@ -212,13 +220,7 @@ pub fn to_embed_response(
 		.collect();
 
 	// Create usage information - Gemini doesn't provide token counts in embedding responses
-	let usage = Usage {
-		prompt_tokens: None, // Gemini doesn't provide token counts for embeddings
-		completion_tokens: None,
-		total_tokens: None,
-		prompt_tokens_details: None,
-		completion_tokens_details: None,
-	};
+	let usage = embed_usage(None);
 
 	// Create provider model identifier
 	let provider_model_iden = ModelIden {
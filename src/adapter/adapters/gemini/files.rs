@@ -0,0 +1,241 @@
+//! Gemini Files API implementation
+//! API Documentation: https://ai.google.dev/api/files
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::gemini::GeminiAdapter;
+use crate::files::{FileRef, FileUpload};
+use crate::webc::{self, WebClient};
+use crate::{Error, Headers, Result, ServiceTarget};
+use serde::Deserialize;
+use serde_json::json;
+use value_ext::JsonValueExt;
+
+/// The boundary of the `multipart/related` upload body.
+const MULTIPART_BOUNDARY: &str = "genai-file-upload-boundary";
+
+/// Above this size, the file is uploaded with the resumable protocol (session start + finalize upload),
+/// which is the protocol recommended by Gemini for the large media (video, audio, PDF).
+const RESUMABLE_UPLOAD_MIN_BYTES: usize = 8 * 1024 * 1024;
+
+// region:    --- Gemini File Response
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiFile {
+	/// The resource name (e.g., `files/abc-123`)
+	name: String,
+	display_name: Option<String>,
+	mime_type: Option<String>,
+	/// NOTE: int64 are serialized as string by the Gemini API
+	size_bytes: Option<String>,
+	uri: Option<String>,
+	/// `PROCESSING`, `ACTIVE`, or `FAILED` (video files are processed before they can be used)
+	state: Option<String>,
+}
+
+// endregion: --- Gemini File Response
+
+impl GeminiAdapter {
+	pub(in crate::adapter) async fn upload_file(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		file: FileUpload,
+	) -> Result<FileRef> {
+		let (base_url, headers) = files_base_url_and_headers(target)?;
+
+		if file.content.len() >= RESUMABLE_UPLOAD_MIN_BYTES {
+			return upload_file_resumable(web_client, &base_url, headers, file).await;
+		}
+
+		// NOTE: The upload uses the `/upload/` prefixed path (e.g., `.../upload/v1beta/files`)
+		let url = format!("{}files?uploadType=multipart", upload_base_url(&base_url));
+
+		let FileUpload {
+			file_name,
+			content_type,
+			content,
+			// NOTE: Gemini does not have a purpose
+			purpose: _,
+		} = file;
+
+		// -- Build the multipart/related body (metadata json part + media part)
+		let metadata = json!({"file": {"display_name": file_name}});
+		let mut body: Vec<u8> = Vec::with_capacity(content.len() + 512);
+		body.extend_from_slice(
+			format!("--{MULTIPART_BOUNDARY}\r\nContent-Type: application/json; charset=UTF-8\r\n\r\n{metadata}\r\n")
+				.as_bytes(),
+		);
+		body.extend_from_slice(format!("--{MULTIPART_BOUNDARY}\r\nContent-Type: {content_type}\r\n\r\n").as_bytes());
+		body.extend_from_slice(&content);
+		body.extend_from_slice(format!("\r\n--{MULTIPART_BOUNDARY}--\r\n").as_bytes());
+
+		let mut headers = headers;
+		headers.merge(("X-Goog-Upload-Protocol", "multipart"));
+
+		let mut web_res = web_client
+			.do_post_bytes(
+				&url,
+				&headers,
+				&format!("multipart/related; boundary={MULTIPART_BOUNDARY}"),
+				body,
+			)
+			.await
+			.map_err(map_webc_error)?;
+
+		let file: GeminiFile = web_res.body.x_take("file")?;
+		Ok(into_file_ref(file))
+	}
+
+	pub(in crate::adapter) async fn list_files(web_client: &WebClient, target: ServiceTarget) -> Result<Vec<FileRef>> {
+		let (base_url, headers) = files_base_url_and_headers(target)?;
+		let url = format!("{base_url}files");
+
+		let mut web_res = web_client.do_get(&url, &headers).await.map_err(map_webc_error)?;
+
+		// NOTE: When there are no files, the `files` property is absent
+		let files: Vec<GeminiFile> = web_res.body.x_take::<Option<Vec<GeminiFile>>>("files")?.unwrap_or_default();
+		Ok(files.into_iter().map(into_file_ref).collect())
+	}
+
+	pub(in crate::adapter) async fn get_file(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		file_id: &str,
+	) -> Result<FileRef> {
+		let (base_url, headers) = files_base_url_and_headers(target)?;
+		let url = format!("{base_url}{}", file_name_path(file_id));
+
+		let web_res = web_client.do_get(&url, &headers).await.map_err(map_webc_error)?;
+
+		let file: GeminiFile = serde_json::from_value(web_res.body)?;
+		Ok(into_file_ref(file))
+	}
+
+	pub(in crate::adapter) async fn delete_file(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		file_id: &str,
+	) -> Result<()> {
+		let (base_url, headers) = files_base_url_and_headers(target)?;
+		let url = format!("{base_url}{}", file_name_path(file_id));
+
+		web_client.do_delete(&url, &headers).await.map_err(map_webc_error)?;
+
+		Ok(())
+	}
+}
+
+// region:    --- Resumable Upload
+
+/// Upload a large file with the resumable protocol:
+/// 1. Start the upload session with the file metadata, which returns the session url in the `X-Goog-Upload-URL` header.
+/// 2. Upload the content to the session url, and finalize it in the same request.
+async fn upload_file_resumable(
+	web_client: &WebClient,
+	base_url: &str,
+	headers: Headers,
+	file: FileUpload,
+) -> Result<FileRef> {
+	let url = format!("{}files", upload_base_url(base_url));
+
+	let FileUpload {
+		file_name,
+		content_type,
+		content,
+		// NOTE: Gemini does not have a purpose
+		purpose: _,
+	} = file;
+
+	// -- Start the upload session
+	let mut start_headers = headers.clone();
+	start_headers.merge(Headers::from([
+		("X-Goog-Upload-Protocol".to_string(), "resumable".to_string()),
+		("X-Goog-Upload-Command".to_string(), "start".to_string()),
+		(
+			"X-Goog-Upload-Header-Content-Length".to_string(),
+			content.len().to_string(),
+		),
+		("X-Goog-Upload-Header-Content-Type".to_string(), content_type.clone()),
+	]));
+	let metadata = json!({"file": {"display_name": file_name}});
+
+	let start_res = web_client
+		.do_post(&url, &start_headers, metadata)
+		.await
+		.map_err(map_webc_error)?;
+
+	let upload_url = start_res
+		.headers
+		.get("x-goog-upload-url")
+		.and_then(|v| v.to_str().ok())
+		.ok_or(Error::InvalidJsonResponseElement {
+			info: "Gemini resumable upload start response has no 'x-goog-upload-url' header",
+		})?
+		.to_string();
+
+	// -- Upload the content and finalize
+	let mut upload_headers = headers;
+	upload_headers.merge(Headers::from([
+		("X-Goog-Upload-Offset".to_string(), "0".to_string()),
+		("X-Goog-Upload-Command".to_string(), "upload, finalize".to_string()),
+	]));
+
+	let mut web_res = web_client
+		.do_post_bytes(&upload_url, &upload_headers, &content_type, content.to_vec())
+		.await
+		.map_err(map_webc_error)?;
+
+	let file: GeminiFile = web_res.body.x_take("file")?;
+	Ok(into_file_ref(file))
+}
+
+// endregion: --- Resumable Upload
+
+// region:    --- Support
+
+fn files_base_url_and_headers(target: ServiceTarget) -> Result<(String, Headers)> {
+	let ServiceTarget { endpoint, auth, model } = target;
+	let api_key = get_api_key(auth, &model)?;
+	let headers = Headers::from(("x-goog-api-key".to_string(), api_key));
+	Ok((endpoint.base_url().to_string(), headers))
+}
+
+/// `https://generativelanguage.googleapis.com/v1beta/` -> `https://generativelanguage.googleapis.com/upload/v1beta/`
+fn upload_base_url(base_url: &str) -> String {
+	match base_url
+		.find("://")
+		.and_then(|idx| base_url[idx + 3..].find('/').map(|path_idx| idx + 3 + path_idx))
+	{
+		Some(path_idx) => format!("{}/upload{}", &base_url[..path_idx], &base_url[path_idx..]),
+		None => format!("{base_url}/upload/"),
+	}
+}
+
+/// Accept both the resource name (`files/abc-123`) and the bare id (`abc-123`).
+fn file_name_path(file_id: &str) -> String {
+	if file_id.starts_with("files/") {
+		file_id.to_string()
+	} else {
+		format!("files/{file_id}")
+	}
+}
+
+fn map_webc_error(webc_error: webc::Error) -> Error {
+	Error::WebAdapterCall {
+		adapter_kind: AdapterKind::Gemini,
+		webc_error,
+	}
+}
+
+fn into_file_ref(file: GeminiFile) -> FileRef {
+	let mut file_ref = FileRef::new(AdapterKind::Gemini, file.name);
+	file_ref.uri = file.uri;
+	file_ref.file_name = file.display_name;
+	file_ref.content_type = file.mime_type;
+	file_ref.size = file.size_bytes.and_then(|size| size.parse().ok());
+	file_ref.state = file.state;
+	file_ref
+}
+
+// endregion: --- Support
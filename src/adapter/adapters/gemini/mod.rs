@@ -5,8 +5,15 @@
 // region:    --- Modules
 
 mod adapter_impl;
+mod cached_content;
+mod count_tokens;
 mod embed;
+mod files;
+mod model_info;
+mod ping;
+mod realtime;
 mod streamer;
+mod videogen;
 
 pub use adapter_impl::*;
 pub use streamer::*;
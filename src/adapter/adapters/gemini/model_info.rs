@@ -0,0 +1,36 @@
+//! Model metadata of the Gemini API.
+//! API Documentation: https://ai.google.dev/api/models#method:-models.get
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::gemini::GeminiAdapter;
+use crate::webc::WebClient;
+use crate::{Error, Headers, ModelInfo, Result, ServiceTarget};
+use value_ext::JsonValueExt;
+
+impl GeminiAdapter {
+	pub(in crate::adapter) async fn model_info(web_client: &WebClient, target: ServiceTarget) -> Result<ModelInfo> {
+		let ServiceTarget { endpoint, auth, model } = target;
+		let api_key = get_api_key(auth, &model)?;
+		let headers = Headers::from(("x-goog-api-key".to_string(), api_key));
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+		let url = format!("{}models/{model_name}", endpoint.base_url());
+
+		let web_res = web_client
+			.do_get(&url, &headers)
+			.await
+			.map_err(|webc_error| Error::WebAdapterCall {
+				adapter_kind: AdapterKind::Gemini,
+				webc_error,
+			})?;
+		let body = web_res.body;
+
+		let mut info = ModelInfo::new(model.clone(), Default::default());
+		info.display_name = body.x_get("displayName").ok();
+		info.context_window = body.x_get("inputTokenLimit").ok();
+		info.max_output_tokens = body.x_get("outputTokenLimit").ok();
+		info.captured_raw_body = body;
+
+		Ok(info)
+	}
+}
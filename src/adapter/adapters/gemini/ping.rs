@@ -0,0 +1,27 @@
+//! Health check of the Gemini API with the models listing.
+//! API Documentation: https://ai.google.dev/api/models#method:-models.list
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::gemini::GeminiAdapter;
+use crate::webc::WebClient;
+use crate::{Error, Headers, Result, ServiceTarget};
+
+impl GeminiAdapter {
+	pub(in crate::adapter) async fn ping(web_client: &WebClient, target: ServiceTarget) -> Result<u16> {
+		let ServiceTarget { endpoint, auth, model } = target;
+		let api_key = get_api_key(auth, &model)?;
+		let headers = Headers::from(("x-goog-api-key".to_string(), api_key));
+		let url = format!("{}models?pageSize=1", endpoint.base_url());
+
+		let web_res = web_client
+			.do_get(&url, &headers)
+			.await
+			.map_err(|webc_error| Error::WebAdapterCall {
+				adapter_kind: AdapterKind::Gemini,
+				webc_error,
+			})?;
+
+		Ok(web_res.status.as_u16())
+	}
+}
@@ -0,0 +1,220 @@
+//! Gemini Live API implementation (WebSocket)
+//! API Documentation: https://ai.google.dev/api/live
+
+use super::adapter_impl::GeminiChatRequestParts;
+use crate::adapter::WebRequestData;
+use crate::adapter::adapters::support::{get_api_key, into_ws_url};
+use crate::adapter::gemini::GeminiAdapter;
+use crate::chat::{ChatRequest, ToolCall, Usage};
+use crate::realtime::{RealtimeConfig, RealtimeEvent, RealtimeInput, RealtimeModality, TurnDetection};
+use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use serde_json::{Value, json};
+use value_ext::JsonValueExt;
+
+/// The Live API WebSocket path (from the endpoint origin).
+const LIVE_PATH: &str = "ws/google.ai.generativelanguage.v1beta.GenerativeService.BidiGenerateContent";
+
+/// The Live API input audio must be 16-bit PCM, and is sent as 16kHz.
+const INPUT_AUDIO_MIME_TYPE: &str = "audio/pcm;rate=16000";
+
+impl GeminiAdapter {
+	/// The Live WebSocket url and headers, with the required `setup` message as payload.
+	pub(in crate::adapter) fn to_realtime_request_data(
+		target: ServiceTarget,
+		config: Option<&RealtimeConfig>,
+	) -> Result<WebRequestData> {
+		let ServiceTarget { endpoint, auth, model } = target;
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+
+		// -- api_key & headers
+		let api_key = get_api_key(auth, &model)?;
+		let headers = Headers::from(("x-goog-api-key".to_string(), api_key));
+
+		// -- url (the Live API is at the origin, not under the version path)
+		let ws_url = into_ws_url(endpoint.base_url());
+		// e.g., `wss://generativelanguage.googleapis.com/v1beta/` -> `wss://generativelanguage.googleapis.com`
+		let origin = ws_url.split('/').take(3).collect::<Vec<_>>().join("/");
+		let url = format!("{origin}/{LIVE_PATH}");
+
+		// -- payload (the setup message is required, even without config)
+		let mut setup = json!({
+			"model": format!("models/{model_name}"),
+		});
+		if let Some(config) = config {
+			Self::insert_live_setup_config(&model, &mut setup, config)?;
+		}
+		let payload = json!({ "setup": setup });
+
+		Ok(WebRequestData { url, headers, payload })
+	}
+
+	pub(in crate::adapter) fn to_realtime_messages(model_iden: &ModelIden, input: RealtimeInput) -> Result<Vec<Value>> {
+		let message = match input {
+			// NOTE: The turn is completed with `CreateResponse` (to match the other realtime providers).
+			RealtimeInput::Text(text) => json!({
+				"clientContent": {
+					"turns": [{"role": "user", "parts": [{"text": text}]}],
+					"turnComplete": false
+				}
+			}),
+			RealtimeInput::AudioChunk(audio) => json!({
+				"realtimeInput": {
+					"audio": {"data": BASE64.encode(audio), "mimeType": INPUT_AUDIO_MIME_TYPE}
+				}
+			}),
+			RealtimeInput::VideoFrame { content_type, data } => json!({
+				"realtimeInput": {
+					"video": {"data": BASE64.encode(data), "mimeType": content_type}
+				}
+			}),
+			// NOTE: Flushes the cached input audio (e.g., when the microphone is paused).
+			RealtimeInput::AudioCommit => json!({"realtimeInput": {"audioStreamEnd": true}}),
+			RealtimeInput::ToolResponse(tool_response) => json!({
+				"toolResponse": {
+					"functionResponses": [{
+						"id": tool_response.call_id,
+						"response": {"result": tool_response.content}
+					}]
+				}
+			}),
+			RealtimeInput::CreateResponse => json!({"clientContent": {"turnComplete": true}}),
+			// NOTE: Gemini interrupts the response automatically on user activity (no explicit cancel).
+			RealtimeInput::CancelResponse => return Ok(Vec::new()),
+			RealtimeInput::SessionUpdate(_) => {
+				return Err(Error::AdapterNotSupported {
+					adapter_kind: model_iden.adapter_kind,
+					feature: "realtime session update (config must be given on connect)".to_string(),
+				});
+			}
+		};
+
+		Ok(vec![message])
+	}
+
+	pub(in crate::adapter) fn to_realtime_events(
+		model_iden: &ModelIden,
+		mut message: Value,
+	) -> Result<Vec<RealtimeEvent>> {
+		let mut events = Vec::new();
+
+		if message.get("setupComplete").is_some() {
+			events.push(RealtimeEvent::SessionCreated);
+		}
+
+		if let Ok(mut server_content) = message.x_take::<Value>("serverContent") {
+			// NOTE: Interrupted means the user started speaking during the response (stop the playback).
+			if server_content.x_get::<bool>("interrupted").unwrap_or(false) {
+				events.push(RealtimeEvent::SpeechStarted);
+			}
+			if let Ok(text) = server_content.x_take::<String>("/inputTranscription/text") {
+				events.push(RealtimeEvent::InputTranscript(text));
+			}
+			if let Ok(parts) = server_content.x_take::<Vec<Value>>("/modelTurn/parts") {
+				for mut part in parts {
+					if let Ok(text) = part.x_take::<String>("text") {
+						events.push(RealtimeEvent::TextDelta(text));
+					} else if let Ok(data) = part.x_take::<String>("/inlineData/data") {
+						let audio = BASE64.decode(data).map_err(|err| Error::WebStream {
+							model_iden: model_iden.clone(),
+							cause: format!("Invalid base64 audio data. Cause: {err}"),
+						})?;
+						events.push(RealtimeEvent::AudioDelta(audio));
+					}
+				}
+			}
+			if let Ok(text) = server_content.x_take::<String>("/outputTranscription/text") {
+				events.push(RealtimeEvent::AudioTranscriptDelta(text));
+			}
+			if server_content.x_get::<bool>("turnComplete").unwrap_or(false) {
+				let usage = message
+					.x_take::<Value>("usageMetadata")
+					.ok()
+					.map(into_live_usage)
+					.unwrap_or_default();
+				events.push(RealtimeEvent::ResponseDone { usage });
+			}
+		} else if let Ok(function_calls) = message.x_take::<Vec<Value>>("/toolCall/functionCalls") {
+			for mut function_call in function_calls {
+				events.push(RealtimeEvent::ToolCall(ToolCall {
+					call_id: function_call.x_take("id").unwrap_or_default(),
+					fn_name: function_call.x_take("name")?,
+					fn_arguments: function_call.x_take("args").unwrap_or_default(),
+				}));
+			}
+		} else if events.is_empty() {
+			events.push(RealtimeEvent::Other(message));
+		}
+
+		Ok(events)
+	}
+}
+
+// region:    --- Support
+
+impl GeminiAdapter {
+	fn insert_live_setup_config(model_iden: &ModelIden, setup: &mut Value, config: &RealtimeConfig) -> Result<()> {
+		// -- System instruction & tools (same conversion as the chat request)
+		let chat_req = ChatRequest {
+			system: config.instructions.clone(),
+			tools: config.tools.clone(),
+			..Default::default()
+		};
+		let GeminiChatRequestParts { system, tools, .. } = Self::into_gemini_request_parts(model_iden, chat_req)?;
+		if let Some(system) = system {
+			setup.x_insert("systemInstruction", json!({"parts": [{"text": system}]}))?;
+		}
+		if let Some(tools) = tools {
+			setup.x_insert("tools", tools)?;
+		}
+
+		// -- Generation config
+		if let Some(output_modality) = config.output_modality {
+			let modality = match output_modality {
+				RealtimeModality::Audio => "AUDIO",
+				RealtimeModality::Text => "TEXT",
+			};
+			setup.x_insert("/generationConfig/responseModalities", [modality])?;
+			if output_modality == RealtimeModality::Audio {
+				setup.x_insert("outputAudioTranscription", json!({}))?;
+			}
+		}
+		if let Some(voice) = &config.voice {
+			setup.x_insert(
+				"/generationConfig/speechConfig/voiceConfig/prebuiltVoiceConfig/voiceName",
+				voice,
+			)?;
+		}
+		if let Some(max_output_tokens) = config.max_output_tokens {
+			setup.x_insert("/generationConfig/maxOutputTokens", max_output_tokens)?;
+		}
+
+		// -- Turn detection (automatic by default)
+		if config.turn_detection == Some(TurnDetection::Manual) {
+			setup.x_insert("/realtimeInputConfig/automaticActivityDetection/disabled", true)?;
+		}
+
+		// NOTE: The input transcription model cannot be selected for Gemini.
+		if config.input_transcription_model.is_some() {
+			setup.x_insert("inputAudioTranscription", json!({}))?;
+		}
+
+		// NOTE: The audio format is fixed for Gemini (16kHz PCM input, 24kHz PCM output).
+
+		Ok(())
+	}
+}
+
+/// The Live usage has the `responseTokenCount` (not the generateContent `candidatesTokenCount`).
+fn into_live_usage(mut usage_value: Value) -> Usage {
+	Usage {
+		prompt_tokens: usage_value.x_take("promptTokenCount").ok(),
+		prompt_tokens_details: None,
+		completion_tokens: usage_value.x_take("responseTokenCount").ok(),
+		completion_tokens_details: None,
+		total_tokens: usage_value.x_take("totalTokenCount").ok(),
+	}
+}
+
+// endregion: --- Support
@@ -1,12 +1,13 @@
 use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions};
 use crate::adapter::gemini::{GeminiAdapter, GeminiChatResponse};
 use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
-use crate::chat::{ChatOptionsSet, ToolCall};
+use crate::chat::{ChatOptionsSet, FinishReason, ToolCall};
 use crate::webc::WebStream;
 use crate::{Error, ModelIden, Result};
 use serde_json::Value;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use value_ext::JsonValueExt;
 
 use super::GeminiChatContent;
 
@@ -55,6 +56,8 @@ impl futures::Stream for GeminiStreamer {
 								captured_text_content: self.captured_data.content.take(),
 								captured_reasoning_content: self.captured_data.reasoning_content.take(),
 								captured_tool_calls: self.captured_data.tool_calls.take(),
+								finish_reason: self.captured_data.finish_reason.take(),
+								stop_sequence: None,
 							};
 
 							InterStreamEvent::End(inter_stream_end)
@@ -74,6 +77,19 @@ impl futures::Stream for GeminiStreamer {
 								}
 							};
 
+							// -- Capture the eventual finish reason (in the last block)
+							if let Ok(finish_reason) = json_block.x_get_as::<&str>("/candidates/0/finishReason") {
+								self.captured_data.finish_reason = Some(FinishReason::from_provider(finish_reason));
+							}
+
+							// -- Capture the eventual prompt block (no candidates, the stream ends with the block reason)
+							if let Ok(block_reason) = json_block.x_get_as::<&str>("/promptFeedback/blockReason")
+								&& json_block.pointer("/candidates/0/content/parts").is_none()
+							{
+								self.captured_data.finish_reason = Some(FinishReason::from_provider(block_reason));
+								continue;
+							}
+
 							// -- Extract the Gemini Response
 							let gemini_response =
 								match GeminiAdapter::body_to_gemini_chat_response(&self.options.model_iden, json_block)
@@ -99,6 +115,11 @@ impl futures::Stream for GeminiStreamer {
 										stream_reasoning_content.push_str(&text)
 									}
 									GeminiChatContent::ToolCall(tool_call) => stream_tool_call = Some(tool_call),
+									// NOTE: Image outputs are not streamed for now (use the non-streaming chat)
+									GeminiChatContent::Image { .. } => (),
+									// NOTE: The code execution parts are not streamed for now (use the non-streaming chat)
+									GeminiChatContent::ExecutableCode { .. }
+									| GeminiChatContent::CodeExecutionResult { .. } => (),
 								}
 							}
 
@@ -0,0 +1,130 @@
+//! Gemini video generation implementation (Veo, long-running operations)
+//! API Documentation: https://ai.google.dev/gemini-api/docs/video
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::gemini::GeminiAdapter;
+use crate::videogen::{VideoJob, VideoJobStatus, VideoRequest};
+use crate::webc::{self, WebClient};
+use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use serde_json::{Value, json};
+use value_ext::JsonValueExt;
+
+impl GeminiAdapter {
+	pub(in crate::adapter) async fn create_video_job(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		model_name: &str,
+		req: VideoRequest,
+	) -> Result<VideoJob> {
+		let (base_url, headers) = videos_base_url_and_headers(target)?;
+		let url = format!("{base_url}models/{model_name}:predictLongRunning");
+
+		let VideoRequest {
+			prompt,
+			duration_seconds,
+			aspect_ratio,
+			negative_prompt,
+			..
+		} = req;
+
+		let mut payload = json!({
+			"instances": [{"prompt": prompt}],
+		});
+		if let Some(duration_seconds) = duration_seconds {
+			payload.x_insert("/parameters/durationSeconds", duration_seconds)?;
+		}
+		if let Some(aspect_ratio) = aspect_ratio {
+			payload.x_insert("/parameters/aspectRatio", aspect_ratio)?;
+		}
+		if let Some(negative_prompt) = negative_prompt {
+			payload.x_insert("/parameters/negativePrompt", negative_prompt)?;
+		}
+
+		let web_res = web_client.do_post(&url, &headers, payload).await.map_err(map_webc_error)?;
+
+		into_video_job(model_name, web_res.body)
+	}
+
+	pub(in crate::adapter) async fn get_video_job(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		job: &VideoJob,
+	) -> Result<VideoJob> {
+		let (base_url, headers) = videos_base_url_and_headers(target)?;
+		// The job id is the operation name (e.g., `models/veo-3.0-generate-001/operations/...`)
+		let url = format!("{base_url}{}", job.id);
+
+		let web_res = web_client.do_get(&url, &headers).await.map_err(map_webc_error)?;
+
+		into_video_job(&job.model_iden.model_name, web_res.body)
+	}
+
+	pub(in crate::adapter) async fn download_video(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		job: &VideoJob,
+	) -> Result<Vec<u8>> {
+		let (_, headers) = videos_base_url_and_headers(target)?;
+		let Some(video_uri) = job.video_uri.as_deref() else {
+			return Err(Error::InvalidJsonResponseElement {
+				info: "video job has no video uri (not completed yet?)",
+			});
+		};
+
+		let bytes = web_client.do_get_bytes(video_uri, &headers).await.map_err(map_webc_error)?;
+
+		Ok(bytes.to_vec())
+	}
+}
+
+// region:    --- Support
+
+fn videos_base_url_and_headers(target: ServiceTarget) -> Result<(String, Headers)> {
+	let ServiceTarget { endpoint, auth, model } = target;
+	let api_key = get_api_key(auth, &model)?;
+	let headers = Headers::from(("x-goog-api-key".to_string(), api_key));
+	Ok((endpoint.base_url().to_string(), headers))
+}
+
+fn map_webc_error(webc_error: webc::Error) -> Error {
+	Error::WebAdapterCall {
+		adapter_kind: AdapterKind::Gemini,
+		webc_error,
+	}
+}
+
+/// Build the VideoJob from a Gemini long-running operation.
+fn into_video_job(model_name: &str, mut operation: Value) -> Result<VideoJob> {
+	let id: String = operation.x_take("name")?;
+	let done = operation.x_get::<bool>("done").unwrap_or(false);
+	let mut error_message: Option<String> = operation.x_take("/error/message").ok();
+	let video_uri: Option<String> = operation
+		.x_take("/response/generateVideoResponse/generatedSamples/0/video/uri")
+		.ok();
+
+	// A done operation without video was filtered (e.g., `raiMediaFilteredReasons`)
+	if done && error_message.is_none() && video_uri.is_none() {
+		let filtered_reason: Option<String> = operation
+			.x_take("/response/generateVideoResponse/raiMediaFilteredReasons/0")
+			.ok();
+		error_message = Some(filtered_reason.unwrap_or_else(|| "No generated video".to_string()));
+	}
+
+	let status = match (done, &error_message) {
+		(false, _) => VideoJobStatus::InProgress,
+		(true, Some(_)) => VideoJobStatus::Failed,
+		(true, None) => VideoJobStatus::Completed,
+	};
+
+	Ok(VideoJob {
+		model_iden: ModelIden::new(AdapterKind::Gemini, model_name),
+		id,
+		status,
+		progress: None,
+		video_uri,
+		error_message,
+	})
+}
+
+// endregion: --- Support
@@ -5,7 +5,7 @@ use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::WebResponse;
-use crate::{Error, Result};
+use crate::{Error, Headers, Result};
 use crate::{ModelIden, ServiceTarget};
 use reqwest::RequestBuilder;
 use serde_json::Value;
@@ -39,10 +39,13 @@ impl Adapter for OllamaAdapter {
 
 		// TODO: Need to get the WebClient from the client.
 		let web_c = crate::webc::WebClient::default();
-		let mut res = web_c.do_get(&url, &[]).await.map_err(|webc_error| Error::WebAdapterCall {
-			adapter_kind,
-			webc_error,
-		})?;
+		let mut res = web_c
+			.do_get(&url, &Headers::default())
+			.await
+			.map_err(|webc_error| Error::WebAdapterCall {
+				adapter_kind,
+				webc_error,
+			})?;
 
 		let mut models: Vec<String> = Vec::new();
 
@@ -93,7 +96,7 @@ impl Adapter for OllamaAdapter {
 		embed_req: crate::embed::EmbedRequest,
 		options_set: crate::embed::EmbedOptionsSet<'_, '_>,
 	) -> Result<crate::adapter::WebRequestData> {
-		OpenAIAdapter::to_embed_request_data(service_target, embed_req, options_set)
+		super::embed::to_embed_request_data(service_target, embed_req, options_set)
 	}
 
 	fn to_embed_response(
@@ -101,6 +104,6 @@ impl Adapter for OllamaAdapter {
 		web_response: crate::webc::WebResponse,
 		options_set: crate::embed::EmbedOptionsSet<'_, '_>,
 	) -> Result<crate::embed::EmbedResponse> {
-		OpenAIAdapter::to_embed_response(model_iden, web_response, options_set)
+		super::embed::to_embed_response(model_iden, web_response, options_set)
 	}
 }
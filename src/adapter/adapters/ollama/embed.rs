@@ -0,0 +1,127 @@
+//! Ollama Embeddings API implementation (native Ollama API)
+//! API Documentation: https://github.com/ollama/ollama/blob/main/docs/api.md#generate-embeddings
+
+use crate::adapter::WebRequestData;
+use crate::adapter::adapters::support::embed_usage;
+use crate::adapter::ollama::manage::native_api_base_url;
+use crate::embed::{EmbedInput, EmbedOptionsSet, EmbedRequest, EmbedResponse, Embedding};
+use crate::webc::WebResponse;
+use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use serde::{Deserialize, Serialize};
+
+// region:    --- Ollama Embed Request
+
+#[derive(Debug, Serialize)]
+struct OllamaEmbedRequest {
+	model: String,
+	input: OllamaEmbedInput,
+	/// When false, inputs longer than the context length return an error (instead of being truncated)
+	#[serde(skip_serializing_if = "Option::is_none")]
+	truncate: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	dimensions: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum OllamaEmbedInput {
+	Single(String),
+	Batch(Vec<String>),
+}
+
+// endregion: --- Ollama Embed Request
+
+// region:    --- Ollama Embed Response
+
+#[derive(Debug, Deserialize)]
+struct OllamaEmbedResponse {
+	model: String,
+	embeddings: Vec<Vec<f32>>,
+	prompt_eval_count: Option<i32>,
+}
+
+// endregion: --- Ollama Embed Response
+
+// region:    --- Public Functions
+
+pub fn to_embed_request_data(
+	service_target: ServiceTarget,
+	embed_req: EmbedRequest,
+	options_set: EmbedOptionsSet<'_, '_>,
+) -> Result<WebRequestData> {
+	let url = format!("{}embed", native_api_base_url(&service_target));
+	let model = service_target.model;
+
+	// NOTE: The local Ollama server does not require an API key
+	let mut headers = Headers::from(("Content-Type".to_string(), "application/json".to_string()));
+	if let Some(custom_headers) = options_set.headers() {
+		headers.merge_with(custom_headers);
+	}
+
+	let input = match embed_req.input {
+		EmbedInput::Single(text) => OllamaEmbedInput::Single(text),
+		EmbedInput::Batch(texts) => OllamaEmbedInput::Batch(texts),
+		EmbedInput::Parts(_) => {
+			return Err(Error::AdapterNotSupported {
+				adapter_kind: model.adapter_kind,
+				feature: "multimodal embeddings".to_string(),
+			});
+		}
+	};
+
+	let (model_name, _) = model.model_name.as_model_name_and_namespace();
+
+	let ollama_req = OllamaEmbedRequest {
+		model: model_name.to_string(),
+		input,
+		// Ollama only supports enabling/disabling the truncation (at the end)
+		truncate: options_set.truncate().map(|truncate| !truncate.eq_ignore_ascii_case("NONE")),
+		dimensions: options_set.dimensions(),
+	};
+
+	let payload = serde_json::to_value(ollama_req).map_err(|serde_error| Error::StreamParse {
+		model_iden: model.clone(),
+		serde_error,
+	})?;
+
+	Ok(WebRequestData { url, headers, payload })
+}
+
+pub fn to_embed_response(
+	model_iden: ModelIden,
+	web_response: WebResponse,
+	options_set: EmbedOptionsSet<'_, '_>,
+) -> Result<EmbedResponse> {
+	let WebResponse { body, .. } = web_response;
+
+	let ollama_res: OllamaEmbedResponse =
+		serde_json::from_value(body.clone()).map_err(|serde_error| Error::StreamParse {
+			model_iden: model_iden.clone(),
+			serde_error,
+		})?;
+
+	let embeddings: Vec<Embedding> = ollama_res
+		.embeddings
+		.into_iter()
+		.enumerate()
+		.map(|(index, vector)| Embedding::new(vector, index))
+		.collect();
+
+	// The input tokens are in `prompt_eval_count`
+	let usage = embed_usage(ollama_res.prompt_eval_count);
+
+	let provider_model_iden = ModelIden {
+		adapter_kind: model_iden.adapter_kind,
+		model_name: ollama_res.model.into(),
+	};
+
+	let mut response = EmbedResponse::new(embeddings, model_iden, provider_model_iden, usage);
+
+	if options_set.capture_raw_body() {
+		response = response.with_captured_raw_body(body);
+	}
+
+	Ok(response)
+}
+
+// endregion: --- Public Functions
@@ -0,0 +1,97 @@
+//! Ollama model management API implementation (native Ollama API)
+//! API Documentation: https://github.com/ollama/ollama/blob/main/docs/api.md
+
+use crate::adapter::AdapterKind;
+use crate::adapter::ollama::OllamaAdapter;
+use crate::ollama::{OllamaModelInfo, OllamaPullStream, OllamaRunningModel};
+use crate::webc::{self, WebClient, WebStream};
+use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use serde_json::json;
+use value_ext::JsonValueExt;
+
+impl OllamaAdapter {
+	pub(in crate::adapter) fn pull_model(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		model_name: &str,
+	) -> Result<OllamaPullStream> {
+		let url = format!("{}pull", native_api_base_url(&target));
+
+		let payload = json!({
+			"model": model_name,
+			"stream": true,
+		});
+		let reqwest_builder = web_client
+			.new_req_builder(&url, &Headers::default(), payload)
+			.map_err(map_webc_error)?;
+
+		// The native Ollama API streams `application/x-ndjson`
+		let web_stream = WebStream::new_with_delimiter(reqwest_builder, "\n");
+		let model_iden = ModelIden::new(AdapterKind::Ollama, model_name);
+
+		Ok(OllamaPullStream::new(web_stream, model_iden))
+	}
+
+	pub(in crate::adapter) async fn show_model(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		model_name: &str,
+	) -> Result<OllamaModelInfo> {
+		let url = format!("{}show", native_api_base_url(&target));
+
+		let web_res = web_client
+			.do_post(&url, &Headers::default(), json!({"model": model_name}))
+			.await
+			.map_err(map_webc_error)?;
+
+		let model_info: OllamaModelInfo = serde_json::from_value(web_res.body)?;
+		Ok(model_info)
+	}
+
+	pub(in crate::adapter) async fn delete_model(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		model_name: &str,
+	) -> Result<()> {
+		let url = format!("{}delete", native_api_base_url(&target));
+
+		web_client
+			.do_delete_json(&url, &Headers::default(), json!({"model": model_name}))
+			.await
+			.map_err(map_webc_error)?;
+
+		Ok(())
+	}
+
+	pub(in crate::adapter) async fn running_models(
+		web_client: &WebClient,
+		target: ServiceTarget,
+	) -> Result<Vec<OllamaRunningModel>> {
+		let url = format!("{}ps", native_api_base_url(&target));
+
+		let mut web_res = web_client.do_get(&url, &Headers::default()).await.map_err(map_webc_error)?;
+
+		let models: Vec<OllamaRunningModel> = web_res.body.x_take("models")?;
+		Ok(models)
+	}
+}
+
+// region:    --- Support
+
+/// The native API base url from the OpenAI compatibility endpoint
+/// (e.g., `http://localhost:11434/v1/` to `http://localhost:11434/api/`).
+pub(super) fn native_api_base_url(target: &ServiceTarget) -> String {
+	let base_url = target.endpoint.base_url();
+	let base_url = base_url.strip_suffix("v1/").unwrap_or(base_url);
+	let sep = if base_url.ends_with('/') { "" } else { "/" };
+	format!("{base_url}{sep}api/")
+}
+
+fn map_webc_error(webc_error: webc::Error) -> Error {
+	Error::WebAdapterCall {
+		adapter_kind: AdapterKind::Ollama,
+		webc_error,
+	}
+}
+
+// endregion: --- Support
@@ -1,4 +1,4 @@
-//! NOTE:           Currently, GenAI uses the OpenAI compatibility layer, except for listing models.
+//! NOTE:           Currently, GenAI uses the OpenAI compatibility layer, except for listing models, embeddings, and the model management API.
 //! OPENAI API DOC: https://platform.openai.com/docs/api-reference/chat
 //! OLLAMA API DOC: https://github.com/ollama/ollama/blob/main/docs/api.md
 //!  OLLAMA Models: https://ollama.com/library
@@ -6,6 +6,8 @@
 // region:    --- Modules
 
 mod adapter_impl;
+mod embed;
+mod manage;
 
 pub use adapter_impl::*;
 
@@ -1,9 +1,10 @@
 use crate::adapter::adapters::support::get_api_key;
-use crate::adapter::openai::OpenAIStreamer;
+use crate::adapter::openai::{OpenAIResponsesStreamer, OpenAIStreamer};
 use crate::adapter::{Adapter, AdapterDispatcher, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
-	ChatOptionsSet, ChatRequest, ChatResponse, ChatResponseFormat, ChatRole, ChatStream, ChatStreamResponse,
-	ContentPart, ImageSource, MessageContent, ReasoningEffort, ToolCall, Usage,
+	ChatChoice, ChatOptionsSet, ChatRequest, ChatResponse, ChatResponseFormat, ChatRole, ChatStream,
+	ChatStreamResponse, ContentFilterInfo, ContentPart, FinishReason, GroqTiming, ImageSource, MessageContent,
+	ProviderExts, ReasoningEffort, ToolCall, ToolChoice, Usage,
 };
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::WebResponse;
@@ -59,6 +60,22 @@ impl Adapter for OpenAIAdapter {
 		chat_req: ChatRequest,
 		chat_options: ChatOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
+		// -- The `file_search` and built-in tools are only available in the Responses API
+		if Self::requires_responses_api(&chat_req) {
+			return match service_type {
+				ServiceType::Chat => Self::to_responses_web_request_data(target, service_type, chat_req, chat_options),
+				_ => Err(Error::AdapterNotSupported {
+					adapter_kind: target.model.adapter_kind,
+					feature: "file_search and built-in tools (streaming)".to_string(),
+				}),
+			};
+		}
+
+		// -- The reasoning summaries are only available in the Responses API (see `to_chat_stream` for the streaming)
+		if Self::uses_responses_api_reasoning(&target.model, &chat_options) {
+			return Self::to_responses_web_request_data(target, service_type, chat_req, chat_options);
+		}
+
 		OpenAIAdapter::util_to_web_request_data(target, service_type, chat_req, chat_options)
 	}
 
@@ -67,6 +84,10 @@ impl Adapter for OpenAIAdapter {
 		web_response: WebResponse,
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatResponse> {
+		if Self::is_responses_api_body(&web_response.body) {
+			return Self::to_responses_chat_response(model_iden, web_response, options_set);
+		}
+
 		let WebResponse { mut body, .. } = web_response;
 
 		let captured_raw_body = options_set.capture_raw_body().unwrap_or_default().then(|| body.clone());
@@ -74,6 +95,10 @@ impl Adapter for OpenAIAdapter {
 		// -- Capture the provider_model_iden
 		let provider_model_name: Option<String> = body.x_remove("model").ok();
 		let provider_model_iden = model_iden.from_optional_name(provider_model_name);
+		let system_fingerprint: Option<String> = body.x_remove("system_fingerprint").ok();
+
+		// -- Capture the eventual provider extras (before the usage is taken)
+		let provider_exts = Self::into_provider_exts(model_iden.adapter_kind, &body);
 
 		// -- Capture the usage
 		let usage = body
@@ -81,62 +106,63 @@ impl Adapter for OpenAIAdapter {
 			.map(|value| OpenAIAdapter::into_usage(model_iden.adapter_kind, value))
 			.unwrap_or_default();
 
-		// -- Capture the content
-		let mut content: Vec<MessageContent> = Vec::new();
-		let mut reasoning_content: Option<String> = None;
-
-		if let Ok(Some(mut first_choice)) = body.x_take::<Option<Value>>("/choices/0") {
-			// Check if reasoning is present
-			// Can be in two places:
-			// - /message/reasoning
-			// - /message/reasoning_content
-			// Extracted before content as some model can return reasoning without content
-			reasoning_content = first_choice
-				.x_take::<Option<String>>("/message/reasoning")
-				.ok()
-				.unwrap_or_else(|| {
-					first_choice
-						.x_take::<Option<String>>("/message/reasoning_content")
-						.ok()
-						.flatten()
-				})
-				.map(|s| s.trim().to_string());
-
-			// -- Push eventual text message
-			if let Ok(Some(mut text_content)) = first_choice.x_take::<Option<String>>("/message/content") {
-				text_content = text_content.trim().to_string();
-				// If not reasoning_content, but
-				if reasoning_content.is_none() && options_set.normalize_reasoning_content().unwrap_or_default() {
-					let (content_tmp, reasoning_content_tmp) = extract_think(text_content);
-					reasoning_content = reasoning_content_tmp;
-					text_content = content_tmp;
-				}
-
-				// After extracting reasoning_content, sometimes the content is empty.
-				if !text_content.is_empty() {
-					content.push(text_content.into());
-				}
-			}
-
-			// -- Push eventual ToolCalls
-			if let Some(tool_calls) = first_choice
-				.x_take("/message/tool_calls")
-				.ok()
-				.map(parse_tool_calls)
-				.transpose()?
-				.map(MessageContent::from_tool_calls)
-			{
-				content.push(tool_calls);
+		// -- Capture the eventual content filter info (before the choices are taken)
+		let content_filter = Self::into_content_filter_info(&body);
+
+		// -- Capture the eventual stop sequence (vLLM `stop_reason`, which is the token id when stopped by a stop token)
+		let stop_sequence: Option<String> = body.x_get("/choices/0/stop_reason").ok();
+
+		// -- Capture the choices (the first one is the response content)
+		let normalize_reasoning_content = options_set.normalize_reasoning_content().unwrap_or_default();
+		let mut choices = body
+			.x_take::<Option<Vec<Value>>>("choices")
+			.ok()
+			.flatten()
+			.unwrap_or_default()
+			.into_iter()
+			.enumerate()
+			.map(|(idx, choice)| Self::into_chat_choice(idx, choice, normalize_reasoning_content))
+			.collect::<Result<Vec<ChatChoice>>>()?;
+		let (content, reasoning_content, finish_reason, logprobs) = match choices.first() {
+			Some(first_choice) if choices.len() > 1 => (
+				first_choice.content.clone(),
+				first_choice.reasoning_content.clone(),
+				first_choice.finish_reason.clone(),
+				first_choice.logprobs.clone(),
+			),
+			Some(_) => {
+				let first_choice = choices.remove(0);
+				(
+					first_choice.content,
+					first_choice.reasoning_content,
+					first_choice.finish_reason,
+					first_choice.logprobs,
+				)
 			}
-		}
+			None => (Vec::new(), None, None, None),
+		};
 
 		Ok(ChatResponse {
 			content,
 			reasoning_content,
 			model_iden,
 			provider_model_iden,
+			system_fingerprint,
 			usage,
 			captured_raw_body,
+			response_digest: None,
+			response_headers: None,
+			prompt_leaks: None,
+			stop_sequence,
+			file_citations: None,
+			web_citations: None,
+			output_repair: None,
+			ai_disclosure: None,
+			provider_exts,
+			finish_reason,
+			choices,
+			logprobs,
+			content_filter,
 		})
 	}
 
@@ -146,8 +172,13 @@ impl Adapter for OpenAIAdapter {
 		options_sets: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatStreamResponse> {
 		let event_source = EventSource::new(reqwest_builder)?;
-		let openai_stream = OpenAIStreamer::new(event_source, model_iden.clone(), options_sets);
-		let chat_stream = ChatStream::from_inter_stream(openai_stream);
+		let chat_stream = if Self::uses_responses_api_reasoning(&model_iden, &options_sets) {
+			let responses_stream = OpenAIResponsesStreamer::new(event_source, model_iden.clone(), options_sets);
+			ChatStream::from_inter_stream(responses_stream)
+		} else {
+			let openai_stream = OpenAIStreamer::new(event_source, model_iden.clone(), options_sets);
+			ChatStream::from_inter_stream(openai_stream)
+		};
 
 		Ok(ChatStreamResponse {
 			model_iden,
@@ -207,17 +238,18 @@ impl OpenAIAdapter {
 		// -- api_key
 		let api_key = get_api_key(auth, &model)?;
 
+		// -- Qwen thinking, only for the OpenAI adapter on a custom OpenAI-compatible endpoint (e.g., DashScope, vLLM)
+		// NOTE: The other OpenAI-compatible adapters (e.g., Groq, OpenRouter) take the regular reasoning parameters.
+		let qwen_thinking = matches!(adapter_kind, AdapterKind::OpenAI)
+			&& endpoint.base_url() != Self::default_endpoint().base_url()
+			&& model_name.to_lowercase().contains("qwen");
+
 		// -- url
 		let url = AdapterDispatcher::get_service_url(&model, service_type, endpoint);
 
 		// -- headers
 		let mut headers = Headers::from(("Authorization".to_string(), format!("Bearer {api_key}")));
 
-		// -- extra headers
-		if let Some(extra_headers) = options_set.extra_headers() {
-			headers.merge_with(extra_headers);
-		}
-
 		let stream = matches!(service_type, ServiceType::ChatStream);
 
 		// -- compute reasoning_effort and eventual trimmed model_name
@@ -244,9 +276,12 @@ impl OpenAIAdapter {
 			"stream": stream
 		});
 
-		// -- Set reasoning effort
+		// -- Set reasoning effort (see the `ReasoningEffort` provider mapping table)
+		// NOTE: The Qwen models on a custom endpoint (see `qwen_thinking` above) use `enable_thinking` instead.
 		if let Some(reasoning_effort) = reasoning_effort {
-			if let Some(keyword) = reasoning_effort.as_keyword() {
+			if qwen_thinking {
+				payload.x_insert("enable_thinking", reasoning_effort.qwen_enable_thinking())?;
+			} else if let Some(keyword) = reasoning_effort.openai_effort() {
 				payload.x_insert("reasoning_effort", keyword)?;
 			}
 		}
@@ -255,6 +290,21 @@ impl OpenAIAdapter {
 		if let Some(tools) = tools {
 			payload.x_insert("/tools", tools)?;
 		}
+		if let Some(tool_choice) = options_set.tool_choice() {
+			let tool_choice = match tool_choice {
+				ToolChoice::Auto => json!("auto"),
+				ToolChoice::None => json!("none"),
+				ToolChoice::Required => json!("required"),
+				ToolChoice::Named(name) => json!({"type": "function", "function": {"name": name}}),
+			};
+			payload.x_insert("tool_choice", tool_choice)?;
+		}
+		// NOTE: OpenAI rejects `parallel_tool_calls` without tools.
+		if let Some(parallel_tool_calls) = options_set.parallel_tool_calls()
+			&& payload.get("tools").is_some()
+		{
+			payload.x_insert("parallel_tool_calls", parallel_tool_calls)?;
+		}
 
 		// -- Add options
 		let response_format = if let Some(response_format) = options_set.response_format() {
@@ -284,6 +334,8 @@ impl OpenAIAdapter {
 						}
 					}))
 				}
+				// Not a response_format, see below
+				ChatResponseFormat::Grammar(_) => None,
 			}
 		} else {
 			None
@@ -293,6 +345,31 @@ impl OpenAIAdapter {
 			payload["response_format"] = response_format;
 		}
 
+		// -- Grammar (only for the local inference servers used with the OpenAI adapter)
+		// NOTE: llama.cpp server uses `grammar` and vLLM uses `guided_grammar`, and each ignores the other one.
+		if let (AdapterKind::OpenAI, Some(ChatResponseFormat::Grammar(grammar))) =
+			(adapter_kind, options_set.response_format())
+		{
+			payload.x_insert("grammar", grammar)?;
+			payload.x_insert("guided_grammar", grammar)?;
+		}
+
+		// -- KV-cache hints (only for the local inference servers used with the OpenAI adapter)
+		if let (AdapterKind::OpenAI, Some(kv_cache_hints)) = (adapter_kind, options_set.kv_cache_hints()) {
+			if let Some(cache_prompt) = kv_cache_hints.cache_prompt {
+				payload.x_insert("cache_prompt", cache_prompt)?;
+			}
+			if let Some(slot_id) = kv_cache_hints.slot_id {
+				payload.x_insert("id_slot", slot_id)?;
+			}
+			if let Some(cache_salt) = kv_cache_hints.cache_salt.as_ref() {
+				payload.x_insert("cache_salt", cache_salt)?;
+			}
+			if let Some(session_header) = kv_cache_hints.session_header_entry() {
+				headers.merge(session_header);
+			}
+		}
+
 		// -- Add supported ChatOptions
 		if stream & options_set.capture_usage().unwrap_or(false) {
 			payload.x_insert("stream_options", json!({"include_usage": true}))?;
@@ -312,9 +389,55 @@ impl OpenAIAdapter {
 		if let Some(top_p) = options_set.top_p() {
 			payload.x_insert("top_p", top_p)?;
 		}
+		if let Some(candidate_count) = options_set.candidate_count() {
+			payload.x_insert("n", candidate_count)?;
+		}
+		if let Some(logprobs) = options_set.logprobs() {
+			payload.x_insert("logprobs", logprobs)?;
+		}
+		if let Some(logit_bias) = options_set.logit_bias() {
+			payload.x_insert("logit_bias", logit_bias)?;
+		}
+		if let Some(frequency_penalty) = options_set.frequency_penalty() {
+			payload.x_insert("frequency_penalty", frequency_penalty)?;
+		}
+		if let Some(presence_penalty) = options_set.presence_penalty() {
+			payload.x_insert("presence_penalty", presence_penalty)?;
+		}
 		if let Some(seed) = options_set.seed() {
 			payload.x_insert("seed", seed)?;
 		}
+
+		// -- User, metadata, store & prediction (OpenAI only, the compatible servers might reject them)
+		if matches!(adapter_kind, AdapterKind::OpenAI) {
+			if let Some(user) = options_set.user() {
+				payload.x_insert("user", user)?;
+			}
+			if let Some(metadata) = options_set.metadata() {
+				payload.x_insert("metadata", metadata)?;
+			}
+			if let Some(store) = options_set.store() {
+				payload.x_insert("store", store)?;
+			}
+			if let Some(prediction) = options_set.prediction() {
+				payload.x_insert("prediction", json!({"type": "content", "content": prediction}))?;
+			}
+		}
+
+		// -- Sampling extras (only for the local/open-model servers used with the OpenAI adapter, and Ollama)
+		// NOTE: vLLM/Together use `repetition_penalty` and llama.cpp uses `repeat_penalty` (each ignores the other one).
+		if matches!(adapter_kind, AdapterKind::OpenAI | AdapterKind::Ollama) {
+			if let Some(top_k) = options_set.top_k() {
+				payload.x_insert("top_k", top_k)?;
+			}
+			if let Some(min_p) = options_set.min_p() {
+				payload.x_insert("min_p", min_p)?;
+			}
+			if let Some(repetition_penalty) = options_set.repetition_penalty() {
+				payload.x_insert("repetition_penalty", repetition_penalty)?;
+				payload.x_insert("repeat_penalty", repetition_penalty)?;
+			}
+		}
 		Ok(WebRequestData { url, headers, payload })
 	}
 
@@ -345,10 +468,114 @@ impl OpenAIAdapter {
 		usage
 	}
 
+	/// The eventual typed provider extras of the response body (Groq `usage` timing info).
+	fn into_provider_exts(adapter_kind: AdapterKind, body: &Value) -> ProviderExts {
+		let mut provider_exts = ProviderExts::default();
+		if matches!(adapter_kind, AdapterKind::Groq)
+			&& let Some(timing) = body
+				.x_get::<GroqTiming>("usage")
+				.ok()
+				.filter(|timing| timing.total_time.is_some())
+		{
+			provider_exts.insert(timing);
+		}
+		provider_exts
+	}
+
+	/// The eventual content filter info from the OpenAI / Azure OpenAI response body
+	/// (`prompt_filter_results`, `choices[0].content_filter_results`, and `finish_reason: content_filter`),
+	/// or the eventual model refusal (`choices[0].message.refusal`).
+	fn into_content_filter_info(body: &Value) -> Option<ContentFilterInfo> {
+		if let Ok(refusal) = body.x_get::<String>("/choices/0/message/refusal") {
+			return Some(ContentFilterInfo::refusal(Some(refusal)));
+		}
+
+		let finish_reason = body.x_get_as::<&str>("/choices/0/finish_reason").ok();
+
+		let mut categories = Vec::new();
+		if let Some(prompt_filter_results) = body.get("prompt_filter_results").and_then(Value::as_array) {
+			for prompt_filter_result in prompt_filter_results {
+				if let Some(results) = prompt_filter_result.get("content_filter_results") {
+					categories.extend(ContentFilterInfo::categories_from_openai_filter_results(results));
+				}
+			}
+		}
+		if let Some(results) = body.pointer("/choices/0/content_filter_results") {
+			categories.extend(ContentFilterInfo::categories_from_openai_filter_results(results));
+		}
+
+		let finish_filtered = finish_reason == Some("content_filter");
+		if categories.is_empty() && !finish_filtered {
+			return None;
+		}
+
+		Some(ContentFilterInfo {
+			blocked: finish_filtered || categories.iter().any(|category| category.filtered),
+			reason: finish_filtered.then(|| "content_filter".to_string()),
+			categories,
+			message: None,
+		})
+	}
+
+	/// Parse a response choice (`choices[]` item) into a `ChatChoice`.
+	fn into_chat_choice(idx: usize, mut choice: Value, normalize_reasoning_content: bool) -> Result<ChatChoice> {
+		let index = choice.x_get::<usize>("index").unwrap_or(idx);
+		let finish_reason = choice.x_get_as::<&str>("finish_reason").ok().map(FinishReason::from_provider);
+		let logprobs = choice.x_take::<Value>("logprobs").ok().filter(|value| !value.is_null());
+
+		let mut content: Vec<MessageContent> = Vec::new();
+
+		// Check if reasoning is present
+		// Can be in two places:
+		// - /message/reasoning
+		// - /message/reasoning_content
+		// Extracted before content as some model can return reasoning without content
+		let mut reasoning_content = choice
+			.x_take::<Option<String>>("/message/reasoning")
+			.ok()
+			.unwrap_or_else(|| choice.x_take::<Option<String>>("/message/reasoning_content").ok().flatten())
+			.map(|s| s.trim().to_string());
+
+		// -- Push eventual text message
+		if let Ok(Some(mut text_content)) = choice.x_take::<Option<String>>("/message/content") {
+			text_content = text_content.trim().to_string();
+			// If not reasoning_content, but
+			if reasoning_content.is_none() && normalize_reasoning_content {
+				let (content_tmp, reasoning_content_tmp) = extract_think(text_content);
+				reasoning_content = reasoning_content_tmp;
+				text_content = content_tmp;
+			}
+
+			// After extracting reasoning_content, sometimes the content is empty.
+			if !text_content.is_empty() {
+				content.push(text_content.into());
+			}
+		}
+
+		// -- Push eventual ToolCalls
+		if let Some(tool_calls) = choice
+			.x_take("/message/tool_calls")
+			.ok()
+			.map(parse_tool_calls)
+			.transpose()?
+			.map(MessageContent::from_tool_calls)
+		{
+			content.push(tool_calls);
+		}
+
+		Ok(ChatChoice {
+			index,
+			content,
+			reasoning_content,
+			finish_reason,
+			logprobs,
+		})
+	}
+
 	/// Takes the genai ChatMessages and builds the OpenAIChatRequestParts
 	/// - `genai::ChatRequest.system`, if present, is added as the first message with role 'system'.
 	/// - All messages get added with the corresponding roles (tools are not supported for now)
-	fn into_openai_request_parts(_model_iden: &ModelIden, chat_req: ChatRequest) -> Result<OpenAIRequestParts> {
+	fn into_openai_request_parts(model_iden: &ModelIden, chat_req: ChatRequest) -> Result<OpenAIRequestParts> {
 		let mut messages: Vec<Value> = Vec::new();
 
 		// -- Process the system
@@ -367,6 +594,16 @@ impl OpenAIAdapter {
 					}
 					// TODO: Probably need to warn if it is a ToolCalls type of content
 				}
+				// NOTE: The OpenAI-compatible servers might not support `developer`, so it falls back to `system`
+				ChatRole::Developer => {
+					if let MessageContent::Text(content) = msg.content {
+						let role = match model_iden.adapter_kind {
+							AdapterKind::OpenAI => "developer",
+							_ => "system",
+						};
+						messages.push(json!({"role": role, "content": content}))
+					}
+				}
 				ChatRole::User => {
 					let content = match msg.content {
 						MessageContent::Text(content) => json!(content),
@@ -375,20 +612,51 @@ impl OpenAIAdapter {
 								parts
 									.iter()
 									.map(|part| match part {
-										ContentPart::Text(text) => json!({"type": "text", "text": text.clone()}),
+										ContentPart::Text(text) => Ok(json!({"type": "text", "text": text.clone()})),
 										ContentPart::Image { content_type, source } => {
-											match source {
-												ImageSource::Url(url) => {
-													json!({"type": "image_url", "image_url": {"url": url}})
-												}
+											let image_url = match source {
+												ImageSource::Url(url) => url.clone(),
 												ImageSource::Base64(content) => {
-													let image_url = format!("data:{content_type};base64,{content}");
-													json!({"type": "image_url", "image_url": {"url": image_url}})
+													format!("data:{content_type};base64,{content}")
 												}
-											}
+											};
+											Ok(json!({"type": "image_url", "image_url": {"url": image_url}}))
 										}
+										ContentPart::File(file_ref) => {
+											Ok(json!({"type": "file", "file": {"file_id": file_ref.id}}))
+										}
+										ContentPart::Document { content_type, source } => match source {
+											ImageSource::Base64(content) => Ok(json!({
+												"type": "file",
+												"file": {
+													"filename": ContentPart::document_file_name(content_type),
+													"file_data": format!("data:{content_type};base64,{content}"),
+												}
+											})),
+											ImageSource::Url(_) => Err(Error::MessageContentTypeNotSupported {
+												model_iden: model_iden.clone(),
+												cause: "Document URLs are not supported (use a base64 document or an uploaded file)",
+											}),
+										},
+										ContentPart::Audio { content_type, source } => match source {
+											ImageSource::Base64(content) => Ok(json!({
+												"type": "input_audio",
+												"input_audio": {
+													"data": content,
+													"format": ContentPart::audio_format(content_type),
+												}
+											})),
+											ImageSource::Url(_) => Err(Error::MessageContentTypeNotSupported {
+												model_iden: model_iden.clone(),
+												cause: "Audio URLs are not supported (use a base64 audio)",
+											}),
+										},
+										ContentPart::Video { .. } => Err(Error::MessageContentTypeNotSupported {
+											model_iden: model_iden.clone(),
+											cause: "Video inputs are not supported",
+										}),
 									})
-									.collect::<Vec<Value>>()
+									.collect::<Result<Vec<Value>>>()?
 							)
 						}
 						// Use `match` instead of `if let`. This will allow to future-proof this
@@ -443,27 +711,39 @@ impl OpenAIAdapter {
 		}
 
 		// -- Process the tools
-		let tools = chat_req.tools.map(|tools| {
-			tools
-				.into_iter()
-				.map(|tool| {
-					// TODO: Need to handle the error correctly
-					// TODO: Needs to have a custom serializer (tool should not have to match to a provider)
-					// NOTE: Right now, low probability, so, we just return null if cannot convert to value.
-					json!({
-						"type": "function",
-						"function": {
-							"name": tool.name,
-							"description": tool.description,
-							"parameters": tool.schema,
-							// TODO: If we need to support `strict: true` we need to add additionalProperties: false into the schema
-							//       above (like structured output)
-							"strict": false,
+		let tools = chat_req
+			.tools
+			.map(|tools| {
+				tools
+					.into_iter()
+					.map(|tool| {
+						// NOTE: The built-in tools are only supported by the OpenAI Responses API (see `requires_responses_api`).
+						if let Some(builtin) = &tool.builtin {
+							return Err(Error::AdapterNotSupported {
+								adapter_kind: model_iden.adapter_kind,
+								feature: format!("built-in tool {}", builtin.name()),
+							});
 						}
+						// TODO: Needs to have a custom serializer (tool should not have to match to a provider)
+						// NOTE: For `strict: true`, the schema gets `additionalProperties: false` and all properties required.
+						let parameters = if tool.is_strict() {
+							Some(tool.strict_schema()?)
+						} else {
+							tool.schema
+						};
+						Ok(json!({
+							"type": "function",
+							"function": {
+								"name": tool.name,
+								"description": tool.description,
+								"parameters": parameters,
+								"strict": tool.strict.unwrap_or(false),
+							}
+						}))
 					})
-				})
-				.collect::<Vec<Value>>()
-		});
+					.collect::<Result<Vec<Value>>>()
+			})
+			.transpose()?;
 
 		Ok(OpenAIRequestParts { messages, tools })
 	}
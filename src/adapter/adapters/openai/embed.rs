@@ -1,12 +1,13 @@
 //! OpenAI Embeddings API implementation
 //! API Documentation: https://platform.openai.com/docs/api-reference/embeddings
 
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{check_embed_dimensions, embed_usage, get_api_key};
 use crate::adapter::{Adapter, ServiceType, WebRequestData};
-use crate::chat::Usage;
-use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse, Embedding};
+use crate::embed::{EmbedBilledUnits, EmbedOptionsSet, EmbedRequest, EmbedResponse, Embedding};
 use crate::webc::WebResponse;
 use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
 use serde::{Deserialize, Serialize};
 
 // region:    --- OpenAI Embed Request
@@ -43,14 +44,21 @@ struct OpenAIEmbedResponse {
 
 #[derive(Debug, Deserialize)]
 struct OpenAIEmbedData {
-	embedding: Vec<f32>,
+	embedding: OpenAIEmbedVector,
 	index: usize,
 }
 
+/// The embedding, as a float array (default), or as base64 little-endian f32s (`encoding_format: base64`).
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum OpenAIEmbedVector {
+	Float(Vec<f32>),
+	Base64(String),
+}
+
 #[derive(Debug, Deserialize)]
 struct OpenAIEmbedUsage {
 	prompt_tokens: u32,
-	total_tokens: u32,
 }
 
 // endregion: --- OpenAI Embed Response
@@ -80,16 +88,26 @@ pub fn to_embed_request_data(
 	let input = match embed_req.input {
 		crate::embed::EmbedInput::Single(text) => OpenAIEmbedInput::Single(text),
 		crate::embed::EmbedInput::Batch(texts) => OpenAIEmbedInput::Batch(texts),
+		crate::embed::EmbedInput::Parts(_) => {
+			return Err(Error::AdapterNotSupported {
+				adapter_kind: model.adapter_kind,
+				feature: "multimodal embeddings".to_string(),
+			});
+		}
 	};
 
 	// Extract the actual model name (without namespace)
 	let (model_name, _) = model.model_name.as_model_name_and_namespace();
 
+	// The text-embedding-ada-002 model has fixed dimensions
+	let dimensions = options_set.dimensions();
+	check_embed_dimensions(&model, dimensions, !model_name.starts_with("text-embedding-ada"))?;
+
 	let openai_req = OpenAIEmbedRequest {
 		input,
 		model: model_name.to_string(),
 		encoding_format: options_set.encoding_format().map(|s| s.to_string()),
-		dimensions: options_set.dimensions(),
+		dimensions,
 		user: options_set.user().map(|s| s.to_string()),
 	};
 
@@ -123,19 +141,24 @@ pub fn to_embed_response(
 		})?;
 
 	// Convert to our format
-	let embeddings: Vec<Embedding> = openai_res
+	let embeddings = openai_res
 		.data
 		.into_iter()
-		.map(|data| Embedding::new(data.embedding, data.index))
-		.collect();
-
-	// Create usage information
-	let usage = Usage {
-		prompt_tokens: Some(openai_res.usage.prompt_tokens as i32),
-		completion_tokens: None, // Embeddings don't have output tokens
-		total_tokens: Some(openai_res.usage.total_tokens as i32),
-		prompt_tokens_details: None,
-		completion_tokens_details: None,
+		.map(|data| {
+			let vector = match data.embedding {
+				OpenAIEmbedVector::Float(vector) => vector,
+				OpenAIEmbedVector::Base64(b64) => decode_base64_f32s(&b64)?,
+			};
+			Ok(Embedding::new(vector, data.index))
+		})
+		.collect::<Result<Vec<Embedding>>>()?;
+
+	// Create usage information (the prompt tokens are the billed tokens)
+	let input_tokens = Some(openai_res.usage.prompt_tokens as i32);
+	let usage = embed_usage(input_tokens);
+	let billed_units = EmbedBilledUnits {
+		input_tokens,
+		..Default::default()
 	};
 
 	// Create provider model identifier
@@ -144,7 +167,8 @@ pub fn to_embed_response(
 		model_name: openai_res.model.into(),
 	};
 
-	let mut response = EmbedResponse::new(embeddings, model_iden, provider_model_iden, usage);
+	let mut response =
+		EmbedResponse::new(embeddings, model_iden, provider_model_iden, usage).with_billed_units(billed_units);
 
 	// Capture raw body if requested
 	if options_set.capture_raw_body() {
@@ -155,3 +179,24 @@ pub fn to_embed_response(
 }
 
 // endregion: --- Public Functions
+
+// region:    --- Support
+
+/// Decode the base64 little-endian f32s of an embedding.
+fn decode_base64_f32s(b64: &str) -> Result<Vec<f32>> {
+	let bytes = BASE64.decode(b64).map_err(|_| Error::InvalidJsonResponseElement {
+		info: "embedding is not valid base64",
+	})?;
+	if bytes.len() % 4 != 0 {
+		return Err(Error::InvalidJsonResponseElement {
+			info: "base64 embedding is not a sequence of f32",
+		});
+	}
+
+	Ok(bytes
+		.chunks_exact(4)
+		.map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+		.collect())
+}
+
+// endregion: --- Support
@@ -0,0 +1,122 @@
+//! OpenAI Files API implementation
+//! API Documentation: https://platform.openai.com/docs/api-reference/files
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::openai::OpenAIAdapter;
+use crate::files::{FileRef, FileUpload};
+use crate::webc::{self, WebClient};
+use crate::{Error, Headers, Result, ServiceTarget};
+use reqwest::multipart::{Form, Part};
+use serde::Deserialize;
+use value_ext::JsonValueExt;
+
+/// The default purpose, which allows the files to be referenced from chat messages.
+const DEFAULT_PURPOSE: &str = "user_data";
+
+// region:    --- OpenAI File Response
+
+#[derive(Debug, Deserialize)]
+struct OpenAIFile {
+	id: String,
+	filename: Option<String>,
+	bytes: Option<u64>,
+}
+
+// endregion: --- OpenAI File Response
+
+impl OpenAIAdapter {
+	pub(in crate::adapter) async fn upload_file(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		file: FileUpload,
+	) -> Result<FileRef> {
+		let (base_url, headers) = files_base_url_and_headers(target)?;
+		let url = format!("{base_url}files");
+
+		let FileUpload {
+			file_name,
+			content_type,
+			content,
+			purpose,
+		} = file;
+
+		let part = Part::bytes(content.to_vec())
+			.file_name(file_name)
+			.mime_str(&content_type)
+			.map_err(|err| map_webc_error(err.into()))?;
+		let form = Form::new()
+			.text("purpose", purpose.unwrap_or_else(|| DEFAULT_PURPOSE.to_string()))
+			.part("file", part);
+
+		let web_res = web_client
+			.do_post_multipart(&url, &headers, form)
+			.await
+			.map_err(map_webc_error)?;
+
+		let file: OpenAIFile = serde_json::from_value(web_res.body)?;
+		Ok(into_file_ref(file).with_content_type(content_type))
+	}
+
+	pub(in crate::adapter) async fn list_files(web_client: &WebClient, target: ServiceTarget) -> Result<Vec<FileRef>> {
+		let (base_url, headers) = files_base_url_and_headers(target)?;
+		let url = format!("{base_url}files");
+
+		let mut web_res = web_client.do_get(&url, &headers).await.map_err(map_webc_error)?;
+
+		let files: Vec<OpenAIFile> = web_res.body.x_take("data")?;
+		Ok(files.into_iter().map(into_file_ref).collect())
+	}
+
+	pub(in crate::adapter) async fn get_file(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		file_id: &str,
+	) -> Result<FileRef> {
+		let (base_url, headers) = files_base_url_and_headers(target)?;
+		let url = format!("{base_url}files/{file_id}");
+
+		let web_res = web_client.do_get(&url, &headers).await.map_err(map_webc_error)?;
+
+		let file: OpenAIFile = serde_json::from_value(web_res.body)?;
+		Ok(into_file_ref(file))
+	}
+
+	pub(in crate::adapter) async fn delete_file(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		file_id: &str,
+	) -> Result<()> {
+		let (base_url, headers) = files_base_url_and_headers(target)?;
+		let url = format!("{base_url}files/{file_id}");
+
+		web_client.do_delete(&url, &headers).await.map_err(map_webc_error)?;
+
+		Ok(())
+	}
+}
+
+// region:    --- Support
+
+fn files_base_url_and_headers(target: ServiceTarget) -> Result<(String, Headers)> {
+	let ServiceTarget { endpoint, auth, model } = target;
+	let api_key = get_api_key(auth, &model)?;
+	let headers = Headers::from(("Authorization".to_string(), format!("Bearer {api_key}")));
+	Ok((endpoint.base_url().to_string(), headers))
+}
+
+fn map_webc_error(webc_error: webc::Error) -> Error {
+	Error::WebAdapterCall {
+		adapter_kind: AdapterKind::OpenAI,
+		webc_error,
+	}
+}
+
+fn into_file_ref(file: OpenAIFile) -> FileRef {
+	let mut file_ref = FileRef::new(AdapterKind::OpenAI, file.id);
+	file_ref.file_name = file.filename;
+	file_ref.size = file.bytes;
+	file_ref
+}
+
+// endregion: --- Support
@@ -0,0 +1,140 @@
+//! OpenAI Fine-tuning API implementation
+//! API Documentation: https://platform.openai.com/docs/api-reference/fine-tuning
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::openai::OpenAIAdapter;
+use crate::finetune::{FineTuneJob, FineTuneRequest};
+use crate::webc::{self, WebClient};
+use crate::{Error, Headers, Result, ServiceTarget};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use value_ext::JsonValueExt;
+
+// region:    --- OpenAI Fine-tuning Job Response
+
+#[derive(Debug, Deserialize)]
+struct OpenAIFineTuneJob {
+	id: String,
+	model: String,
+	status: String,
+	fine_tuned_model: Option<String>,
+	training_file: Option<String>,
+	created_at: Option<i64>,
+	error: Option<OpenAIFineTuneError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIFineTuneError {
+	message: Option<String>,
+}
+
+// endregion: --- OpenAI Fine-tuning Job Response
+
+impl OpenAIAdapter {
+	pub(in crate::adapter) async fn create_finetune_job(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		req: FineTuneRequest,
+	) -> Result<FineTuneJob> {
+		let (base_url, headers) = finetune_base_url_and_headers(target)?;
+		let url = format!("{base_url}fine_tuning/jobs");
+
+		let FineTuneRequest {
+			model,
+			training_file_id,
+			validation_file_id,
+			suffix,
+			n_epochs,
+		} = req;
+
+		let mut payload = json!({
+			"model": model,
+			"training_file": training_file_id,
+		});
+		if let Some(validation_file_id) = validation_file_id {
+			payload.x_insert("validation_file", validation_file_id)?;
+		}
+		if let Some(suffix) = suffix {
+			payload.x_insert("suffix", suffix)?;
+		}
+		if let Some(n_epochs) = n_epochs {
+			payload.x_insert("/hyperparameters/n_epochs", n_epochs)?;
+		}
+
+		let web_res = web_client.do_post(&url, &headers, payload).await.map_err(map_webc_error)?;
+
+		into_finetune_job(web_res.body)
+	}
+
+	pub(in crate::adapter) async fn list_finetune_jobs(
+		web_client: &WebClient,
+		target: ServiceTarget,
+	) -> Result<Vec<FineTuneJob>> {
+		let (base_url, headers) = finetune_base_url_and_headers(target)?;
+		let url = format!("{base_url}fine_tuning/jobs");
+
+		let mut web_res = web_client.do_get(&url, &headers).await.map_err(map_webc_error)?;
+
+		let jobs: Vec<Value> = web_res.body.x_take("data")?;
+		jobs.into_iter().map(into_finetune_job).collect()
+	}
+
+	pub(in crate::adapter) async fn get_finetune_job(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		job_id: &str,
+	) -> Result<FineTuneJob> {
+		let (base_url, headers) = finetune_base_url_and_headers(target)?;
+		let url = format!("{base_url}fine_tuning/jobs/{job_id}");
+
+		let web_res = web_client.do_get(&url, &headers).await.map_err(map_webc_error)?;
+
+		into_finetune_job(web_res.body)
+	}
+
+	pub(in crate::adapter) async fn cancel_finetune_job(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		job_id: &str,
+	) -> Result<FineTuneJob> {
+		let (base_url, headers) = finetune_base_url_and_headers(target)?;
+		let url = format!("{base_url}fine_tuning/jobs/{job_id}/cancel");
+
+		let web_res = web_client.do_post(&url, &headers, json!({})).await.map_err(map_webc_error)?;
+
+		into_finetune_job(web_res.body)
+	}
+}
+
+// region:    --- Support
+
+fn finetune_base_url_and_headers(target: ServiceTarget) -> Result<(String, Headers)> {
+	let ServiceTarget { endpoint, auth, model } = target;
+	let api_key = get_api_key(auth, &model)?;
+	let headers = Headers::from(("Authorization".to_string(), format!("Bearer {api_key}")));
+	Ok((endpoint.base_url().to_string(), headers))
+}
+
+fn map_webc_error(webc_error: webc::Error) -> Error {
+	Error::WebAdapterCall {
+		adapter_kind: AdapterKind::OpenAI,
+		webc_error,
+	}
+}
+
+fn into_finetune_job(body: Value) -> Result<FineTuneJob> {
+	let job: OpenAIFineTuneJob = serde_json::from_value(body)?;
+	Ok(FineTuneJob {
+		adapter_kind: AdapterKind::OpenAI,
+		id: job.id,
+		model: job.model,
+		status: job.status.as_str().into(),
+		fine_tuned_model: job.fine_tuned_model,
+		training_file_id: job.training_file,
+		created_at: job.created_at,
+		error_message: job.error.and_then(|err| err.message),
+	})
+}
+
+// endregion: --- Support
@@ -6,9 +6,19 @@
 
 mod adapter_impl;
 mod embed;
+mod files;
+mod finetune;
+mod model_info;
+mod ping;
+mod realtime;
+mod responses;
+mod responses_streamer;
 mod streamer;
+mod vector_store;
+mod videogen;
 
 pub use adapter_impl::*;
+pub use responses_streamer::*;
 pub use streamer::*;
 
 // endregion: --- Modules
@@ -0,0 +1,76 @@
+//! Model metadata of the OpenAI compatible APIs.
+//! API Documentation: https://platform.openai.com/docs/api-reference/models/retrieve
+//!
+//! NOTE: Used for all the OpenAI compatible adapters, which return different properties:
+//! - OpenAI: only the id and owner
+//! - Groq: `context_window`, `max_completion_tokens`
+//! - Cohere: `context_length`
+//! - xAI: `language-models/{id}` with the `input_modalities` and `output_modalities`
+//! - OpenRouter: no model endpoint, so the model is taken from the `models` listing
+//!   (`context_length`, `top_provider.max_completion_tokens`, `architecture.*_modalities`)
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::openai::OpenAIAdapter;
+use crate::webc::{self, WebClient};
+use crate::{Error, Headers, ModelInfo, Result, ServiceTarget};
+use serde_json::Value;
+use value_ext::JsonValueExt;
+
+impl OpenAIAdapter {
+	pub(in crate::adapter) async fn model_info(web_client: &WebClient, target: ServiceTarget) -> Result<ModelInfo> {
+		let ServiceTarget { endpoint, auth, model } = target;
+		let api_key = get_api_key(auth, &model)?;
+		let headers = Headers::from(("Authorization".to_string(), format!("Bearer {api_key}")));
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+		let base_url = endpoint.base_url();
+
+		let map_webc_error = |webc_error: webc::Error| Error::WebAdapterCall {
+			adapter_kind: model.adapter_kind,
+			webc_error,
+		};
+
+		let body = match model.adapter_kind {
+			AdapterKind::OpenRouter => {
+				let mut web_res = web_client
+					.do_get(&format!("{base_url}models"), &headers)
+					.await
+					.map_err(map_webc_error)?;
+				let models: Vec<Value> = web_res.body.x_take("data")?;
+				models
+					.into_iter()
+					.find(|item| item.x_get_str("id").is_ok_and(|id| id == model_name))
+					.ok_or(Error::InvalidJsonResponseElement {
+						info: "OpenRouter models listing does not contain the model",
+					})?
+			}
+			AdapterKind::Xai => {
+				let url = format!("{base_url}language-models/{model_name}");
+				web_client.do_get(&url, &headers).await.map_err(map_webc_error)?.body
+			}
+			_ => {
+				let url = format!("{base_url}models/{model_name}");
+				web_client.do_get(&url, &headers).await.map_err(map_webc_error)?.body
+			}
+		};
+
+		let mut info = ModelInfo::new(model.clone(), Default::default());
+		info.display_name = body.x_get("name").ok();
+		info.context_window = body.x_get("context_window").or_else(|_| body.x_get("context_length")).ok();
+		info.max_output_tokens = body
+			.x_get("max_completion_tokens")
+			.or_else(|_| body.x_get("/top_provider/max_completion_tokens"))
+			.ok();
+		info.input_modalities = body
+			.x_get("input_modalities")
+			.or_else(|_| body.x_get("/architecture/input_modalities"))
+			.ok();
+		info.output_modalities = body
+			.x_get("output_modalities")
+			.or_else(|_| body.x_get("/architecture/output_modalities"))
+			.ok();
+		info.captured_raw_body = body;
+
+		Ok(info)
+	}
+}
@@ -0,0 +1,27 @@
+//! Health check of the OpenAI compatible APIs (OpenAI, Groq, xAI, DeepSeek, Ollama, ...) with the models listing.
+//! API Documentation: https://platform.openai.com/docs/api-reference/models/list
+
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::openai::OpenAIAdapter;
+use crate::webc::WebClient;
+use crate::{Error, Headers, Result, ServiceTarget};
+
+impl OpenAIAdapter {
+	/// NOTE: Used for all the OpenAI compatible adapters, so the error carries the target adapter kind.
+	pub(in crate::adapter) async fn ping(web_client: &WebClient, target: ServiceTarget) -> Result<u16> {
+		let ServiceTarget { endpoint, auth, model } = target;
+		let api_key = get_api_key(auth, &model)?;
+		let headers = Headers::from(("Authorization".to_string(), format!("Bearer {api_key}")));
+		let url = format!("{}models", endpoint.base_url());
+
+		let web_res = web_client
+			.do_get(&url, &headers)
+			.await
+			.map_err(|webc_error| Error::WebAdapterCall {
+				adapter_kind: model.adapter_kind,
+				webc_error,
+			})?;
+
+		Ok(web_res.status.as_u16())
+	}
+}
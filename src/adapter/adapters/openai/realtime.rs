@@ -0,0 +1,229 @@
+//! OpenAI Realtime API implementation (WebSocket)
+//! API Documentation: https://platform.openai.com/docs/api-reference/realtime
+
+use crate::adapter::adapters::support::{get_api_key, into_ws_url};
+use crate::adapter::openai::OpenAIAdapter;
+use crate::adapter::{AdapterKind, WebRequestData};
+use crate::chat::{PromptTokensDetails, ToolCall, Usage};
+use crate::realtime::{
+	RealtimeAudioFormat, RealtimeConfig, RealtimeEvent, RealtimeInput, RealtimeModality, TurnDetection,
+};
+use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use serde_json::{Value, json};
+use value_ext::JsonValueExt;
+
+impl OpenAIAdapter {
+	/// The realtime WebSocket url and headers, with the eventual `session.update` as payload (Null otherwise).
+	pub(in crate::adapter) fn to_realtime_request_data(
+		target: ServiceTarget,
+		config: Option<&RealtimeConfig>,
+	) -> Result<WebRequestData> {
+		let ServiceTarget { endpoint, auth, model } = target;
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+
+		// -- api_key & headers
+		let api_key = get_api_key(auth, &model)?;
+		let headers = Headers::from(("Authorization".to_string(), format!("Bearer {api_key}")));
+
+		// -- url
+		let url = format!("{}realtime?model={model_name}", into_ws_url(endpoint.base_url()));
+
+		// -- payload
+		let payload = match config {
+			Some(config) => session_update(config)?,
+			None => Value::Null,
+		};
+
+		Ok(WebRequestData { url, headers, payload })
+	}
+
+	pub(in crate::adapter) fn to_realtime_messages(input: RealtimeInput) -> Result<Vec<Value>> {
+		let message = match input {
+			RealtimeInput::Text(text) => json!({
+				"type": "conversation.item.create",
+				"item": {
+					"type": "message",
+					"role": "user",
+					"content": [{"type": "input_text", "text": text}]
+				}
+			}),
+			RealtimeInput::AudioChunk(audio) => json!({
+				"type": "input_audio_buffer.append",
+				"audio": BASE64.encode(audio)
+			}),
+			RealtimeInput::VideoFrame { .. } => {
+				return Err(Error::AdapterNotSupported {
+					adapter_kind: AdapterKind::OpenAI,
+					feature: "realtime video frames".to_string(),
+				});
+			}
+			RealtimeInput::AudioCommit => json!({"type": "input_audio_buffer.commit"}),
+			RealtimeInput::ToolResponse(tool_response) => json!({
+				"type": "conversation.item.create",
+				"item": {
+					"type": "function_call_output",
+					"call_id": tool_response.call_id,
+					"output": tool_response.content
+				}
+			}),
+			RealtimeInput::CreateResponse => json!({"type": "response.create"}),
+			RealtimeInput::CancelResponse => json!({"type": "response.cancel"}),
+			RealtimeInput::SessionUpdate(config) => session_update(&config)?,
+		};
+
+		Ok(vec![message])
+	}
+
+	pub(in crate::adapter) fn to_realtime_events(
+		model_iden: &ModelIden,
+		mut message: Value,
+	) -> Result<Vec<RealtimeEvent>> {
+		let typ: String = message.x_get("type")?;
+
+		// NOTE: The beta event names (e.g., `response.text.delta`) are supported as well.
+		let event = match typ.as_str() {
+			"session.created" => RealtimeEvent::SessionCreated,
+			"session.updated" => RealtimeEvent::SessionUpdated,
+			"input_audio_buffer.speech_started" => RealtimeEvent::SpeechStarted,
+			"input_audio_buffer.speech_stopped" => RealtimeEvent::SpeechStopped,
+			"conversation.item.input_audio_transcription.completed" => {
+				RealtimeEvent::InputTranscript(message.x_take("transcript")?)
+			}
+			"response.output_text.delta" | "response.text.delta" => RealtimeEvent::TextDelta(message.x_take("delta")?),
+			"response.output_audio.delta" | "response.audio.delta" => {
+				let delta: String = message.x_take("delta")?;
+				let audio = BASE64.decode(delta).map_err(|err| Error::WebStream {
+					model_iden: model_iden.clone(),
+					cause: format!("Invalid base64 audio delta. Cause: {err}"),
+				})?;
+				RealtimeEvent::AudioDelta(audio)
+			}
+			"response.output_audio_transcript.delta" | "response.audio_transcript.delta" => {
+				RealtimeEvent::AudioTranscriptDelta(message.x_take("delta")?)
+			}
+			"response.function_call_arguments.done" => {
+				let call_id: String = message.x_take("call_id")?;
+				let fn_name: String = message.x_take("name")?;
+				let arguments: String = message.x_take("arguments")?;
+				// NOTE: If the arguments are not valid JSON, keep them as a JSON string
+				let fn_arguments = serde_json::from_str(&arguments).unwrap_or(Value::String(arguments));
+				RealtimeEvent::ToolCall(ToolCall {
+					call_id,
+					fn_name,
+					fn_arguments,
+				})
+			}
+			"response.done" => {
+				let usage = message
+					.x_take::<Value>("/response/usage")
+					.ok()
+					.map(into_realtime_usage)
+					.unwrap_or_default();
+				RealtimeEvent::ResponseDone { usage }
+			}
+			"error" => RealtimeEvent::Error {
+				message: message.x_take("/error/message").unwrap_or_default(),
+				code: message.x_take("/error/code").ok(),
+			},
+			_ => RealtimeEvent::Other(message),
+		};
+
+		Ok(vec![event])
+	}
+}
+
+// region:    --- Support
+
+fn session_update(config: &RealtimeConfig) -> Result<Value> {
+	let mut session = json!({"type": "realtime"});
+
+	if let Some(instructions) = &config.instructions {
+		session.x_insert("instructions", instructions)?;
+	}
+
+	if let Some(output_modality) = config.output_modality {
+		let modality = match output_modality {
+			RealtimeModality::Audio => "audio",
+			RealtimeModality::Text => "text",
+		};
+		session.x_insert("output_modalities", [modality])?;
+	}
+
+	if let Some(audio_format) = config.audio_format {
+		let format = match audio_format {
+			RealtimeAudioFormat::Pcm16 { rate } => json!({"type": "audio/pcm", "rate": rate}),
+			RealtimeAudioFormat::G711Ulaw => json!({"type": "audio/pcmu"}),
+			RealtimeAudioFormat::G711Alaw => json!({"type": "audio/pcma"}),
+		};
+		session.x_insert("/audio/input/format", format.clone())?;
+		session.x_insert("/audio/output/format", format)?;
+	}
+
+	if let Some(turn_detection) = config.turn_detection {
+		let turn_detection = match turn_detection {
+			TurnDetection::ServerVad => json!({"type": "server_vad"}),
+			TurnDetection::SemanticVad => json!({"type": "semantic_vad"}),
+			TurnDetection::Manual => Value::Null,
+		};
+		session.x_insert("/audio/input/turn_detection", turn_detection)?;
+	}
+
+	if let Some(model) = &config.input_transcription_model {
+		session.x_insert("/audio/input/transcription", json!({"model": model}))?;
+	}
+
+	if let Some(voice) = &config.voice {
+		session.x_insert("/audio/output/voice", voice)?;
+	}
+
+	if let Some(tools) = &config.tools {
+		let tools = tools
+			.iter()
+			.map(|tool| {
+				json!({
+					"type": "function",
+					"name": tool.name,
+					"description": tool.description,
+					"parameters": tool.schema,
+				})
+			})
+			.collect::<Vec<Value>>();
+		session.x_insert("tools", tools)?;
+	}
+
+	if let Some(max_output_tokens) = config.max_output_tokens {
+		session.x_insert("max_output_tokens", max_output_tokens)?;
+	}
+
+	Ok(json!({
+		"type": "session.update",
+		"session": session
+	}))
+}
+
+/// The realtime usage has the `input_tokens` and `output_tokens` (not the chat completions `prompt_tokens`).
+fn into_realtime_usage(mut usage_value: Value) -> Usage {
+	let prompt_tokens: Option<i32> = usage_value.x_take("input_tokens").ok();
+	let completion_tokens: Option<i32> = usage_value.x_take("output_tokens").ok();
+	let total_tokens: Option<i32> = usage_value.x_take("total_tokens").ok();
+
+	let cached_tokens: Option<i32> = usage_value.x_take("/input_token_details/cached_tokens").ok();
+	let audio_tokens: Option<i32> = usage_value.x_take("/input_token_details/audio_tokens").ok();
+	let prompt_tokens_details = (cached_tokens.is_some() || audio_tokens.is_some()).then_some(PromptTokensDetails {
+		cache_creation_tokens: None,
+		cached_tokens,
+		audio_tokens,
+	});
+
+	Usage {
+		prompt_tokens,
+		prompt_tokens_details,
+		completion_tokens,
+		completion_tokens_details: None,
+		total_tokens,
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,399 @@
+//! OpenAI Responses API implementation, used for the chat requests with the `file_search` built-in tool
+//! or the reasoning summaries (which are not available in the Chat Completions API).
+//! API Documentation: https://platform.openai.com/docs/api-reference/responses
+//! Tool Documentation: https://platform.openai.com/docs/guides/tools-file-search
+
+use crate::adapter::adapters::support::{check_logit_bias, get_api_key};
+use crate::adapter::openai::OpenAIAdapter;
+use crate::adapter::{AdapterKind, ServiceType, WebRequestData};
+use crate::chat::{
+	BuiltInTool, ChatOptionsSet, ChatRequest, ChatResponse, ChatRole, Citation, CitationSource,
+	CompletionTokensDetails, ContentBlock, ContentFilterInfo, ContentPart, FileCitation, FinishReason, ImageSource,
+	MessageContent, PromptTokensDetails, ReasoningEffort, ToolChoice, Usage,
+};
+use crate::webc::WebResponse;
+use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use serde_json::{Value, json};
+use value_ext::JsonValueExt;
+
+impl OpenAIAdapter {
+	/// Returns true when the chat request must be sent to the Responses API
+	/// (i.e., has a `file_search`, `image_generation`, or `BuiltInTool::WebSearch` tool).
+	pub(super) fn requires_responses_api(chat_req: &ChatRequest) -> bool {
+		chat_req.tools.as_ref().is_some_and(|tools| {
+			tools.iter().any(|tool| {
+				tool.is_file_search()
+					|| tool.is_image_generation()
+					|| matches!(tool.builtin, Some(BuiltInTool::WebSearch(_)))
+			})
+		})
+	}
+
+	/// Returns true when the chat request asks for the reasoning summaries (`ChatOptions::reasoning_summary`),
+	/// which are only available in the Responses API (OpenAI adapter only).
+	pub(super) fn uses_responses_api_reasoning(model: &ModelIden, options_set: &ChatOptionsSet<'_, '_>) -> bool {
+		matches!(model.adapter_kind, AdapterKind::OpenAI) && options_set.reasoning_summary().is_some()
+	}
+
+	/// Returns true when the response body is a Responses API response.
+	pub(super) fn is_responses_api_body(body: &Value) -> bool {
+		body.get("object").and_then(Value::as_str) == Some("response")
+	}
+
+	pub(super) fn to_responses_web_request_data(
+		target: ServiceTarget,
+		service_type: ServiceType,
+		chat_req: ChatRequest,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		let ServiceTarget { model, auth, endpoint } = target;
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+		check_logit_bias(&model, &options_set)?;
+
+		// -- compute reasoning_effort and eventual trimmed model_name (as for the Chat Completions API)
+		let (reasoning_effort, model_name) = options_set
+			.reasoning_effort()
+			.cloned()
+			.map(|v| (Some(v), model_name))
+			.unwrap_or_else(|| ReasoningEffort::from_model_name(model_name));
+
+		// -- url & headers
+		let api_key = get_api_key(auth, &model)?;
+		let url = format!("{}responses", endpoint.base_url());
+		let headers = Headers::from(("Authorization".to_string(), format!("Bearer {api_key}")));
+
+		// -- payload
+		let tools = chat_req
+			.tools
+			.as_ref()
+			.map(|tools| {
+				tools
+					.iter()
+					.map(|tool| {
+						if let Some(builtin) = &tool.builtin {
+							match builtin {
+								BuiltInTool::WebSearch(config) => {
+									let mut tool_value = json!({"type": "web_search"});
+									if let Some(allowed_domains) = &config.allowed_domains {
+										tool_value.x_insert("/filters/allowed_domains", allowed_domains)?;
+									}
+									Ok(tool_value)
+								}
+								BuiltInTool::Computer(_)
+								| BuiltInTool::CodeExecution
+								| BuiltInTool::TextEditor(_)
+								| BuiltInTool::Bash => Err(Error::AdapterNotSupported {
+									adapter_kind: model.adapter_kind,
+									feature: format!("built-in tool {}", builtin.name()),
+								}),
+							}
+						} else if tool.is_file_search() || tool.is_image_generation() {
+							let mut tool_value = json!({"type": tool.name});
+							if let Some(Value::Object(config)) = tool.config.clone() {
+								for (name, value) in config {
+									tool_value[name] = value;
+								}
+							}
+							Ok(tool_value)
+						} else {
+							let parameters = if tool.is_strict() {
+								Some(tool.strict_schema()?)
+							} else {
+								tool.schema.clone()
+							};
+							Ok(json!({
+								"type": "function",
+								"name": tool.name,
+								"description": tool.description,
+								"parameters": parameters,
+								"strict": tool.strict.unwrap_or(false),
+							}))
+						}
+					})
+					.collect::<Result<Vec<Value>>>()
+			})
+			.transpose()?;
+
+		let mut payload = json!({
+			"model": model_name,
+			"input": into_responses_input(chat_req),
+		});
+		if matches!(service_type, ServiceType::ChatStream) {
+			payload.x_insert("stream", true)?;
+		}
+		if let Some(keyword) = reasoning_effort.as_ref().and_then(ReasoningEffort::openai_effort) {
+			payload.x_insert("/reasoning/effort", keyword)?;
+		}
+		if let Some(reasoning_summary) = options_set.reasoning_summary() {
+			payload.x_insert("/reasoning/summary", reasoning_summary.as_str())?;
+		}
+		if let Some(tools) = tools {
+			payload.x_insert("tools", tools)?;
+		}
+		if let Some(tool_choice) = options_set.tool_choice() {
+			let tool_choice = match tool_choice {
+				ToolChoice::Auto => json!("auto"),
+				ToolChoice::None => json!("none"),
+				ToolChoice::Required => json!("required"),
+				ToolChoice::Named(name) => json!({"type": "function", "name": name}),
+			};
+			payload.x_insert("tool_choice", tool_choice)?;
+		}
+		// NOTE: OpenAI rejects `parallel_tool_calls` without tools.
+		if let Some(parallel_tool_calls) = options_set.parallel_tool_calls()
+			&& payload.get("tools").is_some()
+		{
+			payload.x_insert("parallel_tool_calls", parallel_tool_calls)?;
+		}
+		if let Some(temperature) = options_set.temperature() {
+			payload.x_insert("temperature", temperature)?;
+		}
+		if let Some(max_tokens) = options_set.max_tokens() {
+			payload.x_insert("max_output_tokens", max_tokens)?;
+		}
+		if let Some(top_p) = options_set.top_p() {
+			payload.x_insert("top_p", top_p)?;
+		}
+		if let Some(user) = options_set.user() {
+			payload.x_insert("user", user)?;
+		}
+		if let Some(metadata) = options_set.metadata() {
+			payload.x_insert("metadata", metadata)?;
+		}
+		if let Some(store) = options_set.store() {
+			payload.x_insert("store", store)?;
+		}
+		if options_set.prediction().is_some() {
+			tracing::warn!("The OpenAI Responses API does not support prediction, ignoring it.");
+		}
+		if options_set.frequency_penalty().is_some() || options_set.presence_penalty().is_some() {
+			tracing::warn!(
+				"The OpenAI Responses API does not support frequency_penalty/presence_penalty, ignoring them."
+			);
+		}
+
+		Ok(WebRequestData { url, headers, payload })
+	}
+
+	pub(super) fn to_responses_chat_response(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatResponse> {
+		let WebResponse { mut body, .. } = web_response;
+
+		let captured_raw_body = options_set.capture_raw_body().unwrap_or_default().then(|| body.clone());
+
+		// -- Capture the provider_model_iden
+		let provider_model_name: Option<String> = body.x_remove("model").ok();
+		let provider_model_iden = model_iden.from_optional_name(provider_model_name);
+
+		// -- Capture the usage
+		let usage = body.x_take("usage").map(into_responses_usage).unwrap_or_default();
+
+		// -- Capture the eventual finish reason (the `incomplete_details.reason`, e.g., `max_output_tokens`)
+		let finish_reason = body
+			.x_get_as::<&str>("/incomplete_details/reason")
+			.or_else(|_| body.x_get_as::<&str>("status"))
+			.ok()
+			.map(FinishReason::from_provider);
+
+		// -- Capture the text content and the file citations
+		let output: Vec<Value> = body.x_take("output").unwrap_or_default();
+		let mut text = String::new();
+		let mut file_citations: Vec<FileCitation> = Vec::new();
+		let mut web_citations: Vec<Citation> = Vec::new();
+		let mut content_filter: Option<ContentFilterInfo> = None;
+		for item in output
+			.iter()
+			.filter(|item| item.x_get_as::<&str>("type").ok() == Some("message"))
+		{
+			let Some(parts) = item.get("content").and_then(Value::as_array) else {
+				continue;
+			};
+			if let Some(refusal) = parts.iter().find(|part| part.x_get_as::<&str>("type").ok() == Some("refusal")) {
+				content_filter = Some(ContentFilterInfo::refusal(refusal.x_get("refusal").ok()));
+			}
+			for part in parts
+				.iter()
+				.filter(|part| part.x_get_as::<&str>("type").ok() == Some("output_text"))
+			{
+				// NOTE: The citation index is relative to the part text, so it is offset by the text captured so far.
+				let offset = text.chars().count();
+				if let Ok(part_text) = part.x_get_as::<&str>("text") {
+					text.push_str(part_text);
+				}
+				let annotations = part.get("annotations").and_then(Value::as_array).into_iter().flatten();
+				for annotation in annotations {
+					if annotation.x_get_as::<&str>("type").ok() == Some("url_citation") {
+						let start = annotation.x_get::<usize>("start_index").unwrap_or_default() + offset;
+						let end = annotation.x_get::<usize>("end_index").unwrap_or_default() + offset;
+						web_citations.push(Citation {
+							source: CitationSource::Web,
+							id: annotation.x_get("url").unwrap_or_default(),
+							title: annotation.x_get("title").ok(),
+							span: Some(start..end.max(start)),
+							cited_text: None,
+						});
+						continue;
+					}
+					if annotation.x_get_as::<&str>("type").ok() != Some("file_citation") {
+						continue;
+					}
+					let Ok(file_id) = annotation.x_get::<String>("file_id") else {
+						continue;
+					};
+					file_citations.push(FileCitation {
+						file_id,
+						file_name: annotation.x_get("filename").ok(),
+						index: annotation.x_get::<usize>("index").ok().map(|index| index + offset),
+					});
+				}
+			}
+		}
+
+		// -- Capture the reasoning summaries (the `reasoning` items, when `ChatOptions::reasoning_summary` is set)
+		let reasoning_summaries: Vec<&str> = output
+			.iter()
+			.filter(|item| item.x_get_as::<&str>("type").ok() == Some("reasoning"))
+			.filter_map(|item| item.get("summary").and_then(Value::as_array))
+			.flatten()
+			.filter(|summary| summary.x_get_as::<&str>("type").ok() == Some("summary_text"))
+			.filter_map(|summary| summary.x_get_as::<&str>("text").ok())
+			.collect();
+		let reasoning_content = (!reasoning_summaries.is_empty()).then(|| reasoning_summaries.join("\n\n"));
+
+		// -- Capture the generated images (the `image_generation` tool calls)
+		let images: Vec<ContentBlock> = output
+			.iter()
+			.filter(|item| item.x_get_as::<&str>("type").ok() == Some("image_generation_call"))
+			.filter_map(|item| {
+				let data = item.x_get_as::<&str>("result").ok()?;
+				let format = item.x_get_as::<&str>("output_format").unwrap_or("png");
+				Some(ContentBlock::Image {
+					content_type: format!("image/{format}"),
+					source: ImageSource::Base64(data.into()),
+				})
+			})
+			.collect();
+
+		let text = text.trim().to_string();
+		let mut content: Vec<MessageContent> = if text.is_empty() { Vec::new() } else { vec![text.into()] };
+		if !images.is_empty() {
+			content.push(MessageContent::Blocks(images));
+		}
+
+		Ok(ChatResponse {
+			content,
+			reasoning_content,
+			model_iden,
+			provider_model_iden,
+			system_fingerprint: None,
+			usage,
+			captured_raw_body,
+			response_digest: None,
+			response_headers: None,
+			finish_reason,
+			choices: Vec::new(),
+			logprobs: None,
+			content_filter,
+			prompt_leaks: None,
+			stop_sequence: None,
+			file_citations: Some(file_citations),
+			web_citations: (!web_citations.is_empty()).then_some(web_citations),
+			output_repair: None,
+			ai_disclosure: None,
+			provider_exts: Default::default(),
+		})
+	}
+}
+
+// region:    --- Support
+
+/// Build the Responses API `input` messages.
+/// NOTE: Like for the Chat Completions API, the tool calls and tool responses are not supported for now (skipped).
+fn into_responses_input(chat_req: ChatRequest) -> Vec<Value> {
+	let mut input: Vec<Value> = Vec::new();
+
+	if let Some(system) = chat_req.system {
+		input.push(json!({"role": "system", "content": system}));
+	}
+
+	for msg in chat_req.messages {
+		let role = match msg.role {
+			ChatRole::System => "system",
+			ChatRole::Developer => "developer",
+			ChatRole::User => "user",
+			ChatRole::Assistant => "assistant",
+			ChatRole::Tool => continue,
+		};
+		let content = match msg.content {
+			MessageContent::Text(content) => json!(content),
+			MessageContent::Parts(parts) => json!(
+				parts
+					.iter()
+					.filter_map(|part| match part {
+						ContentPart::Text(text) => Some(json!({"type": "input_text", "text": text})),
+						ContentPart::Image { content_type, source } => match source {
+							ImageSource::Url(url) => Some(json!({"type": "input_image", "image_url": url})),
+							ImageSource::Base64(content) => Some(
+								json!({"type": "input_image", "image_url": format!("data:{content_type};base64,{content}")})
+							),
+						},
+						ContentPart::File(file_ref) => Some(json!({"type": "input_file", "file_id": file_ref.id})),
+						ContentPart::Document { content_type, source } => match source {
+							ImageSource::Url(url) => Some(json!({"type": "input_file", "file_url": url})),
+							ImageSource::Base64(content) => Some(json!({
+								"type": "input_file",
+								"filename": ContentPart::document_file_name(content_type),
+								"file_data": format!("data:{content_type};base64,{content}"),
+							})),
+						},
+						ContentPart::Audio { .. } => {
+							tracing::warn!(
+								"The OpenAI Responses API does not support audio inputs, skipping the audio part"
+							);
+							None
+						}
+						ContentPart::Video { .. } => {
+							tracing::warn!(
+								"The OpenAI Responses API does not support video inputs, skipping the video part"
+							);
+							None
+						}
+					})
+					.collect::<Vec<Value>>()
+			),
+			MessageContent::ToolCalls(_) | MessageContent::ToolResponses(_) | MessageContent::Blocks(_) => continue,
+		};
+		input.push(json!({"role": role, "content": content}));
+	}
+
+	input
+}
+
+pub(super) fn into_responses_usage(mut usage_value: Value) -> Usage {
+	let prompt_tokens: Option<i32> = usage_value.x_take("input_tokens").ok();
+	let completion_tokens: Option<i32> = usage_value.x_take("output_tokens").ok();
+	let total_tokens: Option<i32> = usage_value.x_take("total_tokens").ok();
+	let cached_tokens: Option<i32> = usage_value.x_take("/input_tokens_details/cached_tokens").ok();
+	let reasoning_tokens: Option<i32> = usage_value.x_take("/output_tokens_details/reasoning_tokens").ok();
+
+	let mut usage = Usage {
+		prompt_tokens,
+		prompt_tokens_details: Some(PromptTokensDetails {
+			cached_tokens: cached_tokens.filter(|v| *v > 0),
+			..Default::default()
+		}),
+		completion_tokens,
+		completion_tokens_details: Some(CompletionTokensDetails {
+			reasoning_tokens: reasoning_tokens.filter(|v| *v > 0),
+			..Default::default()
+		}),
+		total_tokens,
+	};
+	usage.compact_details();
+	usage
+}
+
+// endregion: --- Support
@@ -0,0 +1,148 @@
+//! The streamer of the OpenAI Responses API, used for the streamed chat requests with the reasoning summaries.
+//! API Documentation: https://platform.openai.com/docs/api-reference/responses-streaming
+
+use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions};
+use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
+use crate::adapter::openai::responses::into_responses_usage;
+use crate::chat::{ChatOptionsSet, FinishReason};
+use crate::{Error, ModelIden, Result};
+use reqwest_eventsource::{Event, EventSource};
+use serde_json::Value;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use value_ext::JsonValueExt;
+
+pub struct OpenAIResponsesStreamer {
+	inner: EventSource,
+	options: StreamerOptions,
+
+	// -- Set by the poll_next
+	/// Flag to prevent polling the EventSource after the `response.completed` event
+	done: bool,
+	/// If a reasoning summary part was started (of any reasoning item), to separate the next ones
+	has_reasoning_summary: bool,
+	captured_data: StreamerCapturedData,
+}
+
+impl OpenAIResponsesStreamer {
+	pub fn new(inner: EventSource, model_iden: ModelIden, options_set: ChatOptionsSet<'_, '_>) -> Self {
+		Self {
+			inner,
+			done: false,
+			has_reasoning_summary: false,
+			options: StreamerOptions::new(model_iden, options_set),
+			captured_data: Default::default(),
+		}
+	}
+}
+
+impl futures::Stream for OpenAIResponsesStreamer {
+	type Item = Result<InterStreamEvent>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		if self.done {
+			return Poll::Ready(None);
+		}
+		while let Poll::Ready(event) = Pin::new(&mut self.inner).poll_next(cx) {
+			match event {
+				Some(Ok(Event::Open)) => return Poll::Ready(Some(Ok(InterStreamEvent::Start))),
+				Some(Ok(Event::Message(message))) => {
+					let mut message_data: Value =
+						serde_json::from_str(&message.data).map_err(|serde_error| Error::StreamParse {
+							model_iden: self.options.model_iden.clone(),
+							serde_error,
+						})?;
+
+					let event_type: String = message_data.x_take("type").unwrap_or_default();
+					match event_type.as_str() {
+						// -- Text Content
+						"response.output_text.delta" => {
+							let content: String = message_data.x_take("delta").unwrap_or_default();
+							if self.options.capture_content {
+								match self.captured_data.content {
+									Some(ref mut c) => c.push_str(&content),
+									None => self.captured_data.content = Some(content.clone()),
+								}
+							}
+							return Poll::Ready(Some(Ok(InterStreamEvent::Chunk(content))));
+						}
+
+						// -- Reasoning Summary
+						// NOTE: The summary parts are separated by a blank line (as in `ChatResponse.reasoning_content`),
+						//       also across the reasoning items (the `summary_index` restarts at 0 for each item).
+						"response.reasoning_summary_part.added" => {
+							let is_next_summary = std::mem::replace(&mut self.has_reasoning_summary, true);
+							if is_next_summary {
+								let separator = "\n\n".to_string();
+								if self.options.capture_reasoning_content
+									&& let Some(ref mut c) = self.captured_data.reasoning_content
+								{
+									c.push_str(&separator);
+								}
+								return Poll::Ready(Some(Ok(InterStreamEvent::ReasoningChunk(separator))));
+							}
+						}
+						"response.reasoning_summary_text.delta" => {
+							let reasoning_content: String = message_data.x_take("delta").unwrap_or_default();
+							if self.options.capture_reasoning_content {
+								match self.captured_data.reasoning_content {
+									Some(ref mut c) => c.push_str(&reasoning_content),
+									None => self.captured_data.reasoning_content = Some(reasoning_content.clone()),
+								}
+							}
+							return Poll::Ready(Some(Ok(InterStreamEvent::ReasoningChunk(reasoning_content))));
+						}
+
+						// -- End Message
+						"response.completed" | "response.incomplete" => {
+							self.done = true;
+
+							let mut response: Value = message_data.x_take("response").unwrap_or_default();
+							let finish_reason = response
+								.x_get_as::<&str>("/incomplete_details/reason")
+								.or_else(|_| response.x_get_as::<&str>("status"))
+								.ok()
+								.map(FinishReason::from_provider);
+							let captured_usage = if self.options.capture_usage {
+								response.x_take("usage").ok().map(into_responses_usage)
+							} else {
+								None
+							};
+
+							let inter_stream_end = InterStreamEnd {
+								captured_usage,
+								captured_text_content: self.captured_data.content.take(),
+								captured_reasoning_content: self.captured_data.reasoning_content.take(),
+								captured_tool_calls: self.captured_data.tool_calls.take(),
+								finish_reason,
+								stop_sequence: None,
+							};
+
+							return Poll::Ready(Some(Ok(InterStreamEvent::End(inter_stream_end))));
+						}
+
+						// -- Error
+						"response.failed" | "error" => {
+							self.done = true;
+							return Poll::Ready(Some(Err(Error::StreamEventError {
+								model_iden: self.options.model_iden.clone(),
+								body: message_data,
+							})));
+						}
+
+						// NOTE: The other events (e.g., `response.created`, `response.output_item.done`) are not needed.
+						_ => (),
+					}
+				}
+				Some(Err(err)) => {
+					tracing::error!("Error: {}", err);
+					return Poll::Ready(Some(Err(Error::ReqwestEventSource(err.into()))));
+				}
+				None => {
+					return Poll::Ready(None);
+				}
+			}
+		}
+		Poll::Pending
+	}
+}
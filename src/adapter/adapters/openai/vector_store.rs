@@ -0,0 +1,163 @@
+//! OpenAI Vector Stores API implementation
+//! API Documentation: https://platform.openai.com/docs/api-reference/vector-stores
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::openai::OpenAIAdapter;
+use crate::files::{VectorStore, VectorStoreFile};
+use crate::webc::{self, WebClient};
+use crate::{Error, Headers, Result, ServiceTarget};
+use serde::Deserialize;
+use serde_json::json;
+use value_ext::JsonValueExt;
+
+// region:    --- OpenAI Vector Store Responses
+
+#[derive(Debug, Deserialize)]
+struct OpenAIVectorStore {
+	id: String,
+	name: Option<String>,
+	status: String,
+	#[serde(default)]
+	file_counts: OpenAIFileCounts,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OpenAIFileCounts {
+	#[serde(default)]
+	completed: u32,
+	#[serde(default)]
+	in_progress: u32,
+	#[serde(default)]
+	failed: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIVectorStoreFile {
+	id: String,
+	vector_store_id: String,
+	status: String,
+	last_error: Option<OpenAIVectorStoreFileError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIVectorStoreFileError {
+	message: Option<String>,
+}
+
+// endregion: --- OpenAI Vector Store Responses
+
+impl OpenAIAdapter {
+	pub(in crate::adapter) async fn create_vector_store(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		name: &str,
+		file_ids: Vec<String>,
+	) -> Result<VectorStore> {
+		let (base_url, headers) = vector_store_base_url_and_headers(target)?;
+		let url = format!("{base_url}vector_stores");
+
+		let payload = json!({
+			"name": name,
+			"file_ids": file_ids,
+		});
+
+		let web_res = web_client.do_post(&url, &headers, payload).await.map_err(map_webc_error)?;
+
+		let vector_store: OpenAIVectorStore = serde_json::from_value(web_res.body)?;
+		Ok(into_vector_store(vector_store))
+	}
+
+	pub(in crate::adapter) async fn list_vector_stores(
+		web_client: &WebClient,
+		target: ServiceTarget,
+	) -> Result<Vec<VectorStore>> {
+		let (base_url, headers) = vector_store_base_url_and_headers(target)?;
+		let url = format!("{base_url}vector_stores");
+
+		let mut web_res = web_client.do_get(&url, &headers).await.map_err(map_webc_error)?;
+
+		let vector_stores: Vec<OpenAIVectorStore> = web_res.body.x_take("data")?;
+		Ok(vector_stores.into_iter().map(into_vector_store).collect())
+	}
+
+	pub(in crate::adapter) async fn get_vector_store(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		vector_store_id: &str,
+	) -> Result<VectorStore> {
+		let (base_url, headers) = vector_store_base_url_and_headers(target)?;
+		let url = format!("{base_url}vector_stores/{vector_store_id}");
+
+		let web_res = web_client.do_get(&url, &headers).await.map_err(map_webc_error)?;
+
+		let vector_store: OpenAIVectorStore = serde_json::from_value(web_res.body)?;
+		Ok(into_vector_store(vector_store))
+	}
+
+	pub(in crate::adapter) async fn delete_vector_store(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		vector_store_id: &str,
+	) -> Result<()> {
+		let (base_url, headers) = vector_store_base_url_and_headers(target)?;
+		let url = format!("{base_url}vector_stores/{vector_store_id}");
+
+		web_client.do_delete(&url, &headers).await.map_err(map_webc_error)?;
+
+		Ok(())
+	}
+
+	pub(in crate::adapter) async fn attach_vector_store_file(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		vector_store_id: &str,
+		file_id: &str,
+	) -> Result<VectorStoreFile> {
+		let (base_url, headers) = vector_store_base_url_and_headers(target)?;
+		let url = format!("{base_url}vector_stores/{vector_store_id}/files");
+
+		let web_res = web_client
+			.do_post(&url, &headers, json!({"file_id": file_id}))
+			.await
+			.map_err(map_webc_error)?;
+
+		let file: OpenAIVectorStoreFile = serde_json::from_value(web_res.body)?;
+		Ok(VectorStoreFile {
+			file_id: file.id,
+			vector_store_id: file.vector_store_id,
+			status: file.status,
+			error_message: file.last_error.and_then(|err| err.message),
+		})
+	}
+}
+
+// region:    --- Support
+
+fn vector_store_base_url_and_headers(target: ServiceTarget) -> Result<(String, Headers)> {
+	let ServiceTarget { endpoint, auth, model } = target;
+	let api_key = get_api_key(auth, &model)?;
+	let headers = Headers::from(("Authorization".to_string(), format!("Bearer {api_key}")));
+	Ok((endpoint.base_url().to_string(), headers))
+}
+
+fn map_webc_error(webc_error: webc::Error) -> Error {
+	Error::WebAdapterCall {
+		adapter_kind: AdapterKind::OpenAI,
+		webc_error,
+	}
+}
+
+fn into_vector_store(vector_store: OpenAIVectorStore) -> VectorStore {
+	VectorStore {
+		adapter_kind: AdapterKind::OpenAI,
+		id: vector_store.id,
+		name: vector_store.name,
+		status: vector_store.status,
+		completed_file_count: vector_store.file_counts.completed,
+		in_progress_file_count: vector_store.file_counts.in_progress,
+		failed_file_count: vector_store.file_counts.failed,
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,121 @@
+//! OpenAI Videos API implementation (Sora)
+//! API Documentation: https://platform.openai.com/docs/api-reference/videos
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::openai::OpenAIAdapter;
+use crate::videogen::{VideoJob, VideoJobStatus, VideoRequest};
+use crate::webc::{self, WebClient};
+use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use reqwest::multipart::Form;
+use serde::Deserialize;
+
+// region:    --- OpenAI Video Response
+
+#[derive(Debug, Deserialize)]
+struct OpenAIVideo {
+	id: String,
+	model: String,
+	status: String,
+	progress: Option<u32>,
+	error: Option<OpenAIVideoError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIVideoError {
+	message: Option<String>,
+}
+
+// endregion: --- OpenAI Video Response
+
+impl OpenAIAdapter {
+	pub(in crate::adapter) async fn create_video_job(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		model_name: &str,
+		req: VideoRequest,
+	) -> Result<VideoJob> {
+		let (base_url, headers) = videos_base_url_and_headers(target)?;
+		let url = format!("{base_url}videos");
+
+		let mut form = Form::new().text("model", model_name.to_string()).text("prompt", req.prompt);
+		if let Some(duration_seconds) = req.duration_seconds {
+			form = form.text("seconds", duration_seconds.to_string());
+		}
+		if let Some(size) = req.size {
+			form = form.text("size", size);
+		}
+
+		let web_res = web_client
+			.do_post_multipart(&url, &headers, form)
+			.await
+			.map_err(map_webc_error)?;
+
+		let video: OpenAIVideo = serde_json::from_value(web_res.body)?;
+		Ok(into_video_job(video))
+	}
+
+	pub(in crate::adapter) async fn get_video_job(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		job_id: &str,
+	) -> Result<VideoJob> {
+		let (base_url, headers) = videos_base_url_and_headers(target)?;
+		let url = format!("{base_url}videos/{job_id}");
+
+		let web_res = web_client.do_get(&url, &headers).await.map_err(map_webc_error)?;
+
+		let video: OpenAIVideo = serde_json::from_value(web_res.body)?;
+		Ok(into_video_job(video))
+	}
+
+	pub(in crate::adapter) async fn download_video(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		job: &VideoJob,
+	) -> Result<Vec<u8>> {
+		let (base_url, headers) = videos_base_url_and_headers(target)?;
+		let url = format!("{base_url}videos/{}/content", job.id);
+
+		let bytes = web_client.do_get_bytes(&url, &headers).await.map_err(map_webc_error)?;
+
+		Ok(bytes.to_vec())
+	}
+}
+
+// region:    --- Support
+
+fn videos_base_url_and_headers(target: ServiceTarget) -> Result<(String, Headers)> {
+	let ServiceTarget { endpoint, auth, model } = target;
+	let api_key = get_api_key(auth, &model)?;
+	let headers = Headers::from(("Authorization".to_string(), format!("Bearer {api_key}")));
+	Ok((endpoint.base_url().to_string(), headers))
+}
+
+fn map_webc_error(webc_error: webc::Error) -> Error {
+	Error::WebAdapterCall {
+		adapter_kind: AdapterKind::OpenAI,
+		webc_error,
+	}
+}
+
+fn into_video_job(video: OpenAIVideo) -> VideoJob {
+	let status = match video.status.as_str() {
+		"queued" => VideoJobStatus::Queued,
+		"completed" => VideoJobStatus::Completed,
+		"failed" => VideoJobStatus::Failed,
+		// "in_progress" and the eventual future statuses
+		_ => VideoJobStatus::InProgress,
+	};
+
+	VideoJob {
+		model_iden: ModelIden::new(AdapterKind::OpenAI, video.model),
+		id: video.id,
+		status,
+		progress: video.progress,
+		video_uri: None,
+		error_message: video.error.and_then(|err| err.message),
+	}
+}
+
+// endregion: --- Support
@@ -13,6 +13,52 @@ pub fn get_api_key(auth: AuthData, model: &ModelIden) -> Result<String> {
 	})
 }
 
+/// Fail when the embedding dimensions (`EmbedOptions.dimensions`) are set for a model
+/// which does not support the dimensions reduction (rather than the provider silently ignoring it or failing).
+pub fn check_embed_dimensions(model: &ModelIden, dimensions: Option<usize>, supported: bool) -> Result<()> {
+	if dimensions.is_some() && !supported {
+		return Err(Error::EmbedDimensionsNotSupported {
+			model_iden: model.clone(),
+		});
+	}
+	Ok(())
+}
+
+/// Fail when the `ChatOptions.logit_bias` is set for an adapter which does not support it
+/// (rather than the provider silently ignoring it).
+pub fn check_logit_bias(model: &ModelIden, options_set: &ChatOptionsSet<'_, '_>) -> Result<()> {
+	if options_set.logit_bias().is_some() {
+		return Err(Error::AdapterNotSupported {
+			adapter_kind: model.adapter_kind,
+			feature: "logit_bias".to_string(),
+		});
+	}
+	Ok(())
+}
+
+/// The normalized usage of an embed response: the input tokens are both the `prompt_tokens` and the `total_tokens`
+/// (embeddings have no output tokens), so the cost accounting is the same for all the embed adapters.
+pub fn embed_usage(input_tokens: Option<i32>) -> Usage {
+	Usage {
+		prompt_tokens: input_tokens,
+		prompt_tokens_details: None,
+		completion_tokens: None,
+		completion_tokens_details: None,
+		total_tokens: input_tokens,
+	}
+}
+
+/// Turn a `https://` (or `http://`) base url into its WebSocket `wss://` (or `ws://`) url.
+pub fn into_ws_url(url: &str) -> String {
+	if let Some(rest) = url.strip_prefix("https://") {
+		format!("wss://{rest}")
+	} else if let Some(rest) = url.strip_prefix("http://") {
+		format!("ws://{rest}")
+	} else {
+		url.to_string()
+	}
+}
+
 // region:    --- StreamerChatOptions
 
 #[derive(Debug)]
@@ -46,6 +92,8 @@ pub struct StreamerCapturedData {
 	pub content: Option<String>,
 	pub reasoning_content: Option<String>,
 	pub tool_calls: Option<Vec<crate::chat::ToolCall>>,
+	pub finish_reason: Option<crate::chat::FinishReason>,
+	pub stop_sequence: Option<String>,
 }
 
 // endregion: --- Streamer Captured Data
@@ -1,4 +1,3 @@
-use crate::ModelIden;
 use crate::adapter::anthropic::AnthropicAdapter;
 use crate::adapter::cohere::CohereAdapter;
 use crate::adapter::gemini::GeminiAdapter;
@@ -6,11 +5,20 @@ use crate::adapter::ollama::OllamaAdapter;
 use crate::adapter::openai::OpenAIAdapter;
 use crate::adapter::openrouter::OpenRouterAdapter;
 use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
-use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
+use crate::chat::{CachedContent, ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse, TokenCount};
 use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse};
-use crate::webc::WebResponse;
-use crate::{Result, ServiceTarget};
+use crate::files::{FileRef, FileUpload, VectorStore, VectorStoreFile};
+use crate::finetune::{FineTuneJob, FineTuneRequest};
+use crate::ollama::{OllamaModelInfo, OllamaPullStream, OllamaRunningModel};
+use crate::realtime::{RealtimeConfig, RealtimeEvent, RealtimeInput};
+use crate::usage_report::{CostReport, UsageReport, UsageReportRequest};
+use crate::videogen::{VideoJob, VideoRequest};
+use crate::webc::{WebClient, WebResponse};
+use crate::{Error, Result, ServiceTarget};
+use crate::{ModelIden, ModelInfo};
 use reqwest::RequestBuilder;
+use serde_json::Value;
+use std::time::Duration;
 
 use super::groq::GroqAdapter;
 use crate::adapter::deepseek::DeepSeekAdapter;
@@ -97,8 +105,10 @@ impl AdapterDispatcher {
 		chat_req: ChatRequest,
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
+		let extra_headers = options_set.extra_headers().cloned();
+		let extra_body = options_set.extra_body().cloned();
 		let adapter_kind = &target.model.adapter_kind;
-		match adapter_kind {
+		let mut web_request_data = match adapter_kind {
 			AdapterKind::OpenAI => OpenAIAdapter::to_web_request_data(target, service_type, chat_req, options_set),
 			AdapterKind::Anthropic => {
 				AnthropicAdapter::to_web_request_data(target, service_type, chat_req, options_set)
@@ -114,7 +124,19 @@ impl AdapterDispatcher {
 			AdapterKind::Xai => XaiAdapter::to_web_request_data(target, service_type, chat_req, options_set),
 			AdapterKind::DeepSeek => DeepSeekAdapter::to_web_request_data(target, service_type, chat_req, options_set),
 			AdapterKind::Zhipu => ZhipuAdapter::to_web_request_data(target, service_type, chat_req, options_set),
+		}?;
+
+		// NOTE: The extra headers are merged after the adapter auth headers, so they can override them.
+		if let Some(extra_headers) = extra_headers {
+			web_request_data.headers.merge(extra_headers);
+		}
+
+		// NOTE: The extra body is merged last, so it can override (or remove with null) what the adapter built.
+		if let Some(extra_body) = extra_body {
+			merge_json(&mut web_request_data.payload, extra_body);
 		}
+
+		Ok(web_request_data)
 	}
 
 	pub fn to_chat_response(
@@ -198,3 +220,450 @@ impl AdapterDispatcher {
 		}
 	}
 }
+
+/// Ping API
+///
+/// NOTE: Supported for all adapters (the OpenAI compatible ones, including Cohere and Ollama, use the `models` listing).
+impl AdapterDispatcher {
+	/// Returns the success HTTP status.
+	pub async fn ping(web_client: &WebClient, target: ServiceTarget) -> Result<u16> {
+		match target.model.adapter_kind {
+			AdapterKind::Anthropic => AnthropicAdapter::ping(web_client, target).await,
+			AdapterKind::Gemini => GeminiAdapter::ping(web_client, target).await,
+			AdapterKind::OpenAI
+			| AdapterKind::Ollama
+			| AdapterKind::Cohere
+			| AdapterKind::Groq
+			| AdapterKind::Nebius
+			| AdapterKind::OpenRouter
+			| AdapterKind::Xai
+			| AdapterKind::DeepSeek
+			| AdapterKind::Zhipu => OpenAIAdapter::ping(web_client, target).await,
+		}
+	}
+}
+
+/// Model Info API
+///
+/// NOTE: Supported for all adapters (the OpenAI compatible ones, including Cohere and Ollama, use the `models/{id}` endpoint).
+impl AdapterDispatcher {
+	pub async fn model_info(web_client: &WebClient, target: ServiceTarget) -> Result<ModelInfo> {
+		match target.model.adapter_kind {
+			AdapterKind::Anthropic => AnthropicAdapter::model_info(web_client, target).await,
+			AdapterKind::Gemini => GeminiAdapter::model_info(web_client, target).await,
+			AdapterKind::OpenAI
+			| AdapterKind::Ollama
+			| AdapterKind::Cohere
+			| AdapterKind::Groq
+			| AdapterKind::Nebius
+			| AdapterKind::OpenRouter
+			| AdapterKind::Xai
+			| AdapterKind::DeepSeek
+			| AdapterKind::Zhipu => OpenAIAdapter::model_info(web_client, target).await,
+		}
+	}
+}
+
+/// Files API
+///
+/// NOTE: Only OpenAI, Anthropic, and Gemini support the Files API for now.
+///       Those are not part of the `Adapter` trait, as they execute the web requests directly
+///       (multipart uploads), and only few adapters support them.
+impl AdapterDispatcher {
+	pub async fn upload_file(web_client: &WebClient, target: ServiceTarget, file: FileUpload) -> Result<FileRef> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::upload_file(web_client, target, file).await,
+			AdapterKind::Anthropic => AnthropicAdapter::upload_file(web_client, target, file).await,
+			AdapterKind::Gemini => GeminiAdapter::upload_file(web_client, target, file).await,
+			adapter_kind => Err(files_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn list_files(web_client: &WebClient, target: ServiceTarget) -> Result<Vec<FileRef>> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::list_files(web_client, target).await,
+			AdapterKind::Anthropic => AnthropicAdapter::list_files(web_client, target).await,
+			AdapterKind::Gemini => GeminiAdapter::list_files(web_client, target).await,
+			adapter_kind => Err(files_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn get_file(web_client: &WebClient, target: ServiceTarget, file_id: &str) -> Result<FileRef> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::get_file(web_client, target, file_id).await,
+			AdapterKind::Anthropic => AnthropicAdapter::get_file(web_client, target, file_id).await,
+			AdapterKind::Gemini => GeminiAdapter::get_file(web_client, target, file_id).await,
+			adapter_kind => Err(files_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn delete_file(web_client: &WebClient, target: ServiceTarget, file_id: &str) -> Result<()> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::delete_file(web_client, target, file_id).await,
+			AdapterKind::Anthropic => AnthropicAdapter::delete_file(web_client, target, file_id).await,
+			AdapterKind::Gemini => GeminiAdapter::delete_file(web_client, target, file_id).await,
+			adapter_kind => Err(files_not_supported(adapter_kind)),
+		}
+	}
+}
+
+/// Vector Stores API
+///
+/// NOTE: Only OpenAI supports vector stores (for the `file_search` tool) for now.
+impl AdapterDispatcher {
+	pub async fn create_vector_store(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		name: &str,
+		file_ids: Vec<String>,
+	) -> Result<VectorStore> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::create_vector_store(web_client, target, name, file_ids).await,
+			adapter_kind => Err(vector_store_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn list_vector_stores(web_client: &WebClient, target: ServiceTarget) -> Result<Vec<VectorStore>> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::list_vector_stores(web_client, target).await,
+			adapter_kind => Err(vector_store_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn get_vector_store(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		vector_store_id: &str,
+	) -> Result<VectorStore> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::get_vector_store(web_client, target, vector_store_id).await,
+			adapter_kind => Err(vector_store_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn delete_vector_store(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		vector_store_id: &str,
+	) -> Result<()> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::delete_vector_store(web_client, target, vector_store_id).await,
+			adapter_kind => Err(vector_store_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn attach_vector_store_file(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		vector_store_id: &str,
+		file_id: &str,
+	) -> Result<VectorStoreFile> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => {
+				OpenAIAdapter::attach_vector_store_file(web_client, target, vector_store_id, file_id).await
+			}
+			adapter_kind => Err(vector_store_not_supported(adapter_kind)),
+		}
+	}
+}
+
+/// Count Tokens API
+///
+/// NOTE: Only Anthropic and Gemini support counting tokens for now.
+impl AdapterDispatcher {
+	pub async fn count_tokens(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		chat_req: ChatRequest,
+	) -> Result<TokenCount> {
+		match target.model.adapter_kind {
+			AdapterKind::Anthropic => AnthropicAdapter::count_tokens(web_client, target, chat_req).await,
+			AdapterKind::Gemini => GeminiAdapter::count_tokens(web_client, target, chat_req).await,
+			adapter_kind => Err(Error::AdapterNotSupported {
+				adapter_kind,
+				feature: "count_tokens".to_string(),
+			}),
+		}
+	}
+}
+
+/// Cached Content API
+///
+/// NOTE: Only Gemini supports the explicit context caching API for now
+///       (Anthropic caching is done in the chat request with `CacheControl`).
+impl AdapterDispatcher {
+	pub async fn create_cached_content(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		model_name: &str,
+		chat_req: ChatRequest,
+		ttl: Option<Duration>,
+	) -> Result<CachedContent> {
+		match target.model.adapter_kind {
+			AdapterKind::Gemini => {
+				GeminiAdapter::create_cached_content(web_client, target, model_name, chat_req, ttl).await
+			}
+			adapter_kind => Err(cached_content_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn get_cached_content(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		name: &str,
+	) -> Result<CachedContent> {
+		match target.model.adapter_kind {
+			AdapterKind::Gemini => GeminiAdapter::get_cached_content(web_client, target, name).await,
+			adapter_kind => Err(cached_content_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn delete_cached_content(web_client: &WebClient, target: ServiceTarget, name: &str) -> Result<()> {
+		match target.model.adapter_kind {
+			AdapterKind::Gemini => GeminiAdapter::delete_cached_content(web_client, target, name).await,
+			adapter_kind => Err(cached_content_not_supported(adapter_kind)),
+		}
+	}
+}
+
+/// Fine-tuning API
+///
+/// NOTE: Only OpenAI supports the fine-tuning API for now.
+impl AdapterDispatcher {
+	pub async fn create_finetune_job(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		req: FineTuneRequest,
+	) -> Result<FineTuneJob> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::create_finetune_job(web_client, target, req).await,
+			adapter_kind => Err(finetune_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn list_finetune_jobs(web_client: &WebClient, target: ServiceTarget) -> Result<Vec<FineTuneJob>> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::list_finetune_jobs(web_client, target).await,
+			adapter_kind => Err(finetune_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn get_finetune_job(web_client: &WebClient, target: ServiceTarget, job_id: &str) -> Result<FineTuneJob> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::get_finetune_job(web_client, target, job_id).await,
+			adapter_kind => Err(finetune_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn cancel_finetune_job(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		job_id: &str,
+	) -> Result<FineTuneJob> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::cancel_finetune_job(web_client, target, job_id).await,
+			adapter_kind => Err(finetune_not_supported(adapter_kind)),
+		}
+	}
+}
+
+/// Ollama Management API
+///
+/// NOTE: Only for the Ollama adapter (native Ollama API).
+impl AdapterDispatcher {
+	pub fn ollama_pull(web_client: &WebClient, target: ServiceTarget, model_name: &str) -> Result<OllamaPullStream> {
+		match target.model.adapter_kind {
+			AdapterKind::Ollama => OllamaAdapter::pull_model(web_client, target, model_name),
+			adapter_kind => Err(ollama_manage_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn ollama_show(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		model_name: &str,
+	) -> Result<OllamaModelInfo> {
+		match target.model.adapter_kind {
+			AdapterKind::Ollama => OllamaAdapter::show_model(web_client, target, model_name).await,
+			adapter_kind => Err(ollama_manage_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn ollama_delete(web_client: &WebClient, target: ServiceTarget, model_name: &str) -> Result<()> {
+		match target.model.adapter_kind {
+			AdapterKind::Ollama => OllamaAdapter::delete_model(web_client, target, model_name).await,
+			adapter_kind => Err(ollama_manage_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn ollama_ps(web_client: &WebClient, target: ServiceTarget) -> Result<Vec<OllamaRunningModel>> {
+		match target.model.adapter_kind {
+			AdapterKind::Ollama => OllamaAdapter::running_models(web_client, target).await,
+			adapter_kind => Err(ollama_manage_not_supported(adapter_kind)),
+		}
+	}
+}
+
+/// Video Generation API
+///
+/// NOTE: Only OpenAI (Sora) and Gemini (Veo) support video generation for now.
+impl AdapterDispatcher {
+	pub async fn create_video_job(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		model_name: &str,
+		req: VideoRequest,
+	) -> Result<VideoJob> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::create_video_job(web_client, target, model_name, req).await,
+			AdapterKind::Gemini => GeminiAdapter::create_video_job(web_client, target, model_name, req).await,
+			adapter_kind => Err(videogen_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn get_video_job(web_client: &WebClient, target: ServiceTarget, job: &VideoJob) -> Result<VideoJob> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::get_video_job(web_client, target, &job.id).await,
+			AdapterKind::Gemini => GeminiAdapter::get_video_job(web_client, target, job).await,
+			adapter_kind => Err(videogen_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn download_video(web_client: &WebClient, target: ServiceTarget, job: &VideoJob) -> Result<Vec<u8>> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::download_video(web_client, target, job).await,
+			AdapterKind::Gemini => GeminiAdapter::download_video(web_client, target, job).await,
+			adapter_kind => Err(videogen_not_supported(adapter_kind)),
+		}
+	}
+}
+
+/// Usage Report API
+///
+/// NOTE: Only Anthropic (Admin API) supports the organization usage and cost reports for now.
+impl AdapterDispatcher {
+	pub async fn usage_report(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		req: UsageReportRequest,
+	) -> Result<UsageReport> {
+		match target.model.adapter_kind {
+			AdapterKind::Anthropic => AnthropicAdapter::usage_report(web_client, target, req).await,
+			adapter_kind => Err(usage_report_not_supported(adapter_kind)),
+		}
+	}
+
+	pub async fn cost_report(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		req: UsageReportRequest,
+	) -> Result<CostReport> {
+		match target.model.adapter_kind {
+			AdapterKind::Anthropic => AnthropicAdapter::cost_report(web_client, target, req).await,
+			adapter_kind => Err(usage_report_not_supported(adapter_kind)),
+		}
+	}
+}
+
+/// Realtime API
+///
+/// NOTE: Only OpenAI (Realtime API) and Gemini (Live API) support the realtime API for now.
+impl AdapterDispatcher {
+	/// The WebSocket url and headers, with the eventual first message to send as payload (Null when none).
+	pub fn to_realtime_request_data(target: ServiceTarget, config: Option<&RealtimeConfig>) -> Result<WebRequestData> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::to_realtime_request_data(target, config),
+			AdapterKind::Gemini => GeminiAdapter::to_realtime_request_data(target, config),
+			adapter_kind => Err(realtime_not_supported(adapter_kind)),
+		}
+	}
+
+	pub fn to_realtime_messages(model_iden: &ModelIden, input: RealtimeInput) -> Result<Vec<Value>> {
+		match model_iden.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::to_realtime_messages(input),
+			AdapterKind::Gemini => GeminiAdapter::to_realtime_messages(model_iden, input),
+			adapter_kind => Err(realtime_not_supported(adapter_kind)),
+		}
+	}
+
+	pub fn to_realtime_events(model_iden: &ModelIden, message: Value) -> Result<Vec<RealtimeEvent>> {
+		match model_iden.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::to_realtime_events(model_iden, message),
+			AdapterKind::Gemini => GeminiAdapter::to_realtime_events(model_iden, message),
+			adapter_kind => Err(realtime_not_supported(adapter_kind)),
+		}
+	}
+}
+
+fn realtime_not_supported(adapter_kind: AdapterKind) -> Error {
+	Error::AdapterNotSupported {
+		adapter_kind,
+		feature: "realtime".to_string(),
+	}
+}
+
+fn files_not_supported(adapter_kind: AdapterKind) -> Error {
+	Error::AdapterNotSupported {
+		adapter_kind,
+		feature: "files".to_string(),
+	}
+}
+
+fn vector_store_not_supported(adapter_kind: AdapterKind) -> Error {
+	Error::AdapterNotSupported {
+		adapter_kind,
+		feature: "vector_store".to_string(),
+	}
+}
+
+fn finetune_not_supported(adapter_kind: AdapterKind) -> Error {
+	Error::AdapterNotSupported {
+		adapter_kind,
+		feature: "finetune".to_string(),
+	}
+}
+
+/// Deep merge the `overlay` into the `base` json value (JSON merge patch semantic):
+/// the objects are merged recursively, a null removes the key, and any other value replaces the base one.
+fn merge_json(base: &mut Value, overlay: Value) {
+	match (base, overlay) {
+		(Value::Object(base), Value::Object(overlay)) => {
+			for (key, value) in overlay {
+				if value.is_null() {
+					base.remove(&key);
+				} else {
+					merge_json(base.entry(key).or_insert(Value::Null), value);
+				}
+			}
+		}
+		(base, overlay) => *base = overlay,
+	}
+}
+
+fn ollama_manage_not_supported(adapter_kind: AdapterKind) -> Error {
+	Error::AdapterNotSupported {
+		adapter_kind,
+		feature: "ollama_manage".to_string(),
+	}
+}
+
+fn videogen_not_supported(adapter_kind: AdapterKind) -> Error {
+	Error::AdapterNotSupported {
+		adapter_kind,
+		feature: "videogen".to_string(),
+	}
+}
+
+fn cached_content_not_supported(adapter_kind: AdapterKind) -> Error {
+	Error::AdapterNotSupported {
+		adapter_kind,
+		feature: "cached_content".to_string(),
+	}
+}
+
+fn usage_report_not_supported(adapter_kind: AdapterKind) -> Error {
+	Error::AdapterNotSupported {
+		adapter_kind,
+		feature: "usage_report".to_string(),
+	}
+}
@@ -5,7 +5,7 @@
 //!
 //! NOTE: This might be removed at some point as it may not be needed, and we could go directly to the GenAI stream.
 
-use crate::chat::Usage;
+use crate::chat::{FinishReason, Usage};
 
 #[derive(Debug, Default)]
 pub struct InterStreamEnd {
@@ -20,6 +20,12 @@ pub struct InterStreamEnd {
 
 	// When `ChatOptions..capture_tool_calls == true`
 	pub captured_tool_calls: Option<Vec<crate::chat::ToolCall>>,
+
+	// Always captured (when returned by the provider)
+	pub finish_reason: Option<FinishReason>,
+
+	// Always captured (when returned by the provider)
+	pub stop_sequence: Option<String>,
 }
 
 /// Intermediary StreamEvent
@@ -0,0 +1,284 @@
+//! The `genai` CLI (feature `cli`), exposing the client capabilities from the command line:
+//! one-shot chat, streaming, model listing, embeddings, and batch file processing.
+//!
+//! The defaults (model, chat and embed options) come from the JSON config file
+//! (`--config`, or the `GENAI_CONFIG` env var, or `./genai.json` when present), e.g.:
+//!
+//! ```json
+//! {
+//!   "model": "gpt-4o-mini",
+//!   "embed_model": "text-embedding-3-small",
+//!   "chat_options": { "temperature": 0.2, "max_tokens": 1000 },
+//!   "embed_options": { "dimensions": 512 }
+//! }
+//! ```
+//!
+//! Usage: `cargo run --features cli -- chat "Why is the sky blue?"` (see `genai --help`).
+
+use clap::{Parser, Subcommand};
+use genai::adapter::AdapterKind;
+use genai::chat::printer::{PrintChatStreamOptions, print_chat_stream};
+use genai::chat::{ChatMessage, ChatOptions, ChatRequest};
+use genai::embed::EmbedOptions;
+use genai::{Client, ClientConfig};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+const CONFIG_ENV_NAME: &str = "GENAI_CONFIG";
+const DEFAULT_CONFIG_FILE: &str = "genai.json";
+
+// region:    --- Args
+
+#[derive(Parser)]
+#[command(name = "genai", version, about = "Multi-AI providers client (genai crate CLI)")]
+struct Args {
+	/// The JSON config file (default: `GENAI_CONFIG` env var, or `./genai.json` when present)
+	#[arg(long, global = true)]
+	config: Option<PathBuf>,
+
+	#[command(subcommand)]
+	command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// One-shot chat (prints the response text)
+	Chat {
+		prompt: String,
+		#[command(flatten)]
+		chat: ChatArgs,
+		/// Stream the response
+		#[arg(long)]
+		stream: bool,
+	},
+	/// Streamed chat (same as `chat --stream`)
+	Stream {
+		prompt: String,
+		#[command(flatten)]
+		chat: ChatArgs,
+	},
+	/// List the model names of an adapter (e.g., `openai`, `anthropic`, `ollama`)
+	Models { adapter: String },
+	/// Embed the texts (prints one JSON vector per line)
+	Embed {
+		texts: Vec<String>,
+		/// The embedding model (default: the config `embed_model`)
+		#[arg(short, long)]
+		model: Option<String>,
+	},
+	/// Chat each line of a JSONL file (a user prompt string or a chat request JSON),
+	/// and write one JSON result per line
+	Batch {
+		input: PathBuf,
+		/// The output JSONL file (default: stdout)
+		#[arg(short, long)]
+		output: Option<PathBuf>,
+		#[command(flatten)]
+		chat: ChatArgs,
+	},
+}
+
+#[derive(clap::Args)]
+struct ChatArgs {
+	/// The model (default: the config `model`)
+	#[arg(short, long)]
+	model: Option<String>,
+	/// The system prompt
+	#[arg(short, long)]
+	system: Option<String>,
+	/// Print the raw provider response body (to debug the adapter behavior, not for streams)
+	#[arg(long)]
+	raw: bool,
+	/// Print the usage (to stderr)
+	#[arg(long)]
+	usage: bool,
+}
+
+// endregion: --- Args
+
+// region:    --- Config
+
+/// The CLI config file.
+#[derive(Debug, Default, Deserialize)]
+struct CliConfig {
+	model: Option<String>,
+	embed_model: Option<String>,
+	chat_options: Option<ChatOptions>,
+	embed_options: Option<EmbedOptions>,
+}
+
+impl CliConfig {
+	fn load(path: Option<&Path>) -> Result<Self> {
+		let path = path
+			.map(Path::to_path_buf)
+			.or_else(|| std::env::var_os(CONFIG_ENV_NAME).map(PathBuf::from))
+			.or_else(|| {
+				Path::new(DEFAULT_CONFIG_FILE)
+					.exists()
+					.then(|| PathBuf::from(DEFAULT_CONFIG_FILE))
+			});
+		let Some(path) = path else {
+			return Ok(Self::default());
+		};
+		let content = std::fs::read_to_string(&path)
+			.map_err(|err| format!("Cannot read config file '{}': {err}", path.display()))?;
+		let config =
+			serde_json::from_str(&content).map_err(|err| format!("Invalid config file '{}': {err}", path.display()))?;
+		Ok(config)
+	}
+
+	fn client(&self) -> Client {
+		let mut client_config = ClientConfig::default();
+		if let Some(chat_options) = self.chat_options.clone() {
+			client_config = client_config.with_chat_options(chat_options);
+		}
+		if let Some(embed_options) = self.embed_options.clone() {
+			client_config = client_config.with_embed_options(embed_options);
+		}
+		Client::builder().with_config(client_config).build()
+	}
+
+	fn model<'a>(&'a self, model: Option<&'a str>) -> Result<&'a str> {
+		model
+			.or(self.model.as_deref())
+			.ok_or_else(|| "No model (use --model, or set `model` in the config file)".into())
+	}
+}
+
+// endregion: --- Config
+
+#[tokio::main]
+async fn main() {
+	let args = Args::parse();
+	if let Err(err) = run(args).await {
+		eprintln!("Error: {err}");
+		std::process::exit(1);
+	}
+}
+
+async fn run(args: Args) -> Result<()> {
+	let config = CliConfig::load(args.config.as_deref())?;
+	let client = config.client();
+
+	match args.command {
+		Command::Chat { prompt, chat, stream } => exec_chat(&client, &config, &prompt, &chat, stream).await,
+		Command::Stream { prompt, chat } => exec_chat(&client, &config, &prompt, &chat, true).await,
+		Command::Models { adapter } => {
+			let adapter_kind = AdapterKind::from_lower_str(&adapter.to_lowercase())
+				.ok_or_else(|| format!("Unknown adapter '{adapter}'"))?;
+			for model_name in client.all_model_names(adapter_kind).await? {
+				println!("{model_name}");
+			}
+			Ok(())
+		}
+		Command::Embed { texts, model } => {
+			let model = model
+				.as_deref()
+				.or(config.embed_model.as_deref())
+				.ok_or("No embedding model (use --model, or set `embed_model` in the config file)")?;
+			let embed_res = client.embed_batch(model, texts, None).await?;
+			for vector in embed_res.vectors() {
+				println!("{}", serde_json::to_string(vector)?);
+			}
+			Ok(())
+		}
+		Command::Batch { input, output, chat } => exec_batch(&client, &config, &input, output.as_deref(), &chat).await,
+	}
+}
+
+// region:    --- Commands
+
+async fn exec_chat(client: &Client, config: &CliConfig, prompt: &str, chat: &ChatArgs, stream: bool) -> Result<()> {
+	let model = config.model(chat.model.as_deref())?;
+	let chat_req = chat_request(prompt, chat.system.as_deref());
+
+	if stream {
+		let options = ChatOptions::default().with_capture_usage(chat.usage);
+		let chat_stream = client.exec_chat_stream(model, chat_req, Some(&options)).await?;
+		print_chat_stream(chat_stream, Some(&PrintChatStreamOptions::from_print_events(false))).await?;
+		println!();
+		return Ok(());
+	}
+
+	let options = ChatOptions::default().with_capture_raw_body(chat.raw);
+	let chat_res = client.exec_chat(model, chat_req, Some(&options)).await?;
+	if let Some(raw_body) = chat_res.captured_raw_body.as_ref() {
+		eprintln!("{}", serde_json::to_string_pretty(raw_body)?);
+	}
+	println!("{}", chat_res.first_text().unwrap_or_default());
+	if chat.usage {
+		eprintln!("usage: {}", serde_json::to_string(&chat_res.usage)?);
+	}
+
+	Ok(())
+}
+
+/// Chat each line of the input file (sequentially), and write one JSON result per line
+/// (`line`, `text`, `usage`, or `error`). A failed line does not stop the batch.
+async fn exec_batch(
+	client: &Client,
+	config: &CliConfig,
+	input: &Path,
+	output: Option<&Path>,
+	chat: &ChatArgs,
+) -> Result<()> {
+	let model = config.model(chat.model.as_deref())?;
+	let reader = BufReader::new(std::fs::File::open(input)?);
+	let mut writer: Box<dyn Write> = match output {
+		Some(output) => Box::new(BufWriter::new(std::fs::File::create(output)?)),
+		None => Box::new(std::io::stdout()),
+	};
+	let options = ChatOptions::default().with_capture_raw_body(chat.raw);
+
+	let (mut ok_count, mut error_count) = (0, 0);
+	for (idx, line) in reader.lines().enumerate() {
+		let line = line?;
+		if line.trim().is_empty() {
+			continue;
+		}
+		let chat_req = match serde_json::from_str::<Value>(&line) {
+			Ok(Value::String(prompt)) => chat_request(&prompt, chat.system.as_deref()),
+			Ok(value @ Value::Object(_)) => serde_json::from_value(value)?,
+			_ => chat_request(&line, chat.system.as_deref()),
+		};
+
+		let result = match client.exec_chat(model, chat_req, Some(&options)).await {
+			Ok(chat_res) => {
+				ok_count += 1;
+				json!({
+					"line": idx + 1,
+					"text": chat_res.first_text(),
+					"usage": chat_res.usage,
+					"raw_body": chat_res.captured_raw_body,
+				})
+			}
+			Err(err) => {
+				error_count += 1;
+				json!({"line": idx + 1, "error": err.to_string()})
+			}
+		};
+		writeln!(writer, "{result}")?;
+	}
+	writer.flush()?;
+	eprintln!("batch done: {ok_count} ok, {error_count} failed");
+
+	Ok(())
+}
+
+// endregion: --- Commands
+
+// region:    --- Support
+
+fn chat_request(prompt: &str, system: Option<&str>) -> ChatRequest {
+	let chat_req = ChatRequest::new(vec![ChatMessage::user(prompt)]);
+	match system {
+		Some(system) => chat_req.with_system(system),
+		None => chat_req,
+	}
+}
+
+// endregion: --- Support
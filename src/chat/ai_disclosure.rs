@@ -0,0 +1,159 @@
+//! This module contains the AI disclosure, an optional tagging of the chat responses as AI-generated content
+//! (e.g., for publishing workflows).
+
+use crate::chat::{ChatResponse, ContentBlock, MessageContent};
+use serde::{Deserialize, Serialize};
+
+/// The default disclosure text.
+const DEFAULT_TEXT: &str = "This content was generated by AI.";
+
+/// The default separator between the response text and the disclosure text.
+const DEFAULT_SEPARATOR: &str = "\n\n";
+
+// region:    --- AiDisclosure
+
+/// An optional AI-generated-content disclosure (see `ChatOptions::with_ai_disclosure(..)`),
+/// added to the response text (appended or prepended), or only as metadata.
+///
+/// The applied disclosure is always reported in `ChatResponse.ai_disclosure` (the metadata),
+/// so the publishing code can also render it its own way.
+///
+/// Typically set on the client default chat options, and opted out per call with
+/// `ChatOptions::default().with_ai_disclosure(AiDisclosure::disabled())`.
+///
+/// NOTES:
+/// - Only for `Client::exec_chat(..)` for now (not the chat stream).
+/// - The text is never added to the structured outputs (any `ChatOptions.response_format`), only the metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiDisclosure {
+	/// When false, no disclosure is applied (for the per-call opt-out of a client default).
+	pub enabled: bool,
+
+	/// The disclosure text (default "This content was generated by AI.").
+	pub text: String,
+
+	/// Where the disclosure is added (default `Append`).
+	pub placement: AiDisclosurePlacement,
+
+	/// The separator between the response text and the disclosure text (default "\n\n").
+	pub separator: String,
+}
+
+/// Where the `AiDisclosure` is added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AiDisclosurePlacement {
+	/// Appended to the last text of the response.
+	#[default]
+	Append,
+	/// Prepended to the first text of the response.
+	Prepend,
+	/// Only reported in `ChatResponse.ai_disclosure` (the response text is unchanged).
+	MetadataOnly,
+}
+
+impl Default for AiDisclosure {
+	fn default() -> Self {
+		Self {
+			enabled: true,
+			text: DEFAULT_TEXT.to_string(),
+			placement: AiDisclosurePlacement::default(),
+			separator: DEFAULT_SEPARATOR.to_string(),
+		}
+	}
+}
+
+/// Constructors
+impl AiDisclosure {
+	/// Create an AiDisclosure with a custom text.
+	pub fn new(text: impl Into<String>) -> Self {
+		Self {
+			text: text.into(),
+			..Default::default()
+		}
+	}
+
+	/// A disabled AiDisclosure, to opt out of the client default for a given call.
+	pub fn disabled() -> Self {
+		Self {
+			enabled: false,
+			..Default::default()
+		}
+	}
+}
+
+/// Chainable Setters
+impl AiDisclosure {
+	pub fn with_placement(mut self, placement: AiDisclosurePlacement) -> Self {
+		self.placement = placement;
+		self
+	}
+
+	pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+		self.separator = separator.into();
+		self
+	}
+}
+
+/// Crate Functions
+impl AiDisclosure {
+	/// Add the disclosure to the response text (unless `structured` or `MetadataOnly`),
+	/// and set the `ChatResponse.ai_disclosure`.
+	pub(crate) fn apply(&self, chat_res: &mut ChatResponse, structured: bool) {
+		if !self.enabled {
+			return;
+		}
+
+		let placement = if structured {
+			AiDisclosurePlacement::MetadataOnly
+		} else {
+			self.placement
+		};
+
+		let mut texts = chat_res.content.iter_mut().flat_map(|content| match content {
+			MessageContent::Text(text) => vec![text],
+			MessageContent::Blocks(blocks) => blocks
+				.iter_mut()
+				.filter_map(|block| match block {
+					ContentBlock::Text { text, .. } => Some(text),
+					_ => None,
+				})
+				.collect(),
+			_ => Vec::new(),
+		});
+
+		let in_text = match placement {
+			AiDisclosurePlacement::Append => texts.last().map(|text| {
+				text.push_str(&self.separator);
+				text.push_str(&self.text);
+			}),
+			AiDisclosurePlacement::Prepend => texts.next().map(|text| {
+				text.insert_str(0, &self.separator);
+				text.insert_str(0, &self.text);
+			}),
+			AiDisclosurePlacement::MetadataOnly => None,
+		}
+		.is_some();
+
+		chat_res.ai_disclosure = Some(AiDisclosureTag {
+			text: self.text.clone(),
+			in_text,
+		});
+	}
+}
+
+// endregion: --- AiDisclosure
+
+// region:    --- AiDisclosureTag
+
+/// The AI disclosure applied to a response (the disclosure metadata).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiDisclosureTag {
+	/// The disclosure text.
+	pub text: String,
+
+	/// True when the disclosure text was added to the response text
+	/// (false for `MetadataOnly`, structured outputs, or responses without text, e.g., tool calls only).
+	pub in_text: bool,
+}
+
+// endregion: --- AiDisclosureTag
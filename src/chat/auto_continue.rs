@@ -0,0 +1,130 @@
+//! This module contains the `AutoContinue`, the opt-in continuation of the outputs truncated by the max tokens
+//! (see `ChatOptions::with_auto_continue(..)`).
+
+use crate::adapter::AdapterKind;
+use crate::chat::{ChatMessage, ChatRequest, ChatResponse, FinishReason, MessageContent};
+use serde::{Deserialize, Serialize};
+
+/// The user message of the continuation requests, for the providers without assistant prefill.
+const DEFAULT_CONTINUE_PROMPT: &str =
+	"Continue exactly where you left off, without repeating anything and without any preamble.";
+
+// region:    --- AutoContinue
+
+/// When the output is truncated (`FinishReason::Length`), re-request the continuation of the partial output,
+/// and stitch it to the response text, until the model stops or a limit is reached.
+///
+/// - Anthropic: the partial output is sent as the assistant prefill (the model continues it).
+/// - Other providers: the partial output is sent as an assistant message, followed by the `continue_prompt` user message.
+///
+/// The usage of the continuations is added to the `ChatResponse.usage`,
+/// and the `ChatResponse.finish_reason` is the one of the last continuation.
+///
+/// NOTE: Only for `Client::exec_chat(..)` (not the streams), and only for the text responses
+///       (a response with tool calls or reasoning blocks is not continued).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoContinue {
+	/// The max number of continuation requests (default 3).
+	pub max_continuations: u32,
+
+	/// The eventual max of the total completion tokens (of the first response and the continuations).
+	/// No continuation is requested once reached.
+	pub max_total_tokens: Option<u32>,
+
+	/// The user message of the continuation requests, for the providers without assistant prefill.
+	pub continue_prompt: String,
+}
+
+impl Default for AutoContinue {
+	fn default() -> Self {
+		Self {
+			max_continuations: 3,
+			max_total_tokens: None,
+			continue_prompt: DEFAULT_CONTINUE_PROMPT.to_string(),
+		}
+	}
+}
+
+/// Constructors & Chainable Setters
+impl AutoContinue {
+	pub fn new(max_continuations: u32) -> Self {
+		Self {
+			max_continuations,
+			..Default::default()
+		}
+	}
+
+	pub fn with_max_total_tokens(mut self, max_total_tokens: u32) -> Self {
+		self.max_total_tokens = Some(max_total_tokens);
+		self
+	}
+
+	pub fn with_continue_prompt(mut self, continue_prompt: impl Into<String>) -> Self {
+		self.continue_prompt = continue_prompt.into();
+		self
+	}
+}
+
+/// Crate Functions
+impl AutoContinue {
+	/// Returns the partial output to continue, when the response was truncated and the limits are not reached.
+	pub(crate) fn partial_output<'a>(&self, chat_res: &'a ChatResponse, continuations: u32) -> Option<&'a str> {
+		if chat_res.finish_reason != Some(FinishReason::Length) || continuations >= self.max_continuations {
+			return None;
+		}
+		let completion_tokens = chat_res.usage.completion_tokens.unwrap_or(0).max(0) as u32;
+		if self.max_total_tokens.is_some_and(|max| completion_tokens >= max) {
+			return None;
+		}
+		match chat_res.content.as_slice() {
+			[MessageContent::Text(text)] if !text.trim().is_empty() => Some(text),
+			_ => None,
+		}
+	}
+
+	/// Returns the continuation request of the partial output.
+	///
+	/// NOTE: Anthropic rejects a prefill ending with whitespace, so the prefill is trimmed
+	///       (see `stitch(..)` for the matching stitching).
+	pub(crate) fn continuation_request(
+		&self,
+		adapter_kind: AdapterKind,
+		chat_req: &ChatRequest,
+		partial_output: &str,
+	) -> ChatRequest {
+		let chat_req = chat_req.clone();
+		if supports_prefill(adapter_kind) {
+			chat_req.append_message(ChatMessage::assistant(partial_output.trim_end()))
+		} else {
+			chat_req
+				.append_message(ChatMessage::assistant(partial_output))
+				.append_message(ChatMessage::user(self.continue_prompt.clone()))
+		}
+	}
+
+	/// Stitch the continuation to the response (text, usage, finish reason, stop sequence).
+	pub(crate) fn stitch(adapter_kind: AdapterKind, chat_res: &mut ChatResponse, continuation: ChatResponse) {
+		if let [MessageContent::Text(text)] = chat_res.content.as_mut_slice()
+			&& let Some(continuation_text) = continuation.first_text()
+		{
+			if supports_prefill(adapter_kind) {
+				text.truncate(text.trim_end().len());
+			}
+			text.push_str(continuation_text);
+		}
+		chat_res.usage.accumulate(&continuation.usage);
+		chat_res.finish_reason = continuation.finish_reason;
+		chat_res.stop_sequence = continuation.stop_sequence;
+	}
+}
+
+// endregion: --- AutoContinue
+
+// region:    --- Support
+
+/// The providers continuing a trailing assistant message (prefill).
+fn supports_prefill(adapter_kind: AdapterKind) -> bool {
+	matches!(adapter_kind, AdapterKind::Anthropic)
+}
+
+// endregion: --- Support
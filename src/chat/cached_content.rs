@@ -0,0 +1,28 @@
+//! This module contains the types related to an explicit provider context cache (Gemini `cachedContents`).
+
+use crate::ModelIden;
+use serde::{Deserialize, Serialize};
+
+// region:    --- CachedContent
+
+/// An explicit provider context cache, as returned by `Client::create_cached_content(..)`.
+///
+/// Reference it in the chat requests with `ChatOptions::with_cached_content(cached_content.name)`.
+///
+/// NOTE: The cached content is model-scoped, so it can only be used with the model it was created for.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedContent {
+	/// The model the cached content was created for.
+	pub model_iden: ModelIden,
+
+	/// The provider resource name (e.g., `cachedContents/abc-123`).
+	pub name: String,
+
+	/// The eventual expiration time (RFC 3339).
+	pub expire_time: Option<String>,
+
+	/// The eventual number of cached tokens.
+	pub token_count: Option<i32>,
+}
+
+// endregion: --- CachedContent
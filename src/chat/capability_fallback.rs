@@ -0,0 +1,131 @@
+//! This module contains the `CapabilityFallback`, the optional switch to a capable model when the request
+//! needs a capability (vision, tools) the requested model does not have (e.g., model names from user config).
+
+use crate::chat::{ChatRequest, ContentPart, MessageContent};
+use serde::{Deserialize, Serialize};
+
+// region:    --- CapabilityFallback
+
+/// The capable fallback models used instead of the requested model when the request has images or tools
+/// and the requested model is declared without that capability (see `ChatOptions::with_capability_fallback(..)`).
+///
+/// The models are matched by name, with or without namespace (e.g., `llama3.1:8b` or `ollama::llama3.1:8b`),
+/// or by prefix with a trailing `*` (e.g., `deepseek-*`).
+///
+/// A warning is logged (`tracing::warn!`) when the fallback model is used,
+/// and the used model is the `ChatResponse.model_iden`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CapabilityFallback {
+	/// The model used for the requests with images, when the requested model is in `text_only_models`.
+	pub vision_model: Option<String>,
+
+	/// The models without vision (image inputs).
+	pub text_only_models: Vec<String>,
+
+	/// The model used for the requests with tools, when the requested model is in `no_tools_models`.
+	pub tools_model: Option<String>,
+
+	/// The models without tool calling.
+	pub no_tools_models: Vec<String>,
+}
+
+/// Constructors & Chainable Setters
+impl CapabilityFallback {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Set the vision fallback model, and the models without vision.
+	pub fn with_vision_fallback<I, S>(mut self, vision_model: impl Into<String>, text_only_models: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		self.vision_model = Some(vision_model.into());
+		self.text_only_models = text_only_models.into_iter().map(Into::into).collect();
+		self
+	}
+
+	/// Set the tools fallback model, and the models without tool calling.
+	pub fn with_tools_fallback<I, S>(mut self, tools_model: impl Into<String>, no_tools_models: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		self.tools_model = Some(tools_model.into());
+		self.no_tools_models = no_tools_models.into_iter().map(Into::into).collect();
+		self
+	}
+}
+
+/// Crate Functions
+impl CapabilityFallback {
+	/// Returns the model to use for the request (the fallback model, with a warning, or the requested model).
+	pub(crate) fn resolve_model<'a>(&'a self, model: &'a str, chat_req: &ChatRequest) -> &'a str {
+		match self.fallback_model(model, chat_req) {
+			Some((fallback_model, capability)) => {
+				tracing::warn!(
+					"Model '{model}' does not support {capability}, using the fallback model '{fallback_model}'"
+				);
+				fallback_model
+			}
+			None => model,
+		}
+	}
+
+	/// Returns the eventual fallback model for the request, and the missing capability (`vision` or `tools`).
+	///
+	/// NOTE: The vision fallback takes precedence, and the tools fallback is only used when the resulting
+	///       model is declared without tools.
+	fn fallback_model(&self, model: &str, chat_req: &ChatRequest) -> Option<(&str, &'static str)> {
+		let mut fallback: Option<(&str, &'static str)> = None;
+
+		if let Some(vision_model) = self.vision_model.as_deref()
+			&& has_images(chat_req)
+			&& matches_any(model, &self.text_only_models)
+		{
+			fallback = Some((vision_model, "vision"));
+		}
+
+		let current = fallback.map(|(model, _)| model).unwrap_or(model);
+		if let Some(tools_model) = self.tools_model.as_deref()
+			&& has_tools(chat_req)
+			&& matches_any(current, &self.no_tools_models)
+		{
+			fallback = Some((tools_model, "tools"));
+		}
+
+		fallback
+	}
+}
+
+// endregion: --- CapabilityFallback
+
+// region:    --- Support
+
+fn has_images(chat_req: &ChatRequest) -> bool {
+	chat_req.messages.iter().any(|msg| {
+		matches!(&msg.content, MessageContent::Parts(parts) if parts.iter().any(|part| matches!(part, ContentPart::Image { .. })))
+	})
+}
+
+fn has_tools(chat_req: &ChatRequest) -> bool {
+	chat_req.tools.as_ref().is_some_and(|tools| !tools.is_empty())
+		|| chat_req.messages.iter().any(|msg| {
+			matches!(
+				msg.content,
+				MessageContent::ToolCalls(_) | MessageContent::ToolResponses(_)
+			)
+		})
+}
+
+/// Returns true if the model (with or without its namespace) matches one of the names or `*` prefixes.
+fn matches_any(model: &str, names: &[String]) -> bool {
+	let bare_model = model.split_once("::").map(|(_, name)| name).unwrap_or(model);
+	names.iter().any(|name| match name.strip_suffix('*') {
+		Some(prefix) => model.starts_with(prefix) || bare_model.starts_with(prefix),
+		None => model == name || bare_model == name,
+	})
+}
+
+// endregion: --- Support
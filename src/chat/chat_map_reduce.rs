@@ -0,0 +1,67 @@
+//! This module contains the usage aggregation of the map-reduce combinators
+//! (see `Client::map_concurrent(..)` and `Client::reduce_with_model(..)`).
+
+use crate::ModelPrice;
+use crate::Result;
+use crate::chat::{ChatResponse, Usage};
+use serde::{Deserialize, Serialize};
+
+// region:    --- OperationUsage
+
+/// The aggregated usage of all the sub-requests of a parent operation (e.g., a map-reduce summarization),
+/// so that the total token usage and cost of the operation can be reported as one.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OperationUsage {
+	/// The sum of the usage of the successful sub-requests.
+	pub usage: Usage,
+
+	/// The number of sub-requests executed (successful or failed).
+	pub request_count: usize,
+
+	/// The number of failed sub-requests.
+	pub error_count: usize,
+}
+
+/// Constructors
+impl OperationUsage {
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+/// Public Functions
+impl OperationUsage {
+	/// Add the usage of a successful sub-request.
+	pub fn add_usage(&mut self, usage: &Usage) {
+		self.usage.accumulate(usage);
+		self.request_count += 1;
+	}
+
+	/// Add the result of a sub-request (its usage when successful, otherwise counted as an error).
+	pub fn add_result(&mut self, result: &Result<ChatResponse>) {
+		match result {
+			Ok(chat_res) => self.add_usage(&chat_res.usage),
+			Err(_) => {
+				self.request_count += 1;
+				self.error_count += 1;
+			}
+		}
+	}
+
+	/// Merge the usage of a child operation into this one.
+	pub fn merge(&mut self, other: &OperationUsage) {
+		self.usage.accumulate(&other.usage);
+		self.request_count += other.request_count;
+		self.error_count += other.error_count;
+	}
+
+	/// The cost of the aggregated usage for a model price.
+	///
+	/// NOTE: When the sub-requests use different models, the cost should be computed per model operation,
+	///       then summed.
+	pub fn cost(&self, price: &ModelPrice) -> f64 {
+		price.cost(&self.usage)
+	}
+}
+
+// endregion: --- OperationUsage
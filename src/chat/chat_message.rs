@@ -1,5 +1,4 @@
 use crate::chat::{MessageContent, ToolCall, ToolResponse};
-use derive_more::From;
 use serde::{Deserialize, Serialize};
 
 /// An individual chat message, for System, User, Assistant, Tool, or ToolResponse
@@ -37,6 +36,15 @@ impl ChatMessage {
 		}
 	}
 
+	/// Create a new ChatMessage with the role `ChatRole::Developer`.
+	pub fn developer(content: impl Into<MessageContent>) -> Self {
+		Self {
+			role: ChatRole::Developer,
+			content: content.into(),
+			options: None,
+		}
+	}
+
 	/// Create a new ChatMessage with the role `ChatRole::Assistant`.
 	pub fn assistant(content: impl Into<MessageContent>) -> Self {
 		Self {
@@ -61,13 +69,55 @@ impl ChatMessage {
 		self.options = Some(options.into());
 		self
 	}
+
+	/// Pin (or unpin) the message, so that the history edit functions keep it verbatim (see `MessageOptions.pinned`).
+	pub fn with_pinned(mut self, pinned: bool) -> Self {
+		self.options.get_or_insert_with(MessageOptions::default).pinned = pinned;
+		self
+	}
+
+	/// Set the cache control of the message, keeping the other message options.
+	pub fn with_cache_control(mut self, cache_control: impl Into<CacheControl>) -> Self {
+		self.options.get_or_insert_with(MessageOptions::default).cache_control = Some(cache_control.into());
+		self
+	}
+
+	/// Place a cache breakpoint at the end of this message (Anthropic only, with the default cache TTL).
+	/// The content up to and including this message is cached (up to four breakpoints per request).
+	pub fn with_cache_point(self) -> Self {
+		self.with_cache_control(CacheControl::Ephemeral)
+	}
+}
+
+/// Getters
+impl ChatMessage {
+	/// Returns true if the message is pinned (see `MessageOptions.pinned`).
+	pub fn is_pinned(&self) -> bool {
+		self.options.as_ref().is_some_and(|options| options.pinned)
+	}
 }
+
 // region:    --- MessageOptions
 
-#[derive(Debug, Clone, Serialize, Deserialize, From)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MessageOptions {
-	#[from]
 	pub cache_control: Option<CacheControl>,
+
+	/// A pinned message (e.g., the task spec, key constraints) is kept verbatim by the `ChatRequest` history edit
+	/// functions (not truncated, not replaced, not removed).
+	///
+	/// Its size can be checked against the context budget with `Client::check_pinned_budget(..)`.
+	#[serde(default)]
+	pub pinned: bool,
+}
+
+impl From<Option<CacheControl>> for MessageOptions {
+	fn from(cache_control: Option<CacheControl>) -> Self {
+		Self {
+			cache_control,
+			pinned: false,
+		}
+	}
 }
 
 /// Cache control
@@ -78,13 +128,57 @@ pub struct MessageOptions {
 /// Note: OpenAI is transparent, and Gemini has a separate call for it (so not supported for now)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CacheControl {
+	/// Ephemeral cache with the default TTL (`ChatOptions::cache_ttl`, or `CacheTtl::OneHour` when not set)
 	Ephemeral,
+	/// Ephemeral cache with an explicit TTL (e.g., `CacheTtl::FiveMinutes`, which is billed lower than 1h)
+	EphemeralWithTtl(CacheTtl),
+}
+
+impl CacheControl {
+	/// The TTL of this cache control, or the given default TTL for `CacheControl::Ephemeral`.
+	pub fn ttl_or(&self, default_ttl: CacheTtl) -> CacheTtl {
+		match self {
+			Self::Ephemeral => default_ttl,
+			Self::EphemeralWithTtl(ttl) => *ttl,
+		}
+	}
+}
+
+/// The time-to-live of a `CacheControl` (Anthropic supports 5m and 1h).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CacheTtl {
+	FiveMinutes,
+	#[default]
+	OneHour,
+}
+
+impl CacheTtl {
+	/// The provider TTL value (e.g., `5m`, `1h`).
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::FiveMinutes => "5m",
+			Self::OneHour => "1h",
+		}
+	}
+}
+
+impl From<CacheTtl> for CacheControl {
+	fn from(ttl: CacheTtl) -> Self {
+		Self::EphemeralWithTtl(ttl)
+	}
+}
+
+impl From<CacheTtl> for MessageOptions {
+	fn from(ttl: CacheTtl) -> Self {
+		CacheControl::from(ttl).into()
+	}
 }
 
 impl From<CacheControl> for MessageOptions {
 	fn from(cache_control: CacheControl) -> Self {
 		Self {
 			cache_control: Some(cache_control),
+			pinned: false,
 		}
 	}
 }
@@ -95,6 +189,9 @@ impl From<CacheControl> for MessageOptions {
 #[allow(missing_docs)]
 pub enum ChatRole {
 	System,
+	/// The OpenAI `developer` role, which replaces `system` for the o-series and GPT-5 models.
+	/// The other adapters (and the OpenAI-compatible ones) handle it as a `System` message.
+	Developer,
 	User,
 	Assistant,
 	Tool,
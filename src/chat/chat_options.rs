@@ -7,8 +7,14 @@
 
 use crate::Headers;
 use crate::chat::chat_req_response_format::ChatResponseFormat;
+use crate::chat::{
+	AiDisclosure, AutoContinue, CacheTtl, CapabilityFallback, KvCacheHints, OutputFixer, PromptShield, TimeContext,
+	ToolChoice,
+};
 use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 use std::ops::Deref;
 
 /// Chat Options that are considered for any `Client::exec...` calls.
@@ -29,6 +35,41 @@ pub struct ChatOptions {
 	/// Specifies sequences used as end markers when generating text
 	pub stop_sequences: Vec<String>,
 
+	/// The number of completions (choices) to generate (OpenAI `n`, Gemini `candidateCount`),
+	/// returned in `ChatResponse.choices`.
+	/// NOTE: OpenAI-compatible and Gemini only, and not supported for streaming (only the first choice is streamed).
+	pub candidate_count: Option<u32>,
+
+	/// Return the log probabilities of the output tokens in `ChatChoice.logprobs`
+	/// (OpenAI `logprobs`, Gemini `responseLogprobs`).
+	pub logprobs: Option<bool>,
+
+	/// The bias added to the logits of the tokens (token id -> bias, typically -100 to 100),
+	/// e.g., `-100` to ban a token, or `100` to force it.
+	/// NOTE: For the OpenAI-compatible adapters only (fails with `Error::AdapterNotSupported` for the others).
+	pub logit_bias: Option<HashMap<u32, i32>>,
+
+	/// Penalize the tokens by their frequency so far (typically -2.0 to 2.0)
+	/// NOTE: Skipped with a warning for the providers not supporting it (e.g., Anthropic).
+	pub frequency_penalty: Option<f64>,
+
+	/// Penalize the tokens already present so far (typically -2.0 to 2.0)
+	/// NOTE: Skipped with a warning for the providers not supporting it (e.g., Anthropic).
+	pub presence_penalty: Option<f64>,
+
+	/// Sample only from the `top_k` most likely tokens
+	/// NOTE: For the local/open-model servers used with the OpenAI adapter (llama.cpp, vLLM, Together), Ollama,
+	///       Gemini (`topK`), Anthropic, and Cohere (`k`).
+	pub top_k: Option<u32>,
+
+	/// Discard the tokens with a probability below `min_p` times the most likely token probability
+	/// NOTE: For the local/open-model servers used with the OpenAI adapter (llama.cpp, vLLM, Together), and Ollama.
+	pub min_p: Option<f64>,
+
+	/// Penalize the repeated tokens (`1.0` is no penalty)
+	/// NOTE: For the local/open-model servers used with the OpenAI adapter (llama.cpp, vLLM, Together), and Ollama.
+	pub repetition_penalty: Option<f64>,
+
 	// -- Stream Options
 	/// (for streaming only) Capture the meta usage when in stream mode
 	/// `StreamEnd` event payload will contain `captured_usage`
@@ -49,12 +90,27 @@ pub struct ChatOptions {
 
 	pub capture_raw_body: Option<bool>,
 
+	/// Capture the `ChatResponse.response_digest` (sha256 hash and timestamp of the exact response body)
+	/// NOTE: Not supported for streaming.
+	pub capture_response_digest: Option<bool>,
+
+	/// Capture the `ChatResponse.response_headers` (request id, rate limits, and processing time)
+	/// NOTE: Not supported for streaming.
+	pub capture_headers: Option<bool>,
+
 	/// Specifies the response format for a chat request.
 	/// - `ChatResponseFormat::JsonMode` is for OpenAI-like API usage, where the user must specify in the prompt that they want a JSON format response.
 	///
 	/// NOTE: More response formats are coming soon.
 	pub response_format: Option<ChatResponseFormat>,
 
+	/// Whether and which tool the model calls (see `ToolChoice`)
+	pub tool_choice: Option<ToolChoice>,
+
+	/// Allow the model to call several tools in one turn (`false` for the agent loops executing one tool per turn)
+	/// NOTE: OpenAI and Anthropic only, and only sent when the request has tools.
+	pub parallel_tool_calls: Option<bool>,
+
 	// -- Reasoning options
 	/// Denote if the content should be parsed to extract eventual `<think>...</think>` content
 	/// into `ChatResponse.reasoning_content`
@@ -62,12 +118,99 @@ pub struct ChatOptions {
 
 	pub reasoning_effort: Option<ReasoningEffort>,
 
+	/// Request a summary of the model reasoning, returned as `ChatResponse.reasoning_content`
+	/// (and as `ReasoningChunk` events when streaming).
+	/// NOTE: OpenAI only (the chat request is sent to the Responses API `reasoning.summary`).
+	pub reasoning_summary: Option<ReasoningSummary>,
+
 	/// Set the seed
-	/// This is useful for reproducibility.
+	/// This is useful for reproducibility (see `ChatResponse.system_fingerprint`).
+	/// NOTE: OpenAI-compatible (e.g., Groq, Ollama), Gemini, and Cohere only.
 	pub seed: Option<u64>,
 
-	// Extra headers
+	/// The predicted output (e.g., the current content of a file to edit), to speed up the responses
+	/// which reuse most of it. The accepted and rejected prediction tokens are in `Usage.completion_tokens_details`.
+	/// NOTE: OpenAI only (sent as `prediction`).
+	pub prediction: Option<String>,
+
+	/// The name of an explicit provider context cache to use for this request (Gemini only, e.g., `cachedContents/abc-123`)
+	/// See `Client::create_cached_content(..)`.
+	pub cached_content: Option<String>,
+
+	/// The safety filter threshold per harm category (Gemini only, sent as `safetySettings`).
+	/// The blocked prompts and responses are returned with `ChatResponse.content_filter` (see `ChatResponse::is_refusal()`).
+	pub safety_settings: Option<Vec<SafetySetting>>,
+
+	/// Enable the provider citations of the document inputs
+	/// (Anthropic only, returned in the `ContentBlock::Text` citations, see `ChatResponse::citations()`).
+	pub citations: Option<bool>,
+
+	/// Enable the interleaved thinking, so the model can think between its tool calls
+	/// (Anthropic only, with the `interleaved-thinking-2025-05-14` beta, for the Claude 4 models with a reasoning effort).
+	/// NOTE: The assistant `MessageContent::Blocks` of the response must be sent back as is, to keep the thinking blocks.
+	pub interleaved_thinking: Option<bool>,
+
+	/// The default TTL of the `CacheControl::Ephemeral` messages (Anthropic only, `CacheTtl::OneHour` when not set).
+	pub cache_ttl: Option<CacheTtl>,
+
+	/// Stamp the automatic cache breakpoint on the last tool (Anthropic only, true when not set).
+	/// Set to false when managing the cache breakpoints explicitly, since each request allows only four.
+	pub tools_cache_control: Option<bool>,
+
+	/// The TTL of the automatic cache breakpoint of the last tool (Anthropic only, `cache_ttl` when not set).
+	pub tools_cache_ttl: Option<CacheTtl>,
+
+	/// Check the response for the verbatim leakage of the system prompt or registered secrets
+	/// NOTE: Not supported for streaming.
+	pub prompt_shield: Option<PromptShield>,
+
+	/// Repair the invalid JSON structured outputs with a fixer model
+	/// NOTE: Not supported for streaming.
+	pub output_fixer: Option<OutputFixer>,
+
+	/// Tag the response as AI-generated content (see `AiDisclosure`)
+	/// NOTE: Not supported for streaming.
+	pub ai_disclosure: Option<AiDisclosure>,
+
+	/// Inject the current date/time, timezone, and locale in the system prompt (see `TimeContext`)
+	pub time_context: Option<TimeContext>,
+
+	/// KV-cache / session reuse hints for the local inference servers (llama.cpp, vLLM)
+	pub kv_cache_hints: Option<KvCacheHints>,
+
+	/// Use a capable fallback model when the request has images or tools the model does not support
+	/// (see `CapabilityFallback`)
+	pub capability_fallback: Option<CapabilityFallback>,
+
+	/// Re-request the continuation of the outputs truncated by the max tokens, and stitch it (see `AutoContinue`)
+	pub auto_continue: Option<AutoContinue>,
+
+	/// The tenant id of this request, for the eventual client `QuotaManager`.
+	pub tenant_id: Option<String>,
+
+	/// The end-user id of this request, for the provider abuse attribution
+	/// (OpenAI `user`, Anthropic `metadata.user_id`, ignored by the other adapters).
+	pub user: Option<String>,
+
+	/// The key/value metadata of this request, for the provider-side analytics
+	/// (OpenAI `metadata`, ignored by the other adapters).
+	/// NOTE: With the OpenAI Chat Completions API, the metadata is only kept for the stored completions (see `store`).
+	pub metadata: Option<HashMap<String, String>>,
+
+	/// Store the completion on the provider side (e.g., in the OpenAI dashboard, for the distillation and the evals),
+	/// with its eventual `metadata` to filter it.
+	/// NOTE: OpenAI only (sent as `store`).
+	pub store: Option<bool>,
+
+	/// The extra headers merged into the request headers, after the adapter auth headers
+	/// (e.g., Anthropic beta flags, OpenRouter attribution headers, gateway routing hints).
+	/// NOTE: A header with the same name replaces the adapter one (e.g., an `anthropic-beta` header
+	///       should also list the betas the request needs).
 	pub extra_headers: Option<Headers>,
+
+	/// The raw json deep merged into the provider request payload after the adapter built it
+	/// (e.g., to use a new provider parameter before it has a typed option). A null value removes the key.
+	pub extra_body: Option<Value>,
 }
 
 /// Chainable Setters
@@ -90,6 +233,54 @@ impl ChatOptions {
 		self
 	}
 
+	/// Set the `candidate_count` for this request (the number of choices in `ChatResponse.choices`).
+	pub fn with_candidate_count(mut self, value: u32) -> Self {
+		self.candidate_count = Some(value);
+		self
+	}
+
+	/// Set the `logprobs` for this request.
+	pub fn with_logprobs(mut self, value: bool) -> Self {
+		self.logprobs = Some(value);
+		self
+	}
+
+	/// Set the `logit_bias` for this request (token id -> bias).
+	pub fn with_logit_bias(mut self, value: impl IntoIterator<Item = (u32, i32)>) -> Self {
+		self.logit_bias = Some(value.into_iter().collect());
+		self
+	}
+
+	/// Set the `frequency_penalty` for this request.
+	pub fn with_frequency_penalty(mut self, value: f64) -> Self {
+		self.frequency_penalty = Some(value);
+		self
+	}
+
+	/// Set the `presence_penalty` for this request.
+	pub fn with_presence_penalty(mut self, value: f64) -> Self {
+		self.presence_penalty = Some(value);
+		self
+	}
+
+	/// Set the `top_k` for this request.
+	pub fn with_top_k(mut self, value: u32) -> Self {
+		self.top_k = Some(value);
+		self
+	}
+
+	/// Set the `min_p` for this request.
+	pub fn with_min_p(mut self, value: f64) -> Self {
+		self.min_p = Some(value);
+		self
+	}
+
+	/// Set the `repetition_penalty` for this request.
+	pub fn with_repetition_penalty(mut self, value: f64) -> Self {
+		self.repetition_penalty = Some(value);
+		self
+	}
+
 	/// Set the `capture_usage` for this request.
 	pub fn with_capture_usage(mut self, value: bool) -> Self {
 		self.capture_usage = Some(value);
@@ -119,6 +310,18 @@ impl ChatOptions {
 		self
 	}
 
+	/// Set the `capture_response_digest` for this request.
+	pub fn with_capture_response_digest(mut self, value: bool) -> Self {
+		self.capture_response_digest = Some(value);
+		self
+	}
+
+	/// Set the `capture_headers` for this request.
+	pub fn with_capture_headers(mut self, value: bool) -> Self {
+		self.capture_headers = Some(value);
+		self
+	}
+
 	pub fn with_stop_sequences(mut self, values: Vec<String>) -> Self {
 		self.stop_sequences = values;
 		self
@@ -135,21 +338,176 @@ impl ChatOptions {
 		self
 	}
 
+	/// Set the `tool_choice` for this request (e.g., `ToolChoice::named("get_weather")` to force a function call).
+	pub fn with_tool_choice(mut self, value: ToolChoice) -> Self {
+		self.tool_choice = Some(value);
+		self
+	}
+
+	/// Set the `parallel_tool_calls` for this request (`false` to get at most one tool call per response).
+	pub fn with_parallel_tool_calls(mut self, value: bool) -> Self {
+		self.parallel_tool_calls = Some(value);
+		self
+	}
+
 	pub fn with_reasoning_effort(mut self, value: ReasoningEffort) -> Self {
 		self.reasoning_effort = Some(value);
 		self
 	}
 
+	/// Set the `reasoning_summary` for this request (OpenAI only).
+	pub fn with_reasoning_summary(mut self, value: ReasoningSummary) -> Self {
+		self.reasoning_summary = Some(value);
+		self
+	}
+
 	pub fn with_seed(mut self, value: u64) -> Self {
 		self.seed = Some(value);
 		self
 	}
 
+	/// Set the predicted output of this request (OpenAI only).
+	pub fn with_prediction(mut self, value: impl Into<String>) -> Self {
+		self.prediction = Some(value.into());
+		self
+	}
+
+	/// Set the `cached_content` name for this request (Gemini only).
+	///
+	/// NOTE: The system instruction and tools are part of the cached content,
+	///       so they should not be in the chat request anymore.
+	pub fn with_cached_content(mut self, value: impl Into<String>) -> Self {
+		self.cached_content = Some(value.into());
+		self
+	}
+
+	/// Set the `safety_settings` of this request, as (category, threshold) pairs (Gemini only).
+	pub fn with_safety_settings<I>(mut self, settings: I) -> Self
+	where
+		I: IntoIterator<Item = (SafetyCategory, SafetyThreshold)>,
+	{
+		self.safety_settings = Some(
+			settings
+				.into_iter()
+				.map(|(category, threshold)| SafetySetting { category, threshold })
+				.collect(),
+		);
+		self
+	}
+
+	/// Set the `citations` of the document inputs for this request (Anthropic only).
+	pub fn with_citations(mut self, value: bool) -> Self {
+		self.citations = Some(value);
+		self
+	}
+
+	/// Set the `interleaved_thinking` between the tool calls (Anthropic only).
+	pub fn with_interleaved_thinking(mut self, value: bool) -> Self {
+		self.interleaved_thinking = Some(value);
+		self
+	}
+
+	/// Set the default `cache_ttl` of the `CacheControl::Ephemeral` messages (Anthropic only).
+	pub fn with_cache_ttl(mut self, value: CacheTtl) -> Self {
+		self.cache_ttl = Some(value);
+		self
+	}
+
+	/// Set whether the last tool gets the automatic cache breakpoint (Anthropic only).
+	pub fn with_tools_cache_control(mut self, value: bool) -> Self {
+		self.tools_cache_control = Some(value);
+		self
+	}
+
+	/// Set the `tools_cache_ttl` of the automatic cache breakpoint of the last tool (Anthropic only).
+	pub fn with_tools_cache_ttl(mut self, value: CacheTtl) -> Self {
+		self.tools_cache_ttl = Some(value);
+		self
+	}
+
+	/// Set the `prompt_shield` output check for this request.
+	pub fn with_prompt_shield(mut self, value: PromptShield) -> Self {
+		self.prompt_shield = Some(value);
+		self
+	}
+
+	/// Set the `output_fixer` for this request (see `OutputFixer`).
+	pub fn with_output_fixer(mut self, value: OutputFixer) -> Self {
+		self.output_fixer = Some(value);
+		self
+	}
+
+	/// Set the `ai_disclosure` for this request (`AiDisclosure::disabled()` to opt out of the client default).
+	pub fn with_ai_disclosure(mut self, value: AiDisclosure) -> Self {
+		self.ai_disclosure = Some(value);
+		self
+	}
+
+	/// Set the `time_context` for this request (the current date/time block of the system prompt).
+	pub fn with_time_context(mut self, value: TimeContext) -> Self {
+		self.time_context = Some(value);
+		self
+	}
+
+	/// Set the `capability_fallback` for this request (typically on the client default chat options).
+	pub fn with_capability_fallback(mut self, value: CapabilityFallback) -> Self {
+		self.capability_fallback = Some(value);
+		self
+	}
+
+	/// Set the `auto_continue` for this request (`Client::exec_chat(..)` only).
+	pub fn with_auto_continue(mut self, value: AutoContinue) -> Self {
+		self.auto_continue = Some(value);
+		self
+	}
+
+	/// Set the `kv_cache_hints` for this request (local inference servers only).
+	pub fn with_kv_cache_hints(mut self, value: KvCacheHints) -> Self {
+		self.kv_cache_hints = Some(value);
+		self
+	}
+
+	/// Set the `tenant_id` for this request (see `ClientBuilder::with_quota_manager(..)`).
+	pub fn with_tenant_id(mut self, value: impl Into<String>) -> Self {
+		self.tenant_id = Some(value.into());
+		self
+	}
+
+	/// Set the end-user id of this request (OpenAI `user`, Anthropic `metadata.user_id`).
+	pub fn with_user(mut self, value: impl Into<String>) -> Self {
+		self.user = Some(value.into());
+		self
+	}
+
+	/// Set the key/value metadata of this request (OpenAI only).
+	pub fn with_metadata<I, K, V>(mut self, metadata: I) -> Self
+	where
+		I: IntoIterator<Item = (K, V)>,
+		K: Into<String>,
+		V: Into<String>,
+	{
+		self.metadata = Some(metadata.into_iter().map(|(k, v)| (k.into(), v.into())).collect());
+		self
+	}
+
+	/// Set the `store` of this request (OpenAI only).
+	pub fn with_store(mut self, value: bool) -> Self {
+		self.store = Some(value);
+		self
+	}
+
+	/// Set the `extra_headers` merged into the request headers.
 	pub fn with_extra_headers(mut self, headers: impl Into<Headers>) -> Self {
 		self.extra_headers = Some(headers.into());
 		self
 	}
 
+	/// Set the `extra_body` json deep merged into the provider request payload.
+	pub fn with_extra_body(mut self, value: impl Into<Value>) -> Self {
+		self.extra_body = Some(value.into());
+		self
+	}
+
 	// -- Deprecated
 
 	/// Set the `json_mode` for this request.
@@ -171,8 +529,13 @@ impl ChatOptions {
 
 // region:    --- ReasoningEffort
 
+/// The reasoning (thinking) effort of the request, mapped to each provider by the
+/// `ReasoningEffort` provider mapping table below (see `ReasoningEffort::openai_effort()` and the other mappers).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ReasoningEffort {
+	/// No reasoning (when the model allows disabling it)
+	Disabled,
+	Minimal,
 	Low,
 	Medium,
 	High,
@@ -184,6 +547,8 @@ impl ReasoningEffort {
 	/// Budget always returns "budget" regardless of the number.
 	pub fn variant_name(&self) -> &'static str {
 		match self {
+			ReasoningEffort::Disabled => "disabled",
+			ReasoningEffort::Minimal => "minimal",
 			ReasoningEffort::Low => "low",
 			ReasoningEffort::Medium => "medium",
 			ReasoningEffort::High => "high",
@@ -191,10 +556,12 @@ impl ReasoningEffort {
 		}
 	}
 
-	/// Keywords are just the "high", "medium", "low",
+	/// Keywords are just the "none", "minimal", "low", "medium", "high" (the OpenAI `reasoning_effort` values),
 	/// Budget will be None
 	pub fn as_keyword(&self) -> Option<&'static str> {
 		match self {
+			ReasoningEffort::Disabled => Some("none"),
+			ReasoningEffort::Minimal => Some("minimal"),
 			ReasoningEffort::Low => Some("low"),
 			ReasoningEffort::Medium => Some("medium"),
 			ReasoningEffort::High => Some("high"),
@@ -202,10 +569,12 @@ impl ReasoningEffort {
 		}
 	}
 
-	/// Keywords are just the "high", "medium", "low",
+	/// Keywords are just the "none", "minimal", "low", "medium", "high",
 	/// This function will not create budget variant (no)
 	pub fn from_keyword(name: &str) -> Option<Self> {
 		match name {
+			"none" => Some(ReasoningEffort::Disabled),
+			"minimal" => Some(ReasoningEffort::Minimal),
 			"low" => Some(ReasoningEffort::Low),
 			"medium" => Some(ReasoningEffort::Medium),
 			"high" => Some(ReasoningEffort::High),
@@ -230,13 +599,90 @@ impl ReasoningEffort {
 	}
 }
 
+/// The provider mapping table of the `ReasoningEffort` keywords.
+///
+/// | ReasoningEffort | OpenAI `reasoning_effort` | Anthropic `budget_tokens` | Gemini `thinkingBudget` | Qwen `enable_thinking` |
+/// |-----------------|---------------------------|---------------------------|-------------------------|------------------------|
+/// | `Disabled`      | `none`                    | (thinking disabled)       | 0                       | false                  |
+/// | `Minimal`       | `minimal`                 | 1024                      | 512                     | true                   |
+/// | `Low`           | `low`                     | 4096                      | 1000                    | true                   |
+/// | `Medium`        | `medium`                  | 16384                     | 8000                    | true                   |
+/// | `High`          | `high`                    | 32768                     | 24000                   | true                   |
+/// | `Budget(n)`     | (not sent)                | n (disabled when 0)       | n                       | n > 0                  |
+///
+/// NOTE: The Anthropic budget is then clamped by the adapter (min 1024, and less than the max tokens).
+/// NOTE: Gemini also sets `includeThoughts` when the budget is > 0 (the thoughts are returned as `reasoning_content`).
+/// NOTE: Qwen `enable_thinking` is only sent by the OpenAI adapter on a custom endpoint (e.g., DashScope, vLLM).
+const REASONING_EFFORT_TABLE: [(&str, ReasoningMapping); 5] = [
+	("none", ReasoningMapping::new(None, 0, false)),
+	("minimal", ReasoningMapping::new(Some(1024), 512, true)),
+	("low", ReasoningMapping::new(Some(4096), 1000, true)),
+	("medium", ReasoningMapping::new(Some(16384), 8000, true)),
+	("high", ReasoningMapping::new(Some(32768), 24000, true)),
+];
+
+/// One row of the `REASONING_EFFORT_TABLE` (the OpenAI keyword is the `ReasoningEffort` keyword).
+#[derive(Clone, Copy)]
+struct ReasoningMapping {
+	anthropic_budget: Option<u32>,
+	gemini_budget: u32,
+	qwen_enable_thinking: bool,
+}
+
+impl ReasoningMapping {
+	const fn new(anthropic_budget: Option<u32>, gemini_budget: u32, qwen_enable_thinking: bool) -> Self {
+		Self {
+			anthropic_budget,
+			gemini_budget,
+			qwen_enable_thinking,
+		}
+	}
+}
+
+/// Provider Mappers (see the `REASONING_EFFORT_TABLE`)
+impl ReasoningEffort {
+	fn mapping(&self) -> ReasoningMapping {
+		match self {
+			ReasoningEffort::Budget(budget) => {
+				ReasoningMapping::new(Some(*budget).filter(|budget| *budget > 0), *budget, *budget > 0)
+			}
+			_ => {
+				let keyword = self.as_keyword().unwrap_or_default();
+				REASONING_EFFORT_TABLE
+					.iter()
+					.find(|(table_keyword, _)| *table_keyword == keyword)
+					.map(|(_, mapping)| *mapping)
+					.unwrap_or(ReasoningMapping::new(None, 0, false))
+			}
+		}
+	}
+
+	/// The OpenAI `reasoning_effort` value (None for a `Budget`, which is not supported).
+	pub fn openai_effort(&self) -> Option<&'static str> {
+		self.as_keyword()
+	}
+
+	/// The Anthropic thinking `budget_tokens` (None when thinking is disabled).
+	pub fn anthropic_budget(&self) -> Option<u32> {
+		self.mapping().anthropic_budget
+	}
+
+	/// The Gemini `thinkingBudget` (0 disables thinking for the models that allow it).
+	pub fn gemini_budget(&self) -> u32 {
+		self.mapping().gemini_budget
+	}
+
+	/// The Qwen `enable_thinking` value.
+	pub fn qwen_enable_thinking(&self) -> bool {
+		self.mapping().qwen_enable_thinking
+	}
+}
+
 impl std::fmt::Display for ReasoningEffort {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		match self {
-			ReasoningEffort::Low => write!(f, "low"),
-			ReasoningEffort::Medium => write!(f, "medium"),
-			ReasoningEffort::High => write!(f, "high"),
 			ReasoningEffort::Budget(n) => write!(f, "{n}"),
+			_ => write!(f, "{}", self.as_keyword().unwrap_or_default()),
 		}
 	}
 }
@@ -253,6 +699,93 @@ impl std::str::FromStr for ReasoningEffort {
 
 // endregion: --- ReasoningEffort
 
+// region:    --- ReasoningSummary
+
+/// The detail level of the reasoning summary (OpenAI Responses API `reasoning.summary`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReasoningSummary {
+	/// The most detailed summary available for the model.
+	#[default]
+	Auto,
+	Concise,
+	Detailed,
+}
+
+impl ReasoningSummary {
+	/// The provider value (e.g., `auto`).
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			ReasoningSummary::Auto => "auto",
+			ReasoningSummary::Concise => "concise",
+			ReasoningSummary::Detailed => "detailed",
+		}
+	}
+}
+
+// endregion: --- ReasoningSummary
+
+// region:    --- SafetySetting
+
+/// The safety filter threshold of a harm category (see `ChatOptions::with_safety_settings(..)`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SafetySetting {
+	pub category: SafetyCategory,
+	pub threshold: SafetyThreshold,
+}
+
+/// The harm category of a `SafetySetting` (Gemini `HARM_CATEGORY_*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SafetyCategory {
+	Harassment,
+	HateSpeech,
+	SexuallyExplicit,
+	DangerousContent,
+	CivicIntegrity,
+}
+
+impl SafetyCategory {
+	/// The provider value (e.g., `HARM_CATEGORY_HARASSMENT`).
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			SafetyCategory::Harassment => "HARM_CATEGORY_HARASSMENT",
+			SafetyCategory::HateSpeech => "HARM_CATEGORY_HATE_SPEECH",
+			SafetyCategory::SexuallyExplicit => "HARM_CATEGORY_SEXUALLY_EXPLICIT",
+			SafetyCategory::DangerousContent => "HARM_CATEGORY_DANGEROUS_CONTENT",
+			SafetyCategory::CivicIntegrity => "HARM_CATEGORY_CIVIC_INTEGRITY",
+		}
+	}
+}
+
+/// The blocking threshold of a `SafetySetting` (Gemini `HarmBlockThreshold`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SafetyThreshold {
+	/// Block when the probability is low, medium, or high.
+	BlockLowAndAbove,
+	/// Block when the probability is medium or high.
+	BlockMediumAndAbove,
+	/// Block only when the probability is high.
+	BlockOnlyHigh,
+	/// Never block (the ratings are still returned).
+	BlockNone,
+	/// Turn off the safety filter of the category.
+	Off,
+}
+
+impl SafetyThreshold {
+	/// The provider value (e.g., `BLOCK_ONLY_HIGH`).
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			SafetyThreshold::BlockLowAndAbove => "BLOCK_LOW_AND_ABOVE",
+			SafetyThreshold::BlockMediumAndAbove => "BLOCK_MEDIUM_AND_ABOVE",
+			SafetyThreshold::BlockOnlyHigh => "BLOCK_ONLY_HIGH",
+			SafetyThreshold::BlockNone => "BLOCK_NONE",
+			SafetyThreshold::Off => "OFF",
+		}
+	}
+}
+
+// endregion: --- SafetySetting
+
 // region:    --- ChatOptionsSet
 
 /// This is an internal crate struct to resolve the ChatOptions value in a cascading manner.
@@ -301,6 +834,54 @@ impl ChatOptionsSet<'_, '_> {
 			.unwrap_or(&[])
 	}
 
+	pub fn candidate_count(&self) -> Option<u32> {
+		self.chat
+			.and_then(|chat| chat.candidate_count)
+			.or_else(|| self.client.and_then(|client| client.candidate_count))
+	}
+
+	pub fn logprobs(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.logprobs)
+			.or_else(|| self.client.and_then(|client| client.logprobs))
+	}
+
+	pub fn logit_bias(&self) -> Option<&HashMap<u32, i32>> {
+		self.chat
+			.and_then(|chat| chat.logit_bias.as_ref())
+			.or_else(|| self.client.and_then(|client| client.logit_bias.as_ref()))
+	}
+
+	pub fn frequency_penalty(&self) -> Option<f64> {
+		self.chat
+			.and_then(|chat| chat.frequency_penalty)
+			.or_else(|| self.client.and_then(|client| client.frequency_penalty))
+	}
+
+	pub fn presence_penalty(&self) -> Option<f64> {
+		self.chat
+			.and_then(|chat| chat.presence_penalty)
+			.or_else(|| self.client.and_then(|client| client.presence_penalty))
+	}
+
+	pub fn top_k(&self) -> Option<u32> {
+		self.chat
+			.and_then(|chat| chat.top_k)
+			.or_else(|| self.client.and_then(|client| client.top_k))
+	}
+
+	pub fn min_p(&self) -> Option<f64> {
+		self.chat
+			.and_then(|chat| chat.min_p)
+			.or_else(|| self.client.and_then(|client| client.min_p))
+	}
+
+	pub fn repetition_penalty(&self) -> Option<f64> {
+		self.chat
+			.and_then(|chat| chat.repetition_penalty)
+			.or_else(|| self.client.and_then(|client| client.repetition_penalty))
+	}
+
 	pub fn capture_usage(&self) -> Option<bool> {
 		self.chat
 			.and_then(|chat| chat.capture_usage)
@@ -331,12 +912,36 @@ impl ChatOptionsSet<'_, '_> {
 			.or_else(|| self.client.and_then(|client| client.capture_raw_body))
 	}
 
+	pub fn capture_response_digest(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.capture_response_digest)
+			.or_else(|| self.client.and_then(|client| client.capture_response_digest))
+	}
+
+	pub fn capture_headers(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.capture_headers)
+			.or_else(|| self.client.and_then(|client| client.capture_headers))
+	}
+
 	pub fn response_format(&self) -> Option<&ChatResponseFormat> {
 		self.chat
 			.and_then(|chat| chat.response_format.as_ref())
 			.or_else(|| self.client.and_then(|client| client.response_format.as_ref()))
 	}
 
+	pub fn tool_choice(&self) -> Option<&ToolChoice> {
+		self.chat
+			.and_then(|chat| chat.tool_choice.as_ref())
+			.or_else(|| self.client.and_then(|client| client.tool_choice.as_ref()))
+	}
+
+	pub fn parallel_tool_calls(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.parallel_tool_calls)
+			.or_else(|| self.client.and_then(|client| client.parallel_tool_calls))
+	}
+
 	pub fn normalize_reasoning_content(&self) -> Option<bool> {
 		self.chat
 			.and_then(|chat| chat.normalize_reasoning_content)
@@ -349,12 +954,138 @@ impl ChatOptionsSet<'_, '_> {
 			.or_else(|| self.client.and_then(|client| client.reasoning_effort.as_ref()))
 	}
 
+	pub fn reasoning_summary(&self) -> Option<ReasoningSummary> {
+		self.chat
+			.and_then(|chat| chat.reasoning_summary)
+			.or_else(|| self.client.and_then(|client| client.reasoning_summary))
+	}
+
 	pub fn seed(&self) -> Option<u64> {
 		self.chat
 			.and_then(|chat| chat.seed)
 			.or_else(|| self.client.and_then(|client| client.seed))
 	}
 
+	pub fn prediction(&self) -> Option<&str> {
+		self.chat
+			.and_then(|chat| chat.prediction.as_deref())
+			.or_else(|| self.client.and_then(|client| client.prediction.as_deref()))
+	}
+
+	pub fn cached_content(&self) -> Option<&str> {
+		self.chat
+			.and_then(|chat| chat.cached_content.as_deref())
+			.or_else(|| self.client.and_then(|client| client.cached_content.as_deref()))
+	}
+
+	pub fn safety_settings(&self) -> Option<&[SafetySetting]> {
+		self.chat
+			.and_then(|chat| chat.safety_settings.as_deref())
+			.or_else(|| self.client.and_then(|client| client.safety_settings.as_deref()))
+	}
+
+	pub fn citations(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.citations)
+			.or_else(|| self.client.and_then(|client| client.citations))
+	}
+
+	pub fn interleaved_thinking(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.interleaved_thinking)
+			.or_else(|| self.client.and_then(|client| client.interleaved_thinking))
+	}
+
+	pub fn cache_ttl(&self) -> Option<CacheTtl> {
+		self.chat
+			.and_then(|chat| chat.cache_ttl)
+			.or_else(|| self.client.and_then(|client| client.cache_ttl))
+	}
+
+	pub fn tools_cache_control(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.tools_cache_control)
+			.or_else(|| self.client.and_then(|client| client.tools_cache_control))
+	}
+
+	pub fn tools_cache_ttl(&self) -> Option<CacheTtl> {
+		self.chat
+			.and_then(|chat| chat.tools_cache_ttl)
+			.or_else(|| self.client.and_then(|client| client.tools_cache_ttl))
+	}
+
+	pub fn prompt_shield(&self) -> Option<&PromptShield> {
+		self.chat
+			.and_then(|chat| chat.prompt_shield.as_ref())
+			.or_else(|| self.client.and_then(|client| client.prompt_shield.as_ref()))
+	}
+
+	pub fn output_fixer(&self) -> Option<&OutputFixer> {
+		self.chat
+			.and_then(|chat| chat.output_fixer.as_ref())
+			.or_else(|| self.client.and_then(|client| client.output_fixer.as_ref()))
+	}
+
+	pub fn ai_disclosure(&self) -> Option<&AiDisclosure> {
+		self.chat
+			.and_then(|chat| chat.ai_disclosure.as_ref())
+			.or_else(|| self.client.and_then(|client| client.ai_disclosure.as_ref()))
+	}
+
+	pub fn time_context(&self) -> Option<&TimeContext> {
+		self.chat
+			.and_then(|chat| chat.time_context.as_ref())
+			.or_else(|| self.client.and_then(|client| client.time_context.as_ref()))
+	}
+
+	pub fn capability_fallback(&self) -> Option<&CapabilityFallback> {
+		self.chat
+			.and_then(|chat| chat.capability_fallback.as_ref())
+			.or_else(|| self.client.and_then(|client| client.capability_fallback.as_ref()))
+	}
+
+	pub fn auto_continue(&self) -> Option<&AutoContinue> {
+		self.chat
+			.and_then(|chat| chat.auto_continue.as_ref())
+			.or_else(|| self.client.and_then(|client| client.auto_continue.as_ref()))
+	}
+
+	pub fn kv_cache_hints(&self) -> Option<&KvCacheHints> {
+		self.chat
+			.and_then(|chat| chat.kv_cache_hints.as_ref())
+			.or_else(|| self.client.and_then(|client| client.kv_cache_hints.as_ref()))
+	}
+
+	pub fn tenant_id(&self) -> Option<&str> {
+		self.chat
+			.and_then(|chat| chat.tenant_id.as_deref())
+			.or_else(|| self.client.and_then(|client| client.tenant_id.as_deref()))
+	}
+
+	pub fn user(&self) -> Option<&str> {
+		self.chat
+			.and_then(|chat| chat.user.as_deref())
+			.or_else(|| self.client.and_then(|client| client.user.as_deref()))
+	}
+
+	pub fn store(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.store)
+			.or_else(|| self.client.and_then(|client| client.store))
+	}
+
+	pub fn metadata(&self) -> Option<&HashMap<String, String>> {
+		self.chat
+			.and_then(|chat| chat.metadata.as_ref())
+			.or_else(|| self.client.and_then(|client| client.metadata.as_ref()))
+	}
+
+	pub fn extra_body(&self) -> Option<&Value> {
+		self.chat
+			.and_then(|chat| chat.extra_body.as_ref())
+			.or_else(|| self.client.and_then(|client| client.extra_body.as_ref()))
+	}
+
 	pub fn extra_headers(&self) -> Option<&Headers> {
 		self.chat
 			.and_then(|chat| chat.extra_headers.as_ref())
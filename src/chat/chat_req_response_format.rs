@@ -1,7 +1,12 @@
 use derive_more::From;
+use schemars::JsonSchema;
+use schemars::generate::SchemaSettings;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+/// The string formats supported by the providers structured outputs (the other formats, e.g., `uint32`, are removed).
+const SUPPORTED_FORMATS: &[&str] = &["date-time", "date", "time", "duration", "email", "uuid"];
+
 /// The chat response format for the ChatRequest for structured output.
 /// This will be taken into consideration only if the provider supports it.
 ///
@@ -13,9 +18,25 @@ pub enum ChatResponseFormat {
 	/// Note: Make sure to add "Reply in JSON format." to the prompt or system to ensure it works correctly.
 	JsonMode,
 
-	/// Request to return a structured output.
+	/// Request to return a structured output, validated against the JSON schema of the `JsonSpec`.
+	/// - OpenAI (and the OpenAI compatible adapters): `response_format` `json_schema` (strict)
+	/// - Gemini: `responseSchema`
+	/// - Anthropic: a forced tool with the schema as `input_schema`, returned as the text content of the response
+	///   (only instructed, not forced, with extended thinking)
+	/// - Ollama: `response_format` json schema (mapped to the Ollama `format` by the server)
+	///
+	/// NOTE: For the local inference servers with an OpenAI compatible API (llama.cpp, vLLM, Ollama),
+	///       this is sent as the `response_format` json schema, which they enforce server-side as well.
 	#[from]
 	JsonSpec(JsonSpec),
+
+	/// Request a grammar-constrained output with a GBNF grammar (local inference servers only).
+	/// - llama.cpp server: sent as `grammar`
+	/// - vLLM: sent as `guided_grammar`
+	///
+	/// NOTE: Only for the OpenAI adapter with a custom local endpoint (e.g., with a `ServiceTargetResolver`),
+	///       as both properties are sent (each server ignores the other one). Ignored by the other adapters (e.g., Ollama does not support grammars).
+	Grammar(String),
 }
 
 /// The JSON specification for the structured output format.
@@ -42,6 +63,37 @@ impl JsonSpec {
 			schema: schema.into(),
 		}
 	}
+
+	/// Create a new JsonSpec from the JSON schema of a type (see `Client::exec_chat_structured(..)`).
+	///
+	/// The schema is made portable for the providers structured outputs:
+	/// - The sub schemas are inlined (no `$ref`), and the `$schema` and `title` are removed.
+	/// - All the properties are required (the optional ones are nullable), as for the OpenAI strict mode.
+	/// - The unsupported `format`s (e.g., `uint32`, `double`) are removed.
+	pub fn from_type<T: JsonSchema>() -> Self {
+		let generator = SchemaSettings::default()
+			.with(|settings| settings.inline_subschemas = true)
+			.into_generator();
+		let mut schema = generator.into_root_schema_for::<T>().to_value();
+		if let Some(root) = schema.as_object_mut() {
+			root.remove("$schema");
+		}
+		make_portable(&mut schema);
+
+		let name: String = T::schema_name()
+			.chars()
+			.map(|c| {
+				if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+					c
+				} else {
+					'_'
+				}
+			})
+			.take(64)
+			.collect();
+
+		Self::new(name, schema)
+	}
 }
 
 /// Setters
@@ -52,3 +104,38 @@ impl JsonSpec {
 		self
 	}
 }
+
+// region:    --- Support
+
+/// Make the schema portable (see `JsonSpec::from_type()`), recursively.
+fn make_portable(value: &mut Value) {
+	match value {
+		Value::Object(map) => {
+			map.remove("title");
+			if map
+				.get("format")
+				.and_then(Value::as_str)
+				.is_some_and(|format| !SUPPORTED_FORMATS.contains(&format))
+			{
+				map.remove("format");
+			}
+			if let Some(Value::Object(properties)) = map.get("properties") {
+				let required: Vec<Value> = properties.keys().map(|key| Value::from(key.as_str())).collect();
+				map.insert("required".to_string(), required.into());
+			}
+			// NOTE: The property names are not schemas (e.g., a property named `title`), so only their values are walked,
+			//       and the values (e.g., `enum`, `default`) are left unchanged.
+			for (key, child) in map.iter_mut() {
+				match (key.as_str(), child) {
+					("properties", Value::Object(properties)) => properties.values_mut().for_each(make_portable),
+					("enum" | "const" | "default" | "examples", _) => {}
+					(_, child) => make_portable(child),
+				}
+			}
+		}
+		Value::Array(items) => items.iter_mut().for_each(make_portable),
+		_ => {}
+	}
+}
+
+// endregion: --- Support
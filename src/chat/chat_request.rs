@@ -1,6 +1,6 @@
 //! This module contains all the types related to a Chat Request (except ChatOptions, which has its own file).
 
-use crate::chat::{ChatMessage, ChatRole, MessageContent, Tool};
+use crate::chat::{CacheControl, ChatMessage, ChatRole, MessageContent, Tool};
 use serde::{Deserialize, Serialize};
 
 // region:    --- ChatRequest
@@ -11,6 +11,10 @@ pub struct ChatRequest {
 	/// The initial system content of the request.
 	pub system: Option<String>,
 
+	/// The eventual cache breakpoint of the `.system` content (see `ChatRequest::with_system_cache_point()`).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub system_cache_control: Option<CacheControl>,
+
 	/// The messages of the request.
 	pub messages: Vec<ChatMessage>,
 
@@ -24,6 +28,7 @@ impl ChatRequest {
 		Self {
 			messages,
 			system: None,
+			system_cache_control: None,
 			tools: None,
 		}
 	}
@@ -32,6 +37,7 @@ impl ChatRequest {
 	pub fn from_system(content: impl Into<String>) -> Self {
 		Self {
 			system: Some(content.into()),
+			system_cache_control: None,
 			messages: Vec::new(),
 			tools: None,
 		}
@@ -41,6 +47,7 @@ impl ChatRequest {
 	pub fn from_user(content: impl Into<String>) -> Self {
 		Self {
 			system: None,
+			system_cache_control: None,
 			messages: vec![ChatMessage::user(content.into())],
 			tools: None,
 		}
@@ -50,6 +57,7 @@ impl ChatRequest {
 	pub fn from_messages(messages: Vec<ChatMessage>) -> Self {
 		Self {
 			system: None,
+			system_cache_control: None,
 			messages,
 			tools: None,
 		}
@@ -64,6 +72,12 @@ impl ChatRequest {
 		self
 	}
 
+	/// Place a cache breakpoint at the end of the `.system` content (Anthropic only, with the default cache TTL).
+	pub fn with_system_cache_point(mut self) -> Self {
+		self.system_cache_control = Some(CacheControl::Ephemeral);
+		self
+	}
+
 	/// Append a message to the request.
 	pub fn append_message(mut self, msg: impl Into<ChatMessage>) -> Self {
 		self.messages.push(msg.into());
@@ -95,7 +109,7 @@ impl ChatRequest {
 			.iter()
 			.map(|s| s.as_str())
 			.chain(self.messages.iter().filter_map(|message| match message.role {
-				ChatRole::System => match message.content {
+				ChatRole::System | ChatRole::Developer => match message.content {
 					MessageContent::Text(ref content) => Some(content.as_str()),
 					// If system content is not text, then we do not add it for now.
 					_ => None,
@@ -128,6 +142,22 @@ impl ChatRequest {
 
 		systems
 	}
+
+	/// Iterate through the pinned messages (see `MessageOptions.pinned`).
+	pub fn iter_pinned(&self) -> impl Iterator<Item = &ChatMessage> {
+		self.messages.iter().filter(|message| message.is_pinned())
+	}
+
+	/// Returns a ChatRequest with only the `.system` and the pinned messages of this request
+	/// (e.g., to count the tokens the pinned content will always take).
+	pub fn pinned_request(&self) -> ChatRequest {
+		ChatRequest {
+			system: self.system.clone(),
+			system_cache_control: self.system_cache_control.clone(),
+			messages: self.iter_pinned().cloned().collect(),
+			tools: None,
+		}
+	}
 }
 
 // endregion: --- ChatRequest
@@ -3,7 +3,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::ModelIden;
-use crate::chat::{ChatStream, MessageContent, ToolCall, Usage};
+use crate::chat::citation::provider_citations;
+use crate::chat::content_filter::looks_like_refusal;
+use crate::chat::{
+	AiDisclosureTag, ChatStream, Citation, ContentBlock, ContentFilterInfo, FileCitation, ImageSource, MessageContent,
+	OutputRepair, PromptLeak, ProviderExts, ResponseDigest, ResponseHeaders, ToolCall, Usage,
+};
 
 // region:    --- ChatResponse
 
@@ -25,12 +30,66 @@ pub struct ChatResponse {
 	/// For example, `gpt-4o` model_iden might have a provider_model_iden as `gpt-4o-2024-08-06`
 	pub provider_model_iden: ModelIden,
 
+	/// The eventual provider backend configuration fingerprint (OpenAI-compatible `system_fingerprint`).
+	/// With a `ChatOptions::seed`, the responses are only expected to be reproducible for the same fingerprint.
+	pub system_fingerprint: Option<String>,
+
 	// pub model
 	/// The eventual usage of the chat response
 	pub usage: Usage,
 
 	/// The raw value of the response body, which can be used for provider specific features.
 	pub captured_raw_body: Option<serde_json::Value>,
+
+	/// The eventual digest (sha256 hash and timestamp) of the exact response body.
+	/// Captured with `ChatOptions::with_capture_response_digest(true)` (not available for streaming)
+	pub response_digest: Option<ResponseDigest>,
+
+	/// The eventual selected response headers (request id, rate limits, processing time).
+	/// Captured with `ChatOptions::with_capture_headers(true)` (not available for streaming)
+	pub response_headers: Option<ResponseHeaders>,
+
+	/// The eventual normalized reason why the model stopped generating, when returned by the provider
+	/// (e.g., `FinishReason::Length` when the output was truncated, see `ChatOptions::with_auto_continue(..)`).
+	pub finish_reason: Option<FinishReason>,
+
+	/// The eventual stop sequence which ended the generation, when returned by the provider
+	/// (Anthropic `stop_sequence`, vLLM `stop_reason`), to know which of the `ChatOptions.stop_sequences` matched.
+	pub stop_sequence: Option<String>,
+
+	/// All the response choices (candidates), when the provider returned more than one
+	/// (see `ChatOptions::with_candidate_count(..)`), empty otherwise.
+	/// The first choice is also the response `content`, `reasoning_content`, `finish_reason`, and `logprobs`.
+	pub choices: Vec<ChatChoice>,
+
+	/// The eventual raw provider log probabilities of the first choice (see `ChatOptions::with_logprobs(..)`),
+	/// also set when there is only one choice (the `choices` being empty).
+	pub logprobs: Option<serde_json::Value>,
+
+	/// The eventual normalized content filter information, when returned by the provider
+	/// (e.g., Azure OpenAI `content_filter_results`, Gemini safety ratings, Anthropic refusal).
+	pub content_filter: Option<ContentFilterInfo>,
+
+	/// The eventual leaks detected by the `ChatOptions::with_prompt_shield(..)` check
+	/// (`None` when no prompt shield is set, empty when no leaks were found).
+	pub prompt_leaks: Option<Vec<PromptLeak>>,
+
+	/// The eventual files cited by the provider `file_search` tool (OpenAI only for now, see `Tool::file_search(..)`).
+	pub file_citations: Option<Vec<FileCitation>>,
+
+	/// The eventual web sources cited by the provider web search tool (OpenAI Responses API only for now,
+	/// see `BuiltInTool::WebSearch`). See `citations()` for all the normalized citations.
+	pub web_citations: Option<Vec<Citation>>,
+
+	/// The eventual repair of an invalid JSON output by the `ChatOptions::with_output_fixer(..)` fixer model.
+	pub output_repair: Option<OutputRepair>,
+
+	/// The eventual AI disclosure applied by the `ChatOptions::with_ai_disclosure(..)` (the disclosure metadata).
+	pub ai_disclosure: Option<AiDisclosureTag>,
+
+	/// The typed provider-specific extras (e.g., `GroqTiming`, `GeminiGroundingMetadata`), see `provider_ext::<T>()`.
+	#[serde(skip)]
+	pub provider_exts: ProviderExts,
 }
 
 // Getters
@@ -77,6 +136,35 @@ impl ChatResponse {
 		all_texts
 	}
 
+	/// Returns the image outputs as `(content_type, source)`
+	/// (e.g., Gemini image generation models, OpenAI `Tool::image_generation(..)`).
+	pub fn images(&self) -> Vec<(&str, &ImageSource)> {
+		let mut all_images = Vec::new();
+		for content_item in &self.content {
+			if let MessageContent::Blocks(blocks) = content_item {
+				for block in blocks {
+					if let ContentBlock::Image { content_type, source } = block {
+						all_images.push((content_type.as_str(), source));
+					}
+				}
+			}
+		}
+		all_images
+	}
+
+	/// Returns the typed provider-specific extra of type `T`, when set by the adapter.
+	/// - Groq: `GroqTiming`
+	/// - Gemini: `GeminiGroundingMetadata` (with the `googleSearch` tool)
+	pub fn provider_ext<T: std::any::Any + Send + Sync>(&self) -> Option<&T> {
+		self.provider_exts.get::<T>()
+	}
+
+	/// Returns the provider native citations as `Citation`s (the `file_citations`, and the Gemini grounding
+	/// web sources with the span of the supported text). See `RagCitations` for the citations of retrieved chunks.
+	pub fn citations(&self) -> Vec<Citation> {
+		provider_citations(self)
+	}
+
 	/// Returns a vector of references to all captured tool calls.
 	pub fn tool_calls(&self) -> Vec<&ToolCall> {
 		let mut all_tool_calls: Vec<&ToolCall> = Vec::new();
@@ -91,6 +179,21 @@ impl ChatResponse {
 		all_tool_calls
 	}
 
+	/// Returns true when the model refused to answer, from the provider signals
+	/// (`content_filter` blocked, e.g., OpenAI `message.refusal`, Anthropic `stop_reason: refusal`, Gemini safety block),
+	/// or, without a provider signal, when the text starts like a refusal (e.g., "I'm sorry, but I can't help with that").
+	///
+	/// NOTE: The pattern fallback is a heuristic (English only), and only applies to the responses without tool calls.
+	pub fn is_refusal(&self) -> bool {
+		if self.content_filter.as_ref().is_some_and(|info| info.blocked) {
+			return true;
+		}
+		if !self.tool_calls().is_empty() {
+			return false;
+		}
+		self.first_text().is_some_and(looks_like_refusal)
+	}
+
 	/// Consumes the `ChatResponse` and returns a vector of all captured tool calls.
 	pub fn into_tool_calls(self) -> Vec<ToolCall> {
 		let mut all_tool_calls: Vec<ToolCall> = Vec::new();
@@ -123,6 +226,79 @@ impl ChatResponse {
 
 // endregion: --- ChatResponse
 
+// region:    --- ChatChoice
+
+/// One choice (candidate) of a chat response with multiple completions (see `ChatResponse.choices`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatChoice {
+	/// The index of the choice, as returned by the provider.
+	pub index: usize,
+
+	/// The content of the choice.
+	pub content: Vec<MessageContent>,
+
+	/// The eventual reasoning content of the choice.
+	pub reasoning_content: Option<String>,
+
+	/// The eventual normalized reason why the model stopped generating this choice.
+	pub finish_reason: Option<FinishReason>,
+
+	/// The eventual raw provider log probabilities of the choice (see `ChatOptions::with_logprobs(..)`),
+	/// e.g., OpenAI `choices[].logprobs`, Gemini `candidates[].logprobsResult`.
+	pub logprobs: Option<serde_json::Value>,
+}
+
+impl ChatChoice {
+	/// Returns a reference to the first text content if available.
+	pub fn first_text(&self) -> Option<&str> {
+		self.content.iter().find_map(|content_item| match content_item {
+			MessageContent::Text(content) => Some(content.as_str()),
+			_ => None,
+		})
+	}
+}
+
+// endregion: --- ChatChoice
+
+// region:    --- FinishReason
+
+/// The normalized reason why the model stopped generating.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FinishReason {
+	/// A natural stop point or a stop sequence.
+	Stop,
+
+	/// The max tokens (or the provider output limit) was reached, so the output is truncated.
+	Length,
+
+	/// The model requested tool calls.
+	ToolCalls,
+
+	/// The output was filtered or refused (see `ChatResponse.content_filter`).
+	ContentFilter,
+
+	/// Another provider reason (as returned by the provider).
+	Other(String),
+}
+
+impl FinishReason {
+	/// Normalize the provider finish reason
+	/// (OpenAI `finish_reason`, Anthropic `stop_reason`, Gemini `finishReason`, Cohere `finish_reason`).
+	pub(crate) fn from_provider(reason: &str) -> Self {
+		match reason {
+			"stop" | "end_turn" | "stop_sequence" | "STOP" | "COMPLETE" | "completed" => FinishReason::Stop,
+			"length" | "max_tokens" | "max_output_tokens" | "MAX_TOKENS" => FinishReason::Length,
+			"tool_calls" | "function_call" | "tool_use" | "TOOL_CALL" => FinishReason::ToolCalls,
+			"content_filter" | "refusal" | "SAFETY" | "PROHIBITED_CONTENT" | "BLOCKLIST" | "SPII" | "RECITATION" => {
+				FinishReason::ContentFilter
+			}
+			other => FinishReason::Other(other.to_string()),
+		}
+	}
+}
+
+// endregion: --- FinishReason
+
 // region:    --- ChatStreamResponse
 
 /// The result returned from the chat stream.
@@ -135,3 +311,17 @@ pub struct ChatStreamResponse {
 }
 
 // endregion: --- ChatStreamResponse
+
+// region:    --- ChatStructuredResponse
+
+/// The result of `Client::exec_chat_structured::<T>(..)`, the typed value and the raw chat response.
+#[derive(Debug, Clone)]
+pub struct ChatStructuredResponse<T> {
+	/// The value deserialized from the JSON text of the response.
+	pub value: T,
+
+	/// The raw chat response (e.g., for the usage).
+	pub chat_response: ChatResponse,
+}
+
+// endregion: --- ChatStructuredResponse
@@ -0,0 +1,126 @@
+//! This module contains the speculative draft-and-verify chat stream (see `Client::exec_chat_speculative(..)`).
+
+use crate::Result;
+use crate::chat::{ChatResponse, ChatStream, ChatStreamEvent};
+use futures::{Future, Stream};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+type VerifyFuture = Pin<Box<dyn Future<Output = Result<ChatResponse>> + Send>>;
+
+// region:    --- SpeculativeEvent
+
+/// The event of a `SpeculativeStream`.
+#[derive(Debug)]
+pub enum SpeculativeEvent {
+	/// An event of the draft model stream, to be displayed immediately.
+	Draft(ChatStreamEvent),
+
+	/// The last event when the verify model answer is the same as the draft (whitespace-insensitive).
+	Confirmed(ChatResponse),
+
+	/// The last event when the verify model answer differs from the draft.
+	/// The displayed draft should be replaced by the `response` content.
+	Correction {
+		/// The full draft text, as streamed.
+		draft: String,
+		/// The verify model response.
+		response: ChatResponse,
+	},
+}
+
+// endregion: --- SpeculativeEvent
+
+// region:    --- SpeculativeStream
+
+/// A stream of the draft model events, ending with the `Confirmed` or `Correction` event of the verify model,
+/// which runs concurrently with the draft stream.
+///
+/// NOTE: When the draft stream fails, its error is returned, and the stream still ends with the verify model event
+///       (as a `Correction` of the partial draft).
+pub struct SpeculativeStream {
+	draft_stream: Option<ChatStream>,
+	verify_future: Option<VerifyFuture>,
+	verify_result: Option<Result<ChatResponse>>,
+	draft_content: String,
+	done: bool,
+}
+
+impl SpeculativeStream {
+	pub(crate) fn new(draft_stream: ChatStream, verify_future: VerifyFuture) -> Self {
+		Self {
+			draft_stream: Some(draft_stream),
+			verify_future: Some(verify_future),
+			verify_result: None,
+			draft_content: String::new(),
+			done: false,
+		}
+	}
+}
+
+impl Stream for SpeculativeStream {
+	type Item = Result<SpeculativeEvent>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let this = self.get_mut();
+
+		if this.done {
+			return Poll::Ready(None);
+		}
+
+		// -- Drive the verify request (concurrently with the draft stream)
+		if let Some(Poll::Ready(verify_result)) = this.verify_future.as_mut().map(|fut| fut.as_mut().poll(cx)) {
+			this.verify_result = Some(verify_result);
+			this.verify_future = None;
+		}
+
+		// -- Stream the draft first
+		if let Some(draft_stream) = this.draft_stream.as_mut() {
+			match Pin::new(draft_stream).poll_next(cx) {
+				Poll::Ready(Some(Ok(event))) => {
+					if let ChatStreamEvent::Chunk(chunk) = &event {
+						this.draft_content.push_str(&chunk.content);
+					}
+					return Poll::Ready(Some(Ok(SpeculativeEvent::Draft(event))));
+				}
+				Poll::Ready(Some(Err(err))) => {
+					this.draft_stream = None;
+					return Poll::Ready(Some(Err(err)));
+				}
+				Poll::Ready(None) => this.draft_stream = None,
+				Poll::Pending => return Poll::Pending,
+			}
+		}
+
+		// -- Then the verify model event
+		match this.verify_result.take() {
+			Some(verify_result) => {
+				this.done = true;
+				let event = verify_result.map(|response| {
+					let verified = response.first_text().unwrap_or_default();
+					if is_same_answer(&this.draft_content, verified) {
+						SpeculativeEvent::Confirmed(response)
+					} else {
+						SpeculativeEvent::Correction {
+							draft: std::mem::take(&mut this.draft_content),
+							response,
+						}
+					}
+				});
+				Poll::Ready(Some(event))
+			}
+			// The verify future was polled above, so it will wake up the task
+			None => Poll::Pending,
+		}
+	}
+}
+
+// endregion: --- SpeculativeStream
+
+// region:    --- Support
+
+fn is_same_answer(draft: &str, verified: &str) -> bool {
+	draft.split_whitespace().eq(verified.split_whitespace())
+}
+
+// endregion: --- Support
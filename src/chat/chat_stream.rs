@@ -1,5 +1,5 @@
 use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
-use crate::chat::{MessageContent, ToolCall, Usage};
+use crate::chat::{FinishReason, MessageContent, ToolCall, Usage};
 use futures::Stream;
 use serde::{Deserialize, Serialize};
 use std::pin::Pin;
@@ -110,6 +110,14 @@ pub struct StreamEnd {
 	/// The eventual captured
 	/// Note: This requires the ChatOptions `capture_reasoning` flag to be set to true.
 	pub captured_reasoning_content: Option<String>,
+
+	/// The eventual normalized reason why the model stopped generating, when returned by the provider
+	/// (e.g., `FinishReason::Length` when the output was truncated). Always captured.
+	pub finish_reason: Option<FinishReason>,
+
+	/// The eventual stop sequence which ended the generation, when returned by the provider (Anthropic only for now).
+	/// Always captured.
+	pub stop_sequence: Option<String>,
 }
 
 impl From<InterStreamEnd> for StreamEnd {
@@ -137,6 +145,8 @@ impl From<InterStreamEnd> for StreamEnd {
 			captured_usage: inter_end.captured_usage,
 			captured_content,
 			captured_reasoning_content: inter_end.captured_reasoning_content,
+			finish_reason: inter_end.finish_reason,
+			stop_sequence: inter_end.stop_sequence,
 		}
 	}
 }
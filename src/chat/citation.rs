@@ -0,0 +1,301 @@
+//! This module contains the `Citation`, the unified citation of a response (RAG chunk markers, provider file
+//! citations, web grounding), and the `RagCitations` helper to inject the retrieved chunks and resolve their markers.
+
+use crate::chat::{ChatRequest, ChatResponse, ContentBlock, GeminiGroundingMetadata, MessageContent};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+const DEFAULT_INSTRUCTION: &str = "Answer using the sources below. \
+After each sentence using a source, cite the source ids in square brackets, e.g., [id] or [id1, id2]. \
+Only cite the ids of the sources below.";
+
+/// The max length (in chars) of a marker content, to skip the long bracketed texts quickly.
+const MARKER_MAX_CHARS: usize = 200;
+
+// region:    --- Citation
+
+/// A citation of the response text, from the `RagCitations` markers or from the provider native citations
+/// (see `ChatResponse::citations()`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Citation {
+	/// Where the citation comes from.
+	pub source: CitationSource,
+
+	/// The cited source id (the `RagChunk.id`, the provider file id, or the web uri).
+	pub id: String,
+
+	/// The eventual title of the source (e.g., the file name or the web page title).
+	pub title: Option<String>,
+
+	/// The eventual span of the cited text, as a char range of the response text
+	/// (empty at the citation position when the provider only gives the position, e.g., OpenAI file citations).
+	pub span: Option<Range<usize>>,
+
+	/// The eventual quoted text of the source (e.g., Anthropic `cited_text`).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub cited_text: Option<String>,
+}
+
+/// The origin of a `Citation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CitationSource {
+	/// A retrieved chunk cited with a `RagCitations` marker.
+	Chunk,
+	/// A file cited by the provider `file_search` tool (see `ChatResponse.file_citations`).
+	File,
+	/// A web source of the provider grounding (e.g., Gemini `googleSearch`).
+	Web,
+	/// A document of the request cited by the provider (e.g., Anthropic citations, with the document index as id).
+	Document,
+}
+
+// endregion: --- Citation
+
+// region:    --- RagChunk
+
+/// A retrieved chunk, cited by its `id` in the response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RagChunk {
+	/// The chunk id, as cited in the markers (e.g., `doc3-2`), without brackets, commas, or new lines.
+	pub id: String,
+
+	/// The chunk text.
+	pub text: String,
+
+	/// The eventual title of the chunk source (e.g., the document name).
+	pub title: Option<String>,
+}
+
+/// Constructors & Chainable Setters
+impl RagChunk {
+	pub fn new(id: impl Into<String>, text: impl Into<String>) -> Self {
+		Self {
+			id: id.into(),
+			text: text.into(),
+			title: None,
+		}
+	}
+
+	pub fn with_title(mut self, title: impl Into<String>) -> Self {
+		self.title = Some(title.into());
+		self
+	}
+}
+
+// endregion: --- RagChunk
+
+// region:    --- RagCitations
+
+/// Instructs the model to cite the retrieved chunks with `[id]` markers (see `apply(..)`),
+/// and resolves the markers of the response into `Citation`s (see `resolve(..)`).
+///
+/// The markers of unknown ids are left in the text (e.g., a bracketed text of the answer).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RagCitations {
+	pub chunks: Vec<RagChunk>,
+
+	/// The citation instruction, before the sources block of the system prompt.
+	pub instruction: String,
+}
+
+/// The response text without the citation markers, and its citations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CitedAnswer {
+	pub text: String,
+	pub citations: Vec<Citation>,
+}
+
+/// Constructors & Chainable Setters
+impl RagCitations {
+	pub fn new(chunks: Vec<RagChunk>) -> Self {
+		Self {
+			chunks,
+			instruction: DEFAULT_INSTRUCTION.to_string(),
+		}
+	}
+
+	pub fn with_instruction(mut self, instruction: impl Into<String>) -> Self {
+		self.instruction = instruction.into();
+		self
+	}
+}
+
+/// Public Functions
+impl RagCitations {
+	/// Returns the chat request with the citation instruction and the sources block appended to its system.
+	pub fn apply(&self, mut chat_req: ChatRequest) -> ChatRequest {
+		let mut block = format!("{}\n\n<sources>\n", self.instruction);
+		for chunk in &self.chunks {
+			match chunk.title.as_deref() {
+				Some(title) => block.push_str(&format!("<source id=\"{}\" title=\"{title}\">\n", chunk.id)),
+				None => block.push_str(&format!("<source id=\"{}\">\n", chunk.id)),
+			}
+			block.push_str(chunk.text.trim());
+			block.push_str("\n</source>\n");
+		}
+		block.push_str("</sources>");
+
+		match chat_req.system.as_mut() {
+			Some(system) => {
+				system.push_str("\n\n");
+				system.push_str(&block);
+			}
+			None => chat_req.system = Some(block),
+		}
+		chat_req
+	}
+
+	/// Resolves the markers of the response text into citations, with the span of the cited sentence
+	/// (from the sentence start to the marker), and returns the text without the markers.
+	pub fn resolve(&self, text: &str) -> CitedAnswer {
+		let mut out = String::with_capacity(text.len());
+		let mut out_chars = 0;
+		let mut citations = Vec::new();
+
+		let mut rest = text;
+		while let Some(open) = rest.find('[') {
+			let (before, from_open) = rest.split_at(open);
+			out.push_str(before);
+			out_chars += before.chars().count();
+
+			let Some(ids) = self.marker_ids(from_open) else {
+				out.push('[');
+				out_chars += 1;
+				rest = &from_open[1..];
+				continue;
+			};
+			let close = from_open.find(']').unwrap_or(from_open.len() - 1);
+			rest = &from_open[close + 1..];
+
+			// -- Remove the space before the marker (e.g., `capital [c1].` -> `capital.`)
+			let trimmed = out.trim_end_matches([' ', '\t']).len();
+			out_chars -= out[trimmed..].chars().count();
+			out.truncate(trimmed);
+
+			let span = sentence_span(&out, out_chars);
+			for id in ids {
+				let title = self
+					.chunks
+					.iter()
+					.find(|chunk| chunk.id == id)
+					.and_then(|chunk| chunk.title.clone());
+				citations.push(Citation {
+					source: CitationSource::Chunk,
+					id: id.to_string(),
+					title,
+					span: Some(span.clone()),
+					cited_text: None,
+				});
+			}
+		}
+		out.push_str(rest);
+
+		CitedAnswer { text: out, citations }
+	}
+
+	/// Resolves the markers of the first text of the response (see `resolve(..)`).
+	pub fn resolve_response(&self, chat_res: &ChatResponse) -> Option<CitedAnswer> {
+		chat_res.first_text().map(|text| self.resolve(text))
+	}
+}
+
+/// Support
+impl RagCitations {
+	/// Returns the ids of the marker at the start of the text (`[id]` or `[id1, id2]`),
+	/// when all of them are chunk ids.
+	fn marker_ids<'a>(&self, text: &'a str) -> Option<Vec<&'a str>> {
+		let close = text.char_indices().take(MARKER_MAX_CHARS).find(|(_, c)| *c == ']')?.0;
+		let inner = &text[1..close];
+		if inner.contains(['[', '\n']) {
+			return None;
+		}
+		let ids: Vec<&str> = inner.split(',').map(str::trim).collect();
+		let all_known = ids.iter().all(|id| self.chunks.iter().any(|chunk| chunk.id == *id));
+		all_known.then_some(ids)
+	}
+}
+
+// endregion: --- RagCitations
+
+// region:    --- Crate Functions
+
+/// Returns the provider native citations of the response as `Citation`s
+/// (the `file_citations`, the `web_citations`, the Gemini grounding supports located in the first text,
+/// and the text block citations).
+pub(crate) fn provider_citations(chat_res: &ChatResponse) -> Vec<Citation> {
+	let mut citations: Vec<Citation> = chat_res
+		.file_citations
+		.iter()
+		.flatten()
+		.map(|file_citation| Citation {
+			source: CitationSource::File,
+			id: file_citation.file_id.clone(),
+			title: file_citation.file_name.clone(),
+			span: file_citation.index.map(|index| index..index),
+			cited_text: None,
+		})
+		.collect();
+
+	if let Some(grounding) = chat_res.provider_ext::<GeminiGroundingMetadata>() {
+		let text = chat_res.first_text().unwrap_or_default();
+		for support in &grounding.grounding_supports {
+			let span = support.text.as_deref().and_then(|supported| {
+				let start = text.find(supported)?;
+				let start_chars = text[..start].chars().count();
+				Some(start_chars..start_chars + supported.chars().count())
+			});
+			for chunk in support
+				.grounding_chunk_indices
+				.iter()
+				.filter_map(|index| grounding.grounding_chunks.get(*index))
+			{
+				citations.push(Citation {
+					source: CitationSource::Web,
+					id: chunk.uri.clone().unwrap_or_default(),
+					title: chunk.title.clone(),
+					span: span.clone(),
+					cited_text: None,
+				});
+			}
+		}
+	}
+
+	citations.extend(chat_res.web_citations.iter().flatten().cloned());
+
+	for content in &chat_res.content {
+		if let MessageContent::Blocks(blocks) = content {
+			for block in blocks {
+				if let ContentBlock::Text {
+					citations: Some(block_citations),
+					..
+				} = block
+				{
+					citations.extend(block_citations.iter().cloned());
+				}
+			}
+		}
+	}
+
+	citations
+}
+
+// endregion: --- Crate Functions
+
+// region:    --- Support
+
+/// The char range of the sentence ending at `end` (in chars) of the text, without the leading whitespace.
+fn sentence_span(text: &str, end: usize) -> Range<usize> {
+	// NOTE: The terminal punctuation of the cited sentence itself (marker after the period) is not a boundary.
+	let body = text.trim_end_matches(['.', '!', '?']);
+	let start = body
+		.char_indices()
+		.rev()
+		.find(|(_, c)| matches!(c, '.' | '!' | '?' | '\n'))
+		.map(|(idx, c)| idx + c.len_utf8())
+		.unwrap_or(0);
+	let leading = text[start..].len() - text[start..].trim_start().len();
+	let start_chars = text[..start + leading].chars().count();
+	start_chars.min(end)..end
+}
+
+// endregion: --- Support
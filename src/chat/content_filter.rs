@@ -0,0 +1,127 @@
+//! This module contains the normalized content filter information of a chat response (or error).
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// region:    --- ContentFilterInfo
+
+/// The normalized content filter information, when the provider returns content filter metadata,
+/// so that apps can explain the refusals to their users.
+///
+/// - OpenAI / Azure OpenAI: `content_filter_results` (and `prompt_filter_results`), `finish_reason: content_filter`
+/// - Gemini: `safetyRatings`, `promptFeedback.blockReason`, and the safety `finishReason`s
+/// - Anthropic: `stop_reason: refusal` (no categories)
+/// - OpenAI: `message.refusal` (e.g., structured outputs refusals), with the reason `refusal`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContentFilterInfo {
+	/// True when the prompt or the response was blocked (or truncated) by the provider filters.
+	pub blocked: bool,
+
+	/// The eventual provider reason (e.g., `content_filter`, `SAFETY`, `PROHIBITED_CONTENT`, `refusal`).
+	pub reason: Option<String>,
+
+	/// The eventual categories evaluated by the provider filters.
+	pub categories: Vec<ContentFilterCategory>,
+
+	/// The eventual refusal message of the model (e.g., OpenAI `message.refusal`).
+	pub message: Option<String>,
+}
+
+/// A content filter category as evaluated by the provider.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentFilterCategory {
+	/// The provider category name (e.g., `hate`, `self_harm`, `HARM_CATEGORY_HARASSMENT`).
+	pub name: String,
+
+	/// The eventual provider severity or probability (e.g., `safe`, `medium`, `NEGLIGIBLE`, `HIGH`).
+	pub severity: Option<String>,
+
+	/// True when this category caused the filtering.
+	pub filtered: bool,
+}
+
+/// Constructors
+impl ContentFilterInfo {
+	/// A model refusal (reason `refusal`, without categories), with the eventual refusal message.
+	pub(crate) fn refusal(message: Option<String>) -> Self {
+		Self {
+			blocked: true,
+			reason: Some("refusal".to_string()),
+			categories: Vec::new(),
+			message,
+		}
+	}
+}
+
+/// Getters
+impl ContentFilterInfo {
+	/// Returns the categories which caused the filtering.
+	pub fn filtered_categories(&self) -> impl Iterator<Item = &ContentFilterCategory> {
+		self.categories.iter().filter(|category| category.filtered)
+	}
+}
+
+/// Crate Parsers
+impl ContentFilterInfo {
+	/// Parse the OpenAI / Azure OpenAI `content_filter_results` object
+	/// (e.g., `{"hate": {"filtered": false, "severity": "safe"}, "jailbreak": {"filtered": false, "detected": false}}`).
+	pub(crate) fn categories_from_openai_filter_results(results: &Value) -> Vec<ContentFilterCategory> {
+		let Some(results) = results.as_object() else {
+			return Vec::new();
+		};
+
+		results
+			.iter()
+			.filter(|(_, result)| result.is_object())
+			.map(|(name, result)| ContentFilterCategory {
+				name: name.to_string(),
+				severity: result.get("severity").and_then(Value::as_str).map(String::from),
+				filtered: result.get("filtered").and_then(Value::as_bool).unwrap_or(false),
+			})
+			.collect()
+	}
+}
+
+// endregion: --- ContentFilterInfo
+
+// region:    --- Refusal Patterns
+
+/// The (lowercase) openings of the typical refusal answers, for the responses without a provider refusal signal.
+const REFUSAL_PATTERNS: &[&str] = &[
+	"i can't help with",
+	"i cannot help with",
+	"i can't assist with",
+	"i cannot assist with",
+	"i can't provide",
+	"i cannot provide",
+	"i can't comply",
+	"i cannot comply",
+	"i won't be able to help",
+	"i'm not able to help",
+	"i am not able to help",
+	"i'm unable to help",
+	"i am unable to help",
+	"i'm sorry, but i can't",
+	"i'm sorry, but i cannot",
+	"sorry, but i can't",
+	"sorry, i can't help",
+];
+
+/// The max number of leading characters of the text where a refusal pattern is looked for
+/// (a refusal is at the start of the answer, not in the middle of a long one).
+const REFUSAL_PATTERN_WINDOW: usize = 120;
+
+/// Returns true when the text starts like a refusal answer (e.g., "I'm sorry, but I can't help with that.").
+pub(crate) fn looks_like_refusal(text: &str) -> bool {
+	let head: String = text
+		.trim_start()
+		.chars()
+		.take(REFUSAL_PATTERN_WINDOW)
+		.map(|c| if c == '\u{2019}' { '\'' } else { c })
+		.collect::<String>()
+		.to_lowercase();
+
+	REFUSAL_PATTERNS.iter().any(|pattern| head.contains(pattern))
+}
+
+// endregion: --- Refusal Patterns
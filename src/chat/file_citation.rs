@@ -0,0 +1,16 @@
+//! This module contains the `FileCitation`, a reference to a file used by the provider `file_search` tool.
+
+use serde::{Deserialize, Serialize};
+
+/// A file cited in the response text by the provider `file_search` tool (see `Tool::file_search(..)`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileCitation {
+	/// The provider file id (e.g., `file-...` for OpenAI).
+	pub file_id: String,
+
+	/// The eventual file name.
+	pub file_name: Option<String>,
+
+	/// The eventual position (character index) of the citation in the response text.
+	pub index: Option<usize>,
+}
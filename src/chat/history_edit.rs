@@ -0,0 +1,280 @@
+//! This module contains the history edit functions of the `ChatRequest`, to rewrite the past conversation content
+//! (e.g., to reduce its size) while preserving the provider requirements:
+//! - Each tool response must follow the tool call with the same id (and each tool call must have its response).
+//! - The signed thinking blocks (Anthropic) cannot be modified, only dropped.
+//! - The Gemini thought signatures of the tool calls must be kept.
+//!
+//! The pinned messages (see `MessageOptions.pinned`) are always kept verbatim.
+
+use crate::chat::{ChatMessage, ChatRequest, ChatRole, ContentBlock, MessageContent};
+use crate::{Error, Result};
+use std::collections::HashSet;
+use std::ops::RangeBounds;
+
+/// The default marker appended to the truncated tool results.
+const TRUNCATED_MARKER: &str = "\n[... truncated]";
+
+/// History Edit Functions
+impl ChatRequest {
+	/// Replace the content block `block_idx` of the message `msg_idx` (which must have a `MessageContent::Blocks` content).
+	///
+	/// - A `ToolUse` / `ToolResult` block can only be replaced by a block of the same kind and id
+	///   (its eventual thought signature is kept when the new block has none).
+	/// - A `Thinking` / `RedactedThinking` block cannot be replaced (its signature would not match anymore),
+	///   use `drop_thinking_blocks(..)` instead.
+	/// - The blocks of a pinned message cannot be replaced.
+	pub fn replace_block(&mut self, msg_idx: usize, block_idx: usize, mut block: ContentBlock) -> Result<()> {
+		if self.messages.get(msg_idx).is_some_and(|msg| msg.is_pinned()) {
+			return Err(history_edit_error(format!("message {msg_idx} is pinned")));
+		}
+		let Some(ChatMessage {
+			content: MessageContent::Blocks(blocks),
+			..
+		}) = self.messages.get_mut(msg_idx)
+		else {
+			return Err(history_edit_error(format!("message {msg_idx} has no content blocks")));
+		};
+		let Some(current) = blocks.get_mut(block_idx) else {
+			return Err(history_edit_error(format!(
+				"message {msg_idx} has no content block {block_idx}"
+			)));
+		};
+
+		match (&*current, &mut block) {
+			(ContentBlock::Thinking { .. } | ContentBlock::RedactedThinking { .. }, _) => {
+				return Err(history_edit_error(
+					"thinking blocks cannot be replaced (signed), only dropped".to_string(),
+				));
+			}
+			(
+				ContentBlock::ToolUse {
+					id, thought_signature, ..
+				},
+				ContentBlock::ToolUse {
+					id: new_id,
+					thought_signature: new_thought_signature,
+					..
+				},
+			) => {
+				if id != new_id {
+					return Err(history_edit_error(format!("tool use '{id}' must keep its id")));
+				}
+				if new_thought_signature.is_none() {
+					new_thought_signature.clone_from(thought_signature);
+				}
+			}
+			(
+				ContentBlock::ToolResult { tool_use_id, .. },
+				ContentBlock::ToolResult {
+					tool_use_id: new_tool_use_id,
+					..
+				},
+			) if tool_use_id != new_tool_use_id => {
+				return Err(history_edit_error(format!(
+					"tool result of '{tool_use_id}' must keep its tool_use_id"
+				)));
+			}
+			(ContentBlock::ToolResult { .. }, ContentBlock::ToolResult { .. }) => (),
+			(ContentBlock::ToolUse { .. } | ContentBlock::ToolResult { .. }, _) => {
+				return Err(history_edit_error(
+					"tool use and tool result blocks can only be replaced by the same kind of block".to_string(),
+				));
+			}
+			_ => (),
+		}
+
+		*current = block;
+		Ok(())
+	}
+
+	/// Truncate the tool responses (and tool result blocks) longer than `max_chars` characters,
+	/// keeping their call ids, and returns the number of truncated tool responses.
+	///
+	/// A marker (`[... truncated]`) is appended to the truncated content.
+	/// The tool responses of the pinned messages are not truncated.
+	pub fn truncate_tool_results(&mut self, max_chars: usize) -> usize {
+		let mut count = 0;
+		let mut truncate = |content: &mut String| {
+			if let Some((byte_idx, _)) = content.char_indices().nth(max_chars) {
+				content.truncate(byte_idx);
+				content.push_str(TRUNCATED_MARKER);
+				count += 1;
+			}
+		};
+
+		for msg in self.messages.iter_mut().filter(|msg| !msg.is_pinned()) {
+			match &mut msg.content {
+				MessageContent::ToolResponses(tool_responses) => {
+					tool_responses.iter_mut().for_each(|res| truncate(&mut res.content));
+				}
+				MessageContent::Blocks(blocks) => {
+					for block in blocks.iter_mut() {
+						if let ContentBlock::ToolResult { content, .. } = block {
+							truncate(content);
+						}
+					}
+				}
+				_ => (),
+			}
+		}
+
+		count
+	}
+
+	/// Drop the thinking blocks (`Thinking` and `RedactedThinking`) of the assistant messages
+	/// older than the last `keep_last_turns` assistant messages, and returns the number of dropped blocks.
+	///
+	/// NOTES:
+	/// - The thinking blocks of the last assistant message are always kept when it has tool uses
+	///   (the thinking of a pending tool loop is required by Anthropic).
+	/// - The thinking blocks of a message without other blocks are kept (a message cannot be empty).
+	/// - The thinking blocks of the pinned messages are kept.
+	pub fn drop_thinking_blocks(&mut self, keep_last_turns: usize) -> usize {
+		let assistant_idxs: Vec<usize> = self
+			.messages
+			.iter()
+			.enumerate()
+			.filter(|(_, msg)| matches!(msg.role, ChatRole::Assistant))
+			.map(|(idx, _)| idx)
+			.collect();
+		let mut keep_from = assistant_idxs.len().saturating_sub(keep_last_turns);
+
+		// keep the thinking of a pending tool loop
+		if let Some(&last_idx) = assistant_idxs.last() {
+			let has_tool_use = matches!(
+				&self.messages[last_idx].content,
+				MessageContent::Blocks(blocks) if blocks.iter().any(|block| matches!(block, ContentBlock::ToolUse { .. }))
+			);
+			if has_tool_use {
+				keep_from = keep_from.min(assistant_idxs.len() - 1);
+			}
+		}
+
+		let mut count = 0;
+		for &msg_idx in &assistant_idxs[..keep_from] {
+			let msg = &mut self.messages[msg_idx];
+			if msg.is_pinned() {
+				continue;
+			}
+			let MessageContent::Blocks(blocks) = &mut msg.content else {
+				continue;
+			};
+			let is_thinking = |block: &ContentBlock| {
+				matches!(
+					block,
+					ContentBlock::Thinking { .. } | ContentBlock::RedactedThinking { .. }
+				)
+			};
+			if blocks.iter().all(is_thinking) {
+				continue;
+			}
+			let before = blocks.len();
+			blocks.retain(|block| !is_thinking(block));
+			count += before - blocks.len();
+		}
+
+		count
+	}
+
+	/// Replace the messages of the `range` with the `messages` (like `Vec::splice`), and returns the removed messages.
+	///
+	/// The resulting messages must keep the tool call / tool response pairs (see `validate_tool_pairs()`),
+	/// otherwise the request is left unchanged and an error is returned.
+	/// The `range` cannot contain pinned messages.
+	pub fn splice_messages(
+		&mut self,
+		range: impl RangeBounds<usize>,
+		messages: impl IntoIterator<Item = ChatMessage>,
+	) -> Result<Vec<ChatMessage>> {
+		let original = self.messages.clone();
+		let removed: Vec<ChatMessage> = self.messages.splice(range, messages).collect();
+
+		if removed.iter().any(|msg| msg.is_pinned()) {
+			self.messages = original;
+			return Err(history_edit_error("pinned messages cannot be removed".to_string()));
+		}
+		if let Err(err) = self.validate_tool_pairs() {
+			self.messages = original;
+			return Err(err);
+		}
+
+		Ok(removed)
+	}
+
+	/// Check that each tool response follows the tool call with the same id,
+	/// and that each tool call has its tool response (except for the tool calls of the last message, still pending).
+	pub fn validate_tool_pairs(&self) -> Result<()> {
+		let mut pending: HashSet<&str> = HashSet::new();
+		let mut last_has_calls = false;
+
+		for (msg_idx, msg) in self.messages.iter().enumerate() {
+			let (call_ids, response_ids) = tool_ids(&msg.content);
+
+			// the tool calls must be answered by the next message(s)
+			if response_ids.is_empty() && !pending.is_empty() {
+				return Err(unanswered_error(&pending, msg_idx));
+			}
+			for response_id in response_ids {
+				if !pending.remove(response_id) {
+					return Err(history_edit_error(format!(
+						"tool response '{response_id}' (message {msg_idx}) has no preceding tool call"
+					)));
+				}
+			}
+
+			last_has_calls = !call_ids.is_empty();
+			pending.extend(call_ids);
+		}
+
+		// only the tool calls of the last message can be pending
+		if !pending.is_empty() && !last_has_calls {
+			return Err(unanswered_error(&pending, self.messages.len()));
+		}
+
+		Ok(())
+	}
+}
+
+// region:    --- Support
+
+/// Returns the (tool call ids, tool response ids) of a message content.
+fn tool_ids(content: &MessageContent) -> (Vec<&str>, Vec<&str>) {
+	match content {
+		MessageContent::ToolCalls(tool_calls) => (
+			tool_calls.iter().map(|call| call.call_id.as_str()).collect(),
+			Vec::new(),
+		),
+		MessageContent::ToolResponses(tool_responses) => (
+			Vec::new(),
+			tool_responses.iter().map(|res| res.call_id.as_str()).collect(),
+		),
+		MessageContent::Blocks(blocks) => {
+			let mut call_ids = Vec::new();
+			let mut response_ids = Vec::new();
+			for block in blocks {
+				match block {
+					ContentBlock::ToolUse { id, .. } => call_ids.push(id.as_str()),
+					ContentBlock::ToolResult { tool_use_id, .. } => response_ids.push(tool_use_id.as_str()),
+					_ => (),
+				}
+			}
+			(call_ids, response_ids)
+		}
+		_ => (Vec::new(), Vec::new()),
+	}
+}
+
+fn unanswered_error(pending: &HashSet<&str>, msg_idx: usize) -> Error {
+	let mut ids: Vec<&str> = pending.iter().copied().collect();
+	ids.sort();
+	history_edit_error(format!(
+		"tool calls '{}' have no tool response (before message {msg_idx})",
+		ids.join(", ")
+	))
+}
+
+fn history_edit_error(cause: String) -> Error {
+	Error::HistoryEdit { cause }
+}
+
+// endregion: --- Support
@@ -0,0 +1,77 @@
+//! This module contains the KV-cache / session reuse hints for the local inference servers.
+
+use serde::{Deserialize, Serialize};
+
+/// The default header used for the sticky routing of a session (see `KvCacheHints::with_session_id`).
+const DEFAULT_SESSION_HEADER: &str = "x-session-id";
+
+// region:    --- KvCacheHints
+
+/// KV-cache and session reuse hints for the local inference servers with an OpenAI compatible API
+/// (llama.cpp server, vLLM), to reuse the prompt cache across the turns of a multi-turn chat.
+///
+/// - llama.cpp server: `cache_prompt` and `id_slot` (the `slot_id` of the server slot to use)
+/// - vLLM: `cache_salt` (prefix caching is only shared between requests with the same salt)
+/// - Load balancers / routers in front of multiple instances: the session id header, for sticky routing
+///   of a session to the same server instance (e.g., vLLM production-stack session routing, nginx hash).
+///
+/// NOTE: Only for the OpenAI adapter with a custom local endpoint (e.g., with a `ServiceTargetResolver`),
+///       as the properties are sent as-is (each server ignores the ones it does not know). Ignored by the other adapters.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KvCacheHints {
+	/// (llama.cpp) Reuse the KV cache of the previous request if the prompt prefix matches (`cache_prompt`).
+	pub cache_prompt: Option<bool>,
+
+	/// (llama.cpp) The server slot to run the request on (`id_slot`), so that its KV cache is reused.
+	pub slot_id: Option<i32>,
+
+	/// (vLLM) The prefix caching salt (`cache_salt`). Requests share cached prefixes only with the same salt.
+	pub cache_salt: Option<String>,
+
+	/// The session id, sent as the session header for the sticky routing to the same server instance.
+	pub session_id: Option<String>,
+
+	/// The header name for the session id (default `x-session-id`).
+	pub session_header: Option<String>,
+}
+
+/// Chainable Setters
+impl KvCacheHints {
+	pub fn with_cache_prompt(mut self, value: bool) -> Self {
+		self.cache_prompt = Some(value);
+		self
+	}
+
+	pub fn with_slot_id(mut self, value: i32) -> Self {
+		self.slot_id = Some(value);
+		self
+	}
+
+	pub fn with_cache_salt(mut self, value: impl Into<String>) -> Self {
+		self.cache_salt = Some(value.into());
+		self
+	}
+
+	pub fn with_session_id(mut self, value: impl Into<String>) -> Self {
+		self.session_id = Some(value.into());
+		self
+	}
+
+	/// Override the session header name (e.g., `x-user-id` for the vLLM production-stack router).
+	pub fn with_session_header(mut self, value: impl Into<String>) -> Self {
+		self.session_header = Some(value.into());
+		self
+	}
+}
+
+/// Getters
+impl KvCacheHints {
+	/// Returns the session header name and value, when a session id is set.
+	pub fn session_header_entry(&self) -> Option<(String, String)> {
+		let session_id = self.session_id.as_ref()?;
+		let name = self.session_header.as_deref().unwrap_or(DEFAULT_SESSION_HEADER);
+		Some((name.to_string(), session_id.clone()))
+	}
+}
+
+// endregion: --- KvCacheHints
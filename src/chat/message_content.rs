@@ -1,8 +1,10 @@
-use crate::chat::{ToolCall, ToolResponse};
+use crate::chat::{Citation, ToolCall, ToolResponse};
+use crate::files::FileRef;
 use derive_more::derive::From;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Note: MessageContent is use for the ChatRequest as well as the ChatResponse
 #[derive(Debug, Clone, Serialize, Deserialize, From)]
@@ -119,7 +121,38 @@ impl From<Vec<ContentPart>> for MessageContent {
 #[derive(Debug, Clone, Serialize, Deserialize, From)]
 pub enum ContentPart {
 	Text(String),
-	Image { content_type: String, source: ImageSource },
+	Image {
+		content_type: String,
+		source: ImageSource,
+	},
+	/// A file previously uploaded with `Client::upload_file(..)` (provider-scoped).
+	File(FileRef),
+	/// A document (e.g., `application/pdf`), inline (base64) or by URL.
+	/// NOTE: The OpenAI Chat Completions API only supports the base64 documents.
+	#[from(skip)]
+	Document {
+		content_type: String,
+		source: ImageSource,
+	},
+	/// An audio input (e.g., `audio/wav`, `audio/mpeg`), inline (base64) or by URL.
+	/// NOTE: OpenAI (audio models, base64 wav/mp3 only) and Gemini only.
+	#[from(skip)]
+	Audio {
+		content_type: String,
+		source: ImageSource,
+	},
+	/// A video input (e.g., `video/mp4`), inline (base64) or by URL (Gemini file URI or YouTube URL),
+	/// with the optional start/end offsets of the clip to consider.
+	/// NOTE: Gemini only.
+	#[from(skip)]
+	Video {
+		content_type: String,
+		source: ImageSource,
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		start_offset: Option<Duration>,
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		end_offset: Option<Duration>,
+	},
 }
 
 /// Content blocks for providers that need exact sequence preservation (e.g. Anthropic with thinking)
@@ -131,6 +164,9 @@ pub enum ContentBlock {
 		/// Optional thought signature for Gemini-style thinking
 		#[serde(skip_serializing_if = "Option::is_none")]
 		thought_signature: Option<String>,
+		/// Optional citations of the text (Anthropic citations, see `ChatOptions::with_citations(..)`)
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		citations: Option<Vec<Citation>>,
 	},
 	/// Thinking content (Anthropic)
 	Thinking {
@@ -141,6 +177,22 @@ pub enum ContentBlock {
 	},
 	/// Redacted thinking content (Anthropic) - encrypted/hidden thinking
 	RedactedThinking { data: String },
+	/// Image output (e.g., Gemini image generation models, OpenAI Responses `image_generation` tool)
+	Image { content_type: String, source: ImageSource },
+	/// Code executed by the provider code execution tool (see `BuiltInTool::CodeExecution`)
+	ExecutableCode {
+		/// The eventual language of the code (e.g., `python`)
+		#[serde(skip_serializing_if = "Option::is_none")]
+		language: Option<String>,
+		code: String,
+	},
+	/// Result of the provider code execution (see `BuiltInTool::CodeExecution`)
+	CodeExecutionResult {
+		/// The output of the execution (stdout, then the eventual stderr)
+		output: String,
+		/// Whether the execution failed
+		is_error: bool,
+	},
 	/// Tool use request
 	ToolUse {
 		id: String,
@@ -182,6 +234,91 @@ impl ContentPart {
 			source: ImageSource::Url(url.into()),
 		}
 	}
+
+	pub fn from_document_base64(content_type: impl Into<String>, content: impl Into<Arc<str>>) -> ContentPart {
+		ContentPart::Document {
+			content_type: content_type.into(),
+			source: ImageSource::Base64(content.into()),
+		}
+	}
+
+	pub fn from_document_url(content_type: impl Into<String>, url: impl Into<String>) -> ContentPart {
+		ContentPart::Document {
+			content_type: content_type.into(),
+			source: ImageSource::Url(url.into()),
+		}
+	}
+
+	pub fn from_audio_base64(content_type: impl Into<String>, content: impl Into<Arc<str>>) -> ContentPart {
+		ContentPart::Audio {
+			content_type: content_type.into(),
+			source: ImageSource::Base64(content.into()),
+		}
+	}
+
+	pub fn from_audio_url(content_type: impl Into<String>, url: impl Into<String>) -> ContentPart {
+		ContentPart::Audio {
+			content_type: content_type.into(),
+			source: ImageSource::Url(url.into()),
+		}
+	}
+
+	pub fn from_video_base64(content_type: impl Into<String>, content: impl Into<Arc<str>>) -> ContentPart {
+		ContentPart::Video {
+			content_type: content_type.into(),
+			source: ImageSource::Base64(content.into()),
+			start_offset: None,
+			end_offset: None,
+		}
+	}
+
+	/// A video by URL, either a provider file URI (e.g., Gemini `upload_file(..)`) or a YouTube URL.
+	pub fn from_video_url(content_type: impl Into<String>, url: impl Into<String>) -> ContentPart {
+		ContentPart::Video {
+			content_type: content_type.into(),
+			source: ImageSource::Url(url.into()),
+			start_offset: None,
+			end_offset: None,
+		}
+	}
+
+	/// Set the start/end offsets of a video part (no-op for the other parts).
+	pub fn with_video_offsets(mut self, start: Option<Duration>, end: Option<Duration>) -> ContentPart {
+		if let ContentPart::Video {
+			start_offset,
+			end_offset,
+			..
+		} = &mut self
+		{
+			*start_offset = start;
+			*end_offset = end;
+		}
+		self
+	}
+
+	/// Reference a provider file (see `Client::upload_file(..)`).
+	/// NOTE: The FileRef must come from the same provider as the model of the chat request.
+	pub fn from_file(file_ref: FileRef) -> ContentPart {
+		ContentPart::File(file_ref)
+	}
+}
+
+/// Getters
+impl ContentPart {
+	/// The default file name of a document part (e.g., `document.pdf`), for the providers requiring one.
+	pub(crate) fn document_file_name(content_type: &str) -> String {
+		let ext = content_type.rsplit('/').next().filter(|ext| !ext.is_empty()).unwrap_or("bin");
+		format!("document.{ext}")
+	}
+
+	/// The audio format of an audio content type, for the providers requiring one (e.g., OpenAI `wav`, `mp3`).
+	pub(crate) fn audio_format(content_type: &str) -> &str {
+		match content_type {
+			"audio/wav" | "audio/x-wav" | "audio/wave" => "wav",
+			"audio/mpeg" | "audio/mp3" => "mp3",
+			other => other.rsplit('/').next().unwrap_or(other),
+		}
+	}
 }
 
 // region:    --- Froms
@@ -194,6 +331,7 @@ impl<'a> From<&'a str> for ContentPart {
 
 // endregion: --- Froms
 
+/// The source of the binary content parts (the images, the documents, the audios, and the videos).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ImageSource {
 	/// For models/services that support URL as input
@@ -3,24 +3,61 @@
 
 // region:    --- Modules
 
+mod ai_disclosure;
+mod auto_continue;
+mod cached_content;
+mod capability_fallback;
+mod chat_map_reduce;
 mod chat_message;
 mod chat_options;
 mod chat_req_response_format;
 mod chat_request;
 mod chat_response;
+mod chat_speculative;
 mod chat_stream;
+mod citation;
+mod content_filter;
+mod file_citation;
+mod history_edit;
+mod kv_cache_hints;
 mod message_content;
+mod output_fixer;
+mod prompt_shield;
+mod provider_ext;
+mod regeneration_policy;
+mod response_digest;
+mod response_headers;
+mod time_context;
+mod token_count;
 mod tool;
 mod usage;
 
 // -- Flatten
+pub use ai_disclosure::*;
+pub use auto_continue::*;
+pub use cached_content::*;
+pub use capability_fallback::*;
+pub use chat_map_reduce::*;
 pub use chat_message::*;
 pub use chat_options::*;
 pub use chat_req_response_format::*;
 pub use chat_request::*;
 pub use chat_response::*;
+pub use chat_speculative::*;
 pub use chat_stream::*;
+pub use citation::*;
+pub use content_filter::*;
+pub use file_citation::*;
+pub use kv_cache_hints::*;
 pub use message_content::*;
+pub use output_fixer::*;
+pub use prompt_shield::*;
+pub use provider_ext::*;
+pub use regeneration_policy::*;
+pub use response_digest::*;
+pub use response_headers::*;
+pub use time_context::*;
+pub use token_count::*;
 pub use tool::*;
 pub use usage::*;
 
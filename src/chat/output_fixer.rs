@@ -0,0 +1,84 @@
+//! This module contains the output fixer, an optional repair of the invalid JSON structured outputs
+//! with a (cheap) fixer model.
+
+use crate::ModelIden;
+use crate::chat::{ChatMessage, ChatRequest, ChatResponse, ChatResponseFormat, Usage};
+use serde::{Deserialize, Serialize};
+
+const FIXER_SYSTEM: &str = "You repair invalid JSON outputs. \
+Reply only with the repaired JSON, which must be valid JSON and conform to the JSON schema when given. \
+Keep the original values as much as possible.";
+
+// region:    --- OutputFixer
+
+/// An optional repair of the structured outputs (see `ChatOptions::with_output_fixer(..)`).
+///
+/// When the response format is `ChatResponseFormat::JsonMode` or `ChatResponseFormat::JsonSpec`,
+/// and the response text is not valid JSON, the fixer model is called with the invalid output (and the eventual schema),
+/// and its output replaces the response content. The repair is reported in `ChatResponse.output_repair`.
+///
+/// NOTE: Only for `Client::exec_chat(..)` for now (not the chat stream).
+///       The fixer is not applied to the responses of the fixer model itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutputFixer {
+	/// The (cheap) model used to repair the invalid outputs (e.g., `gpt-4o-mini`).
+	pub model: String,
+}
+
+/// Constructors
+impl OutputFixer {
+	pub fn new(model: impl Into<String>) -> Self {
+		Self { model: model.into() }
+	}
+}
+
+/// Crate Functions
+impl OutputFixer {
+	/// Returns the invalid output text to repair (None when the response format is not JSON,
+	/// or when the response has no text or a valid JSON text).
+	pub(crate) fn invalid_output(response_format: &ChatResponseFormat, chat_res: &ChatResponse) -> Option<String> {
+		if !matches!(
+			response_format,
+			ChatResponseFormat::JsonMode | ChatResponseFormat::JsonSpec(_)
+		) {
+			return None;
+		}
+		let text = chat_res.first_text()?;
+		serde_json::from_str::<serde_json::Value>(text)
+			.is_err()
+			.then(|| text.to_string())
+	}
+
+	/// Build the fixer chat request for an invalid output.
+	pub(crate) fn fixer_chat_request(response_format: &ChatResponseFormat, invalid_output: &str) -> ChatRequest {
+		let schema = match response_format {
+			ChatResponseFormat::JsonSpec(json_spec) => format!("JSON schema:\n{}\n\n", json_spec.schema),
+			_ => String::new(),
+		};
+		ChatRequest::from_system(FIXER_SYSTEM)
+			.append_message(ChatMessage::user(format!("{schema}Invalid output:\n{invalid_output}")))
+	}
+}
+
+// endregion: --- OutputFixer
+
+// region:    --- OutputRepair
+
+/// The provenance of an output repair by the `OutputFixer`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutputRepair {
+	/// The model which repaired the output.
+	pub fixer_model_iden: ModelIden,
+
+	/// The original (invalid) output text.
+	pub original_output: String,
+
+	/// True when the fixer output is valid JSON and replaced the response content
+	/// (otherwise, the response content is left as is).
+	pub repaired: bool,
+
+	/// The usage of the fixer call (not included in `ChatResponse.usage`).
+	pub fixer_usage: Usage,
+}
+
+// endregion: --- OutputRepair
@@ -0,0 +1,209 @@
+//! This module contains the prompt shield, an output check for the verbatim leakage
+//! of the system prompt (or registered secrets) in the chat responses.
+
+use crate::chat::{ChatRequest, ChatResponse, ContentBlock, MessageContent};
+use serde::{Deserialize, Serialize};
+
+/// The default minimum number of consecutive system prompt characters to be considered a leak.
+const DEFAULT_MIN_MATCH_CHARS: usize = 40;
+
+/// The replacement text of the leaked content with `PromptShieldAction::Redact`.
+const REDACTED: &str = "[REDACTED]";
+
+// region:    --- PromptShield
+
+/// An optional output check (see `ChatOptions::with_prompt_shield(..)`) that detects the verbatim leakage
+/// of the system prompt, or of the registered secrets, in the response texts (content and reasoning content).
+///
+/// The detected leaks are reported in `ChatResponse.prompt_leaks`, and the leaked text is redacted
+/// with `PromptShieldAction::Redact`.
+///
+/// NOTE: Only for `Client::exec_chat(..)` for now (not the chat stream).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptShield {
+	/// Check for the leakage of the system prompt(s) of the request (default true).
+	pub check_system: bool,
+
+	/// The minimum number of consecutive system prompt characters found in the response to be a leak (default 40).
+	pub min_match_chars: usize,
+
+	/// The secrets (e.g., API keys, internal codes) which must never appear in the responses.
+	pub secrets: Vec<String>,
+
+	/// What to do with the detected leaks (default `Flag`).
+	pub action: PromptShieldAction,
+}
+
+/// The action of the `PromptShield` on the detected leaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PromptShieldAction {
+	/// Only report the leaks in `ChatResponse.prompt_leaks`.
+	#[default]
+	Flag,
+	/// Report the leaks and replace the leaked text with `[REDACTED]`.
+	Redact,
+}
+
+impl Default for PromptShield {
+	fn default() -> Self {
+		Self {
+			check_system: true,
+			min_match_chars: DEFAULT_MIN_MATCH_CHARS,
+			secrets: Vec::new(),
+			action: PromptShieldAction::default(),
+		}
+	}
+}
+
+/// Chainable Setters
+impl PromptShield {
+	pub fn with_check_system(mut self, value: bool) -> Self {
+		self.check_system = value;
+		self
+	}
+
+	pub fn with_min_match_chars(mut self, value: usize) -> Self {
+		self.min_match_chars = value;
+		self
+	}
+
+	/// Register a secret which must never appear in the responses.
+	pub fn append_secret(mut self, secret: impl Into<String>) -> Self {
+		self.secrets.push(secret.into());
+		self
+	}
+
+	pub fn with_action(mut self, action: PromptShieldAction) -> Self {
+		self.action = action;
+		self
+	}
+}
+
+/// Crate Functions
+impl PromptShield {
+	/// The protected texts of a chat request (system prompts and secrets), to be captured before the request is sent.
+	pub(crate) fn protected_texts(&self, chat_req: &ChatRequest) -> Vec<(PromptLeakKind, String)> {
+		let mut protected = Vec::new();
+		if self.check_system {
+			for system in chat_req.iter_systems() {
+				protected.push((PromptLeakKind::SystemPrompt, system.to_string()));
+			}
+		}
+		for secret in self.secrets.iter().filter(|secret| !secret.is_empty()) {
+			protected.push((PromptLeakKind::Secret, secret.clone()));
+		}
+		protected
+	}
+
+	/// Check (and eventually redact) the response texts, and set the `ChatResponse.prompt_leaks`.
+	pub(crate) fn apply(&self, protected: &[(PromptLeakKind, String)], chat_res: &mut ChatResponse) {
+		let mut leaks = Vec::new();
+
+		let texts = chat_res.content.iter_mut().flat_map(|content| match content {
+			MessageContent::Text(text) => vec![text],
+			MessageContent::Blocks(blocks) => blocks
+				.iter_mut()
+				.filter_map(|block| match block {
+					ContentBlock::Text { text, .. } | ContentBlock::Thinking { text, .. } => Some(text),
+					_ => None,
+				})
+				.collect(),
+			_ => Vec::new(),
+		});
+		for text in texts.chain(chat_res.reasoning_content.as_mut()) {
+			self.check_text(protected, text, &mut leaks);
+		}
+
+		chat_res.prompt_leaks = Some(leaks);
+	}
+
+	fn check_text(&self, protected: &[(PromptLeakKind, String)], text: &mut String, leaks: &mut Vec<PromptLeak>) {
+		for (kind, protected_text) in protected {
+			let min_chars = match kind {
+				PromptLeakKind::SystemPrompt => self.min_match_chars.max(1),
+				// a secret leaks only when fully present
+				PromptLeakKind::Secret => protected_text.chars().count(),
+			};
+
+			let ranges = find_verbatim_ranges(protected_text, text, min_chars);
+			if ranges.is_empty() {
+				continue;
+			}
+
+			leaks.push(PromptLeak {
+				kind: *kind,
+				leaked_chars: ranges.iter().map(|(start, end)| text[*start..*end].chars().count()).sum(),
+				redacted: self.action == PromptShieldAction::Redact,
+			});
+
+			if self.action == PromptShieldAction::Redact {
+				for (start, end) in ranges.into_iter().rev() {
+					text.replace_range(start..end, REDACTED);
+				}
+			}
+		}
+	}
+}
+
+// endregion: --- PromptShield
+
+// region:    --- PromptLeak
+
+/// A leak detected by the `PromptShield`.
+///
+/// NOTE: The leaked text itself is not captured, so that the report can be logged safely.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptLeak {
+	pub kind: PromptLeakKind,
+
+	/// The number of leaked characters in the response text.
+	pub leaked_chars: usize,
+
+	/// True when the leaked text was redacted from the response.
+	pub redacted: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PromptLeakKind {
+	SystemPrompt,
+	Secret,
+}
+
+// endregion: --- PromptLeak
+
+// region:    --- Support
+
+/// Returns the merged byte ranges of the `text` which are verbatim copies of
+/// any `min_chars` (or more) consecutive characters of the `protected` text.
+fn find_verbatim_ranges(protected: &str, text: &str, min_chars: usize) -> Vec<(usize, usize)> {
+	let boundaries: Vec<usize> = protected
+		.char_indices()
+		.map(|(idx, _)| idx)
+		.chain(std::iter::once(protected.len()))
+		.collect();
+	// the protected text must have at least `min_chars` characters
+	let protected_chars = boundaries.len() - 1;
+	if min_chars == 0 || protected_chars < min_chars {
+		return Vec::new();
+	}
+
+	// -- Find all the occurrences of each protected window of `min_chars` characters
+	let mut ranges: Vec<(usize, usize)> = Vec::new();
+	for window in boundaries.windows(min_chars + 1) {
+		let needle = &protected[window[0]..window[min_chars]];
+		ranges.extend(text.match_indices(needle).map(|(idx, m)| (idx, idx + m.len())));
+	}
+
+	// -- Merge the overlapping windows
+	ranges.sort_unstable();
+	let mut merged: Vec<(usize, usize)> = Vec::new();
+	for (start, end) in ranges {
+		match merged.last_mut() {
+			Some((_, last_end)) if start <= *last_end => *last_end = (*last_end).max(end),
+			_ => merged.push((start, end)),
+		}
+	}
+	merged
+}
+
+// endregion: --- Support
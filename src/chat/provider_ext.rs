@@ -0,0 +1,117 @@
+//! This module contains the `ProviderExts`, the typed provider-specific extras of a chat response
+//! (e.g., Groq timing info, Gemini grounding metadata), retrieved with `ChatResponse::provider_ext::<T>()`.
+
+use serde::{Deserialize, Serialize};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// region:    --- ProviderExts
+
+/// The typed provider-specific extras of a chat response, keyed by their type.
+///
+/// The adapters stash the extras of their provider here (only one value per type),
+/// so that the `ChatResponse` stays provider agnostic.
+///
+/// NOTE: The extras are not serialized with the `ChatResponse` (use `captured_raw_body` for the full provider body).
+#[derive(Clone, Default)]
+pub struct ProviderExts {
+	exts: HashMap<TypeId, (&'static str, Arc<dyn Any + Send + Sync>)>,
+}
+
+/// Getters
+impl ProviderExts {
+	/// Get the extra of type `T`, if set by the adapter.
+	pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+		self.exts
+			.get(&TypeId::of::<T>())
+			.and_then(|(_, value)| value.downcast_ref::<T>())
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.exts.is_empty()
+	}
+}
+
+/// Crate Functions
+impl ProviderExts {
+	/// Set the extra of type `T` (replacing the eventual previous one).
+	pub(crate) fn insert<T: Any + Send + Sync>(&mut self, value: T) {
+		self.exts
+			.insert(TypeId::of::<T>(), (std::any::type_name::<T>(), Arc::new(value)));
+	}
+}
+
+impl std::fmt::Debug for ProviderExts {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let mut names: Vec<&str> = self.exts.values().map(|(name, _)| *name).collect();
+		names.sort();
+		f.debug_tuple("ProviderExts").field(&names).finish()
+	}
+}
+
+// endregion: --- ProviderExts
+
+// region:    --- GroqTiming
+
+/// The Groq timing info of a chat response (from the Groq `usage`), in seconds.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GroqTiming {
+	pub queue_time: Option<f64>,
+	pub prompt_time: Option<f64>,
+	pub completion_time: Option<f64>,
+	pub total_time: Option<f64>,
+}
+
+// endregion: --- GroqTiming
+
+// region:    --- GeminiGroundingMetadata
+
+/// The Gemini grounding metadata of a chat response (with the `googleSearch` tool),
+/// from the `candidates[0].groundingMetadata`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GeminiGroundingMetadata {
+	/// The search queries made by the model.
+	pub web_search_queries: Vec<String>,
+
+	/// The web sources used by the model.
+	pub grounding_chunks: Vec<GeminiGroundingChunk>,
+
+	/// The parts of the response text supported by the grounding chunks.
+	pub grounding_supports: Vec<GeminiGroundingSupport>,
+
+	/// The eventual HTML/CSS of the Google Search suggestions (`searchEntryPoint.renderedContent`),
+	/// to be displayed with the grounded response (see the Google Search grounding display requirements).
+	pub search_entry_point: Option<String>,
+}
+
+/// A web source of the Gemini grounding metadata.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GeminiGroundingChunk {
+	pub uri: Option<String>,
+	pub title: Option<String>,
+}
+
+/// A part of the response text, supported by some grounding chunks.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GeminiGroundingSupport {
+	/// The supported text of the response.
+	pub text: Option<String>,
+
+	/// The start of the supported segment in the response text part (byte offset).
+	pub start_index: Option<usize>,
+
+	/// The end (exclusive) of the supported segment in the response text part (byte offset).
+	pub end_index: Option<usize>,
+
+	/// The index of the response text part of the supported segment.
+	pub part_index: Option<usize>,
+
+	/// The indices of the supporting `GeminiGroundingMetadata.grounding_chunks`.
+	pub grounding_chunk_indices: Vec<usize>,
+
+	/// The confidence scores of the supporting grounding chunks (same order as `grounding_chunk_indices`).
+	pub confidence_scores: Vec<f64>,
+}
+
+// endregion: --- GeminiGroundingMetadata
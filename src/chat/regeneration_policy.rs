@@ -0,0 +1,152 @@
+//! This module contains the regeneration policy, the option schedule of the regenerations
+//! after a validator rejection or a content filter refusal (see `Client::exec_chat_regenerate(..)`).
+
+use crate::chat::ChatOptions;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+
+/// The temperature the schedule starts from when no temperature is set on the request or client options.
+const DEFAULT_BASE_TEMPERATURE: f64 = 0.7;
+
+// region:    --- RegenerationPolicy
+
+/// The option schedule of the regenerations, so that each attempt differs from the previous one
+/// (identical retries tend to fail identically).
+///
+/// For each regeneration (attempt 2, 3, ...):
+/// - The temperature is bumped by `temperature_step` per regeneration, with a random `temperature_jitter` (±),
+///   capped at `max_temperature`.
+/// - The model is switched from the given attempt (see `with_model_from_attempt(..)`).
+///
+/// The first attempt always uses the original model and options.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegenerationPolicy {
+	/// The max number of attempts, including the first one (default 3).
+	pub max_attempts: usize,
+
+	/// The temperature added per regeneration (default 0.2).
+	pub temperature_step: f64,
+
+	/// The max random temperature offset (±) added per regeneration (default 0.0).
+	pub temperature_jitter: f64,
+
+	/// The max temperature of the schedule (default 1.0).
+	pub max_temperature: f64,
+
+	/// The models to switch to, with the attempt (1-based) from which they are used (e.g., `(3, "gpt-4o")`).
+	pub model_switches: Vec<(usize, String)>,
+
+	/// Whether a content filter refusal (blocked response or content filter error) is regenerated (default true).
+	pub regenerate_on_content_filter: bool,
+}
+
+impl Default for RegenerationPolicy {
+	fn default() -> Self {
+		Self {
+			max_attempts: 3,
+			temperature_step: 0.2,
+			temperature_jitter: 0.0,
+			max_temperature: 1.0,
+			model_switches: Vec::new(),
+			regenerate_on_content_filter: true,
+		}
+	}
+}
+
+/// Constructors
+impl RegenerationPolicy {
+	pub fn new(max_attempts: usize) -> Self {
+		Self {
+			max_attempts,
+			..Default::default()
+		}
+	}
+}
+
+/// Chainable Setters
+impl RegenerationPolicy {
+	pub fn with_temperature_step(mut self, value: f64) -> Self {
+		self.temperature_step = value;
+		self
+	}
+
+	pub fn with_temperature_jitter(mut self, value: f64) -> Self {
+		self.temperature_jitter = value;
+		self
+	}
+
+	pub fn with_max_temperature(mut self, value: f64) -> Self {
+		self.max_temperature = value;
+		self
+	}
+
+	/// Switch to the `model` from the `attempt` (1-based, e.g., `3` for the second regeneration).
+	pub fn with_model_from_attempt(mut self, attempt: usize, model: impl Into<String>) -> Self {
+		self.model_switches.push((attempt, model.into()));
+		self
+	}
+
+	pub fn with_regenerate_on_content_filter(mut self, value: bool) -> Self {
+		self.regenerate_on_content_filter = value;
+		self
+	}
+}
+
+/// Public Functions
+impl RegenerationPolicy {
+	/// Returns the model of an attempt (1-based), the last switch reached or the original `model`.
+	pub fn attempt_model<'a>(&'a self, attempt: usize, model: &'a str) -> &'a str {
+		self.model_switches
+			.iter()
+			.filter(|(from_attempt, _)| *from_attempt <= attempt)
+			.max_by_key(|(from_attempt, _)| *from_attempt)
+			.map(|(_, model)| model.as_str())
+			.unwrap_or(model)
+	}
+
+	/// Returns the temperature of an attempt (1-based), from the `base_temperature` (the request or client one).
+	///
+	/// NOTE: None for the first attempt (the original options are used as is).
+	pub fn attempt_temperature(&self, attempt: usize, base_temperature: Option<f64>) -> Option<f64> {
+		if attempt <= 1 {
+			return None;
+		}
+
+		let base = base_temperature.unwrap_or(DEFAULT_BASE_TEMPERATURE);
+		let regenerations = (attempt - 1) as f64;
+		let jitter = self.temperature_jitter * random_unit();
+		let temperature = base + self.temperature_step * regenerations + jitter;
+
+		Some(temperature.clamp(0.0, self.max_temperature.max(base)))
+	}
+}
+
+/// Crate Functions
+impl RegenerationPolicy {
+	/// Returns the chat options of an attempt (1-based) from the request options.
+	pub(crate) fn attempt_options(
+		&self,
+		attempt: usize,
+		options: Option<&ChatOptions>,
+		base_temperature: Option<f64>,
+	) -> Option<ChatOptions> {
+		let Some(temperature) = self.attempt_temperature(attempt, base_temperature) else {
+			return options.cloned();
+		};
+		let options = options.cloned().unwrap_or_default();
+		Some(options.with_temperature(temperature))
+	}
+}
+
+// endregion: --- RegenerationPolicy
+
+// region:    --- Support
+
+/// Returns a random value in [-1.0, 1.0] (from the random std hasher keys, to avoid a rand dependency).
+fn random_unit() -> f64 {
+	let value = RandomState::new().hash_one(std::time::SystemTime::now());
+	(value as f64 / u64::MAX as f64) * 2.0 - 1.0
+}
+
+// endregion: --- Support
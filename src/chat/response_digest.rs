@@ -0,0 +1,55 @@
+//! This module contains the `ResponseDigest`, the integrity information of a provider response body.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::time::SystemTime;
+
+/// The content hash (and receive timestamp) of the exact provider response body,
+/// captured with `ChatOptions::with_capture_response_digest(true)`.
+///
+/// This allows to later prove that a stored response body (e.g., from `captured_raw_body` or an audit log)
+/// is the one returned by the provider at that time.
+///
+/// NOTE: None of the currently supported providers sign their responses, so only the hash is available.
+///       When a provider offers signing, its signature will be captured here as well.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ResponseDigest {
+	/// The hash algorithm (only `sha256` for now)
+	pub algorithm: String,
+
+	/// The lowercase hex encoded hash of the response body bytes.
+	pub hash: String,
+
+	/// When the response body was received.
+	pub received_at: SystemTime,
+}
+
+/// Constructors
+impl ResponseDigest {
+	/// Compute the digest of the response body bytes, with `received_at` set to now.
+	pub fn from_body(body: &[u8]) -> Self {
+		Self {
+			algorithm: "sha256".to_string(),
+			hash: sha256_hex(body),
+			received_at: SystemTime::now(),
+		}
+	}
+}
+
+/// Verifiers
+impl ResponseDigest {
+	/// Returns true if the body bytes match this digest.
+	///
+	/// NOTE: The bytes must be the exact provider bytes (a re-serialized JSON value will most likely not match).
+	pub fn verify(&self, body: &[u8]) -> bool {
+		self.algorithm == "sha256" && self.hash == sha256_hex(body)
+	}
+}
+
+// region:    --- Support
+
+fn sha256_hex(body: &[u8]) -> String {
+	Sha256::digest(body).iter().map(|b| format!("{b:02x}")).collect()
+}
+
+// endregion: --- Support
@@ -0,0 +1,62 @@
+//! This module contains the `ResponseHeaders`, the normalized provider response headers of a chat response.
+
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+/// The selected provider response headers (request id, rate limits, processing time),
+/// captured with `ChatOptions::with_capture_headers(true)`.
+///
+/// - OpenAI (and Groq, xAI, ...): `x-request-id`, `x-ratelimit-*`, and `openai-processing-ms`
+/// - Anthropic: `request-id` and `anthropic-ratelimit-*`
+///
+/// NOTE: The reset values are kept as returned by the provider
+///       (e.g., a duration like `6m0s` for OpenAI, an RFC 3339 timestamp for Anthropic).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ResponseHeaders {
+	/// The provider request id (to reference the request in a support ticket).
+	pub request_id: Option<String>,
+
+	/// The remaining requests of the current rate limit window.
+	pub ratelimit_remaining_requests: Option<u64>,
+
+	/// The remaining tokens of the current rate limit window.
+	pub ratelimit_remaining_tokens: Option<u64>,
+
+	/// When the requests rate limit resets.
+	pub ratelimit_reset_requests: Option<String>,
+
+	/// When the tokens rate limit resets.
+	pub ratelimit_reset_tokens: Option<String>,
+
+	/// The provider processing time of the request, in milliseconds.
+	pub processing_ms: Option<u64>,
+}
+
+/// Constructors
+impl ResponseHeaders {
+	/// Extract the known headers of the response (the first one found for each field).
+	pub(crate) fn from_header_map(headers: &HeaderMap) -> Self {
+		let get = |names: &[&str]| {
+			names
+				.iter()
+				.find_map(|name| headers.get(*name).and_then(|value| value.to_str().ok()))
+				.map(String::from)
+		};
+		let get_u64 = |names: &[&str]| get(names).and_then(|value| value.trim().parse::<u64>().ok());
+
+		Self {
+			request_id: get(&["x-request-id", "request-id"]),
+			ratelimit_remaining_requests: get_u64(&[
+				"x-ratelimit-remaining-requests",
+				"anthropic-ratelimit-requests-remaining",
+			]),
+			ratelimit_remaining_tokens: get_u64(&[
+				"x-ratelimit-remaining-tokens",
+				"anthropic-ratelimit-tokens-remaining",
+			]),
+			ratelimit_reset_requests: get(&["x-ratelimit-reset-requests", "anthropic-ratelimit-requests-reset"]),
+			ratelimit_reset_tokens: get(&["x-ratelimit-reset-tokens", "anthropic-ratelimit-tokens-reset"]),
+			processing_ms: get_u64(&["openai-processing-ms"]),
+		}
+	}
+}
@@ -0,0 +1,145 @@
+//! This module contains the time context, an optional injection of the current date/time, timezone, and locale
+//! in the system prompt (models otherwise assume their training cutoff is "today").
+
+use crate::chat::ChatRequest;
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The markers of the injected block, used to replace it on the next turns.
+const BLOCK_START: &str = "<current_context>";
+const BLOCK_END: &str = "</current_context>";
+
+const WEEKDAYS: [&str; 7] = ["Thursday", "Friday", "Saturday", "Sunday", "Monday", "Tuesday", "Wednesday"];
+
+// region:    --- TimeContext
+
+/// An optional injection of the current date/time, timezone, and locale in the system prompt
+/// (see `ChatOptions::with_time_context(..)`), as a standardized block, for example:
+///
+/// ```text
+/// <current_context>
+/// Current date: 2025-06-12 (Thursday)
+/// Current time: 14:32 (UTC+02:00, Europe/Paris)
+/// Locale: fr-FR
+/// </current_context>
+/// ```
+///
+/// The block is computed at each `exec_chat(..)` / `exec_chat_stream(..)` call, and replaces the eventual block
+/// of a previous turn, so a conversation (the same `ChatRequest` appended turn after turn) always has the current time.
+///
+/// NOTE: There is no timezone database, so the `utc_offset_minutes` must be given for the non-UTC timezones
+///       (the `timezone` name is informational).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TimeContext {
+	/// The timezone name (e.g., `Europe/Paris`), informational.
+	pub timezone: Option<String>,
+
+	/// The offset of the local time from UTC, in minutes (e.g., `120` for UTC+02:00) (default 0).
+	pub utc_offset_minutes: i32,
+
+	/// The eventual locale of the user (e.g., `fr-FR`).
+	pub locale: Option<String>,
+}
+
+/// Constructors
+impl TimeContext {
+	/// A UTC time context.
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+/// Chainable Setters
+impl TimeContext {
+	/// Set the timezone name and its offset from UTC in minutes (e.g., `("Europe/Paris", 120)`).
+	pub fn with_timezone(mut self, timezone: impl Into<String>, utc_offset_minutes: i32) -> Self {
+		self.timezone = Some(timezone.into());
+		self.utc_offset_minutes = utc_offset_minutes;
+		self
+	}
+
+	pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+		self.locale = Some(locale.into());
+		self
+	}
+}
+
+/// Public Functions
+impl TimeContext {
+	/// Returns the context block for the given time.
+	pub fn context_block_at(&self, time: SystemTime) -> String {
+		let utc_secs = match time.duration_since(UNIX_EPOCH) {
+			Ok(duration) => duration.as_secs() as i64,
+			Err(err) => -(err.duration().as_secs() as i64),
+		};
+		let local_secs = utc_secs + self.utc_offset_minutes as i64 * 60;
+		let days = local_secs.div_euclid(86_400);
+		let secs_of_day = local_secs.rem_euclid(86_400);
+
+		let (year, month, day) = civil_from_days(days);
+		let weekday = WEEKDAYS[days.rem_euclid(7) as usize];
+		let (hour, minute) = (secs_of_day / 3600, (secs_of_day % 3600) / 60);
+
+		let sign = if self.utc_offset_minutes < 0 { '-' } else { '+' };
+		let offset = self.utc_offset_minutes.unsigned_abs();
+		let mut zone = format!("UTC{sign}{:02}:{:02}", offset / 60, offset % 60);
+		if let Some(timezone) = self.timezone.as_deref() {
+			zone.push_str(&format!(", {timezone}"));
+		}
+
+		let mut block = format!(
+			"{BLOCK_START}\nCurrent date: {year:04}-{month:02}-{day:02} ({weekday})\nCurrent time: {hour:02}:{minute:02} ({zone})\n"
+		);
+		if let Some(locale) = self.locale.as_deref() {
+			block.push_str(&format!("Locale: {locale}\n"));
+		}
+		block.push_str(BLOCK_END);
+		block
+	}
+}
+
+/// Crate Functions
+impl TimeContext {
+	/// Set (or replace) the context block of the chat request system, with the current time.
+	pub(crate) fn apply(&self, chat_req: &mut ChatRequest) {
+		let block = self.context_block_at(SystemTime::now());
+
+		let Some(system) = chat_req.system.as_mut() else {
+			chat_req.system = Some(block);
+			return;
+		};
+
+		let existing = system.find(BLOCK_START).and_then(|start| {
+			system[start..]
+				.find(BLOCK_END)
+				.map(|end| (start, start + end + BLOCK_END.len()))
+		});
+		match existing {
+			Some((start, end)) => system.replace_range(start..end, &block),
+			None => {
+				system.push_str("\n\n");
+				system.push_str(&block);
+			}
+		}
+	}
+}
+
+// endregion: --- TimeContext
+
+// region:    --- Support
+
+/// Returns the (year, month, day) of the days since 1970-01-01 (proleptic Gregorian calendar).
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+	let z = days + 719_468;
+	let era = z.div_euclid(146_097);
+	let doe = z.rem_euclid(146_097);
+	let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+	let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+	(year, month, day)
+}
+
+// endregion: --- Support
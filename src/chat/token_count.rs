@@ -0,0 +1,17 @@
+//! This module contains the `TokenCount`, the normalized result of a token count request.
+
+use crate::ModelIden;
+use serde::{Deserialize, Serialize};
+
+/// The normalized input token count of a chat request, as computed by the provider
+/// (without executing the chat request).
+///
+/// Returned by `Client::exec_count_tokens(..)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenCount {
+	/// The resolved Model Identifier (AdapterKind/ModelName) used for this request.
+	pub model_iden: ModelIden,
+
+	/// The number of input tokens of the chat request (system, messages, tools, ...).
+	pub input_tokens: i32,
+}
@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+
+/// A provider built-in (server-side) tool, mapped by each adapter to its native tool (see `Tool::builtin(..)`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum BuiltInTool {
+	/// The web search tool, with the cited sources returned as `Citation`s (see `ChatResponse::citations()`).
+	/// - Anthropic: `web_search_20250305` server tool
+	/// - OpenAI: Responses API `web_search` tool (the chat request is sent to the Responses API)
+	/// - Gemini: `googleSearch` grounding
+	WebSearch(WebSearchConfig),
+
+	/// The computer use tool (Anthropic `computer_20250124`, with the computer use beta header).
+	/// The actions are returned as `ToolCall`s of the `computer` tool, and the screenshots are sent back
+	/// as the `ToolResponse` images (see `ToolResponse::with_image_base64(..)`).
+	Computer(ComputerUseConfig),
+
+	/// The server-side code execution tool, with the executed code and its result returned as
+	/// `ContentBlock::ExecutableCode` and `ContentBlock::CodeExecutionResult`.
+	/// - Anthropic: `code_execution_20250522` server tool (with the code execution beta header)
+	/// - Gemini: `codeExecution`
+	CodeExecution,
+
+	/// The text editor tool (Anthropic `text_editor_*`, with the fixed name of its version).
+	/// The edits are returned as `ToolCall`s of this tool name (e.g., `str_replace_based_edit_tool`),
+	/// to be executed by the client and answered with a `ToolResponse`.
+	TextEditor(TextEditorVersion),
+
+	/// The bash tool (Anthropic `bash_20250124`, named `bash`).
+	/// The commands are returned as `ToolCall`s, to be executed by the client and answered with a `ToolResponse`.
+	Bash,
+}
+
+/// Constructors
+impl BuiltInTool {
+	/// The web search tool with the default config.
+	pub fn web_search() -> Self {
+		Self::WebSearch(WebSearchConfig::default())
+	}
+
+	/// The text editor tool of the latest version (see `TextEditorVersion`).
+	pub fn text_editor() -> Self {
+		Self::TextEditor(TextEditorVersion::default())
+	}
+
+	/// The computer use tool for a display of the given size (in pixels).
+	pub fn computer(display_width_px: u32, display_height_px: u32) -> Self {
+		Self::Computer(ComputerUseConfig::new(display_width_px, display_height_px))
+	}
+}
+
+/// Getters
+impl BuiltInTool {
+	/// The name of the `Tool` of this built-in tool.
+	pub fn name(&self) -> &'static str {
+		match self {
+			Self::WebSearch(_) => "web_search",
+			Self::Computer(_) => "computer",
+			Self::CodeExecution => "code_execution",
+			Self::TextEditor(version) => version.tool_name(),
+			Self::Bash => "bash",
+		}
+	}
+}
+
+// region:    --- WebSearchConfig
+
+/// The config of the `BuiltInTool::WebSearch` tool.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WebSearchConfig {
+	/// The max number of searches for the request (Anthropic only).
+	pub max_uses: Option<u32>,
+
+	/// The only domains to search (Anthropic and OpenAI).
+	pub allowed_domains: Option<Vec<String>>,
+}
+
+/// Chainable Setters
+impl WebSearchConfig {
+	pub fn with_max_uses(mut self, max_uses: u32) -> Self {
+		self.max_uses = Some(max_uses);
+		self
+	}
+
+	pub fn with_allowed_domains<I, S>(mut self, domains: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		self.allowed_domains = Some(domains.into_iter().map(Into::into).collect());
+		self
+	}
+}
+
+impl From<WebSearchConfig> for BuiltInTool {
+	fn from(config: WebSearchConfig) -> Self {
+		Self::WebSearch(config)
+	}
+}
+
+// endregion: --- WebSearchConfig
+
+// region:    --- ComputerUseConfig
+
+/// The config of the `BuiltInTool::Computer` tool.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComputerUseConfig {
+	/// The display width, in pixels.
+	pub display_width_px: u32,
+
+	/// The display height, in pixels.
+	pub display_height_px: u32,
+
+	/// The eventual X11 display number.
+	pub display_number: Option<u32>,
+}
+
+/// Constructor & Chainable Setters
+impl ComputerUseConfig {
+	pub fn new(display_width_px: u32, display_height_px: u32) -> Self {
+		Self {
+			display_width_px,
+			display_height_px,
+			display_number: None,
+		}
+	}
+
+	pub fn with_display_number(mut self, display_number: u32) -> Self {
+		self.display_number = Some(display_number);
+		self
+	}
+}
+
+impl From<ComputerUseConfig> for BuiltInTool {
+	fn from(config: ComputerUseConfig) -> Self {
+		Self::Computer(config)
+	}
+}
+
+// endregion: --- ComputerUseConfig
+
+// region:    --- TextEditorVersion
+
+/// The version of the `BuiltInTool::TextEditor` tool, which gives its fixed type and name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TextEditorVersion {
+	/// `text_editor_20250124` named `str_replace_editor` (Claude Sonnet 3.7)
+	V20250124,
+	/// `text_editor_20250728` named `str_replace_based_edit_tool` (Claude 4 models)
+	#[default]
+	V20250728,
+}
+
+/// Getters
+impl TextEditorVersion {
+	/// The provider tool type (e.g., `text_editor_20250728`).
+	pub fn tool_type(&self) -> &'static str {
+		match self {
+			Self::V20250124 => "text_editor_20250124",
+			Self::V20250728 => "text_editor_20250728",
+		}
+	}
+
+	/// The fixed tool name of this version (e.g., `str_replace_based_edit_tool`).
+	pub fn tool_name(&self) -> &'static str {
+		match self {
+			Self::V20250124 => "str_replace_editor",
+			Self::V20250728 => "str_replace_based_edit_tool",
+		}
+	}
+}
+
+impl From<TextEditorVersion> for BuiltInTool {
+	fn from(version: TextEditorVersion) -> Self {
+		Self::TextEditor(version)
+	}
+}
+
+// endregion: --- TextEditorVersion
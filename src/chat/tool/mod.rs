@@ -1,11 +1,15 @@
 // region:    --- Modules
 
+mod builtin_tool;
 mod tool_base;
 mod tool_call;
+mod tool_choice;
 mod tool_response;
 
+pub use builtin_tool::*;
 pub use tool_base::*;
 pub use tool_call::*;
+pub use tool_choice::*;
 pub use tool_response::*;
 
 // endregion: --- Modules
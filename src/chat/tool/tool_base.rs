@@ -1,5 +1,7 @@
+use crate::chat::BuiltInTool;
+use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value, json};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tool {
@@ -39,6 +41,14 @@ pub struct Tool {
 	///
 	/// This could be usefull when you are using embeded tools like googleSearch of gimini
 	pub config: Option<Value>,
+
+	/// When true, the provider is asked to strictly follow the schema for the tool call arguments
+	/// (OpenAI `strict: true`), with the schema normalized by `Tool::strict_schema()`.
+	pub strict: Option<bool>,
+
+	/// The eventual provider built-in tool (see `Tool::builtin(..)`), mapped by the adapters to their native tool.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub builtin: Option<BuiltInTool>,
 }
 
 /// Constructor
@@ -49,6 +59,96 @@ impl Tool {
 			description: None,
 			schema: None,
 			config: None,
+			strict: None,
+			builtin: None,
+		}
+	}
+
+	/// A provider built-in tool (e.g., `BuiltInTool::web_search()`), named after the built-in tool.
+	pub fn builtin(builtin: impl Into<BuiltInTool>) -> Self {
+		let builtin = builtin.into();
+		let mut tool = Self::new(builtin.name());
+		tool.builtin = Some(builtin);
+		tool
+	}
+
+	/// The provider `file_search` built-in tool over the given vector stores (OpenAI only for now).
+	///
+	/// NOTE: For OpenAI, a chat request with this tool is sent to the Responses API (not streamable for now),
+	///       and the cited files are returned in `ChatResponse.file_citations`.
+	pub fn file_search<I, S>(vector_store_ids: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		let vector_store_ids: Vec<String> = vector_store_ids.into_iter().map(Into::into).collect();
+		Self::new(FILE_SEARCH_TOOL_NAME).with_config(json!({ "vector_store_ids": vector_store_ids }))
+	}
+
+	/// The provider `image_generation` built-in tool (OpenAI Responses API only for now).
+	/// The config is the eventual tool options (e.g., `{"size": "1024x1024", "quality": "low"}`).
+	///
+	/// NOTE: Like `Tool::file_search(..)`, a chat request with this tool is sent to the Responses API,
+	///       and the generated images are returned as `ContentBlock::Image` (see `ChatResponse::images()`).
+	pub fn image_generation(config: Option<Value>) -> Self {
+		Self::new(IMAGE_GENERATION_TOOL_NAME).with_config(config.unwrap_or_else(|| json!({})))
+	}
+}
+
+/// The name of the provider `file_search` built-in tool (see `Tool::file_search(..)`).
+const FILE_SEARCH_TOOL_NAME: &str = "file_search";
+
+/// The name of the provider `image_generation` built-in tool (see `Tool::image_generation(..)`).
+const IMAGE_GENERATION_TOOL_NAME: &str = "image_generation";
+
+impl From<BuiltInTool> for Tool {
+	fn from(builtin: BuiltInTool) -> Self {
+		Self::builtin(builtin)
+	}
+}
+
+/// Getters
+impl Tool {
+	/// Returns true if this is the provider `file_search` built-in tool (name `file_search` with a config).
+	pub fn is_file_search(&self) -> bool {
+		self.name == FILE_SEARCH_TOOL_NAME && self.config.is_some()
+	}
+
+	/// Returns true if this is the provider `image_generation` built-in tool (name `image_generation` with a config).
+	pub fn is_image_generation(&self) -> bool {
+		self.name == IMAGE_GENERATION_TOOL_NAME && self.config.is_some()
+	}
+
+	/// Returns true if the tool was set as strict (see `Tool::with_strict(..)`).
+	pub fn is_strict(&self) -> bool {
+		self.strict.unwrap_or(false)
+	}
+
+	/// Returns the schema normalized for the provider strict mode (e.g., OpenAI `strict: true`):
+	/// - Each object gets `additionalProperties: false` and all of its properties as `required`.
+	/// - The properties that were not required become nullable (e.g., `"type": ["string", "null"]`).
+	/// - No schema gives an empty object schema.
+	///
+	/// Returns an `Error::ToolSchemaInvalid` when the root is not an object schema, or when an object
+	/// allows additional properties (not supported in strict mode).
+	pub fn strict_schema(&self) -> Result<Value> {
+		let mut schema = self
+			.schema
+			.clone()
+			.unwrap_or_else(|| json!({"type": "object", "properties": {}}));
+
+		if schema.get("type").and_then(Value::as_str) != Some("object") {
+			return Err(self.schema_invalid("the root schema must be of type 'object'"));
+		}
+		normalize_strict(&mut schema, "").map_err(|cause| self.schema_invalid(cause))?;
+
+		Ok(schema)
+	}
+
+	fn schema_invalid(&self, cause: impl Into<String>) -> Error {
+		Error::ToolSchemaInvalid {
+			tool_name: self.name.clone(),
+			cause: cause.into(),
 		}
 	}
 }
@@ -70,6 +170,111 @@ impl Tool {
 		self.config = Some(config);
 		self
 	}
+
+	/// Sets the tool as strict, so that the provider generates arguments matching the schema
+	/// (the schema is normalized with `Tool::strict_schema()` when sent).
+	pub fn with_strict(mut self, strict: bool) -> Self {
+		self.strict = Some(strict);
+		self
+	}
 }
 
 // endregion: --- Setters
+
+// region:    --- Support
+
+/// Normalizes the (sub) schema in place for the strict mode (see `Tool::strict_schema()`).
+/// The `path` is the JSON pointer of the sub schema (for the error cause).
+fn normalize_strict(schema: &mut Value, path: &str) -> core::result::Result<(), String> {
+	let Value::Object(map) = schema else {
+		return Ok(());
+	};
+
+	// NOTE: The type can be `["object", "null"]` when made nullable by the parent.
+	let is_object = match map.get("type") {
+		Some(Value::String(typ)) => typ == "object",
+		Some(Value::Array(types)) => types.iter().any(|typ| typ == "object"),
+		_ => false,
+	} || map.contains_key("properties");
+	if is_object {
+		match map.get("additionalProperties") {
+			None | Some(Value::Bool(false)) => (),
+			Some(_) => {
+				return Err(format!(
+					"'{path}/additionalProperties' must be false (additional properties are not supported in strict mode)"
+				));
+			}
+		}
+		map.insert("additionalProperties".to_string(), false.into());
+
+		let required: Vec<String> = map
+			.get("required")
+			.and_then(Value::as_array)
+			.map(|names| names.iter().filter_map(Value::as_str).map(String::from).collect())
+			.unwrap_or_default();
+		let properties = map.entry("properties").or_insert_with(|| Value::Object(Map::new()));
+		let Value::Object(properties) = properties else {
+			return Err(format!("'{path}/properties' must be an object"));
+		};
+		for (name, property) in properties.iter_mut() {
+			if !required.contains(name) {
+				make_nullable(property);
+			}
+		}
+		let all_names: Vec<Value> = properties.keys().map(|name| Value::from(name.as_str())).collect();
+		map.insert("required".to_string(), Value::Array(all_names));
+	}
+
+	// -- Recurse in the sub schemas
+	for (key, value) in map.iter_mut() {
+		match (key.as_str(), value) {
+			("properties" | "$defs" | "definitions", Value::Object(sub_schemas)) => {
+				for (name, sub_schema) in sub_schemas.iter_mut() {
+					normalize_strict(sub_schema, &format!("{path}/{key}/{name}"))?;
+				}
+			}
+			("anyOf" | "allOf" | "oneOf", Value::Array(sub_schemas)) => {
+				for (idx, sub_schema) in sub_schemas.iter_mut().enumerate() {
+					normalize_strict(sub_schema, &format!("{path}/{key}/{idx}"))?;
+				}
+			}
+			("items", sub_schema) => normalize_strict(sub_schema, &format!("{path}/items"))?,
+			_ => (),
+		}
+	}
+
+	Ok(())
+}
+
+/// Makes an optional property nullable, since strict mode requires all the properties.
+fn make_nullable(property: &mut Value) {
+	let Value::Object(map) = property else {
+		return;
+	};
+	match map.get_mut("type") {
+		Some(Value::String(typ)) if typ != "null" => {
+			let typ = std::mem::take(typ);
+			map.insert("type".to_string(), json!([typ, "null"]));
+		}
+		Some(Value::Array(types)) => {
+			if !types.iter().any(|typ| typ == "null") {
+				types.push("null".into());
+			}
+		}
+		Some(_) => (),
+		// e.g., `anyOf` or `$ref`
+		None => {
+			let sub_schema = std::mem::take(map);
+			map.insert("anyOf".to_string(), json!([sub_schema, {"type": "null"}]));
+			return;
+		}
+	}
+	// NOTE: An `enum` must list `null` as well to allow it.
+	if let Some(Value::Array(values)) = map.get_mut("enum")
+		&& !values.contains(&Value::Null)
+	{
+		values.push(Value::Null);
+	}
+}
+
+// endregion: --- Support
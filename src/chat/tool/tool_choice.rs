@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// Controls whether and which tool the model calls (see `ChatOptions::with_tool_choice(..)`).
+///
+/// Provider mapping:
+/// - OpenAI (and compatibles): `tool_choice` (`auto`, `none`, `required`, or the named function).
+/// - Anthropic: `tool_choice` (`auto`, `none`, `any`, or `tool` with the name).
+/// - Gemini: `toolConfig.functionCallingConfig` (`AUTO`, `NONE`, `ANY`, or `ANY` with `allowedFunctionNames`).
+///
+/// NOTE: Anthropic rejects the forced tool use (`Required`, `Named`) with extended thinking,
+///       so the Anthropic adapter disables the thinking of these requests (with a warning).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ToolChoice {
+	/// The model decides whether to call a tool (the providers default).
+	Auto,
+	/// The model does not call any tool.
+	None,
+	/// The model calls at least one tool.
+	Required,
+	/// The model calls the tool of this name.
+	Named(String),
+}
+
+/// Constructors
+impl ToolChoice {
+	pub fn named(tool_name: impl Into<String>) -> Self {
+		Self::Named(tool_name.into())
+	}
+}
+
+/// Getters
+impl ToolChoice {
+	/// Returns true when the model is forced to call a tool (`Required` or `Named`).
+	pub fn is_forced(&self) -> bool {
+		matches!(self, Self::Required | Self::Named(_))
+	}
+}
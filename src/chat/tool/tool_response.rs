@@ -1,4 +1,6 @@
+use crate::chat::ContentPart;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolResponse {
@@ -8,6 +10,10 @@ pub struct ToolResponse {
 	/// Whether this tool response represents an error
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub is_error: Option<bool>,
+	/// The eventual additional parts of the tool response (e.g., the computer use screenshots)
+	/// NOTE: Anthropic only (text and image parts).
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub parts: Vec<ContentPart>,
 }
 
 /// Constructor
@@ -17,10 +23,25 @@ impl ToolResponse {
 			call_id: tool_call_id.into(),
 			content: content.into(),
 			is_error: None,
+			parts: Vec::new(),
 		}
 	}
 }
 
+/// Chainable Setters
+impl ToolResponse {
+	/// Add a part to the tool response (e.g., a screenshot image).
+	pub fn with_part(mut self, part: impl Into<ContentPart>) -> Self {
+		self.parts.push(part.into());
+		self
+	}
+
+	/// Add a base64 image (e.g., a computer use screenshot) to the tool response.
+	pub fn with_image_base64(self, content_type: impl Into<String>, content: impl Into<Arc<str>>) -> Self {
+		self.with_part(ContentPart::from_image_base64(content_type, content))
+	}
+}
+
 /// Getters
 #[allow(unused)]
 impl ToolResponse {
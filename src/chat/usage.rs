@@ -35,6 +35,35 @@ pub struct Usage {
 }
 
 impl Usage {
+	/// Add the tokens of another usage to this one (e.g., to aggregate the usage of multiple requests).
+	pub fn accumulate(&mut self, other: &Usage) {
+		self.prompt_tokens = add_tokens(self.prompt_tokens, other.prompt_tokens);
+		self.completion_tokens = add_tokens(self.completion_tokens, other.completion_tokens);
+		self.total_tokens = add_tokens(self.total_tokens, other.total_tokens);
+
+		if let Some(other_details) = &other.prompt_tokens_details {
+			let details = self.prompt_tokens_details.get_or_insert_with(Default::default);
+			details.cache_creation_tokens =
+				add_tokens(details.cache_creation_tokens, other_details.cache_creation_tokens);
+			details.cached_tokens = add_tokens(details.cached_tokens, other_details.cached_tokens);
+			details.audio_tokens = add_tokens(details.audio_tokens, other_details.audio_tokens);
+		}
+
+		if let Some(other_details) = &other.completion_tokens_details {
+			let details = self.completion_tokens_details.get_or_insert_with(Default::default);
+			details.accepted_prediction_tokens = add_tokens(
+				details.accepted_prediction_tokens,
+				other_details.accepted_prediction_tokens,
+			);
+			details.rejected_prediction_tokens = add_tokens(
+				details.rejected_prediction_tokens,
+				other_details.rejected_prediction_tokens,
+			);
+			details.reasoning_tokens = add_tokens(details.reasoning_tokens, other_details.reasoning_tokens);
+			details.audio_tokens = add_tokens(details.audio_tokens, other_details.audio_tokens);
+		}
+	}
+
 	/// Removes empty details fields if they only contain `None` values.
 	pub fn compact_details(&mut self) {
 		if let Some(details) = &self.prompt_tokens_details {
@@ -97,3 +126,15 @@ impl CompletionTokensDetails {
 			&& self.audio_tokens.is_none()
 	}
 }
+
+// region:    --- Support
+
+/// Sum two token counts, None only when both are None.
+fn add_tokens(a: Option<i32>, b: Option<i32>) -> Option<i32> {
+	match (a, b) {
+		(None, None) => None,
+		(a, b) => Some(a.unwrap_or(0) + b.unwrap_or(0)),
+	}
+}
+
+// endregion: --- Support
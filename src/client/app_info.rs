@@ -0,0 +1,96 @@
+use crate::Headers;
+use crate::adapter::AdapterKind;
+
+/// The application information (product name, version, and url) of the application using genai,
+/// set once on the client with `ClientBuilder::with_app_info(..)`.
+///
+/// It is used for:
+/// - The `User-Agent` header, appended to the genai one (e.g., `genai/0.4.0 my-app/1.2.0`).
+/// - The provider attribution headers:
+///   - OpenRouter: `X-Title` (name) and `HTTP-Referer` (url), overriding the `OPENROUTER_APP_...` environment variables.
+///   - Anthropic: `anthropic-client` (product).
+#[derive(Debug, Clone)]
+pub struct AppInfo {
+	name: String,
+	version: Option<String>,
+	url: Option<String>,
+}
+
+/// Constructors
+impl AppInfo {
+	/// Create an AppInfo with the application product name (e.g., `my-app`).
+	pub fn new(name: impl Into<String>) -> Self {
+		Self {
+			name: name.into(),
+			version: None,
+			url: None,
+		}
+	}
+}
+
+/// Chainable Setters
+impl AppInfo {
+	/// Set the application version (e.g., `1.2.0`).
+	pub fn with_version(mut self, version: impl Into<String>) -> Self {
+		self.version = Some(version.into());
+		self
+	}
+
+	/// Set the application url (e.g., used as OpenRouter `HTTP-Referer`).
+	pub fn with_url(mut self, url: impl Into<String>) -> Self {
+		self.url = Some(url.into());
+		self
+	}
+}
+
+/// Getters
+impl AppInfo {
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn version(&self) -> Option<&str> {
+		self.version.as_deref()
+	}
+
+	pub fn url(&self) -> Option<&str> {
+		self.url.as_deref()
+	}
+
+	/// The `name/version` product token (or just `name` when no version).
+	pub fn product(&self) -> String {
+		match &self.version {
+			Some(version) => format!("{}/{version}", self.name),
+			None => self.name.clone(),
+		}
+	}
+
+	/// The full User-Agent value, with the genai product first.
+	pub fn user_agent(&self) -> String {
+		format!("{} {}", genai_user_agent(), self.product())
+	}
+}
+
+/// Crate Support
+impl AppInfo {
+	/// The provider attribution headers for the given adapter kind (can be empty).
+	pub(crate) fn attribution_headers(&self, adapter_kind: AdapterKind) -> Headers {
+		let mut headers = Headers::default();
+		match adapter_kind {
+			AdapterKind::OpenRouter => {
+				headers.merge(("X-Title", self.name.clone()));
+				if let Some(url) = &self.url {
+					headers.merge(("HTTP-Referer", url.clone()));
+				}
+			}
+			AdapterKind::Anthropic => headers.merge(("anthropic-client", self.product())),
+			_ => (),
+		}
+		headers
+	}
+}
+
+/// The genai User-Agent product (e.g., `genai/0.4.0`).
+pub(crate) fn genai_user_agent() -> String {
+	format!("genai/{}", env!("CARGO_PKG_VERSION"))
+}
@@ -1,10 +1,13 @@
 use crate::chat::ChatOptions;
+use crate::client::app_info::genai_user_agent;
 use crate::resolver::{
-	AuthResolver, IntoAuthResolverFn, IntoModelMapperFn, IntoServiceTargetResolverFn, ModelMapper,
-	ServiceTargetResolver,
+	AuthResolver, IntoAuthResolverFn, IntoModelMapperFn, IntoRequestSignerFn, IntoServiceTargetResolverFn, ModelMapper,
+	RequestSigner, ServiceTargetResolver,
 };
+#[cfg(feature = "sqlite")]
+use crate::trace::SqliteLog;
 use crate::webc::WebClient;
-use crate::{Client, ClientConfig, WebConfig};
+use crate::{AppInfo, Client, ClientConfig, Gateway, QuotaManager, WebConfig};
 use std::sync::Arc;
 
 /// The builder for the `Client` structure.
@@ -79,6 +82,39 @@ impl ClientBuilder {
 		self
 	}
 
+	/// Set the gateway (e.g., `Gateway::helicone()`) for the ClientConfig of this ClientBuilder.
+	pub fn with_gateway(mut self, gateway: Gateway) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.gateway = Some(gateway);
+		self
+	}
+
+	/// Set the AppInfo (e.g., `AppInfo::new("my-app").with_version("1.2.0")`) for the ClientConfig of this ClientBuilder.
+	///
+	/// NOTE: The User-Agent is only set when genai creates the reqwest client (i.e., not with `with_reqwest(..)`).
+	pub fn with_app_info(mut self, app_info: AppInfo) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.app_info = Some(app_info);
+		self
+	}
+
+	/// Set the QuotaManager for the ClientConfig of this ClientBuilder.
+	/// Keep a clone of the QuotaManager to query the remaining tenant quotas.
+	pub fn with_quota_manager(mut self, quota_manager: QuotaManager) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.quota_manager = Some(quota_manager);
+		self
+	}
+
+	/// Set the SqliteLog for the ClientConfig of this ClientBuilder (logs every `exec_chat(..)`).
+	/// Keep a clone of the SqliteLog to query the logged calls.
+	#[cfg(feature = "sqlite")]
+	pub fn with_sqlite_log(mut self, sqlite_log: SqliteLog) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.sqlite_log = Some(sqlite_log);
+		self
+	}
+
 	/// Set the model mapper for the ClientConfig of this ClientBuilder.
 	pub fn with_model_mapper(mut self, model_mapper: ModelMapper) -> Self {
 		let client_config = self.config.get_or_insert_with(ClientConfig::default);
@@ -93,6 +129,21 @@ impl ClientBuilder {
 		client_config.model_mapper = Some(model_mapper);
 		self
 	}
+
+	/// Set the request signer for the ClientConfig of this ClientBuilder.
+	pub fn with_request_signer(mut self, request_signer: RequestSigner) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.request_signer = Some(request_signer);
+		self
+	}
+
+	/// Set the request signer function for the ClientConfig of this ClientBuilder.
+	pub fn with_request_signer_fn(mut self, request_signer_fn: impl IntoRequestSignerFn) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		let request_signer = RequestSigner::from_signer_fn(request_signer_fn);
+		client_config.request_signer = Some(request_signer);
+		self
+	}
 }
 
 impl ClientBuilder {
@@ -104,15 +155,19 @@ impl ClientBuilder {
 		let web_client = if let Some(web_client) = self.web_client {
 			// Use explicitly provided WebClient
 			web_client
-		} else if let Some(req_config) = config.web_config() {
-			// Create WebClient with reqwest configuration
-			let mut builder = reqwest::Client::builder();
-			builder = req_config.apply_to_builder(builder);
+		} else {
+			// Create WebClient with the genai (and eventual app) User-Agent, and the eventual reqwest configuration
+			// NOTE: The WebConfig default headers are applied after, so they can override the User-Agent.
+			let user_agent = match config.app_info() {
+				Some(app_info) => app_info.user_agent(),
+				None => genai_user_agent(),
+			};
+			let mut builder = reqwest::Client::builder().user_agent(user_agent);
+			if let Some(req_config) = config.web_config() {
+				builder = req_config.apply_to_builder(builder);
+			}
 			let reqwest_client = builder.build().expect("Failed to build reqwest client");
 			WebClient::from_reqwest_client(reqwest_client)
-		} else {
-			// Use default WebClient
-			WebClient::default()
 		};
 
 		let inner = super::ClientInner { web_client, config };
@@ -1,8 +1,33 @@
 use crate::adapter::{AdapterDispatcher, AdapterKind, ServiceType, WebRequestData};
-use crate::chat::{ChatOptions, ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
-use crate::embed::{EmbedOptions, EmbedOptionsSet, EmbedRequest, EmbedResponse};
-use crate::resolver::AuthData;
-use crate::{Client, Error, ModelIden, Result, ServiceTarget};
+use crate::chat::{
+	AutoContinue, CachedContent, ChatOptions, ChatOptionsSet, ChatRequest, ChatResponse, ChatResponseFormat,
+	ChatStreamResponse, ChatStructuredResponse, JsonSpec, OperationUsage, OutputFixer, OutputRepair,
+	RegenerationPolicy, ResponseDigest, ResponseHeaders, SpeculativeStream, TokenCount, Usage,
+};
+use crate::embed::{
+	EmbedBilledUnits, EmbedChunkResult, EmbedConcurrency, EmbedOptions, EmbedOptionsSet, EmbedRequest, EmbedResponse,
+};
+use crate::files::{FileRef, FileUpload, VectorStore, VectorStoreFile};
+use crate::finetune::{FineTuneJob, FineTuneRequest};
+use crate::ollama::{OllamaModelInfo, OllamaPullStream, OllamaRunningModel};
+use crate::realtime::{RealtimeConfig, RealtimeSession};
+use crate::resolver::{AuthData, SignableRequest};
+#[cfg(feature = "sqlite")]
+use crate::trace::{SqliteLog, TraceRecord};
+use crate::usage_report::{CostReport, UsageReport, UsageReportRequest};
+use crate::videogen::{VideoJob, VideoRequest};
+use crate::webc::{self, WebSocket};
+use crate::{Client, Error, Headers, ModelIden, ModelInfo, ModelRouter, PingReport, Result, ServiceTarget};
+use futures::{StreamExt, TryStreamExt};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+/// The max number of characters of an `embed_many(..)` batch request
+/// (~100k tokens, below the providers request token limits).
+const EMBED_MANY_MAX_BATCH_CHARS: usize = 400_000;
 
 /// Public AI Functions
 impl Client {
@@ -44,6 +69,8 @@ impl Client {
 	}
 
 	/// Executes a chat.
+	///
+	/// NOTE: With a client `SqliteLog` (`sqlite` feature), the execution is logged (response or error).
 	pub async fn exec_chat(
 		&self,
 		model: &str,
@@ -51,47 +78,41 @@ impl Client {
 		// options not implemented yet
 		options: Option<&ChatOptions>,
 	) -> Result<ChatResponse> {
-		let options_set = ChatOptionsSet::default()
-			.with_chat_options(options)
-			.with_client_options(self.config().chat_options());
+		#[cfg(feature = "sqlite")]
+		if let Some(sqlite_log) = self.config().sqlite_log() {
+			return self.exec_chat_logged(sqlite_log, model, chat_req, options).await;
+		}
 
-		let model = self.default_model(model)?;
-		let target = self.config().resolve_service_target(model).await?;
-		let model = target.model.clone();
-
-		let WebRequestData { headers, payload, url } =
-			AdapterDispatcher::to_web_request_data(target, ServiceType::Chat, chat_req, options_set.clone())?;
-
-		let web_res =
-			self.web_client()
-				.do_post(&url, &headers, payload)
-				.await
-				.map_err(|webc_error| Error::WebModelCall {
-					model_iden: model.clone(),
-					webc_error,
-				})?;
-
-		let chat_res = AdapterDispatcher::to_chat_response(model, web_res, options_set)?;
-
-		Ok(chat_res)
+		self.exec_chat_unlogged(model, chat_req, options).await
 	}
 
 	/// Executes a chat stream response.
 	pub async fn exec_chat_stream(
 		&self,
 		model: &str,
-		chat_req: ChatRequest, // options not implemented yet
+		mut chat_req: ChatRequest, // options not implemented yet
 		options: Option<&ChatOptions>,
 	) -> Result<ChatStreamResponse> {
 		let options_set = ChatOptionsSet::default()
 			.with_chat_options(options)
 			.with_client_options(self.config().chat_options());
+		let model = match options_set.capability_fallback() {
+			Some(capability_fallback) => capability_fallback.resolve_model(model, &chat_req),
+			None => model,
+		};
 
 		let model = self.default_model(model)?;
 		let target = self.config().resolve_service_target(model).await?;
 		let model = target.model.clone();
 		let auth_data = target.auth.clone();
 
+		// NOTE: The stream usage must be recorded by the caller (see `QuotaManager::record_usage(..)`)
+		self.acquire_quota(options_set.tenant_id()).await?;
+
+		if let Some(time_context) = options_set.time_context() {
+			time_context.apply(&mut chat_req);
+		}
+
 		let WebRequestData {
 			mut url,
 			mut headers,
@@ -109,20 +130,65 @@ impl Client {
 			url = override_url;
 			headers = override_headers;
 		};
+		self.apply_app_info_headers(&model, &mut headers);
+		self.apply_gateway_headers(&model, &mut headers)?;
+		let signed_body = self.apply_request_signer(&model, &url, &mut headers, &payload)?;
 
-		let reqwest_builder = self
-			.web_client()
-			.new_req_builder(&url, &headers, payload)
-			.map_err(|webc_error| Error::WebModelCall {
-				model_iden: model.clone(),
-				webc_error,
-			})?;
+		let reqwest_builder = match signed_body {
+			Some(body) => self.web_client().new_req_builder_json_bytes(&url, &headers, body),
+			None => self
+				.web_client()
+				.new_req_builder(&url, &headers, payload)
+				.map_err(|webc_error| Error::WebModelCall {
+					model_iden: model.clone(),
+					webc_error,
+				})?,
+		};
 
 		let res = AdapterDispatcher::to_chat_stream(model, reqwest_builder, options_set)?;
 
 		Ok(res)
 	}
 
+	/// Counts the input tokens of a chat request, without executing it,
+	/// so that the context can be budgeted before sending the request.
+	///
+	/// NOTE: Only supported by Anthropic and Gemini for now. Other adapters return `Error::AdapterNotSupported`.
+	pub async fn exec_count_tokens(&self, model: &str, chat_req: ChatRequest) -> Result<TokenCount> {
+		let model = self.default_model(model)?;
+		let target = self.config().resolve_service_target(model).await?;
+
+		AdapterDispatcher::count_tokens(self.web_client(), target, chat_req).await
+	}
+
+	/// Counts the tokens of the `.system` and pinned messages of the chat request (see `ChatRequest::pinned_request()`),
+	/// and fails with `Error::PinnedBudgetExceeded` when they do not fit in the `budget` (e.g., the part of
+	/// `ModelInfo.context_window` reserved for the pinned content), since they cannot be trimmed.
+	///
+	/// NOTE: Returns a zero count (without request) when there are no pinned messages.
+	///       Relies on `exec_count_tokens(..)`, so only supported by Anthropic and Gemini for now.
+	pub async fn check_pinned_budget(&self, model: &str, chat_req: &ChatRequest, budget: u32) -> Result<TokenCount> {
+		let pinned_req = chat_req.pinned_request();
+		if pinned_req.messages.is_empty() {
+			let model_iden = self.default_model(model)?;
+			return Ok(TokenCount {
+				model_iden,
+				input_tokens: 0,
+			});
+		}
+
+		let token_count = self.exec_count_tokens(model, pinned_req).await?;
+		if token_count.input_tokens > budget as i32 {
+			return Err(Error::PinnedBudgetExceeded {
+				model_iden: token_count.model_iden,
+				pinned_tokens: token_count.input_tokens,
+				budget,
+			});
+		}
+
+		Ok(token_count)
+	}
+
 	/// Executes an embedding request for a single text input.
 	pub async fn embed(
 		&self,
@@ -145,6 +211,122 @@ impl Client {
 		self.exec_embed(model, embed_req, options).await
 	}
 
+	/// Executes an embedding request for any number of text inputs.
+	///
+	/// The inputs exceeding the provider batch limits (see `AdapterKind::embed_max_batch_size()`)
+	/// or the request size limit (~`EMBED_MANY_MAX_BATCH_CHARS` characters) are split into multiple batch requests,
+	/// executed with at most `concurrency` requests in flight.
+	/// The embeddings are returned in the inputs order, with the usage of all the requests.
+	///
+	/// NOTE: Fails on the first failed batch request (the other embeddings are not returned).
+	pub async fn embed_many(
+		&self,
+		model: &str,
+		inputs: Vec<String>,
+		options: Option<&EmbedOptions>,
+		concurrency: usize,
+	) -> Result<EmbedResponse> {
+		let model_iden = self.resolve_service_target(model).await?.model;
+		let max_count = model_iden.adapter_kind.embed_max_batch_size();
+
+		let mut batches = EmbedRequest::split_batches(inputs, max_count, EMBED_MANY_MAX_BATCH_CHARS);
+		if batches.len() <= 1 {
+			let inputs = batches.pop().unwrap_or_default();
+			return self.embed_batch(model, inputs, options).await;
+		}
+
+		let responses: Vec<EmbedResponse> = futures::stream::iter(batches)
+			.map(|batch| self.embed_batch(model, batch, options))
+			.buffered(concurrency.max(1))
+			.try_collect()
+			.await?;
+
+		// -- Reassemble the embeddings in order, with the aggregated usage
+		let mut embeddings = Vec::new();
+		let mut usage = Usage::default();
+		let mut billed_units: Option<EmbedBilledUnits> = None;
+		let mut model_idens = None;
+		for res in responses {
+			usage.accumulate(&res.usage);
+			if let Some(res_billed_units) = &res.billed_units {
+				billed_units.get_or_insert_with(Default::default).accumulate(res_billed_units);
+			}
+			let offset = embeddings.len();
+			embeddings.extend(res.embeddings.into_iter().map(|mut embedding| {
+				embedding.index += offset;
+				embedding
+			}));
+			model_idens.get_or_insert((res.model_iden, res.provider_model_iden));
+		}
+		let (model_iden, provider_model_iden) = model_idens.unwrap_or_else(|| (model_iden.clone(), model_iden));
+
+		let mut res = EmbedResponse::new(embeddings, model_iden, provider_model_iden, usage);
+		if let Some(billed_units) = billed_units {
+			res = res.with_billed_units(billed_units);
+		}
+
+		Ok(res)
+	}
+
+	/// Executes an embedding request for any number of text inputs, split into chunks
+	/// (see `EmbedConcurrency.chunk_size`) executed with at most `EmbedConcurrency.max_concurrent` requests in flight.
+	///
+	/// A rate limited chunk (status `429`) is retried after a backoff (the provider `retry-after` when returned),
+	/// up to `EmbedConcurrency.max_retries` times.
+	/// The result of each chunk is returned in the inputs order, so a failed chunk does not fail the other ones.
+	pub async fn embed_batch_concurrent(
+		&self,
+		model: &str,
+		inputs: Vec<String>,
+		options: Option<&EmbedOptions>,
+		concurrency: &EmbedConcurrency,
+	) -> Result<Vec<EmbedChunkResult>> {
+		let model_iden = self.resolve_service_target(model).await?.model;
+		let provider_max_count = model_iden.adapter_kind.embed_max_batch_size();
+		let max_count = concurrency.chunk_size.unwrap_or(provider_max_count).min(provider_max_count);
+
+		let chunks = EmbedRequest::split_batches(inputs, max_count, EMBED_MANY_MAX_BATCH_CHARS);
+		let mut offset = 0;
+		let chunks: Vec<(Range<usize>, Vec<String>)> = chunks
+			.into_iter()
+			.map(|chunk| {
+				let range = offset..offset + chunk.len();
+				offset = range.end;
+				(range, chunk)
+			})
+			.collect();
+
+		let results = futures::stream::iter(chunks)
+			.map(|(inputs_range, chunk)| async move {
+				let mut retries = 0;
+				let result = loop {
+					match self.embed_batch(model, chunk.clone(), options).await {
+						Err(err) if err.is_rate_limited() && retries < concurrency.max_retries => {
+							retries += 1;
+							tokio::time::sleep(concurrency.backoff(retries, err.retry_after())).await;
+						}
+						result => break result,
+					}
+				};
+				let result = result.map(|mut res| {
+					for embedding in res.embeddings.iter_mut() {
+						embedding.index += inputs_range.start;
+					}
+					res
+				});
+				EmbedChunkResult {
+					inputs_range,
+					retries,
+					result,
+				}
+			})
+			.buffered(concurrency.max_concurrent.max(1))
+			.collect()
+			.await;
+
+		Ok(results)
+	}
+
 	/// Executes an embedding request.
 	pub async fn exec_embed(
 		&self,
@@ -160,20 +342,877 @@ impl Client {
 		let target = self.config().resolve_service_target(model).await?;
 		let model = target.model.clone();
 
-		let WebRequestData { headers, payload, url } =
-			AdapterDispatcher::to_embed_request_data(target, embed_req, options_set.clone())?;
+		let tenant_id = options_set.tenant_id().map(|tenant_id| tenant_id.to_string());
+		self.acquire_quota(tenant_id.as_deref()).await?;
 
-		let web_res =
-			self.web_client()
-				.do_post(&url, &headers, payload)
-				.await
-				.map_err(|webc_error| Error::WebModelCall {
-					model_iden: model.clone(),
-					webc_error,
-				})?;
+		let WebRequestData {
+			mut headers,
+			payload,
+			url,
+		} = AdapterDispatcher::to_embed_request_data(target, embed_req, options_set.clone())?;
+		self.apply_app_info_headers(&model, &mut headers);
+		self.apply_gateway_headers(&model, &mut headers)?;
+		let signed_body = self.apply_request_signer(&model, &url, &mut headers, &payload)?;
+
+		let web_res = match signed_body {
+			Some(body) => self.web_client().do_post_bytes(&url, &headers, "application/json", body).await,
+			None => self.web_client().do_post(&url, &headers, payload).await,
+		}
+		.map_err(|webc_error| Error::WebModelCall {
+			model_iden: model.clone(),
+			webc_error,
+		})?;
+
+		let normalize = options_set.normalize();
+		let mut res = AdapterDispatcher::to_embed_response(model, web_res, options_set)?;
+		if normalize {
+			res.normalize();
+		}
 
-		let res = AdapterDispatcher::to_embed_response(model, web_res, options_set)?;
+		self.record_quota_usage(tenant_id.as_deref(), &res.model_iden, &res.usage).await;
 
 		Ok(res)
 	}
 }
+
+/// Private Support Functions
+impl Client {
+	/// Add the eventual app info attribution headers (e.g., OpenRouter `X-Title`) on top of the adapter request headers.
+	fn apply_app_info_headers(&self, model: &ModelIden, headers: &mut Headers) {
+		if let Some(app_info) = self.config().app_info() {
+			headers.merge(app_info.attribution_headers(model.adapter_kind));
+		}
+	}
+
+	/// Add the eventual gateway headers on top of the adapter request headers.
+	fn apply_gateway_headers(&self, model: &ModelIden, headers: &mut Headers) -> Result<()> {
+		if let Some(gateway) = self.config().gateway() {
+			let gateway_headers = gateway.headers().map_err(|resolver_error| Error::Resolver {
+				model_iden: model.clone(),
+				resolver_error,
+			})?;
+			headers.merge(gateway_headers);
+		}
+		Ok(())
+	}
+
+	/// Executes a chat (see `exec_chat(..)`), without the eventual `SqliteLog`.
+	async fn exec_chat_unlogged(
+		&self,
+		model: &str,
+		mut chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+	) -> Result<ChatResponse> {
+		let options_set = ChatOptionsSet::default()
+			.with_chat_options(options)
+			.with_client_options(self.config().chat_options());
+		let model = match options_set.capability_fallback() {
+			Some(capability_fallback) => capability_fallback.resolve_model(model, &chat_req),
+			None => model,
+		};
+
+		// -- Capture the eventual output fixer (not applied to the fixer model responses)
+		let output_fixer = options_set
+			.output_fixer()
+			.filter(|fixer| fixer.model != model)
+			.cloned()
+			.map(|fixer| (fixer, options_set.response_format().cloned()));
+
+		// -- Capture the eventual AI disclosure (only as metadata for the structured outputs)
+		let ai_disclosure = options_set
+			.ai_disclosure()
+			.cloned()
+			.map(|disclosure| (disclosure, options_set.response_format().is_some()));
+
+		let model = self.default_model(model)?;
+		let target = self.config().resolve_service_target(model).await?;
+
+		let tenant_id = options_set.tenant_id().map(|tenant_id| tenant_id.to_string());
+		self.acquire_quota(tenant_id.as_deref()).await?;
+
+		// -- Capture the eventual prompt shield protected texts (before the request is consumed)
+		let prompt_shield = options_set.prompt_shield().cloned().map(|shield| {
+			let protected = shield.protected_texts(&chat_req);
+			(shield, protected)
+		});
+
+		if let Some(time_context) = options_set.time_context() {
+			time_context.apply(&mut chat_req);
+		}
+
+		// -- Keep the target and request for the eventual continuations (before they are consumed)
+		let auto_continue = options_set
+			.auto_continue()
+			.map(|auto_continue| (auto_continue, target.clone(), chat_req.clone()));
+
+		let mut chat_res = self.exec_chat_web(target, chat_req, &options_set).await?;
+		self.record_quota_usage(tenant_id.as_deref(), &chat_res.model_iden, &chat_res.usage)
+			.await;
+
+		if let Some((auto_continue, target, chat_req)) = auto_continue {
+			self.continue_output(
+				auto_continue,
+				&target,
+				&chat_req,
+				&options_set,
+				tenant_id.as_deref(),
+				&mut chat_res,
+			)
+			.await?;
+		}
+
+		if let Some((fixer, response_format)) = output_fixer {
+			self.repair_output(&fixer, response_format, tenant_id.as_deref(), &mut chat_res)
+				.await?;
+		}
+
+		if let Some((shield, protected)) = prompt_shield {
+			shield.apply(&protected, &mut chat_res);
+		}
+
+		if let Some((disclosure, structured)) = ai_disclosure {
+			disclosure.apply(&mut chat_res, structured);
+		}
+
+		Ok(chat_res)
+	}
+
+	/// Executes a chat, and logs the execution (response or error) in the SqliteLog,
+	/// with the request tenant id as tag.
+	#[cfg(feature = "sqlite")]
+	async fn exec_chat_logged(
+		&self,
+		sqlite_log: &SqliteLog,
+		model: &str,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+	) -> Result<ChatResponse> {
+		let options_set = ChatOptionsSet::default()
+			.with_chat_options(options)
+			.with_client_options(self.config().chat_options());
+		let tag = options_set.tenant_id().map(|tenant_id| tenant_id.to_string());
+		let mut record = TraceRecord::from_chat(self.default_model(model)?, &chat_req);
+		if let Some(options) = options.or(self.config().chat_options()) {
+			record = record.with_options(options);
+		}
+
+		let res = self.exec_chat_unlogged(model, chat_req, options).await;
+		let record = match &res {
+			Ok(chat_res) => {
+				record.model_iden = chat_res.model_iden.clone();
+				record.with_response(chat_res)
+			}
+			Err(err) => record.with_error(err),
+		};
+		if let Err(err) = sqlite_log.log(&record, tag.as_deref()) {
+			tracing::warn!("SqliteLog failed to log the chat execution '{}': {err}", record.id);
+		}
+
+		res
+	}
+
+	/// Check (and count the request against) the eventual tenant quotas.
+	async fn acquire_quota(&self, tenant_id: Option<&str>) -> Result<()> {
+		match (self.config().quota_manager(), tenant_id) {
+			(Some(quota_manager), Some(tenant_id)) => quota_manager.acquire(tenant_id).await,
+			_ => Ok(()),
+		}
+	}
+
+	/// Record the response usage against the eventual tenant quotas.
+	/// NOTE: A failed record (e.g., of the `QuotaStore`) is logged, as the response is already received.
+	async fn record_quota_usage(&self, tenant_id: Option<&str>, model: &ModelIden, usage: &Usage) {
+		if let (Some(quota_manager), Some(tenant_id)) = (self.config().quota_manager(), tenant_id)
+			&& let Err(err) = quota_manager.record_usage(tenant_id, model, usage).await
+		{
+			tracing::warn!("QuotaManager failed to record the usage of tenant '{tenant_id}': {err}");
+		}
+	}
+
+	/// Send the chat request to the service target, and returns the adapter response
+	/// (with the eventual response digest and headers).
+	async fn exec_chat_web(
+		&self,
+		target: ServiceTarget,
+		chat_req: ChatRequest,
+		options_set: &ChatOptionsSet<'_, '_>,
+	) -> Result<ChatResponse> {
+		let model = target.model.clone();
+
+		let WebRequestData {
+			mut headers,
+			payload,
+			url,
+		} = AdapterDispatcher::to_web_request_data(target, ServiceType::Chat, chat_req, options_set.clone())?;
+		self.apply_app_info_headers(&model, &mut headers);
+		self.apply_gateway_headers(&model, &mut headers)?;
+		let signed_body = self.apply_request_signer(&model, &url, &mut headers, &payload)?;
+
+		let web_res = match signed_body {
+			Some(body) => self.web_client().do_post_bytes(&url, &headers, "application/json", body).await,
+			None => self.web_client().do_post(&url, &headers, payload).await,
+		}
+		.map_err(|webc_error| Error::WebModelCall {
+			model_iden: model.clone(),
+			webc_error,
+		})?;
+
+		// -- Compute the eventual digest of the exact response body (before the adapter consumes it)
+		let response_digest = options_set
+			.capture_response_digest()
+			.unwrap_or_default()
+			.then(|| ResponseDigest::from_body(&web_res.body_bytes));
+
+		// -- Capture the eventual response headers
+		let response_headers = options_set
+			.capture_headers()
+			.unwrap_or_default()
+			.then(|| ResponseHeaders::from_header_map(&web_res.headers));
+
+		let mut chat_res = AdapterDispatcher::to_chat_response(model, web_res, options_set.clone())?;
+		chat_res.response_digest = response_digest;
+		chat_res.response_headers = response_headers;
+
+		Ok(chat_res)
+	}
+
+	/// Re-request the continuation of the truncated output, and stitch it to the response (see `AutoContinue`).
+	async fn continue_output(
+		&self,
+		auto_continue: &AutoContinue,
+		target: &ServiceTarget,
+		chat_req: &ChatRequest,
+		options_set: &ChatOptionsSet<'_, '_>,
+		tenant_id: Option<&str>,
+		chat_res: &mut ChatResponse,
+	) -> Result<()> {
+		let adapter_kind = target.model.adapter_kind;
+		let mut continuations = 0;
+		while let Some(partial_output) = auto_continue.partial_output(chat_res, continuations) {
+			let continuation_req = auto_continue.continuation_request(adapter_kind, chat_req, partial_output);
+
+			self.acquire_quota(tenant_id).await?;
+			let continuation = self.exec_chat_web(target.clone(), continuation_req, options_set).await?;
+			self.record_quota_usage(tenant_id, &continuation.model_iden, &continuation.usage)
+				.await;
+
+			AutoContinue::stitch(adapter_kind, chat_res, continuation);
+			continuations += 1;
+		}
+
+		Ok(())
+	}
+
+	/// Repair the eventual invalid JSON output of the chat response with the fixer model.
+	async fn repair_output(
+		&self,
+		fixer: &OutputFixer,
+		response_format: Option<ChatResponseFormat>,
+		tenant_id: Option<&str>,
+		chat_res: &mut ChatResponse,
+	) -> Result<()> {
+		let Some(response_format) = response_format else {
+			return Ok(());
+		};
+		let Some(invalid_output) = OutputFixer::invalid_output(&response_format, chat_res) else {
+			return Ok(());
+		};
+
+		let fixer_req = OutputFixer::fixer_chat_request(&response_format, &invalid_output);
+		let mut fixer_options = ChatOptions::default().with_response_format(response_format);
+		if let Some(tenant_id) = tenant_id {
+			fixer_options = fixer_options.with_tenant_id(tenant_id);
+		}
+		let fixer_res = Box::pin(self.exec_chat(&fixer.model, fixer_req, Some(&fixer_options))).await?;
+
+		let fixed_output = fixer_res
+			.first_text()
+			.map(|text| text.trim().to_string())
+			.filter(|text| serde_json::from_str::<Value>(text).is_ok());
+		let repaired = fixed_output.is_some();
+		if let Some(fixed_output) = fixed_output {
+			chat_res.content = vec![fixed_output.into()];
+		}
+
+		chat_res.output_repair = Some(OutputRepair {
+			fixer_model_iden: fixer_res.model_iden,
+			original_output: invalid_output,
+			repaired,
+			fixer_usage: fixer_res.usage,
+		});
+
+		Ok(())
+	}
+
+	/// When a request signer is set, serialize the payload once, add the signer headers,
+	/// and return the exact body bytes to send (None when no signer is set).
+	fn apply_request_signer(
+		&self,
+		model: &ModelIden,
+		url: &str,
+		headers: &mut Headers,
+		payload: &Value,
+	) -> Result<Option<Vec<u8>>> {
+		let Some(request_signer) = self.config().request_signer() else {
+			return Ok(None);
+		};
+
+		let body = serde_json::to_vec(payload)?;
+		let signable = SignableRequest {
+			model_iden: model,
+			method: "POST",
+			url,
+			headers,
+			body: &body,
+		};
+		let signer_headers = request_signer.sign(signable).map_err(|resolver_error| Error::Resolver {
+			model_iden: model.clone(),
+			resolver_error,
+		})?;
+		headers.merge(signer_headers);
+
+		Ok(Some(body))
+	}
+}
+
+/// Public Speculative Functions
+impl Client {
+	/// Executes a speculative draft-and-verify chat.
+	///
+	/// The `draft_model` (fast/cheap) answer is streamed immediately as `SpeculativeEvent::Draft` events,
+	/// while the `verify_model` (stronger) answers the same request concurrently.
+	/// The stream ends with `SpeculativeEvent::Confirmed` when both answers are the same,
+	/// or `SpeculativeEvent::Correction` with the verify model response when they differ.
+	pub async fn exec_chat_speculative(
+		&self,
+		draft_model: &str,
+		verify_model: &str,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+	) -> Result<SpeculativeStream> {
+		let draft_res = self.exec_chat_stream(draft_model, chat_req.clone(), options).await?;
+
+		let client = self.clone();
+		let verify_model = verify_model.to_string();
+		let options = options.cloned();
+		let verify_future = async move { client.exec_chat(&verify_model, chat_req, options.as_ref()).await };
+
+		Ok(SpeculativeStream::new(draft_res.stream, Box::pin(verify_future)))
+	}
+}
+
+/// Public Structured Output Functions
+impl Client {
+	/// Executes a chat with the JSON schema of `T` as the structured output format (see `JsonSpec::from_type()`),
+	/// and deserializes the response text into `T`.
+	///
+	/// The `response_format` of the options is replaced (the other options are kept, e.g., the `output_fixer`).
+	///
+	/// Fails with `Error::StructuredOutputInvalid` when the response text is not a valid `T` JSON.
+	pub async fn exec_chat_structured<T>(
+		&self,
+		model: &str,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+	) -> Result<ChatStructuredResponse<T>>
+	where
+		T: DeserializeOwned + JsonSchema,
+	{
+		let options = options
+			.cloned()
+			.unwrap_or_default()
+			.with_response_format(JsonSpec::from_type::<T>());
+		let chat_response = self.exec_chat(model, chat_req, Some(&options)).await?;
+
+		let text = chat_response.first_text().unwrap_or_default().trim();
+		let value = serde_json::from_str::<T>(text).map_err(|err| Error::StructuredOutputInvalid {
+			model_iden: chat_response.model_iden.clone(),
+			type_name: std::any::type_name::<T>(),
+			cause: err.to_string(),
+		})?;
+
+		Ok(ChatStructuredResponse { value, chat_response })
+	}
+}
+
+/// Public Routing Functions
+impl Client {
+	/// Executes a chat with the model picked by the `router` among its candidates of at least `min_tier`
+	/// (see `ModelRouter::route(..)`), and records the latency and usage of the response in the router stats.
+	///
+	/// When the picked model is rate limited by the provider (status `429`), the next candidate is tried.
+	/// The other errors are returned immediately.
+	pub async fn exec_chat_routed(
+		&self,
+		router: &ModelRouter,
+		min_tier: u8,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+	) -> Result<ChatResponse> {
+		let models = router.route(min_tier)?;
+		let last_idx = models.len() - 1;
+
+		for (idx, model) in models.iter().enumerate() {
+			router.record_request(model);
+			let start = Instant::now();
+			match self.exec_chat(model, chat_req.clone(), options).await {
+				Ok(chat_res) => {
+					router.record_success(model, start.elapsed(), &chat_res.usage);
+					return Ok(chat_res);
+				}
+				Err(err) => {
+					router.record_error(model, &err);
+					if idx == last_idx || !err.is_rate_limited() {
+						return Err(err);
+					}
+				}
+			}
+		}
+
+		Err(Error::NoRouteCandidate { min_tier })
+	}
+}
+
+/// Public Regeneration Functions
+impl Client {
+	/// Executes a chat, and regenerates it when the `validator` rejects the response
+	/// (or on a content filter refusal), with the option schedule of the `policy`
+	/// (e.g., bump the temperature, switch the model from the third attempt).
+	///
+	/// The `validator` returns `Err(reason)` to reject a response.
+	/// When all the attempts are rejected, `Error::RegenerationExhausted` is returned with the last rejection reason.
+	///
+	/// NOTE: The other errors (e.g., network, auth) are returned immediately (not regenerated).
+	pub async fn exec_chat_regenerate(
+		&self,
+		model: &str,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+		policy: &RegenerationPolicy,
+		validator: impl Fn(&ChatResponse) -> core::result::Result<(), String>,
+	) -> Result<ChatResponse> {
+		let base_temperature = ChatOptionsSet::default()
+			.with_chat_options(options)
+			.with_client_options(self.config().chat_options())
+			.temperature();
+
+		let max_attempts = policy.max_attempts.max(1);
+		let mut attempt = 1;
+		loop {
+			let attempt_model = policy.attempt_model(attempt, model);
+			let attempt_options = policy.attempt_options(attempt, options, base_temperature);
+
+			let (model_iden, reason) =
+				match self.exec_chat(attempt_model, chat_req.clone(), attempt_options.as_ref()).await {
+					Ok(chat_res) => {
+						let blocked = chat_res.content_filter.as_ref().filter(|info| info.blocked);
+						let reason = match blocked {
+							Some(info) if policy.regenerate_on_content_filter => Some(format!(
+								"content filter ({})",
+								info.reason.as_deref().unwrap_or("blocked")
+							)),
+							_ => validator(&chat_res).err(),
+						};
+						match reason {
+							Some(reason) => (chat_res.model_iden, reason),
+							None => return Ok(chat_res),
+						}
+					}
+					Err(err) if policy.regenerate_on_content_filter && err.content_filter_info().is_some() => (
+						self.default_model(attempt_model)?,
+						format!("content filter error: {err}"),
+					),
+					Err(err) => return Err(err),
+				};
+
+			if attempt >= max_attempts {
+				return Err(Error::RegenerationExhausted {
+					model_iden,
+					attempts: max_attempts,
+					reason,
+				});
+			}
+			attempt += 1;
+		}
+	}
+}
+
+/// Public Map-Reduce Functions
+///
+/// NOTE: The usage of each sub-request is added to the given `OperationUsage`, so that the parent operation
+///       (e.g., a map-reduce summarization) can report its total usage and cost.
+impl Client {
+	/// Executes the chat requests with at most `concurrency` requests in flight,
+	/// and returns their results in the same order as the requests.
+	///
+	/// NOTE: A failed request does not stop the others (its error is returned at its position).
+	pub async fn map_concurrent(
+		&self,
+		model: &str,
+		chat_reqs: impl IntoIterator<Item = ChatRequest>,
+		options: Option<&ChatOptions>,
+		concurrency: usize,
+		op_usage: &mut OperationUsage,
+	) -> Vec<Result<ChatResponse>> {
+		let results: Vec<Result<ChatResponse>> = futures::stream::iter(chat_reqs)
+			.map(|chat_req| self.exec_chat(model, chat_req, options))
+			.buffered(concurrency.max(1))
+			.collect()
+			.await;
+
+		for result in results.iter() {
+			op_usage.add_result(result);
+		}
+
+		results
+	}
+
+	/// Reduces the partial results (e.g., the outputs of `map_concurrent(..)`) into a single response,
+	/// with one chat request to the `model` with the `instruction` as system,
+	/// and the numbered partials as the user message.
+	pub async fn reduce_with_model(
+		&self,
+		model: &str,
+		partials: impl IntoIterator<Item = impl Into<String>>,
+		instruction: impl Into<String>,
+		options: Option<&ChatOptions>,
+		op_usage: &mut OperationUsage,
+	) -> Result<ChatResponse> {
+		let content = partials
+			.into_iter()
+			.enumerate()
+			.map(|(idx, partial)| format!("## Part {}\n\n{}", idx + 1, partial.into()))
+			.collect::<Vec<_>>()
+			.join("\n\n");
+		let chat_req = ChatRequest::from_user(content).with_system(instruction);
+
+		let result = self.exec_chat(model, chat_req, options).await;
+		op_usage.add_result(&result);
+
+		result
+	}
+}
+
+/// Public Model Info Functions
+///
+/// NOTE: Supported for all adapters, but the returned properties depend on the provider (see `ModelInfo`).
+impl Client {
+	/// Queries the provider model endpoint for the normalized model metadata
+	/// (context window, max output tokens, modalities), rather than relying on hardcoded lists.
+	pub async fn model_info(&self, model: &str) -> Result<ModelInfo> {
+		let target = self.resolve_service_target(model).await?;
+		AdapterDispatcher::model_info(self.web_client(), target).await
+	}
+}
+
+/// Public Ping Functions
+///
+/// NOTE: Supported for all adapters.
+impl Client {
+	/// Performs a cheap health check of the provider of the adapter kind (the provider models listing),
+	/// and returns its reachability and latency (e.g., for startup checks and dashboards).
+	///
+	/// NOTE: The connection and status errors are reported in the `PingReport`.
+	///       Only the configuration errors (e.g., a missing API key) are returned as `Err`.
+	pub async fn ping(&self, adapter_kind: AdapterKind) -> Result<PingReport> {
+		let target = self.config().resolve_adapter_service_target(adapter_kind).await?;
+
+		let start = Instant::now();
+		let res = AdapterDispatcher::ping(self.web_client(), target).await;
+		let latency = start.elapsed();
+
+		let (reachable, status, error) = match res {
+			Ok(status) => (true, Some(status), None),
+			Err(Error::WebAdapterCall { webc_error, .. }) => match webc_error {
+				webc::Error::ResponseFailedStatus { status, .. } => {
+					(true, Some(status.as_u16()), Some(webc_error.to_string()))
+				}
+				webc_error => (false, None, Some(webc_error.to_string())),
+			},
+			Err(err) => return Err(err),
+		};
+
+		Ok(PingReport {
+			adapter_kind,
+			reachable,
+			ok: error.is_none(),
+			status,
+			latency,
+			error,
+		})
+	}
+}
+
+/// Public Files Functions
+///
+/// NOTE: Supported for OpenAI, Anthropic, and Gemini. Other adapters return `Error::AdapterNotSupported`.
+impl Client {
+	/// Uploads a file to the provider of the adapter kind, and returns the provider-scoped `FileRef`,
+	/// which can be referenced in chat messages with `ContentPart::from_file(file_ref)`.
+	///
+	/// NOTE: For Gemini, large media files (video, audio, PDF) are uploaded with the resumable upload protocol,
+	///       and video files must be processed before use (see `FileRef::is_ready()` and `get_file(..)`).
+	pub async fn upload_file(&self, adapter_kind: AdapterKind, file: FileUpload) -> Result<FileRef> {
+		let target = self.config().resolve_adapter_service_target(adapter_kind).await?;
+		AdapterDispatcher::upload_file(self.web_client(), target, file).await
+	}
+
+	/// Lists the files stored by the provider of the adapter kind.
+	///
+	/// NOTE: For now, only the first page of the provider listing is returned.
+	pub async fn list_files(&self, adapter_kind: AdapterKind) -> Result<Vec<FileRef>> {
+		let target = self.config().resolve_adapter_service_target(adapter_kind).await?;
+		AdapterDispatcher::list_files(self.web_client(), target).await
+	}
+
+	/// Retrieves the `FileRef` of a file id from the provider of the adapter kind.
+	pub async fn get_file(&self, adapter_kind: AdapterKind, file_id: &str) -> Result<FileRef> {
+		let target = self.config().resolve_adapter_service_target(adapter_kind).await?;
+		AdapterDispatcher::get_file(self.web_client(), target, file_id).await
+	}
+
+	/// Deletes a file from its provider.
+	pub async fn delete_file(&self, file_ref: &FileRef) -> Result<()> {
+		let target = self.config().resolve_adapter_service_target(file_ref.adapter_kind).await?;
+		AdapterDispatcher::delete_file(self.web_client(), target, &file_ref.id).await
+	}
+}
+
+/// Public Vector Store Functions
+///
+/// NOTE: Supported for OpenAI only. Other adapters return `Error::AdapterNotSupported`.
+impl Client {
+	/// Creates a vector store with the given (already uploaded) file ids,
+	/// which can then be searched in chat requests with `Tool::file_search([vector_store.id])`.
+	///
+	/// NOTE: The files are processed asynchronously. Use `get_vector_store(..)` and `VectorStore::is_ready()`
+	///       to wait for them to be searchable.
+	pub async fn create_vector_store(
+		&self,
+		adapter_kind: AdapterKind,
+		name: &str,
+		file_ids: Vec<String>,
+	) -> Result<VectorStore> {
+		let target = self.config().resolve_adapter_service_target(adapter_kind).await?;
+		AdapterDispatcher::create_vector_store(self.web_client(), target, name, file_ids).await
+	}
+
+	/// Lists the vector stores of the provider of the adapter kind.
+	///
+	/// NOTE: For now, only the first page of the provider listing is returned.
+	pub async fn list_vector_stores(&self, adapter_kind: AdapterKind) -> Result<Vec<VectorStore>> {
+		let target = self.config().resolve_adapter_service_target(adapter_kind).await?;
+		AdapterDispatcher::list_vector_stores(self.web_client(), target).await
+	}
+
+	/// Retrieves a vector store (e.g., to check its file processing status).
+	pub async fn get_vector_store(&self, adapter_kind: AdapterKind, vector_store_id: &str) -> Result<VectorStore> {
+		let target = self.config().resolve_adapter_service_target(adapter_kind).await?;
+		AdapterDispatcher::get_vector_store(self.web_client(), target, vector_store_id).await
+	}
+
+	/// Deletes a vector store (the files themselves are not deleted).
+	pub async fn delete_vector_store(&self, vector_store: &VectorStore) -> Result<()> {
+		let target = self.config().resolve_adapter_service_target(vector_store.adapter_kind).await?;
+		AdapterDispatcher::delete_vector_store(self.web_client(), target, &vector_store.id).await
+	}
+
+	/// Attaches an uploaded file to a vector store.
+	pub async fn attach_vector_store_file(
+		&self,
+		vector_store: &VectorStore,
+		file_ref: &FileRef,
+	) -> Result<VectorStoreFile> {
+		let target = self.config().resolve_adapter_service_target(vector_store.adapter_kind).await?;
+		AdapterDispatcher::attach_vector_store_file(self.web_client(), target, &vector_store.id, &file_ref.id).await
+	}
+}
+
+/// Public Cached Content Functions
+///
+/// NOTE: Supported for Gemini only (`cachedContents`). Other adapters return `Error::AdapterNotSupported`.
+impl Client {
+	/// Creates an explicit context cache with the system, messages, and tools of the chat request for the model,
+	/// with the eventual time to live (provider default of 1 hour when `None`).
+	///
+	/// Use it with `ChatOptions::with_cached_content(cached_content.name)` with follow-up requests to the same model.
+	pub async fn create_cached_content(
+		&self,
+		model: &str,
+		chat_req: ChatRequest,
+		ttl: Option<Duration>,
+	) -> Result<CachedContent> {
+		let model = self.default_model(model)?;
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+		let target = self.config().resolve_adapter_service_target(model.adapter_kind).await?;
+		AdapterDispatcher::create_cached_content(self.web_client(), target, model_name, chat_req, ttl).await
+	}
+
+	/// Retrieves a cached content by name (e.g., to check its expiration time).
+	pub async fn get_cached_content(&self, adapter_kind: AdapterKind, name: &str) -> Result<CachedContent> {
+		let target = self.config().resolve_adapter_service_target(adapter_kind).await?;
+		AdapterDispatcher::get_cached_content(self.web_client(), target, name).await
+	}
+
+	/// Deletes a cached content (before its expiration).
+	pub async fn delete_cached_content(&self, cached_content: &CachedContent) -> Result<()> {
+		let adapter_kind = cached_content.model_iden.adapter_kind;
+		let target = self.config().resolve_adapter_service_target(adapter_kind).await?;
+		AdapterDispatcher::delete_cached_content(self.web_client(), target, &cached_content.name).await
+	}
+}
+
+/// Public Fine-tuning Functions
+///
+/// NOTE: Supported for OpenAI only for now. Other adapters return `Error::AdapterNotSupported`.
+impl Client {
+	/// Creates a fine-tuning job on the provider of the adapter kind.
+	pub async fn create_finetune_job(&self, adapter_kind: AdapterKind, req: FineTuneRequest) -> Result<FineTuneJob> {
+		let target = self.config().resolve_adapter_service_target(adapter_kind).await?;
+		AdapterDispatcher::create_finetune_job(self.web_client(), target, req).await
+	}
+
+	/// Lists the fine-tuning jobs of the provider of the adapter kind.
+	///
+	/// NOTE: For now, only the first page of the provider listing is returned.
+	pub async fn list_finetune_jobs(&self, adapter_kind: AdapterKind) -> Result<Vec<FineTuneJob>> {
+		let target = self.config().resolve_adapter_service_target(adapter_kind).await?;
+		AdapterDispatcher::list_finetune_jobs(self.web_client(), target).await
+	}
+
+	/// Retrieves a fine-tuning job from the provider of the adapter kind (e.g., to poll its status).
+	pub async fn get_finetune_job(&self, adapter_kind: AdapterKind, job_id: &str) -> Result<FineTuneJob> {
+		let target = self.config().resolve_adapter_service_target(adapter_kind).await?;
+		AdapterDispatcher::get_finetune_job(self.web_client(), target, job_id).await
+	}
+
+	/// Cancels a fine-tuning job, and returns its updated state.
+	pub async fn cancel_finetune_job(&self, job: &FineTuneJob) -> Result<FineTuneJob> {
+		let target = self.config().resolve_adapter_service_target(job.adapter_kind).await?;
+		AdapterDispatcher::cancel_finetune_job(self.web_client(), target, &job.id).await
+	}
+
+	/// Returns the fine-tuned model names of the succeeded jobs of the provider of the adapter kind.
+	/// Those can be used directly as model names (e.g., `ft:gpt-4o-mini-2024-07-18:my-org::abc123`).
+	pub async fn list_finetuned_model_names(&self, adapter_kind: AdapterKind) -> Result<Vec<String>> {
+		let jobs = self.list_finetune_jobs(adapter_kind).await?;
+		Ok(jobs.into_iter().filter_map(|job| job.fine_tuned_model).collect())
+	}
+}
+
+/// Public Usage Report Functions
+///
+/// NOTE: Supported for Anthropic only (Admin API, with the `ANTHROPIC_ADMIN_KEY` environment variable
+///       or an admin key from the AuthResolver). Other adapters return `Error::AdapterNotSupported`.
+impl Client {
+	/// Returns the organization token usage report of the provider of the adapter kind (all pages),
+	/// to reconcile with the `Usage` of the chat responses.
+	pub async fn usage_report(&self, adapter_kind: AdapterKind, req: UsageReportRequest) -> Result<UsageReport> {
+		let target = self.config().resolve_adapter_service_target(adapter_kind).await?;
+		AdapterDispatcher::usage_report(self.web_client(), target, req).await
+	}
+
+	/// Returns the organization cost report of the provider of the adapter kind (all pages).
+	pub async fn cost_report(&self, adapter_kind: AdapterKind, req: UsageReportRequest) -> Result<CostReport> {
+		let target = self.config().resolve_adapter_service_target(adapter_kind).await?;
+		AdapterDispatcher::cost_report(self.web_client(), target, req).await
+	}
+}
+
+/// Public Video Generation Functions
+///
+/// NOTE: Supported for OpenAI (e.g., `sora-2`) and Gemini (e.g., `veo-3.0-generate-001`).
+///       Other adapters return `Error::AdapterNotSupported`.
+impl Client {
+	/// Creates a video generation job for the model, and returns it right away (see `Client::get_video_job(..)` to poll it).
+	pub async fn create_video_job(&self, model: &str, req: VideoRequest) -> Result<VideoJob> {
+		let model = self.default_model(model)?;
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+		let target = self.config().resolve_adapter_service_target(model.adapter_kind).await?;
+		AdapterDispatcher::create_video_job(self.web_client(), target, model_name, req).await
+	}
+
+	/// Retrieves the updated state of a video generation job (e.g., to poll it until `job.is_done()`).
+	pub async fn get_video_job(&self, job: &VideoJob) -> Result<VideoJob> {
+		let target = self
+			.config()
+			.resolve_adapter_service_target(job.model_iden.adapter_kind)
+			.await?;
+		AdapterDispatcher::get_video_job(self.web_client(), target, job).await
+	}
+
+	/// Downloads the bytes of the generated video (mp4) of a completed job.
+	pub async fn download_video(&self, job: &VideoJob) -> Result<Vec<u8>> {
+		let target = self
+			.config()
+			.resolve_adapter_service_target(job.model_iden.adapter_kind)
+			.await?;
+		AdapterDispatcher::download_video(self.web_client(), target, job).await
+	}
+}
+
+/// Public Ollama Management Functions
+///
+/// NOTE: These use the native Ollama API of the resolved Ollama endpoint
+///       (so a `ServiceTargetResolver` can point them to a remote Ollama server).
+impl Client {
+	/// Pulls a model from the Ollama library (e.g., `llama3.2`, `gemma3:4b`),
+	/// and returns the stream of the pull progress events.
+	pub async fn ollama_pull(&self, model_name: &str) -> Result<OllamaPullStream> {
+		let target = self.config().resolve_adapter_service_target(AdapterKind::Ollama).await?;
+		AdapterDispatcher::ollama_pull(self.web_client(), target, model_name)
+	}
+
+	/// Returns the information of a local Ollama model (parameters, template, details, capabilities).
+	pub async fn ollama_show(&self, model_name: &str) -> Result<OllamaModelInfo> {
+		let target = self.config().resolve_adapter_service_target(AdapterKind::Ollama).await?;
+		AdapterDispatcher::ollama_show(self.web_client(), target, model_name).await
+	}
+
+	/// Deletes a local Ollama model.
+	pub async fn ollama_delete(&self, model_name: &str) -> Result<()> {
+		let target = self.config().resolve_adapter_service_target(AdapterKind::Ollama).await?;
+		AdapterDispatcher::ollama_delete(self.web_client(), target, model_name).await
+	}
+
+	/// Lists the models currently loaded in memory by the Ollama server.
+	pub async fn ollama_ps(&self) -> Result<Vec<OllamaRunningModel>> {
+		let target = self.config().resolve_adapter_service_target(AdapterKind::Ollama).await?;
+		AdapterDispatcher::ollama_ps(self.web_client(), target).await
+	}
+}
+
+/// Public Realtime Functions
+///
+/// NOTE: Supported for OpenAI (Realtime API) and Gemini (Live API). Other adapters return `Error::AdapterNotSupported`.
+impl Client {
+	/// Opens a realtime (WebSocket) session with the model (e.g., `gpt-realtime`, `gemini-live-2.5-flash-preview`),
+	/// and sends the eventual session configuration.
+	pub async fn realtime_connect(&self, model: &str, config: Option<&RealtimeConfig>) -> Result<RealtimeSession> {
+		let model = self.default_model(model)?;
+		let target = self.config().resolve_service_target(model).await?;
+		let model = target.model.clone();
+
+		let WebRequestData {
+			mut headers,
+			payload,
+			url,
+		} = AdapterDispatcher::to_realtime_request_data(target, config)?;
+		self.apply_app_info_headers(&model, &mut headers);
+
+		let map_webc_error = |webc_error| Error::WebModelCall {
+			model_iden: model.clone(),
+			webc_error,
+		};
+
+		let web_socket = WebSocket::connect(&url, &headers).await.map_err(map_webc_error)?;
+		let mut session = RealtimeSession::new(model.clone(), web_socket);
+
+		// -- Send the eventual first message (e.g., the session configuration)
+		if !payload.is_null() {
+			session.send_raw(&payload).await?;
+		}
+
+		Ok(session)
+	}
+}
@@ -1,9 +1,11 @@
-use crate::adapter::AdapterDispatcher;
+use crate::adapter::{AdapterDispatcher, AdapterKind};
 use crate::chat::ChatOptions;
 use crate::client::ServiceTarget;
 use crate::embed::EmbedOptions;
-use crate::resolver::{AuthResolver, ModelMapper, ServiceTargetResolver};
-use crate::{Error, ModelIden, Result, WebConfig};
+use crate::resolver::{AuthResolver, ModelMapper, RequestSigner, ServiceTargetResolver};
+#[cfg(feature = "sqlite")]
+use crate::trace::SqliteLog;
+use crate::{AppInfo, Error, Gateway, ModelIden, QuotaManager, Result, WebConfig};
 
 /// The Client configuration used in the configuration builder stage.
 #[derive(Debug, Default, Clone)]
@@ -14,6 +16,12 @@ pub struct ClientConfig {
 	pub(super) web_config: Option<WebConfig>,
 	pub(super) chat_options: Option<ChatOptions>,
 	pub(super) embed_options: Option<EmbedOptions>,
+	pub(super) gateway: Option<Gateway>,
+	pub(super) app_info: Option<AppInfo>,
+	pub(super) request_signer: Option<RequestSigner>,
+	pub(super) quota_manager: Option<QuotaManager>,
+	#[cfg(feature = "sqlite")]
+	pub(super) sqlite_log: Option<SqliteLog>,
 }
 
 /// Chainable setters related to the ClientConfig.
@@ -55,6 +63,42 @@ impl ClientConfig {
 		self
 	}
 
+	/// Set the Gateway for the ClientConfig (e.g., `Gateway::litellm("http://localhost:4000/")`).
+	/// Note: This will be applied before the `service_target_resolver`, and if registered
+	///       the `service_target_resolver` will receive the gateway service target.
+	pub fn with_gateway(mut self, gateway: Gateway) -> Self {
+		self.gateway = Some(gateway);
+		self
+	}
+
+	/// Set the AppInfo (application name/version/url) for the ClientConfig,
+	/// used for the User-Agent and the provider attribution headers.
+	pub fn with_app_info(mut self, app_info: AppInfo) -> Self {
+		self.app_info = Some(app_info);
+		self
+	}
+
+	/// Set the RequestSigner for the ClientConfig, called with the final body bytes
+	/// to add signature headers (e.g., HMAC) right before the request is sent.
+	pub fn with_request_signer(mut self, request_signer: RequestSigner) -> Self {
+		self.request_signer = Some(request_signer);
+		self
+	}
+
+	/// Set the QuotaManager for the ClientConfig, enforcing the per-tenant quotas
+	/// of the requests with a tenant id (e.g., `ChatOptions::with_tenant_id(..)`).
+	pub fn with_quota_manager(mut self, quota_manager: QuotaManager) -> Self {
+		self.quota_manager = Some(quota_manager);
+		self
+	}
+
+	/// Set the SqliteLog for the ClientConfig, logging every `exec_chat(..)` (response or error).
+	#[cfg(feature = "sqlite")]
+	pub fn with_sqlite_log(mut self, sqlite_log: SqliteLog) -> Self {
+		self.sqlite_log = Some(sqlite_log);
+		self
+	}
+
 	/// Set the reqwest client configuration options for the ClientConfig.
 	pub fn with_web_config(mut self, web_config: WebConfig) -> Self {
 		self.web_config = Some(web_config);
@@ -92,6 +136,32 @@ impl ClientConfig {
 	pub fn embed_options(&self) -> Option<&EmbedOptions> {
 		self.embed_options.as_ref()
 	}
+
+	/// Get a reference to the Gateway, if it exists.
+	pub fn gateway(&self) -> Option<&Gateway> {
+		self.gateway.as_ref()
+	}
+
+	/// Get a reference to the AppInfo, if it exists.
+	pub fn app_info(&self) -> Option<&AppInfo> {
+		self.app_info.as_ref()
+	}
+
+	/// Get a reference to the RequestSigner, if it exists.
+	pub fn request_signer(&self) -> Option<&RequestSigner> {
+		self.request_signer.as_ref()
+	}
+
+	/// Get a reference to the QuotaManager, if it exists.
+	pub fn quota_manager(&self) -> Option<&QuotaManager> {
+		self.quota_manager.as_ref()
+	}
+
+	/// Get a reference to the SqliteLog, if it exists.
+	#[cfg(feature = "sqlite")]
+	pub fn sqlite_log(&self) -> Option<&SqliteLog> {
+		self.sqlite_log.as_ref()
+	}
 }
 
 /// Resolvers
@@ -107,6 +177,24 @@ impl ClientConfig {
 			resolver_error,
 		})?;
 
+		self.resolve_service_target_for_model(model, self.gateway()).await
+	}
+
+	/// Resolve the service target for an adapter kind, without a model (e.g., for the Files API).
+	///
+	/// NOTE: The model mapper and the gateway are skipped, and the `AuthResolver` / `ServiceTargetResolver`
+	///       receive a ModelIden with an empty model name.
+	pub async fn resolve_adapter_service_target(&self, adapter_kind: AdapterKind) -> Result<ServiceTarget> {
+		self.resolve_service_target_for_model(ModelIden::new(adapter_kind, ""), None)
+			.await
+	}
+
+	/// Resolve the auth, endpoint, eventual gateway, and service target for an already mapped model.
+	async fn resolve_service_target_for_model(
+		&self,
+		model: ModelIden,
+		gateway: Option<&Gateway>,
+	) -> Result<ServiceTarget> {
 		// -- Get the auth
 		let auth = if let Some(auth) = self.auth_resolver() {
 			// resolve async which may be async
@@ -132,6 +220,12 @@ impl ClientConfig {
 			auth,
 			endpoint,
 		};
+
+		// -- Apply the eventual gateway
+		let service_target = match gateway {
+			Some(gateway) => gateway.apply_to_service_target(service_target),
+			None => service_target,
+		};
 		let service_target = match self.service_target_resolver() {
 			Some(service_target_resolver) => {
 				service_target_resolver
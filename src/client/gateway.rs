@@ -0,0 +1,179 @@
+use crate::adapter::AdapterKind;
+use crate::resolver::{AuthData, Endpoint};
+use crate::{Headers, ModelIden, ServiceTarget};
+
+/// The supported AI gateway kinds (see `Gateway` for the presets).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GatewayKind {
+	/// Helicone AI Gateway (https://docs.helicone.ai/gateway/overview)
+	Helicone,
+	/// LiteLLM Proxy (https://docs.litellm.ai/docs/simple_proxy)
+	LiteLlm,
+	/// Portkey AI Gateway (https://portkey.ai/docs/product/ai-gateway)
+	Portkey,
+}
+
+/// A gateway preset to route all the requests through an OpenAI-compatible AI gateway
+/// (e.g., Helicone, LiteLLM, Portkey), with its required headers, key, and model naming.
+///
+/// When set on the client config (`ClientBuilder::with_gateway(..)`):
+/// - The model is rewritten to an `AdapterKind::OpenAI` model, with the eventual gateway model prefix.
+/// - The endpoint and auth are set to the gateway ones (before the `ServiceTargetResolver`, which can still override them).
+/// - The gateway headers are added to the chat, chat stream, and embed requests.
+///
+/// NOTE: The Files API (`Client::upload_file(..)`, ...) does not go through the gateway.
+#[derive(Debug, Clone)]
+pub struct Gateway {
+	kind: GatewayKind,
+	endpoint: Endpoint,
+	auth: AuthData,
+	headers: Headers,
+	model_prefix: Option<String>,
+	provider_prefix: bool,
+}
+
+/// Constructors
+impl Gateway {
+	/// Helicone AI Gateway, with the `HELICONE_API_KEY` environment variable as key.
+	///
+	/// Model names are passed as-is (e.g., `gpt-4o-mini`, or `claude-3-5-haiku-latest/anthropic` to force the provider).
+	pub fn helicone() -> Self {
+		Self::new(
+			GatewayKind::Helicone,
+			Endpoint::from_static("https://ai-gateway.helicone.ai/"),
+			AuthData::from_env("HELICONE_API_KEY"),
+		)
+	}
+
+	/// LiteLLM Proxy at the given base url (e.g., `http://localhost:4000/`),
+	/// with the `LITELLM_API_KEY` environment variable as key (master or virtual key).
+	///
+	/// By default, model names are prefixed with the genai provider (e.g., `anthropic/claude-3-5-haiku-latest`),
+	/// which is the LiteLLM convention. Use `with_provider_prefix(false)` when the proxy uses model aliases.
+	pub fn litellm(base_url: impl Into<String>) -> Self {
+		Self::new(
+			GatewayKind::LiteLlm,
+			Endpoint::from_owned(normalize_base_url(base_url.into())),
+			AuthData::from_env("LITELLM_API_KEY"),
+		)
+		.with_provider_prefix(true)
+	}
+
+	/// Portkey AI Gateway, with the `PORTKEY_API_KEY` environment variable as key
+	/// (sent as the `x-portkey-api-key` header).
+	///
+	/// Use `with_virtual_key(..)` or `with_model_prefix("@provider-slug/")` to select the provider.
+	pub fn portkey() -> Self {
+		Self::new(
+			GatewayKind::Portkey,
+			Endpoint::from_static("https://api.portkey.ai/v1/"),
+			AuthData::from_env("PORTKEY_API_KEY"),
+		)
+	}
+
+	fn new(kind: GatewayKind, endpoint: Endpoint, auth: AuthData) -> Self {
+		Self {
+			kind,
+			endpoint,
+			auth,
+			headers: Headers::default(),
+			model_prefix: None,
+			provider_prefix: false,
+		}
+	}
+}
+
+/// Chainable Setters
+impl Gateway {
+	/// Override the gateway base url (e.g., for self-hosted Helicone or Portkey gateways).
+	pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+		self.endpoint = Endpoint::from_owned(normalize_base_url(base_url.into()));
+		self
+	}
+
+	/// Override the gateway auth (default from the gateway environment variable).
+	pub fn with_auth(mut self, auth: AuthData) -> Self {
+		self.auth = auth;
+		self
+	}
+
+	/// Add a header to all of the gateway requests (e.g., `Helicone-Property-App`, `x-portkey-trace-id`).
+	pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		self.headers.merge((name.into(), value.into()));
+		self
+	}
+
+	/// Set the Portkey virtual key (`x-portkey-virtual-key` header).
+	pub fn with_virtual_key(self, virtual_key: impl Into<String>) -> Self {
+		self.with_header("x-portkey-virtual-key", virtual_key)
+	}
+
+	/// Set a static prefix for all of the model names (e.g., `@openai-prod/` for the Portkey model catalog).
+	pub fn with_model_prefix(mut self, model_prefix: impl Into<String>) -> Self {
+		self.model_prefix = Some(model_prefix.into());
+		self
+	}
+
+	/// Prefix the model names with the genai provider name (e.g., `anthropic/claude-3-5-haiku-latest`).
+	pub fn with_provider_prefix(mut self, provider_prefix: bool) -> Self {
+		self.provider_prefix = provider_prefix;
+		self
+	}
+}
+
+/// Getters
+impl Gateway {
+	pub fn kind(&self) -> GatewayKind {
+		self.kind
+	}
+
+	pub fn endpoint(&self) -> &Endpoint {
+		&self.endpoint
+	}
+}
+
+/// Crate Functions
+impl Gateway {
+	/// Rewrite the resolved service target to go through the gateway.
+	pub(crate) fn apply_to_service_target(&self, target: ServiceTarget) -> ServiceTarget {
+		let model = &target.model;
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+
+		let mut gateway_model_name = String::new();
+		if let Some(model_prefix) = self.model_prefix.as_deref() {
+			gateway_model_name.push_str(model_prefix);
+		}
+		if self.provider_prefix {
+			gateway_model_name.push_str(model.adapter_kind.as_lower_str());
+			gateway_model_name.push('/');
+		}
+		gateway_model_name.push_str(model_name);
+
+		ServiceTarget {
+			endpoint: self.endpoint.clone(),
+			auth: self.auth.clone(),
+			model: ModelIden::new(AdapterKind::OpenAI, gateway_model_name),
+		}
+	}
+
+	/// The headers to add to each gateway request.
+	pub(crate) fn headers(&self) -> crate::resolver::Result<Headers> {
+		let mut headers = self.headers.clone();
+		if self.kind == GatewayKind::Portkey {
+			let api_key = self.auth.single_key_value()?;
+			headers.merge(("x-portkey-api-key", api_key));
+		}
+		Ok(headers)
+	}
+}
+
+// region:    --- Support
+
+fn normalize_base_url(mut base_url: String) -> String {
+	if !base_url.ends_with('/') {
+		base_url.push('/');
+	}
+	base_url
+}
+
+// endregion: --- Support
@@ -1,17 +1,29 @@
 // region:    --- Modules
 
+mod app_info;
 mod builder;
 mod client_impl;
 mod client_types;
 mod config;
+mod gateway;
 mod headers;
+mod model_router;
+mod ping_report;
+mod quota_manager;
+mod quota_store;
 mod service_target;
 mod web_config;
 
+pub use app_info::*;
 pub use builder::*;
 pub use client_types::*;
 pub use config::*;
+pub use gateway::*;
 pub use headers::*;
+pub use model_router::*;
+pub use ping_report::*;
+pub use quota_manager::*;
+pub use quota_store::*;
 pub use service_target::*;
 pub use web_config::*;
 
@@ -0,0 +1,304 @@
+use crate::chat::Usage;
+use crate::{Error, ModelPrice, Result};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// The window of the candidate rpm limits.
+const RPM_WINDOW: Duration = Duration::from_secs(60);
+
+/// The cooldown of a rate limited candidate, when the provider does not return a `retry-after` header.
+const DEFAULT_RATE_LIMIT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// The weight of the last value in the latency and usage moving averages.
+const EWMA_ALPHA: f64 = 0.3;
+
+/// The usage assumed for the cost estimate before any response was recorded (3:1 input/output ratio).
+const DEFAULT_PROMPT_TOKENS: f64 = 1500.;
+const DEFAULT_COMPLETION_TOKENS: f64 = 500.;
+
+// region:    --- RouteCandidate
+
+/// A candidate model of the `ModelRouter`.
+#[derive(Debug, Clone)]
+pub struct RouteCandidate {
+	/// The model name (as given to `Client::exec_chat(..)`, e.g., `gpt-4o-mini` or `groq::llama-3.1-8b-instant`).
+	pub model: String,
+
+	/// The capability tier of the model (higher is more capable), for the `min_tier` constraint of the routes.
+	pub tier: u8,
+
+	/// The eventual price of the model. Candidates without price are considered free by the cost strategies.
+	pub price: Option<ModelPrice>,
+
+	/// The eventual rpm limit of the model (e.g., the provider account limit), for the rate-limit headroom.
+	pub rpm: Option<u32>,
+}
+
+/// Constructors & Chainable Setters
+impl RouteCandidate {
+	pub fn new(model: impl Into<String>, tier: u8) -> Self {
+		Self {
+			model: model.into(),
+			tier,
+			price: None,
+			rpm: None,
+		}
+	}
+
+	pub fn with_price(mut self, price: ModelPrice) -> Self {
+		self.price = Some(price);
+		self
+	}
+
+	pub fn with_rpm(mut self, rpm: u32) -> Self {
+		self.rpm = Some(rpm);
+		self
+	}
+}
+
+// endregion: --- RouteCandidate
+
+// region:    --- RouteStrategy
+
+/// How the `ModelRouter` orders the eligible candidates.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RouteStrategy {
+	/// The cheapest first (by the estimated cost per request), then the fastest.
+	#[default]
+	LeastCost,
+
+	/// The fastest first (by the latency moving average), then the cheapest.
+	/// The candidates without latency stats yet come first (so that they get measured).
+	LowestLatency,
+
+	/// The lowest weighted sum of the relative cost and the relative latency
+	/// (each divided by the max of the eligible candidates), with `cost_weight` in `0..=1`.
+	Balanced { cost_weight: f64 },
+}
+
+// endregion: --- RouteStrategy
+
+// region:    --- ModelRouter
+
+/// Picks the model of each request among the candidates (see `Client::exec_chat_routed(..)`),
+/// from the live latency stats, the rate-limit headroom, and the price per token, with a minimum capability tier.
+///
+/// - The latency is a moving average of the successful responses of each candidate.
+/// - A candidate without headroom (its `rpm` reached, or rate limited by the provider until its `retry-after`)
+///   is tried last.
+/// - The cost of a request is estimated with the candidate `ModelPrice` and the average usage of the responses.
+///
+/// The ModelRouter is cheap to clone and all clones share the same stats.
+#[derive(Debug, Clone, Default)]
+pub struct ModelRouter {
+	inner: Arc<Mutex<ModelRouterInner>>,
+}
+
+#[derive(Debug, Default)]
+struct ModelRouterInner {
+	candidates: Vec<RouteCandidate>,
+	strategy: RouteStrategy,
+	stats: HashMap<String, RouteStats>,
+	/// The average (prompt, completion) tokens of the responses, for the cost estimate.
+	avg_usage: Option<(f64, f64)>,
+}
+
+/// The live stats of a candidate.
+#[derive(Debug, Clone, Default)]
+pub struct RouteStats {
+	/// The moving average of the latency of the successful responses.
+	pub avg_latency: Option<Duration>,
+
+	/// The number of successful responses.
+	pub success_count: u64,
+
+	/// The number of failed requests (including the rate limited ones).
+	pub error_count: u64,
+
+	/// The eventual time until which the candidate is rate limited by the provider.
+	pub rate_limited_until: Option<Instant>,
+
+	requests: VecDeque<Instant>,
+}
+
+/// Constructors & Chainable Setters
+impl ModelRouter {
+	pub fn new(strategy: RouteStrategy) -> Self {
+		let router = Self::default();
+		router.lock().strategy = strategy;
+		router
+	}
+
+	pub fn with_candidate(self, candidate: RouteCandidate) -> Self {
+		self.lock().candidates.push(candidate);
+		self
+	}
+}
+
+/// Public Functions
+impl ModelRouter {
+	/// Returns the models of the candidates of at least `min_tier`, in the order they should be tried
+	/// (the candidates without headroom last, by the end of their rate limit).
+	///
+	/// Fails with `Error::NoRouteCandidate` when no candidate has the `min_tier`.
+	pub fn route(&self, min_tier: u8) -> Result<Vec<String>> {
+		let mut inner = self.lock();
+		let now = Instant::now();
+		let (prompt_tokens, completion_tokens) =
+			inner.avg_usage.unwrap_or((DEFAULT_PROMPT_TOKENS, DEFAULT_COMPLETION_TOKENS));
+		let estimate_usage = Usage {
+			prompt_tokens: Some(prompt_tokens as i32),
+			completion_tokens: Some(completion_tokens as i32),
+			..Default::default()
+		};
+
+		let ModelRouterInner { candidates, stats, .. } = &mut *inner;
+		let mut routes: Vec<Route> = candidates
+			.iter()
+			.filter(|candidate| candidate.tier >= min_tier)
+			.map(|candidate| {
+				let stats = stats.entry(candidate.model.clone()).or_default();
+				stats.evict(now);
+				Route {
+					model: candidate.model.clone(),
+					cost: candidate.price.as_ref().map(|price| price.cost(&estimate_usage)).unwrap_or(0.),
+					latency: stats.avg_latency.map(|latency| latency.as_secs_f64()),
+					blocked_until: stats.blocked_until(candidate.rpm, now),
+				}
+			})
+			.collect();
+
+		if routes.is_empty() {
+			return Err(Error::NoRouteCandidate { min_tier });
+		}
+
+		let max_cost = routes.iter().map(|route| route.cost).fold(0., f64::max);
+		let max_latency = routes.iter().filter_map(|route| route.latency).fold(0., f64::max);
+		let strategy = inner.strategy;
+		routes.sort_by(|a, b| {
+			a.blocked_until.cmp(&b.blocked_until).then_with(|| match strategy {
+				RouteStrategy::LeastCost => a.cost.total_cmp(&b.cost).then(cmp_latency(a, b)),
+				RouteStrategy::LowestLatency => cmp_latency(a, b).then(a.cost.total_cmp(&b.cost)),
+				RouteStrategy::Balanced { cost_weight } => {
+					let score = |route: &Route| {
+						let cost = if max_cost > 0. { route.cost / max_cost } else { 0. };
+						let latency = match route.latency {
+							Some(latency) if max_latency > 0. => latency / max_latency,
+							_ => 0.,
+						};
+						cost_weight * cost + (1. - cost_weight) * latency
+					};
+					score(a).total_cmp(&score(b))
+				}
+			})
+		});
+
+		Ok(routes.into_iter().map(|route| route.model).collect())
+	}
+
+	/// Returns the live stats of a candidate model.
+	pub fn stats(&self, model: &str) -> Option<RouteStats> {
+		self.lock().stats.get(model).cloned()
+	}
+
+	/// Record a successful response of a candidate, with its latency and usage.
+	pub fn record_success(&self, model: &str, latency: Duration, usage: &Usage) {
+		let mut inner = self.lock();
+
+		let stats = inner.stats.entry(model.to_string()).or_default();
+		stats.success_count += 1;
+		stats.avg_latency = Some(match stats.avg_latency {
+			Some(avg) => avg.mul_f64(1. - EWMA_ALPHA) + latency.mul_f64(EWMA_ALPHA),
+			None => latency,
+		});
+
+		if let (Some(prompt_tokens), Some(completion_tokens)) = (usage.prompt_tokens, usage.completion_tokens) {
+			let (prompt_tokens, completion_tokens) = (prompt_tokens as f64, completion_tokens as f64);
+			inner.avg_usage = Some(match inner.avg_usage {
+				Some((avg_prompt, avg_completion)) => (
+					avg_prompt * (1. - EWMA_ALPHA) + prompt_tokens * EWMA_ALPHA,
+					avg_completion * (1. - EWMA_ALPHA) + completion_tokens * EWMA_ALPHA,
+				),
+				None => (prompt_tokens, completion_tokens),
+			});
+		}
+	}
+
+	/// Record a failed request of a candidate.
+	/// When the provider rate limited it (status `429`), the candidate is skipped until its `retry-after`.
+	pub fn record_error(&self, model: &str, error: &Error) {
+		let mut inner = self.lock();
+		let stats = inner.stats.entry(model.to_string()).or_default();
+		stats.error_count += 1;
+		if error.is_rate_limited() {
+			let cooldown = error.retry_after().unwrap_or(DEFAULT_RATE_LIMIT_COOLDOWN);
+			stats.rate_limited_until = Some(Instant::now() + cooldown);
+		}
+	}
+}
+
+/// Crate Functions
+impl ModelRouter {
+	/// Count a request sent to a candidate (for its rpm headroom).
+	pub(crate) fn record_request(&self, model: &str) {
+		self.lock()
+			.stats
+			.entry(model.to_string())
+			.or_default()
+			.requests
+			.push_back(Instant::now());
+	}
+}
+
+// endregion: --- ModelRouter
+
+// region:    --- Support
+
+/// A candidate being ordered by `ModelRouter::route(..)`.
+struct Route {
+	model: String,
+	cost: f64,
+	latency: Option<f64>,
+	/// None when the candidate has headroom.
+	blocked_until: Option<Instant>,
+}
+
+/// The candidates without latency stats first (to measure them), then the fastest.
+fn cmp_latency(a: &Route, b: &Route) -> std::cmp::Ordering {
+	match (a.latency, b.latency) {
+		(Some(a), Some(b)) => a.total_cmp(&b),
+		(a, b) => a.is_some().cmp(&b.is_some()),
+	}
+}
+
+impl ModelRouter {
+	fn lock(&self) -> MutexGuard<'_, ModelRouterInner> {
+		// NOTE: The stats stay consistent even if a holder panicked, so a poisoned lock is recovered.
+		self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+}
+
+impl RouteStats {
+	/// Remove the requests older than the rpm window.
+	fn evict(&mut self, now: Instant) {
+		while self.requests.front().is_some_and(|at| now.duration_since(*at) >= RPM_WINDOW) {
+			self.requests.pop_front();
+		}
+		if self.rate_limited_until.is_some_and(|until| until <= now) {
+			self.rate_limited_until = None;
+		}
+	}
+
+	/// Returns the time until which the candidate has no headroom (None when it has headroom).
+	fn blocked_until(&self, rpm: Option<u32>, now: Instant) -> Option<Instant> {
+		let rpm_until = rpm
+			.filter(|rpm| self.requests.len() as u32 >= *rpm)
+			.and_then(|_| self.requests.front())
+			.map(|oldest| *oldest + RPM_WINDOW)
+			.filter(|until| *until > now);
+		rpm_until.max(self.rate_limited_until)
+	}
+}
+
+// endregion: --- Support
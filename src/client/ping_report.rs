@@ -0,0 +1,23 @@
+use crate::adapter::AdapterKind;
+use std::time::Duration;
+
+/// The result of a `Client::ping(..)` health check of a provider.
+#[derive(Debug, Clone)]
+pub struct PingReport {
+	pub adapter_kind: AdapterKind,
+
+	/// True when the provider responded (even with an error status, e.g., `401` for an invalid API key).
+	pub reachable: bool,
+
+	/// True when the provider responded with a success status (reachable and authorized).
+	pub ok: bool,
+
+	/// The eventual HTTP status of the response (`None` when not reachable).
+	pub status: Option<u16>,
+
+	/// The round-trip time of the health check request.
+	pub latency: Duration,
+
+	/// The eventual error message (connection error or error status).
+	pub error: Option<String>,
+}
@@ -0,0 +1,417 @@
+use crate::chat::Usage;
+use crate::{Error, ModelIden, QuotaIncr, QuotaStore, Result};
+use derive_more::Display;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// The window of the per-minute quotas (RPM/TPM).
+const QUOTA_WINDOW: Duration = Duration::from_secs(60);
+
+/// The key prefix of the shared counters in the `QuotaStore`.
+const STORE_KEY_PREFIX: &str = "genai:quota";
+
+// region:    --- TenantQuota
+
+/// The quotas of a tenant (e.g., of a SaaS plan). `None` means unlimited.
+#[derive(Debug, Clone, Default)]
+pub struct TenantQuota {
+	/// Max requests per minute.
+	pub rpm: Option<u32>,
+
+	/// Max tokens (prompt + completion) per minute.
+	pub tpm: Option<u32>,
+
+	/// Max cost (in the currency of the `ModelPrice`s) until `QuotaManager::reset_cost(..)`
+	/// (e.g., called at each billing period).
+	pub max_cost: Option<f64>,
+}
+
+/// Chainable Setters
+impl TenantQuota {
+	pub fn with_rpm(mut self, rpm: u32) -> Self {
+		self.rpm = Some(rpm);
+		self
+	}
+
+	pub fn with_tpm(mut self, tpm: u32) -> Self {
+		self.tpm = Some(tpm);
+		self
+	}
+
+	pub fn with_max_cost(mut self, max_cost: f64) -> Self {
+		self.max_cost = Some(max_cost);
+		self
+	}
+}
+
+// endregion: --- TenantQuota
+
+// region:    --- ModelPrice
+
+/// The price of a model, per million tokens, used to compute the tenant costs.
+#[derive(Debug, Clone, Copy)]
+pub struct ModelPrice {
+	pub input_per_mtok: f64,
+	pub output_per_mtok: f64,
+}
+
+impl ModelPrice {
+	pub fn new(input_per_mtok: f64, output_per_mtok: f64) -> Self {
+		Self {
+			input_per_mtok,
+			output_per_mtok,
+		}
+	}
+
+	/// The cost of a usage (prompt tokens at the input price, completion tokens at the output price).
+	pub fn cost(&self, usage: &Usage) -> f64 {
+		let input_tokens = usage.prompt_tokens.unwrap_or(0) as f64;
+		let output_tokens = usage.completion_tokens.unwrap_or(0) as f64;
+		(input_tokens * self.input_per_mtok + output_tokens * self.output_per_mtok) / 1_000_000.
+	}
+}
+
+// endregion: --- ModelPrice
+
+// region:    --- QuotaKind & QuotaRemaining
+
+/// The kind of quota which was exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum QuotaKind {
+	#[display("rpm")]
+	Rpm,
+	#[display("tpm")]
+	Tpm,
+	#[display("cost")]
+	Cost,
+}
+
+/// The remaining quota of a tenant. `None` means unlimited.
+#[derive(Debug, Clone, Default)]
+pub struct QuotaRemaining {
+	/// Requests remaining in the current minute window.
+	pub requests: Option<u32>,
+
+	/// Tokens remaining in the current minute window.
+	pub tokens: Option<u32>,
+
+	/// Cost remaining until the next `QuotaManager::reset_cost(..)`.
+	pub cost: Option<f64>,
+
+	/// The cost spent since the last `QuotaManager::reset_cost(..)`.
+	pub spent_cost: f64,
+}
+
+// endregion: --- QuotaKind & QuotaRemaining
+
+// region:    --- QuotaManager
+
+/// Per-tenant RPM/TPM/cost quotas, enforced by the client on all the chat, chat stream, and embed calls
+/// carrying a tenant id (`ChatOptions::with_tenant_id(..)`, `EmbedOptions::with_tenant_id(..)`).
+///
+/// The QuotaManager is cheap to clone and all clones share the same state,
+/// so a clone can be kept to query the remaining quotas (e.g., `quota_manager.remaining("tenant-a")`)
+/// or change the tenant quotas at runtime.
+///
+/// With a `QuotaStore` (an app-provided shared counter store, see `QuotaManager::with_store(..)`),
+/// the rpm/tpm counters are shared by all the service instances using the same store.
+///
+/// NOTE: For chat streams, only the request is counted when it is sent. The stream usage
+///       (`StreamEnd.captured_usage`) must be recorded with `QuotaManager::record_usage(..)`.
+#[derive(Debug, Clone, Default)]
+pub struct QuotaManager {
+	inner: Arc<Mutex<QuotaManagerInner>>,
+}
+
+#[derive(Debug, Default)]
+struct QuotaManagerInner {
+	default_quota: Option<TenantQuota>,
+	quotas: HashMap<String, TenantQuota>,
+	prices: HashMap<String, ModelPrice>,
+	tenants: HashMap<String, TenantUsage>,
+	store: Option<QuotaStore>,
+}
+
+#[derive(Debug, Default)]
+struct TenantUsage {
+	requests: VecDeque<Instant>,
+	tokens: VecDeque<(Instant, u32)>,
+	spent_cost: f64,
+}
+
+/// Constructors & Chainable Setters
+impl QuotaManager {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Set the quota of the tenants without a specific quota (otherwise, those are unlimited).
+	pub fn with_default_quota(self, quota: TenantQuota) -> Self {
+		self.lock().default_quota = Some(quota);
+		self
+	}
+
+	pub fn with_tenant_quota(self, tenant_id: impl Into<String>, quota: TenantQuota) -> Self {
+		self.set_tenant_quota(tenant_id, quota);
+		self
+	}
+
+	/// Set the shared store of the rpm/tpm counters (see `QuotaStore`).
+	pub fn with_store(self, store: QuotaStore) -> Self {
+		self.lock().store = Some(store);
+		self
+	}
+
+	/// Set the price of a model name (without namespace, e.g., `gpt-4o-mini`), used for the cost quotas.
+	/// Models without a price do not count toward the cost quotas.
+	pub fn with_model_price(self, model_name: impl Into<String>, price: ModelPrice) -> Self {
+		self.lock().prices.insert(model_name.into(), price);
+		self
+	}
+}
+
+/// Public Functions
+impl QuotaManager {
+	/// Set (or change) the quota of a tenant (e.g., on a plan change).
+	pub fn set_tenant_quota(&self, tenant_id: impl Into<String>, quota: TenantQuota) {
+		self.lock().quotas.insert(tenant_id.into(), quota);
+	}
+
+	/// Returns the remaining quota of a tenant.
+	///
+	/// NOTE: With a `QuotaStore`, the rpm/tpm counters are in the store, use `remaining_shared(..)`.
+	pub fn remaining(&self, tenant_id: &str) -> QuotaRemaining {
+		let mut inner = self.lock();
+		let quota = inner.quota(tenant_id);
+		let now = Instant::now();
+		let usage = inner.tenants.entry(tenant_id.to_string()).or_default();
+		usage.evict(now);
+
+		QuotaRemaining {
+			requests: quota.rpm.map(|rpm| rpm.saturating_sub(usage.requests.len() as u32)),
+			tokens: quota.tpm.map(|tpm| tpm.saturating_sub(usage.window_tokens())),
+			cost: quota.max_cost.map(|max_cost| (max_cost - usage.spent_cost).max(0.)),
+			spent_cost: usage.spent_cost,
+		}
+	}
+
+	/// Returns the remaining quota of a tenant, with the rpm/tpm of the current window of the `QuotaStore`
+	/// (same as `remaining(..)` when no store is set).
+	pub async fn remaining_shared(&self, tenant_id: &str) -> Result<QuotaRemaining> {
+		let mut remaining = self.remaining(tenant_id);
+		let (quota, store) = {
+			let inner = self.lock();
+			(inner.quota(tenant_id), inner.store.clone())
+		};
+		let Some(store) = store else {
+			return Ok(remaining);
+		};
+
+		let (window_idx, _) = store_window();
+		if let Some(rpm) = quota.rpm {
+			let requests = store_incr(&store, tenant_id, QuotaKind::Rpm, window_idx, 0).await?;
+			remaining.requests = Some(rpm.saturating_sub(requests.min(u32::MAX as u64) as u32));
+		}
+		if let Some(tpm) = quota.tpm {
+			let tokens = store_incr(&store, tenant_id, QuotaKind::Tpm, window_idx, 0).await?;
+			remaining.tokens = Some(tpm.saturating_sub(tokens.min(u32::MAX as u64) as u32));
+		}
+
+		Ok(remaining)
+	}
+
+	/// Record the usage of a response for a tenant (done by the client for chat and embed,
+	/// but must be done by the caller for chat streams).
+	///
+	/// NOTE: With a `QuotaStore`, the tokens are also added to the store (the local usage is recorded even if the store fails).
+	pub async fn record_usage(&self, tenant_id: &str, model_iden: &ModelIden, usage: &Usage) -> Result<()> {
+		let (model_name, _) = model_iden.model_name.as_model_name_and_namespace();
+		let tokens = usage
+			.total_tokens
+			.unwrap_or(usage.prompt_tokens.unwrap_or(0) + usage.completion_tokens.unwrap_or(0))
+			.max(0) as u32;
+
+		let store = {
+			let mut inner = self.lock();
+			let cost = inner.prices.get(model_name).map(|price| price.cost(usage));
+			let tenant_usage = inner.tenants.entry(tenant_id.to_string()).or_default();
+			tenant_usage.tokens.push_back((Instant::now(), tokens));
+			if let Some(cost) = cost {
+				tenant_usage.spent_cost += cost;
+			}
+			inner.store.clone()
+		};
+
+		if let Some(store) = store {
+			let (window_idx, _) = store_window();
+			store_incr(&store, tenant_id, QuotaKind::Tpm, window_idx, tokens as u64).await?;
+		}
+
+		Ok(())
+	}
+
+	/// Reset the spent cost of a tenant (e.g., at the start of a billing period).
+	pub fn reset_cost(&self, tenant_id: &str) {
+		if let Some(usage) = self.lock().tenants.get_mut(tenant_id) {
+			usage.spent_cost = 0.;
+		}
+	}
+}
+
+/// Crate Functions
+impl QuotaManager {
+	/// Check the quotas of the tenant, and count the request when none is exceeded
+	/// (in the eventual `QuotaStore` for the rpm/tpm).
+	pub(crate) async fn acquire(&self, tenant_id: &str) -> Result<()> {
+		let (quota, store) = {
+			let inner = self.lock();
+			(inner.quota(tenant_id), inner.store.clone())
+		};
+		let Some(store) = store else {
+			return self.acquire_local(tenant_id);
+		};
+
+		// -- The local cost quota
+		let spent_cost = self
+			.lock()
+			.tenants
+			.get(tenant_id)
+			.map(|usage| usage.spent_cost)
+			.unwrap_or_default();
+		if quota.max_cost.is_some_and(|max_cost| spent_cost >= max_cost) {
+			return Err(quota_exceeded(tenant_id, QuotaKind::Cost, None));
+		}
+
+		// -- The shared rpm/tpm quotas (the request is counted last, so that it is not counted when the tpm is exceeded)
+		let (window_idx, retry_after) = store_window();
+		if let Some(tpm) = quota.tpm {
+			let tokens = store_incr(&store, tenant_id, QuotaKind::Tpm, window_idx, 0).await?;
+			if tokens >= tpm as u64 {
+				return Err(quota_exceeded(tenant_id, QuotaKind::Tpm, Some(retry_after)));
+			}
+		}
+		// NOTE: The rpm is checked before the request is counted, so that the rejected requests do not count
+		//       (concurrent instances may then admit a few requests over the rpm at the window limit).
+		if let Some(rpm) = quota.rpm {
+			let requests = store_incr(&store, tenant_id, QuotaKind::Rpm, window_idx, 0).await?;
+			if requests >= rpm as u64 {
+				return Err(quota_exceeded(tenant_id, QuotaKind::Rpm, Some(retry_after)));
+			}
+			store_incr(&store, tenant_id, QuotaKind::Rpm, window_idx, 1).await?;
+		}
+
+		Ok(())
+	}
+
+	/// Check the quotas of the tenant with the local counters, and count the request when none is exceeded.
+	fn acquire_local(&self, tenant_id: &str) -> Result<()> {
+		let mut inner = self.lock();
+		let quota = inner.quota(tenant_id);
+		let now = Instant::now();
+		let usage = inner.tenants.entry(tenant_id.to_string()).or_default();
+		usage.evict(now);
+
+		let exceeded = |quota_kind: QuotaKind, oldest: Option<Instant>| {
+			let retry_after = oldest.map(|oldest| QUOTA_WINDOW.saturating_sub(now.duration_since(oldest)));
+			quota_exceeded(tenant_id, quota_kind, retry_after)
+		};
+
+		if quota.rpm.is_some_and(|rpm| usage.requests.len() as u32 >= rpm) {
+			return Err(exceeded(QuotaKind::Rpm, usage.requests.front().copied()));
+		}
+		if quota.tpm.is_some_and(|tpm| usage.window_tokens() >= tpm) {
+			return Err(exceeded(QuotaKind::Tpm, usage.tokens.front().map(|(at, _)| *at)));
+		}
+		if quota.max_cost.is_some_and(|max_cost| usage.spent_cost >= max_cost) {
+			return Err(exceeded(QuotaKind::Cost, None));
+		}
+
+		usage.requests.push_back(now);
+		Ok(())
+	}
+}
+
+// endregion: --- QuotaManager
+
+// region:    --- Support
+
+impl QuotaManager {
+	fn lock(&self) -> MutexGuard<'_, QuotaManagerInner> {
+		// NOTE: The state stays consistent even if a holder panicked, so a poisoned lock is recovered.
+		self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+}
+
+impl QuotaManagerInner {
+	fn quota(&self, tenant_id: &str) -> TenantQuota {
+		self.quotas
+			.get(tenant_id)
+			.or(self.default_quota.as_ref())
+			.cloned()
+			.unwrap_or_default()
+	}
+}
+
+impl TenantUsage {
+	/// Remove the requests and tokens older than the quota window.
+	fn evict(&mut self, now: Instant) {
+		while self.requests.front().is_some_and(|at| now.duration_since(*at) >= QUOTA_WINDOW) {
+			self.requests.pop_front();
+		}
+		while self
+			.tokens
+			.front()
+			.is_some_and(|(at, _)| now.duration_since(*at) >= QUOTA_WINDOW)
+		{
+			self.tokens.pop_front();
+		}
+	}
+
+	fn window_tokens(&self) -> u32 {
+		self.tokens.iter().map(|(_, tokens)| *tokens).sum()
+	}
+}
+
+fn quota_exceeded(tenant_id: &str, quota_kind: QuotaKind, retry_after: Option<Duration>) -> Error {
+	Error::QuotaExceeded {
+		tenant_id: tenant_id.to_string(),
+		quota_kind,
+		retry_after,
+	}
+}
+
+/// Returns the index of the current fixed window of the shared counters, and the time until the next window.
+fn store_window() -> (u64, Duration) {
+	let secs = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|d| d.as_secs())
+		.unwrap_or_default();
+	let window_secs = QUOTA_WINDOW.as_secs();
+	(
+		secs / window_secs,
+		Duration::from_secs(window_secs - secs % window_secs),
+	)
+}
+
+/// Add the amount to the shared counter of the tenant quota kind for the window, and return its new value.
+async fn store_incr(
+	store: &QuotaStore,
+	tenant_id: &str,
+	quota_kind: QuotaKind,
+	window_idx: u64,
+	amount: u64,
+) -> Result<u64> {
+	let incr = QuotaIncr {
+		key: format!("{STORE_KEY_PREFIX}:{tenant_id}:{quota_kind}:{window_idx}"),
+		amount,
+		// the counter must outlive its window
+		ttl: QUOTA_WINDOW * 2,
+	};
+	store.incr(incr).await.map_err(|cause| Error::QuotaStore {
+		tenant_id: tenant_id.to_string(),
+		cause,
+	})
+}
+
+// endregion: --- Support
@@ -0,0 +1,113 @@
+use crate::resolver::Result;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+// region:    --- QuotaStore
+
+/// A shared store of the `QuotaManager` rpm/tpm window counters, so that multiple service instances
+/// sharing the same tenants (or API keys) coordinate their budgets rather than each instance limiting independently.
+///
+/// genai does not ship a store implementation: the app provides the increment function (`QuotaStore::from_incr_fn(..)`)
+/// with its own client of a shared database. The store only needs an atomic "increment and return" with an expiration,
+/// for example with the app Redis client: `INCRBY key amount` then `EXPIRE key ttl NX` (in a `MULTI`/pipeline),
+/// returning the `INCRBY` result.
+///
+/// NOTES:
+/// - The shared counters use fixed one-minute windows (the window start is part of the key),
+///   while the local counters (without store) use a sliding window.
+/// - The cost quota stays tracked per instance.
+#[derive(Debug, Clone)]
+pub enum QuotaStore {
+	/// The variant that holds the increment function
+	IncrFn(Arc<Box<dyn QuotaStoreIncrFn>>),
+}
+
+impl QuotaStore {
+	/// Create a new `QuotaStore` from an async increment function.
+	pub fn from_incr_fn(incr_fn: impl IntoQuotaStoreIncrFn) -> Self {
+		QuotaStore::IncrFn(incr_fn.into_incr_fn())
+	}
+}
+
+impl QuotaStore {
+	pub(crate) async fn incr(&self, incr: QuotaIncr) -> Result<u64> {
+		match self {
+			QuotaStore::IncrFn(incr_fn) => incr_fn.exec_fn(incr).await,
+		}
+	}
+}
+
+// endregion: --- QuotaStore
+
+// region:    --- QuotaIncr
+
+/// The increment of a shared quota counter given to the `QuotaStore`.
+#[derive(Debug, Clone)]
+pub struct QuotaIncr {
+	/// The counter key (e.g., `genai:quota:tenant-a:rpm:29016542`).
+	pub key: String,
+
+	/// The amount to add (`0` to only read the counter).
+	pub amount: u64,
+
+	/// The time to live of the counter (to be set when the counter is created).
+	pub ttl: Duration,
+}
+
+// endregion: --- QuotaIncr
+
+// region:    --- QuotaStoreIncrFn
+
+/// The `QuotaStoreIncrFn` trait object, which adds the amount to the counter and returns its new value.
+pub trait QuotaStoreIncrFn: Send + Sync {
+	fn exec_fn(&self, incr: QuotaIncr) -> Pin<Box<dyn Future<Output = Result<u64>> + Send>>;
+	fn clone_box(&self) -> Box<dyn QuotaStoreIncrFn>;
+}
+
+impl<F> QuotaStoreIncrFn for F
+where
+	F: Fn(QuotaIncr) -> Pin<Box<dyn Future<Output = Result<u64>> + Send>> + Send + Sync + Clone + 'static,
+{
+	fn exec_fn(&self, incr: QuotaIncr) -> Pin<Box<dyn Future<Output = Result<u64>> + Send>> {
+		self(incr)
+	}
+
+	fn clone_box(&self) -> Box<dyn QuotaStoreIncrFn> {
+		Box::new(self.clone())
+	}
+}
+
+impl std::fmt::Debug for dyn QuotaStoreIncrFn {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "QuotaStoreIncrFn")
+	}
+}
+
+impl Clone for Box<dyn QuotaStoreIncrFn> {
+	fn clone(&self) -> Self {
+		self.clone_box()
+	}
+}
+
+pub trait IntoQuotaStoreIncrFn {
+	fn into_incr_fn(self) -> Arc<Box<dyn QuotaStoreIncrFn>>;
+}
+
+impl IntoQuotaStoreIncrFn for Arc<Box<dyn QuotaStoreIncrFn>> {
+	fn into_incr_fn(self) -> Arc<Box<dyn QuotaStoreIncrFn>> {
+		self
+	}
+}
+
+impl<F> IntoQuotaStoreIncrFn for F
+where
+	F: Fn(QuotaIncr) -> Pin<Box<dyn Future<Output = Result<u64>> + Send>> + Send + Sync + Clone + 'static,
+{
+	fn into_incr_fn(self) -> Arc<Box<dyn QuotaStoreIncrFn>> {
+		Arc::new(Box::new(self))
+	}
+}
+
+// endregion: --- QuotaStoreIncrFn
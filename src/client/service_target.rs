@@ -7,6 +7,7 @@ use crate::resolver::{AuthData, Endpoint};
 /// - `endpoint`: The specific service endpoint to be contacted.
 /// - `auth`: The authentication data required to access the service.
 /// - `model`: The identifier of the model or resource associated with the service call.
+#[derive(Clone)]
 pub struct ServiceTarget {
 	pub endpoint: Endpoint,
 	pub auth: AuthData,
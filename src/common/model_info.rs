@@ -0,0 +1,46 @@
+use crate::ModelIden;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The normalized metadata of a model, as returned by the provider model endpoint (see `Client::model_info(..)`).
+///
+/// NOTE: Providers return different subsets of those properties (e.g., OpenAI only returns the id and owner),
+///       so all of them are optional. The full provider response is in `captured_raw_body`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelInfo {
+	/// The model identifier of the request.
+	pub model_iden: ModelIden,
+
+	/// The eventual display name of the model.
+	pub display_name: Option<String>,
+
+	/// The eventual maximum number of input tokens (the context window).
+	pub context_window: Option<u32>,
+
+	/// The eventual maximum number of output tokens.
+	pub max_output_tokens: Option<u32>,
+
+	/// The eventual input modalities (e.g., `text`, `image`), as named by the provider.
+	pub input_modalities: Option<Vec<String>>,
+
+	/// The eventual output modalities (e.g., `text`, `image`), as named by the provider.
+	pub output_modalities: Option<Vec<String>>,
+
+	/// The raw provider response of the model.
+	pub captured_raw_body: Value,
+}
+
+/// Constructors
+impl ModelInfo {
+	pub(crate) fn new(model_iden: ModelIden, captured_raw_body: Value) -> Self {
+		Self {
+			model_iden,
+			display_name: None,
+			context_window: None,
+			max_output_tokens: None,
+			input_modalities: None,
+			output_modalities: None,
+			captured_raw_body,
+		}
+	}
+}
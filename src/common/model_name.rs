@@ -16,8 +16,11 @@ impl ModelName {
 
 	/// e.g., `openai::gpt4.1` ("gpt4.1", Some("openai"))
 	///       `gpt4.1` ("gpt4.1", None)
+	///       `ft:gpt-4o-mini:my-org::abc123` ("ft:gpt-4o-mini:my-org::abc123", None)
+	///
+	/// NOTE: A namespace cannot contain `:`, so that the OpenAI fine-tuned model names are not split.
 	pub(crate) fn model_name_and_namespace(model: &str) -> (&str, Option<&str>) {
-		if let Some(ns_idx) = model.find("::") {
+		if let Some(ns_idx) = model.find("::").filter(|ns_idx| !model[..*ns_idx].contains(':')) {
 			let ns: &str = &model[..ns_idx];
 			let name: &str = &model[(ns_idx + 2)..];
 			// TODO: assess what to do when name or ns is empty
@@ -0,0 +1,101 @@
+//! This module contains the types of the concurrent batch embedding (see `Client::embed_batch_concurrent(..)`).
+
+use crate::Result;
+use crate::embed::EmbedResponse;
+use std::ops::Range;
+use std::time::Duration;
+
+// region:    --- EmbedConcurrency
+
+/// The concurrency and rate-limit backoff settings of `Client::embed_batch_concurrent(..)`.
+#[derive(Debug, Clone)]
+pub struct EmbedConcurrency {
+	/// The max number of chunk requests in flight (default 4).
+	pub max_concurrent: usize,
+
+	/// The max number of inputs per chunk (default and max: the provider batch limit, see `AdapterKind::embed_max_batch_size()`).
+	pub chunk_size: Option<usize>,
+
+	/// The max number of retries of a rate limited chunk (status `429`) (default 3).
+	pub max_retries: usize,
+
+	/// The backoff before the first retry, doubled at each retry (default 1s).
+	/// The provider `retry-after` is used instead when returned.
+	pub initial_backoff: Duration,
+
+	/// The max backoff between two retries (default 30s).
+	pub max_backoff: Duration,
+}
+
+impl Default for EmbedConcurrency {
+	fn default() -> Self {
+		Self {
+			max_concurrent: 4,
+			chunk_size: None,
+			max_retries: 3,
+			initial_backoff: Duration::from_secs(1),
+			max_backoff: Duration::from_secs(30),
+		}
+	}
+}
+
+/// Chainable Setters
+impl EmbedConcurrency {
+	pub fn with_max_concurrent(mut self, max_concurrent: usize) -> Self {
+		self.max_concurrent = max_concurrent;
+		self
+	}
+
+	pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+		self.chunk_size = Some(chunk_size);
+		self
+	}
+
+	pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+		self.max_retries = max_retries;
+		self
+	}
+
+	pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+		self.initial_backoff = initial_backoff;
+		self
+	}
+
+	pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+		self.max_backoff = max_backoff;
+		self
+	}
+}
+
+/// Crate Functions
+impl EmbedConcurrency {
+	/// The backoff before the retry `retry` (starting at 1), or the provider `retry_after` when returned
+	/// (both capped by `max_backoff`).
+	pub(crate) fn backoff(&self, retry: usize, retry_after: Option<Duration>) -> Duration {
+		let backoff = retry_after.unwrap_or_else(|| {
+			let factor = 2u32.saturating_pow(retry.saturating_sub(1).min(16) as u32);
+			self.initial_backoff.saturating_mul(factor)
+		});
+		backoff.min(self.max_backoff)
+	}
+}
+
+// endregion: --- EmbedConcurrency
+
+// region:    --- EmbedChunkResult
+
+/// The result of a chunk of `Client::embed_batch_concurrent(..)`.
+#[derive(Debug)]
+pub struct EmbedChunkResult {
+	/// The range of the chunk inputs in the request inputs.
+	/// The `Embedding.index` of the response are the indices in the request inputs (not in the chunk).
+	pub inputs_range: Range<usize>,
+
+	/// The number of retries after a rate limit.
+	pub retries: usize,
+
+	/// The chunk response, or its error (after the eventual retries).
+	pub result: Result<EmbedResponse>,
+}
+
+// endregion: --- EmbedChunkResult
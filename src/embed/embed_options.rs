@@ -20,27 +20,47 @@ pub struct EmbedOptions {
 	pub capture_usage: Option<bool>,
 
 	/// The desired dimensionality of the embedding vectors (if supported by the provider).
-	/// Note: Not all providers support custom dimensions.
+	/// - OpenAI: `dimensions` (not for `text-embedding-ada-002`)
+	/// - Gemini: `output_dimensionality` (not for `embedding-001`)
+	/// - Cohere: `output_dimension` (`embed-v4.0` only)
+	/// - Ollama: `dimensions` (for the models which support it)
+	///
+	/// Note: Fails with `Error::EmbedDimensionsNotSupported` for the models known to have fixed dimensions.
 	pub dimensions: Option<usize>,
 
 	/// The encoding format for the embeddings (if supported by the provider).
-	/// Common values: "float", "base64", "binary", etc.
+	/// - OpenAI: "float" (default), "base64" (smaller responses, decoded to the f32 `Embedding.vector`)
+	/// - Cohere: "float" (default), "int8", "uint8", "binary", "ubinary" (see `Embedding.native_vector`)
 	pub encoding_format: Option<String>,
 
 	/// A unique identifier representing your end-user (for OpenAI and similar providers).
 	pub user: Option<String>,
 
 	/// The type/purpose of the embedding request.
-	/// - Cohere: "search_document", "search_query", "classification", "clustering"
+	/// - Cohere (`input_type`): "search_document", "search_query", "classification", "clustering"
 	/// - Gemini: "SEMANTIC_SIMILARITY", "RETRIEVAL_QUERY", "RETRIEVAL_DOCUMENT", "CLASSIFICATION"
 	///
 	/// Default: "search_document" (Cohere), "SEMANTIC_SIMILARITY" (Gemini)
 	pub embedding_type: Option<String>,
 
+	/// The embedding types to return in the same request (supported by Cohere).
+	/// Values: "float", "int8", "uint8", "binary", "ubinary"
+	///
+	/// The first type is the `Embedding.vector` (as f32), and all of them are in `Embedding.typed_vectors`.
+	/// When not set, the single `encoding_format` (or "float") is used.
+	pub embedding_types: Option<Vec<String>>,
+
 	/// How to handle inputs longer than the maximum token length (supported by Cohere).
 	/// Common values: "NONE", "START", "END"
 	/// Default: "END"
 	pub truncate: Option<String>,
+
+	/// Whether to L2-normalize the returned f32 vectors client-side (default false),
+	/// since the providers are not consistent about returning unit vectors.
+	pub normalize: Option<bool>,
+
+	/// The tenant id of this request, for the eventual client `QuotaManager`.
+	pub tenant_id: Option<String>,
 }
 
 /// Constructors
@@ -95,11 +115,33 @@ impl EmbedOptions {
 		self
 	}
 
+	/// Set the embedding types to return in the same request (e.g., `["float", "int8"]`).
+	pub fn with_embedding_types<I, S>(mut self, embedding_types: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		self.embedding_types = Some(embedding_types.into_iter().map(Into::into).collect());
+		self
+	}
+
 	/// Set the truncation method for inputs longer than the maximum token length.
 	pub fn with_truncate(mut self, truncate: impl Into<String>) -> Self {
 		self.truncate = Some(truncate.into());
 		self
 	}
+
+	/// Enable or disable the L2-normalization of the returned vectors.
+	pub fn with_normalize(mut self, normalize: bool) -> Self {
+		self.normalize = Some(normalize);
+		self
+	}
+
+	/// Set the tenant id (see `ClientBuilder::with_quota_manager(..)`).
+	pub fn with_tenant_id(mut self, tenant_id: impl Into<String>) -> Self {
+		self.tenant_id = Some(tenant_id.into());
+		self
+	}
 }
 
 /// Getters
@@ -139,10 +181,25 @@ impl EmbedOptions {
 		self.embedding_type.as_deref()
 	}
 
+	/// Get the embedding types.
+	pub fn embedding_types(&self) -> Option<&[String]> {
+		self.embedding_types.as_deref()
+	}
+
 	/// Get the truncation method.
 	pub fn truncate(&self) -> Option<&str> {
 		self.truncate.as_deref()
 	}
+
+	/// Get whether to L2-normalize the returned vectors.
+	pub fn normalize(&self) -> bool {
+		self.normalize.unwrap_or(false)
+	}
+
+	/// Get the tenant id.
+	pub fn tenant_id(&self) -> Option<&str> {
+		self.tenant_id.as_deref()
+	}
 }
 
 // endregion: --- EmbedOptions
@@ -225,12 +282,34 @@ impl<'client, 'request> EmbedOptionsSet<'client, 'request> {
 			.or_else(|| self.client_options.and_then(|o| o.embedding_type()))
 	}
 
+	/// Get the effective embedding types setting.
+	pub fn embedding_types(&self) -> Option<&[String]> {
+		self.request_options
+			.and_then(|o| o.embedding_types())
+			.or_else(|| self.client_options.and_then(|o| o.embedding_types()))
+	}
+
 	/// Get the effective truncate setting.
 	pub fn truncate(&self) -> Option<&str> {
 		self.request_options
 			.and_then(|o| o.truncate())
 			.or_else(|| self.client_options.and_then(|o| o.truncate()))
 	}
+
+	/// Get the effective normalize setting.
+	pub fn normalize(&self) -> bool {
+		self.request_options
+			.and_then(|o| o.normalize)
+			.or_else(|| self.client_options.and_then(|o| o.normalize))
+			.unwrap_or(false)
+	}
+
+	/// Get the effective tenant id.
+	pub fn tenant_id(&self) -> Option<&str> {
+		self.request_options
+			.and_then(|o| o.tenant_id())
+			.or_else(|| self.client_options.and_then(|o| o.tenant_id()))
+	}
 }
 
 // endregion: --- EmbedOptionsSet
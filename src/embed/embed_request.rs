@@ -1,5 +1,6 @@
 //! This module contains all the types related to an Embed Request.
 
+use crate::chat::ContentPart;
 use serde::{Deserialize, Serialize};
 
 // region:    --- EmbedRequest
@@ -27,6 +28,16 @@ impl EmbedRequest {
 		}
 	}
 
+	/// Create a new EmbedRequest with multimodal inputs, each input being a list of content parts
+	/// (e.g., `ContentPart::from_image_base64(..)`, optionally with a text part).
+	///
+	/// NOTE: Only supported by the multimodal embedding models (e.g., Cohere `embed-v4.0`).
+	pub fn new_parts(inputs: Vec<Vec<ContentPart>>) -> Self {
+		Self {
+			input: EmbedInput::Parts(inputs),
+		}
+	}
+
 	/// Create an EmbedRequest from a single string.
 	pub fn from_text(text: impl Into<String>) -> Self {
 		Self::new(text)
@@ -44,22 +55,35 @@ impl EmbedRequest {
 	pub fn single_input(&self) -> Option<&str> {
 		match &self.input {
 			EmbedInput::Single(text) => Some(text),
-			EmbedInput::Batch(_) => None,
+			EmbedInput::Batch(_) | EmbedInput::Parts(_) => None,
 		}
 	}
 
 	/// Get the input as a vector of strings.
 	/// For single input, returns a vector with one element.
+	/// For multimodal inputs, returns the text parts only.
 	pub fn inputs(&self) -> Vec<&str> {
 		match &self.input {
 			EmbedInput::Single(text) => vec![text],
 			EmbedInput::Batch(texts) => texts.iter().map(|s| s.as_str()).collect(),
+			EmbedInput::Parts(inputs) => inputs
+				.iter()
+				.flatten()
+				.filter_map(|part| match part {
+					ContentPart::Text(text) => Some(text.as_str()),
+					_ => None,
+				})
+				.collect(),
 		}
 	}
 
 	/// Check if this is a batch request.
 	pub fn is_batch(&self) -> bool {
-		matches!(self.input, EmbedInput::Batch(_))
+		match &self.input {
+			EmbedInput::Single(_) => false,
+			EmbedInput::Batch(_) => true,
+			EmbedInput::Parts(inputs) => inputs.len() > 1,
+		}
 	}
 
 	/// Get the number of inputs.
@@ -67,10 +91,38 @@ impl EmbedRequest {
 		match &self.input {
 			EmbedInput::Single(_) => 1,
 			EmbedInput::Batch(texts) => texts.len(),
+			EmbedInput::Parts(inputs) => inputs.len(),
 		}
 	}
 }
 
+/// Crate Functions
+impl EmbedRequest {
+	/// Split the inputs into batches of at most `max_count` inputs and (when possible) `max_chars` characters,
+	/// preserving the order.
+	pub(crate) fn split_batches(inputs: Vec<String>, max_count: usize, max_chars: usize) -> Vec<Vec<String>> {
+		let mut batches: Vec<Vec<String>> = Vec::new();
+		let mut batch: Vec<String> = Vec::new();
+		let mut batch_chars = 0;
+
+		for input in inputs {
+			let input_chars = input.chars().count();
+			let is_full = batch.len() >= max_count.max(1) || batch_chars + input_chars > max_chars;
+			if is_full && !batch.is_empty() {
+				batches.push(std::mem::take(&mut batch));
+				batch_chars = 0;
+			}
+			batch_chars += input_chars;
+			batch.push(input);
+		}
+		if !batch.is_empty() {
+			batches.push(batch);
+		}
+
+		batches
+	}
+}
+
 // endregion: --- EmbedRequest
 
 // region:    --- EmbedInput
@@ -82,6 +134,9 @@ pub enum EmbedInput {
 	Single(String),
 	/// Multiple text inputs for batch processing.
 	Batch(Vec<String>),
+	/// Multimodal inputs (one embedding per input), each input being a list of content parts
+	/// (`ContentPart::Text` and `ContentPart::Image`).
+	Parts(Vec<Vec<ContentPart>>),
 }
 
 impl From<String> for EmbedInput {
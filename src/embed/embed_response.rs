@@ -19,9 +19,14 @@ pub struct EmbedResponse {
 	/// For example, `text-embedding-3-small` model_iden might have a provider_model_iden as `text-embedding-3-small-2024-01-01`
 	pub provider_model_iden: ModelIden,
 
-	/// The eventual usage of the embed response (token counts, etc.)
+	/// The eventual usage of the embed response.
+	/// The input tokens are both the `prompt_tokens` and the `total_tokens` for all the adapters
+	/// (None when the provider does not report them, e.g., Gemini).
 	pub usage: Usage,
 
+	/// The units billed by the provider, when reported (e.g., Cohere `meta.billed_units`).
+	pub billed_units: Option<EmbedBilledUnits>,
+
 	/// The raw value of the response body, which can be used for provider specific features.
 	pub captured_raw_body: Option<serde_json::Value>,
 }
@@ -40,10 +45,17 @@ impl EmbedResponse {
 			model_iden,
 			provider_model_iden,
 			usage,
+			billed_units: None,
 			captured_raw_body: None,
 		}
 	}
 
+	/// Set the units billed by the provider.
+	pub fn with_billed_units(mut self, billed_units: EmbedBilledUnits) -> Self {
+		self.billed_units = Some(billed_units);
+		self
+	}
+
 	/// Create a new EmbedResponse with captured raw body.
 	pub fn with_captured_raw_body(mut self, raw_body: serde_json::Value) -> Self {
 		self.captured_raw_body = Some(raw_body);
@@ -68,6 +80,11 @@ impl EmbedResponse {
 		self.embeddings.iter().map(|e| &e.vector).collect()
 	}
 
+	/// Get the sparse vectors of the embeddings (None for the dense only embeddings).
+	pub fn sparse_vectors(&self) -> Vec<Option<&SparseEmbedding>> {
+		self.embeddings.iter().map(|e| e.sparse_vector.as_ref()).collect()
+	}
+
 	/// Get all embedding vectors as owned data.
 	pub fn into_vectors(self) -> Vec<Vec<f32>> {
 		self.embeddings.into_iter().map(|e| e.vector).collect()
@@ -89,8 +106,50 @@ impl EmbedResponse {
 	}
 }
 
+/// Public Functions
+impl EmbedResponse {
+	/// L2-normalize all the embedding vectors (see `Embedding::normalize()`).
+	pub fn normalize(&mut self) {
+		self.embeddings.iter_mut().for_each(Embedding::normalize);
+	}
+}
+
 // endregion: --- EmbedResponse
 
+// region:    --- EmbedBilledUnits
+
+/// The units billed by the provider for an embed request.
+///
+/// - OpenAI: `input_tokens` (the `usage.prompt_tokens`)
+/// - Cohere: `input_tokens` and `images` (the `meta.billed_units`)
+/// - Gemini, Ollama: not reported
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EmbedBilledUnits {
+	/// The billed input tokens.
+	pub input_tokens: Option<i32>,
+
+	/// The billed input characters (for the providers billing per character).
+	pub input_characters: Option<i32>,
+
+	/// The billed images (multimodal embeddings).
+	pub images: Option<i32>,
+}
+
+impl EmbedBilledUnits {
+	/// Add the units of another response to these ones (e.g., to aggregate the batches of a request).
+	pub fn accumulate(&mut self, other: &EmbedBilledUnits) {
+		let add = |a: Option<i32>, b: Option<i32>| match (a, b) {
+			(None, None) => None,
+			(a, b) => Some(a.unwrap_or(0) + b.unwrap_or(0)),
+		};
+		self.input_tokens = add(self.input_tokens, other.input_tokens);
+		self.input_characters = add(self.input_characters, other.input_characters);
+		self.images = add(self.images, other.images);
+	}
+}
+
+// endregion: --- EmbedBilledUnits
+
 // region:    --- Embedding
 
 /// A single embedding vector with metadata.
@@ -104,6 +163,18 @@ pub struct Embedding {
 
 	/// The dimensionality of the embedding vector.
 	pub dimensions: usize,
+
+	/// The vector in its native (non-float) encoding, when requested (e.g., `I8` for the Cohere `int8` type).
+	/// The `vector` is always the f32 conversion of it.
+	pub native_vector: Option<EmbeddingVector>,
+
+	/// The vectors of each requested embedding type, when multiple types were requested
+	/// (see `EmbedOptions::with_embedding_types(..)`).
+	pub typed_vectors: Option<EmbeddingTypedVectors>,
+
+	/// The sparse vector, for the providers returning sparse embeddings (e.g., BM25-style or SPLADE models),
+	/// alongside or instead of the dense `vector` (empty for the sparse only embeddings).
+	pub sparse_vector: Option<SparseEmbedding>,
 }
 
 /// Constructors
@@ -115,6 +186,9 @@ impl Embedding {
 			vector,
 			index,
 			dimensions,
+			native_vector: None,
+			typed_vectors: None,
+			sparse_vector: None,
 		}
 	}
 
@@ -124,10 +198,42 @@ impl Embedding {
 			vector,
 			index,
 			dimensions,
+			native_vector: None,
+			typed_vectors: None,
+			sparse_vector: None,
+		}
+	}
+
+	/// Create a new sparse only Embedding (empty dense `vector`, and `dimensions` 0).
+	pub fn from_sparse(sparse_vector: SparseEmbedding, index: usize) -> Self {
+		Self {
+			sparse_vector: Some(sparse_vector),
+			..Self::new(Vec::new(), index)
 		}
 	}
 }
 
+/// Chainable Setters
+impl Embedding {
+	/// Set the vector in its native (non-float) encoding.
+	pub fn with_native_vector(mut self, native_vector: EmbeddingVector) -> Self {
+		self.native_vector = Some(native_vector);
+		self
+	}
+
+	/// Set the vectors of each requested embedding type.
+	pub fn with_typed_vectors(mut self, typed_vectors: EmbeddingTypedVectors) -> Self {
+		self.typed_vectors = Some(typed_vectors);
+		self
+	}
+
+	/// Set the sparse vector (alongside the dense vector).
+	pub fn with_sparse_vector(mut self, sparse_vector: SparseEmbedding) -> Self {
+		self.sparse_vector = Some(sparse_vector);
+		self
+	}
+}
+
 /// Getters
 impl Embedding {
 	/// Get the embedding vector.
@@ -149,6 +255,250 @@ impl Embedding {
 	pub fn dimensions(&self) -> usize {
 		self.dimensions
 	}
+
+	/// Get the vector in its native (non-float) encoding.
+	pub fn native_vector(&self) -> Option<&EmbeddingVector> {
+		self.native_vector.as_ref()
+	}
+
+	/// Get the vectors of each requested embedding type.
+	pub fn typed_vectors(&self) -> Option<&EmbeddingTypedVectors> {
+		self.typed_vectors.as_ref()
+	}
+
+	/// Get the sparse vector.
+	pub fn sparse_vector(&self) -> Option<&SparseEmbedding> {
+		self.sparse_vector.as_ref()
+	}
+}
+
+/// Public Functions
+impl Embedding {
+	/// L2-normalize the f32 `vector` (and the eventual `typed_vectors.float`) to a unit vector,
+	/// so that the cosine similarity is a simple dot product.
+	///
+	/// NOTE: The quantized vectors (`native_vector`, other `typed_vectors`) are left unchanged,
+	///       and a zero vector stays a zero vector. The eventual `sparse_vector` values are normalized as well.
+	pub fn normalize(&mut self) {
+		l2_normalize(&mut self.vector);
+		if let Some(float) = self.typed_vectors.as_mut().and_then(|typed| typed.float.as_mut()) {
+			l2_normalize(float);
+		}
+		if let Some(sparse_vector) = self.sparse_vector.as_mut() {
+			l2_normalize(&mut sparse_vector.values);
+		}
+	}
 }
 
 // endregion: --- Embedding
+
+// region:    --- EmbeddingVector
+
+/// An embedding vector in its native encoding.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum EmbeddingVector {
+	F32(Vec<f32>),
+	I8(Vec<i8>),
+	U8(Vec<u8>),
+	/// Packed bits (8 dimensions per byte, most significant bit first).
+	Binary(Vec<u8>),
+}
+
+/// Getters
+impl EmbeddingVector {
+	/// The number of dimensions (8 per byte for `Binary`).
+	pub fn dimensions(&self) -> usize {
+		match self {
+			EmbeddingVector::F32(v) => v.len(),
+			EmbeddingVector::I8(v) => v.len(),
+			EmbeddingVector::U8(v) => v.len(),
+			EmbeddingVector::Binary(v) => v.len() * 8,
+		}
+	}
+
+	/// The vector values as f32 (the packed bytes for `Binary`, see `unpacked_bits()`).
+	pub fn to_f32_vec(&self) -> Vec<f32> {
+		match self {
+			EmbeddingVector::F32(v) => v.clone(),
+			EmbeddingVector::I8(v) => v.iter().map(|&v| v as f32).collect(),
+			EmbeddingVector::U8(v) | EmbeddingVector::Binary(v) => v.iter().map(|&v| v as f32).collect(),
+		}
+	}
+
+	/// The bits of a `Binary` vector, one value (0 or 1) per dimension (None for the other encodings).
+	pub fn unpacked_bits(&self) -> Option<Vec<u8>> {
+		let EmbeddingVector::Binary(bytes) = self else {
+			return None;
+		};
+		Some(
+			bytes
+				.iter()
+				.flat_map(|byte| (0..8).rev().map(move |bit| (byte >> bit) & 1))
+				.collect(),
+		)
+	}
+}
+
+// endregion: --- EmbeddingVector
+
+// region:    --- EmbeddingTypedVectors
+
+/// The vectors of a single embedding in each of the requested embedding types,
+/// with their native element type (e.g., for quantized storage).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EmbeddingTypedVectors {
+	pub float: Option<Vec<f32>>,
+	pub int8: Option<Vec<i8>>,
+	pub uint8: Option<Vec<u8>>,
+	/// Packed bits (8 dimensions per value), signed.
+	pub binary: Option<Vec<i8>>,
+	/// Packed bits (8 dimensions per value), unsigned.
+	pub ubinary: Option<Vec<u8>>,
+}
+
+/// Getters
+impl EmbeddingTypedVectors {
+	/// Get the vector of an embedding type ("float", "int8", "uint8", "binary", "ubinary").
+	pub fn vector(&self, embedding_type: &str) -> Option<EmbeddingVector> {
+		match embedding_type {
+			"float" => self.float.clone().map(EmbeddingVector::F32),
+			"int8" => self.int8.clone().map(EmbeddingVector::I8),
+			"uint8" => self.uint8.clone().map(EmbeddingVector::U8),
+			"binary" => self
+				.binary
+				.as_ref()
+				.map(|v| EmbeddingVector::Binary(v.iter().map(|&v| v as u8).collect())),
+			"ubinary" => self.ubinary.clone().map(EmbeddingVector::Binary),
+			_ => None,
+		}
+	}
+
+	/// Get the vector of an embedding type ("float", "int8", "uint8", "binary", "ubinary") as f32 values.
+	pub fn to_f32_vector(&self, embedding_type: &str) -> Option<Vec<f32>> {
+		self.vector(embedding_type).map(|vector| vector.to_f32_vec())
+	}
+}
+
+// endregion: --- EmbeddingTypedVectors
+
+// region:    --- SparseEmbedding
+
+/// A sparse embedding vector, as the non-zero `values` at their `indices` (e.g., the vocabulary token ids).
+///
+/// The indices are sorted in ascending order and unique (see `SparseEmbedding::new(..)`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SparseEmbedding {
+	pub indices: Vec<u32>,
+	pub values: Vec<f32>,
+}
+
+/// Constructors
+impl SparseEmbedding {
+	/// Create a new SparseEmbedding from its indices and values (paired by position).
+	///
+	/// The pairs are sorted by index, the values of duplicate indices are summed,
+	/// and the extra indices or values (when the lengths differ) are ignored.
+	pub fn new(indices: Vec<u32>, values: Vec<f32>) -> Self {
+		Self::from_pairs(indices.into_iter().zip(values))
+	}
+
+	/// Create a new SparseEmbedding from `(index, value)` pairs (sorted and merged as in `new(..)`).
+	pub fn from_pairs(pairs: impl IntoIterator<Item = (u32, f32)>) -> Self {
+		let mut pairs: Vec<(u32, f32)> = pairs.into_iter().collect();
+		pairs.sort_by_key(|(index, _)| *index);
+
+		let mut sparse = Self::default();
+		for (index, value) in pairs {
+			if sparse.indices.last() == Some(&index)
+				&& let Some(last) = sparse.values.last_mut()
+			{
+				*last += value;
+			} else {
+				sparse.indices.push(index);
+				sparse.values.push(value);
+			}
+		}
+		sparse
+	}
+}
+
+/// Getters
+impl SparseEmbedding {
+	/// The number of non-zero values.
+	pub fn len(&self) -> usize {
+		self.indices.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.indices.is_empty()
+	}
+
+	/// Get the value at an index (0 when absent).
+	pub fn get(&self, index: u32) -> f32 {
+		self.indices.binary_search(&index).map(|pos| self.values[pos]).unwrap_or(0.)
+	}
+
+	/// Iterate over the `(index, value)` pairs.
+	pub fn iter(&self) -> impl Iterator<Item = (u32, f32)> + '_ {
+		self.indices.iter().copied().zip(self.values.iter().copied())
+	}
+}
+
+/// Public Functions
+impl SparseEmbedding {
+	/// The dot product with another sparse vector (the similarity score of the BM25-style and SPLADE models).
+	pub fn dot(&self, other: &SparseEmbedding) -> f32 {
+		let (mut i, mut j, mut sum) = (0, 0, 0f32);
+		while i < self.indices.len() && j < other.indices.len() {
+			match self.indices[i].cmp(&other.indices[j]) {
+				std::cmp::Ordering::Less => i += 1,
+				std::cmp::Ordering::Greater => j += 1,
+				std::cmp::Ordering::Equal => {
+					sum += self.values[i] * other.values[j];
+					i += 1;
+					j += 1;
+				}
+			}
+		}
+		sum
+	}
+
+	/// The dense vector of `dimensions` values (the indices beyond `dimensions` are ignored).
+	pub fn to_dense(&self, dimensions: usize) -> Vec<f32> {
+		let mut dense = vec![0f32; dimensions];
+		for (index, value) in self.iter() {
+			if let Some(slot) = dense.get_mut(index as usize) {
+				*slot = value;
+			}
+		}
+		dense
+	}
+}
+
+// endregion: --- SparseEmbedding
+
+// region:    --- Support
+
+/// The number of independent accumulators of the sum of squares (lets the compiler vectorize the loop).
+const NORMALIZE_LANES: usize = 8;
+
+/// L2-normalize the values in place (unchanged when the norm is zero or not finite).
+fn l2_normalize(values: &mut [f32]) {
+	let mut lanes = [0f32; NORMALIZE_LANES];
+	let mut chunks = values.chunks_exact(NORMALIZE_LANES);
+	for chunk in &mut chunks {
+		for (lane, v) in lanes.iter_mut().zip(chunk) {
+			*lane += v * v;
+		}
+	}
+	let sum_sq = lanes.iter().sum::<f32>() + chunks.remainder().iter().map(|v| v * v).sum::<f32>();
+
+	let norm = sum_sq.sqrt();
+	if norm == 0.0 || !norm.is_finite() {
+		return;
+	}
+	let inv_norm = 1.0 / norm;
+	values.iter_mut().for_each(|v| *v *= inv_norm);
+}
+
+// endregion: --- Support
@@ -3,11 +3,13 @@
 
 // region:    --- Modules
 
+mod embed_concurrency;
 mod embed_options;
 mod embed_request;
 mod embed_response;
 
 // -- Flatten
+pub use embed_concurrency::*;
 pub use embed_options::*;
 pub use embed_request::*;
 pub use embed_response::*;
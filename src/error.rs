@@ -1,7 +1,8 @@
 use crate::adapter::AdapterKind;
-use crate::chat::ChatRole;
-use crate::{ModelIden, resolver, webc};
+use crate::chat::{ChatRole, ContentFilterInfo};
+use crate::{ModelIden, QuotaKind, resolver, webc};
 use derive_more::{Display, From};
+use std::time::Duration;
 use value_ext::JsonValueExtError;
 
 /// GenAI main Result type alias (with genai::Error)
@@ -30,9 +31,40 @@ pub enum Error {
 	#[display("JSON mode requested but no instruction/prompt provided.")]
 	JsonModeWithoutInstruction,
 
+	#[display("Invalid schema for tool '{tool_name}'.\nCause: {cause}")]
+	ToolSchemaInvalid { tool_name: String, cause: String },
+
+	#[display("Chat history edit failed.\nCause: {cause}")]
+	HistoryEdit { cause: String },
+
+	#[display("Prompt template render failed.\nCause: {cause}")]
+	PromptTemplateRender { cause: String },
+
+	#[display(
+		"Pinned messages take {pinned_tokens} tokens, over the budget of {budget} tokens for model '{model_iden}'"
+	)]
+	PinnedBudgetExceeded {
+		model_iden: ModelIden,
+		pinned_tokens: i32,
+		budget: u32,
+	},
+
+	#[display(
+		"Invalid thinking blocks in the chat history (message {message_index}) for model '{model_iden}'.\nCause: {cause}"
+	)]
+	ThinkingHistoryInvalid {
+		model_iden: ModelIden,
+		message_index: usize,
+		cause: &'static str,
+	},
+
 	#[display("Failed to parse reasoning. Actual: '{actual}'")]
 	ReasoningParsingError { actual: String },
 
+	// -- Embed Input
+	#[display("Model '{model_iden}' does not support the embedding dimensions reduction (EmbedOptions.dimensions)")]
+	EmbedDimensionsNotSupported { model_iden: ModelIden },
+
 	// -- Chat Output
 	#[display("No chat response from model '{model_iden}'")]
 	NoChatResponse { model_iden: ModelIden },
@@ -40,6 +72,22 @@ pub enum Error {
 	#[display("Invalid JSON response element: {info}")]
 	InvalidJsonResponseElement { info: &'static str },
 
+	#[display("Chat response from model '{model_iden}' is not a valid '{type_name}' JSON.\nCause: {cause}")]
+	StructuredOutputInvalid {
+		model_iden: ModelIden,
+		type_name: &'static str,
+		cause: String,
+	},
+
+	#[display(
+		"No accepted chat response from model '{model_iden}' after {attempts} attempts.\nLast rejection: {reason}"
+	)]
+	RegenerationExhausted {
+		model_iden: ModelIden,
+		attempts: usize,
+		reason: String,
+	},
+
 	// -- Auth
 	#[display("Model '{model_iden}' requires an API key.")]
 	RequiresApiKey { model_iden: ModelIden },
@@ -93,6 +141,22 @@ pub enum Error {
 		resolver_error: resolver::Error,
 	},
 
+	// -- Quota
+	#[display("Quota '{quota_kind}' exceeded for tenant '{tenant_id}'")]
+	QuotaExceeded {
+		tenant_id: String,
+		quota_kind: QuotaKind,
+		/// The eventual time until the per-minute quota frees up (None for the cost quota).
+		retry_after: Option<Duration>,
+	},
+
+	#[display("Quota store error for tenant '{tenant_id}'.\nCause: {cause}")]
+	QuotaStore { tenant_id: String, cause: resolver::Error },
+
+	// -- Routing
+	#[display("No route candidate with a tier of at least {min_tier}")]
+	NoRouteCandidate { min_tier: u8 },
+
 	// -- Adapter Support
 	#[display("Adapter '{adapter_kind}' does not support feature '{feature}'")]
 	AdapterNotSupported { adapter_kind: AdapterKind, feature: String },
@@ -112,6 +176,74 @@ pub enum Error {
 	#[display("Serde JSON error: {_0}")]
 	#[from]
 	SerdeJson(serde_json::Error),
+
+	#[cfg(feature = "sqlite")]
+	#[display("SQLite error: {_0}")]
+	#[from]
+	Sqlite(rusqlite::Error),
+}
+
+/// Getters
+impl Error {
+	/// Returns true when the provider rate limited the request (status `429`).
+	pub fn is_rate_limited(&self) -> bool {
+		matches!(
+			self,
+			Error::WebModelCall {
+				webc_error: webc::Error::ResponseFailedStatus { status, .. },
+				..
+			} if status.as_u16() == 429
+		)
+	}
+
+	/// Returns the eventual time to wait before retrying, from the provider `retry-after` header (in seconds)
+	/// or the `QuotaExceeded` error.
+	pub fn retry_after(&self) -> Option<Duration> {
+		match self {
+			Error::WebModelCall {
+				webc_error: webc::Error::ResponseFailedStatus { headers, .. },
+				..
+			} => headers
+				.get("retry-after")
+				.and_then(|value| value.to_str().ok())
+				.and_then(|value| value.trim().parse::<u64>().ok())
+				.map(Duration::from_secs),
+			Error::QuotaExceeded { retry_after, .. } => *retry_after,
+			_ => None,
+		}
+	}
+
+	/// Returns the normalized content filter info when the provider rejected the request with a content filter error
+	/// (e.g., Azure OpenAI `400` with `"code": "content_filter"` and its `innererror.content_filter_result`).
+	///
+	/// NOTE: When the provider returns a successful response with filter metadata, see `ChatResponse.content_filter`.
+	pub fn content_filter_info(&self) -> Option<ContentFilterInfo> {
+		let Error::WebModelCall {
+			webc_error: webc::Error::ResponseFailedStatus { body, .. },
+			..
+		} = self
+		else {
+			return None;
+		};
+
+		let body: serde_json::Value = serde_json::from_str(body).ok()?;
+		let error = body.get("error")?;
+		if error.get("code").and_then(|code| code.as_str()) != Some("content_filter") {
+			return None;
+		}
+
+		let categories = error
+			.pointer("/innererror/content_filter_result")
+			.map(ContentFilterInfo::categories_from_openai_filter_results)
+			.unwrap_or_default();
+
+		Some(ContentFilterInfo {
+			blocked: true,
+			reason: Some("content_filter".to_string()),
+			categories,
+			message: None,
+		})
+	}
 }
 
 // region:    --- Error Boilerplate
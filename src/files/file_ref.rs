@@ -0,0 +1,88 @@
+//! This module contains the types related to a provider file reference.
+
+use crate::adapter::AdapterKind;
+use serde::{Deserialize, Serialize};
+
+// region:    --- FileRef
+
+/// A reference to a file stored by a provider.
+///
+/// NOTE: A FileRef is provider-scoped, meaning a file uploaded to OpenAI can only be referenced
+///       in chat requests sent to OpenAI (same for the other providers).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileRef {
+	/// The adapter kind of the provider which stores the file.
+	pub adapter_kind: AdapterKind,
+
+	/// The provider file id
+	/// - OpenAI: `file-...`
+	/// - Anthropic: `file_...`
+	/// - Gemini: `files/...` (the resource name)
+	pub id: String,
+
+	/// The eventual file URI (Gemini only, which references files by `file_uri` in chat requests).
+	pub uri: Option<String>,
+
+	/// The eventual file name (or display name) as stored by the provider.
+	pub file_name: Option<String>,
+
+	/// The eventual mime type as stored by the provider.
+	pub content_type: Option<String>,
+
+	/// The eventual size in bytes.
+	pub size: Option<u64>,
+
+	/// The eventual processing state as returned by the provider
+	/// (Gemini: `PROCESSING`, `ACTIVE`, or `FAILED`, as video files are processed after the upload).
+	pub state: Option<String>,
+}
+
+/// Constructors
+impl FileRef {
+	/// Create a FileRef from a known provider file id.
+	pub fn new(adapter_kind: AdapterKind, id: impl Into<String>) -> Self {
+		Self {
+			adapter_kind,
+			id: id.into(),
+			uri: None,
+			file_name: None,
+			content_type: None,
+			size: None,
+			state: None,
+		}
+	}
+}
+
+/// Chainable Setters
+impl FileRef {
+	pub fn with_uri(mut self, uri: impl Into<String>) -> Self {
+		self.uri = Some(uri.into());
+		self
+	}
+
+	pub fn with_content_type(mut self, content_type: impl Into<String>) -> Self {
+		self.content_type = Some(content_type.into());
+		self
+	}
+}
+
+/// Getters
+impl FileRef {
+	/// Returns `false` while the provider is still processing the file (e.g., Gemini video files),
+	/// in which case it cannot be referenced in a chat request yet.
+	///
+	/// NOTE: Use `Client::get_file(..)` to refresh the FileRef until it is ready.
+	pub fn is_ready(&self) -> bool {
+		match self.state.as_deref() {
+			None => true,
+			Some(state) => state.eq_ignore_ascii_case("ACTIVE"),
+		}
+	}
+
+	/// Returns `true` when the provider failed to process the file.
+	pub fn is_failed(&self) -> bool {
+		self.state.as_deref().is_some_and(|state| state.eq_ignore_ascii_case("FAILED"))
+	}
+}
+
+// endregion: --- FileRef
@@ -0,0 +1,47 @@
+//! This module contains the types related to a file upload request.
+
+use std::sync::Arc;
+
+// region:    --- FileUpload
+
+/// The file to upload with `Client::upload_file(..)`.
+#[derive(Debug, Clone)]
+pub struct FileUpload {
+	/// The file name (e.g., `report.pdf`), sent as the file name or display name depending on the provider.
+	pub file_name: String,
+
+	/// The mime type of the content (e.g., `application/pdf`).
+	pub content_type: String,
+
+	/// The raw bytes of the file.
+	///
+	/// NOTE: Arc to avoid cloning large content when the FileUpload is cloned.
+	pub content: Arc<[u8]>,
+
+	/// The purpose of the file (OpenAI only).
+	/// Default: `user_data` (which allows to reference the file from chat messages)
+	pub purpose: Option<String>,
+}
+
+/// Constructors
+impl FileUpload {
+	pub fn new(file_name: impl Into<String>, content_type: impl Into<String>, content: impl Into<Arc<[u8]>>) -> Self {
+		Self {
+			file_name: file_name.into(),
+			content_type: content_type.into(),
+			content: content.into(),
+			purpose: None,
+		}
+	}
+}
+
+/// Chainable Setters
+impl FileUpload {
+	/// Set the purpose of the file (OpenAI only, e.g., `user_data`, `assistants`, `batch`, `fine-tune`)
+	pub fn with_purpose(mut self, purpose: impl Into<String>) -> Self {
+		self.purpose = Some(purpose.into());
+		self
+	}
+}
+
+// endregion: --- FileUpload
@@ -0,0 +1,21 @@
+//! The genai files module contains the constructs to upload, list, retrieve, and delete
+//! provider files with the `genai::Client` (OpenAI, Anthropic, and Gemini for now).
+//!
+//! The returned `FileRef` is provider-scoped, and can be referenced from chat messages
+//! with `ContentPart::from_file(file_ref)`.
+//!
+//! Files can also be attached to a provider `VectorStore` (OpenAI only for now),
+//! to be searched in chat requests with `Tool::file_search(..)`.
+
+// region:    --- Modules
+
+mod file_ref;
+mod file_upload;
+mod vector_store;
+
+// -- Flatten
+pub use file_ref::*;
+pub use file_upload::*;
+pub use vector_store::*;
+
+// endregion: --- Modules
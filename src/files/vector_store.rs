@@ -0,0 +1,61 @@
+//! This module contains the types related to a provider vector store (used by the `file_search` tool).
+
+use crate::adapter::AdapterKind;
+use serde::{Deserialize, Serialize};
+
+// region:    --- VectorStore
+
+/// A vector store of provider files, searchable in chat requests with `Tool::file_search(..)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorStore {
+	/// The adapter kind of the provider which stores the vector store.
+	pub adapter_kind: AdapterKind,
+
+	/// The provider vector store id (e.g., `vs_...` for OpenAI).
+	pub id: String,
+
+	/// The eventual name of the vector store.
+	pub name: Option<String>,
+
+	/// The provider status (e.g., `in_progress`, `completed`, `expired` for OpenAI).
+	pub status: String,
+
+	/// The number of files processed and ready to be searched.
+	pub completed_file_count: u32,
+
+	/// The number of files still being processed.
+	pub in_progress_file_count: u32,
+
+	/// The number of files which failed to be processed.
+	pub failed_file_count: u32,
+}
+
+/// Getters
+impl VectorStore {
+	/// Returns true when all the files of the vector store have been processed (successfully or not).
+	pub fn is_ready(&self) -> bool {
+		self.in_progress_file_count == 0 && self.status != "in_progress"
+	}
+}
+
+// endregion: --- VectorStore
+
+// region:    --- VectorStoreFile
+
+/// A file attached to a vector store.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorStoreFile {
+	/// The provider file id.
+	pub file_id: String,
+
+	/// The vector store id.
+	pub vector_store_id: String,
+
+	/// The provider processing status (e.g., `in_progress`, `completed`, `failed` for OpenAI).
+	pub status: String,
+
+	/// The eventual processing error message.
+	pub error_message: Option<String>,
+}
+
+// endregion: --- VectorStoreFile
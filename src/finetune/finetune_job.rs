@@ -0,0 +1,79 @@
+//! This module contains the types related to a provider fine-tuning job.
+
+use crate::adapter::AdapterKind;
+use serde::{Deserialize, Serialize};
+
+// region:    --- FineTuneJob
+
+/// A fine-tuning job as reported by its provider.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FineTuneJob {
+	/// The adapter kind of the provider which runs the job.
+	pub adapter_kind: AdapterKind,
+
+	/// The provider job id (e.g., `ftjob-...` for OpenAI).
+	pub id: String,
+
+	/// The base model being fine-tuned.
+	pub model: String,
+
+	/// The status of the job.
+	pub status: FineTuneStatus,
+
+	/// The name of the resulting fine-tuned model, once the job succeeded.
+	/// This can be used directly as a genai model name.
+	pub fine_tuned_model: Option<String>,
+
+	/// The provider file id of the training file.
+	pub training_file_id: Option<String>,
+
+	/// The eventual creation time (unix timestamp in seconds).
+	pub created_at: Option<i64>,
+
+	/// The eventual error message when the job failed.
+	pub error_message: Option<String>,
+}
+
+/// Getters
+impl FineTuneJob {
+	/// Returns true when the job will not change anymore (succeeded, failed, or cancelled).
+	pub fn is_done(&self) -> bool {
+		matches!(
+			self.status,
+			FineTuneStatus::Succeeded | FineTuneStatus::Failed | FineTuneStatus::Cancelled
+		)
+	}
+}
+
+// endregion: --- FineTuneJob
+
+// region:    --- FineTuneStatus
+
+/// The status of a fine-tuning job.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FineTuneStatus {
+	ValidatingFiles,
+	Queued,
+	Running,
+	Succeeded,
+	Failed,
+	Cancelled,
+	/// A provider status not known by genai (the raw provider status).
+	Other(String),
+}
+
+impl From<&str> for FineTuneStatus {
+	fn from(status: &str) -> Self {
+		match status {
+			"validating_files" => Self::ValidatingFiles,
+			"queued" => Self::Queued,
+			"running" => Self::Running,
+			"succeeded" => Self::Succeeded,
+			"failed" => Self::Failed,
+			"cancelled" => Self::Cancelled,
+			other => Self::Other(other.to_string()),
+		}
+	}
+}
+
+// endregion: --- FineTuneStatus
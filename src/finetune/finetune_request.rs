@@ -0,0 +1,60 @@
+//! This module contains the types related to a fine-tuning job creation request.
+
+use serde::{Deserialize, Serialize};
+
+// region:    --- FineTuneRequest
+
+/// The fine-tuning job to create with `Client::create_finetune_job(..)`.
+///
+/// The training file must have been uploaded beforehand with `Client::upload_file(..)`
+/// (with `FileUpload::with_purpose("fine-tune")` for OpenAI).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FineTuneRequest {
+	/// The base model to fine-tune (e.g., `gpt-4o-mini-2024-07-18`).
+	pub model: String,
+
+	/// The provider file id of the training file (JSONL).
+	pub training_file_id: String,
+
+	/// The eventual provider file id of the validation file (JSONL).
+	pub validation_file_id: Option<String>,
+
+	/// The eventual suffix added to the fine-tuned model name (e.g., `ft:gpt-4o-mini:my-org:my-suffix:abc123`).
+	pub suffix: Option<String>,
+
+	/// The eventual number of epochs (provider default when `None`).
+	pub n_epochs: Option<u32>,
+}
+
+/// Constructors
+impl FineTuneRequest {
+	pub fn new(model: impl Into<String>, training_file_id: impl Into<String>) -> Self {
+		Self {
+			model: model.into(),
+			training_file_id: training_file_id.into(),
+			validation_file_id: None,
+			suffix: None,
+			n_epochs: None,
+		}
+	}
+}
+
+/// Chainable Setters
+impl FineTuneRequest {
+	pub fn with_validation_file_id(mut self, validation_file_id: impl Into<String>) -> Self {
+		self.validation_file_id = Some(validation_file_id.into());
+		self
+	}
+
+	pub fn with_suffix(mut self, suffix: impl Into<String>) -> Self {
+		self.suffix = Some(suffix.into());
+		self
+	}
+
+	pub fn with_n_epochs(mut self, n_epochs: u32) -> Self {
+		self.n_epochs = Some(n_epochs);
+		self
+	}
+}
+
+// endregion: --- FineTuneRequest
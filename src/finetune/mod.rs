@@ -0,0 +1,16 @@
+//! The genai finetune module contains the constructs to create, list, retrieve, and cancel
+//! provider fine-tuning jobs with the `genai::Client` (OpenAI only for now).
+//!
+//! The `fine_tuned_model` of a succeeded `FineTuneJob` (e.g., `ft:gpt-4o-mini-2024-07-18:my-org::abc123`)
+//! can be used directly as a genai model name.
+
+// region:    --- Modules
+
+mod finetune_job;
+mod finetune_request;
+
+// -- Flatten
+pub use finetune_job::*;
+pub use finetune_request::*;
+
+// endregion: --- Modules
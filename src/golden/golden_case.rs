@@ -0,0 +1,96 @@
+//! The `GoldenCase` is one stored transcript (the request and its golden output),
+//! and the `GoldenTolerance` is how a new output is compared to the golden output.
+
+use crate::Result;
+use crate::chat::ChatRequest;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// region:    --- GoldenCase
+
+/// A stored transcript: the chat request replayed through the app handler, and its golden (expected) output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoldenCase {
+	/// The case id (reported in the `GoldenReport`).
+	pub id: String,
+
+	/// The transcript replayed through the app handler.
+	pub chat_req: ChatRequest,
+
+	/// The golden output the new output is compared to.
+	pub golden_output: String,
+
+	/// The eventual tolerance of this case (the runner tolerance otherwise).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub tolerance: Option<GoldenTolerance>,
+
+	/// Free-form metadata of the app (e.g., the handler parameters).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub metadata: Option<Value>,
+}
+
+/// Constructors & Chainable Setters
+impl GoldenCase {
+	pub fn new(id: impl Into<String>, chat_req: ChatRequest, golden_output: impl Into<String>) -> Self {
+		Self {
+			id: id.into(),
+			chat_req,
+			golden_output: golden_output.into(),
+			tolerance: None,
+			metadata: None,
+		}
+	}
+
+	pub fn with_tolerance(mut self, tolerance: GoldenTolerance) -> Self {
+		self.tolerance = Some(tolerance);
+		self
+	}
+
+	pub fn with_metadata(mut self, metadata: Value) -> Self {
+		self.metadata = Some(metadata);
+		self
+	}
+}
+
+/// Public Functions
+impl GoldenCase {
+	/// Parse the cases of a JSONL content (one case per line, the blank lines are skipped).
+	pub fn from_jsonl(content: &str) -> Result<Vec<GoldenCase>> {
+		content
+			.lines()
+			.filter(|line| !line.trim().is_empty())
+			.map(|line| Ok(serde_json::from_str(line)?))
+			.collect()
+	}
+
+	/// Render the case as a single JSONL line (with the trailing `\n`).
+	pub fn to_jsonl_line(&self) -> Result<String> {
+		let mut line = serde_json::to_string(self)?;
+		line.push('\n');
+		Ok(line)
+	}
+}
+
+// endregion: --- GoldenCase
+
+// region:    --- GoldenTolerance
+
+/// How a new output is compared to the golden output.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub enum GoldenTolerance {
+	/// The same text.
+	Exact,
+
+	/// The same text, ignoring the case and the whitespace differences (default).
+	#[default]
+	Normalized,
+
+	/// The cosine similarity of the embeddings (with the embedding `model`) is at least the `threshold` (e.g., `0.9`).
+	EmbeddingSimilarity { model: String, threshold: f32 },
+
+	/// The judge `model` considers the output equivalent to the golden output,
+	/// with the eventual app `criteria` (e.g., "The numbers must be the same").
+	Judge { model: String, criteria: Option<String> },
+}
+
+// endregion: --- GoldenTolerance
@@ -0,0 +1,150 @@
+//! The `GoldenReport` is the pass/fail report of a `GoldenRunner::run(..)`, with the CI-friendly exports.
+
+use crate::chat::OperationUsage;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// region:    --- GoldenReport
+
+/// The report of a golden suite run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GoldenReport {
+	/// The result of each case, in the cases order.
+	pub results: Vec<GoldenResult>,
+
+	/// The aggregated usage of the comparisons (the embeddings and judge requests, not the app handler).
+	pub comparison_usage: OperationUsage,
+}
+
+/// The result of a golden case.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoldenResult {
+	pub case_id: String,
+
+	pub passed: bool,
+
+	/// The eventual similarity score in `0..=1` (1 or 0 for the exact comparisons, the cosine similarity,
+	/// or the judge score).
+	pub score: Option<f32>,
+
+	/// The new output of the app handler (None when the handler failed).
+	pub output: Option<String>,
+
+	pub golden_output: String,
+
+	/// The reason of the failure (the mismatch, the judge reason, or the handler / comparison error).
+	pub reason: Option<String>,
+
+	/// The duration of the app handler.
+	pub duration: Duration,
+}
+
+/// Getters
+impl GoldenReport {
+	/// Returns true when all the cases passed (and there is at least one case).
+	pub fn is_pass(&self) -> bool {
+		!self.results.is_empty() && self.results.iter().all(|result| result.passed)
+	}
+
+	pub fn passed_count(&self) -> usize {
+		self.results.iter().filter(|result| result.passed).count()
+	}
+
+	pub fn failed_count(&self) -> usize {
+		self.results.len() - self.passed_count()
+	}
+
+	pub fn failures(&self) -> impl Iterator<Item = &GoldenResult> {
+		self.results.iter().filter(|result| !result.passed)
+	}
+}
+
+/// Exports
+impl GoldenReport {
+	/// A plain text summary, one line per case and a total line, for example:
+	///
+	/// ```text
+	/// PASS  greeting (score 1.00)
+	/// FAIL  refund-policy (score 0.71): similarity below 0.90
+	/// 1 passed, 1 failed
+	/// ```
+	pub fn to_summary(&self) -> String {
+		let mut summary = String::new();
+		for result in &self.results {
+			let status = if result.passed { "PASS" } else { "FAIL" };
+			summary.push_str(&format!("{status}  {}", result.case_id));
+			if let Some(score) = result.score {
+				summary.push_str(&format!(" (score {score:.2})"));
+			}
+			if let Some(reason) = result.reason.as_deref().filter(|_| !result.passed) {
+				summary.push_str(&format!(": {}", reason.lines().next().unwrap_or_default()));
+			}
+			summary.push('\n');
+		}
+		summary.push_str(&format!(
+			"{} passed, {} failed\n",
+			self.passed_count(),
+			self.failed_count()
+		));
+		summary
+	}
+
+	/// A JUnit XML report (one `testcase` per case), as ingested by most CI systems.
+	pub fn to_junit_xml(&self, suite_name: &str) -> String {
+		let total_secs: f64 = self.results.iter().map(|result| result.duration.as_secs_f64()).sum();
+		let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+		xml.push_str(&format!(
+			"<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{total_secs:.3}\">\n",
+			xml_escape(suite_name),
+			self.results.len(),
+			self.failed_count()
+		));
+		for result in &self.results {
+			xml.push_str(&format!(
+				"  <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+				xml_escape(&result.case_id),
+				xml_escape(suite_name),
+				result.duration.as_secs_f64()
+			));
+			if result.passed {
+				xml.push_str("/>\n");
+				continue;
+			}
+			let reason = result.reason.as_deref().unwrap_or("output does not match the golden output");
+			xml.push_str(">\n");
+			xml.push_str(&format!(
+				"    <failure message=\"{}\">{}</failure>\n",
+				xml_escape(reason.lines().next().unwrap_or_default()),
+				xml_escape(&format!(
+					"{reason}\n\n--- golden\n{}\n\n--- output\n{}",
+					result.golden_output,
+					result.output.as_deref().unwrap_or_default()
+				))
+			));
+			xml.push_str("  </testcase>\n");
+		}
+		xml.push_str("</testsuite>\n");
+		xml
+	}
+}
+
+// endregion: --- GoldenReport
+
+// region:    --- Support
+
+fn xml_escape(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	for c in text.chars() {
+		match c {
+			'&' => escaped.push_str("&amp;"),
+			'<' => escaped.push_str("&lt;"),
+			'>' => escaped.push_str("&gt;"),
+			'"' => escaped.push_str("&quot;"),
+			'\'' => escaped.push_str("&apos;"),
+			c => escaped.push(c),
+		}
+	}
+	escaped
+}
+
+// endregion: --- Support
@@ -0,0 +1,213 @@
+//! The `GoldenRunner` replays the golden cases through an app handler, and compares the new outputs
+//! to the golden outputs (see `GoldenTolerance`).
+
+use crate::chat::{ChatMessage, ChatOptions, ChatRequest, JsonSpec, OperationUsage};
+use crate::golden::{GoldenCase, GoldenReport, GoldenResult, GoldenTolerance};
+use crate::{Client, Error, Result};
+use serde_json::{Value, json};
+use std::fmt::Display;
+use std::time::Instant;
+
+const JUDGE_SYSTEM: &str = "You compare a new output of an application to its golden (expected) output. \
+The new output is equivalent when it conveys the same information and intent as the golden output \
+(the wording may differ), and respects the eventual criteria.";
+
+// region:    --- GoldenRunner
+
+/// Replays the golden cases through an app handler, and compares the new outputs to the golden outputs.
+#[derive(Debug, Clone)]
+pub struct GoldenRunner {
+	client: Client,
+
+	/// The tolerance of the cases without their own tolerance.
+	tolerance: GoldenTolerance,
+}
+
+/// Constructors & Chainable Setters
+impl GoldenRunner {
+	/// Create a runner with the client of the comparisons (embeddings, judge), and the `Normalized` tolerance.
+	pub fn new(client: Client) -> Self {
+		Self {
+			client,
+			tolerance: GoldenTolerance::default(),
+		}
+	}
+
+	pub fn with_tolerance(mut self, tolerance: GoldenTolerance) -> Self {
+		self.tolerance = tolerance;
+		self
+	}
+}
+
+/// Public Functions
+impl GoldenRunner {
+	/// Replays each case through the app `handler` (which returns the new output of the case transcript),
+	/// and compares the new output to the golden output, one case at a time.
+	///
+	/// NOTE: A handler or comparison error fails the case (it does not stop the run).
+	pub async fn run<F, Fut, E>(&self, cases: &[GoldenCase], handler: F) -> GoldenReport
+	where
+		F: Fn(&GoldenCase) -> Fut,
+		Fut: Future<Output = core::result::Result<String, E>>,
+		E: Display,
+	{
+		let mut report = GoldenReport::default();
+
+		for case in cases {
+			let start = Instant::now();
+			let output = handler(case).await;
+			let duration = start.elapsed();
+
+			let mut result = GoldenResult {
+				case_id: case.id.clone(),
+				passed: false,
+				score: None,
+				output: None,
+				golden_output: case.golden_output.clone(),
+				reason: None,
+				duration,
+			};
+
+			match output {
+				Ok(output) => {
+					let tolerance = case.tolerance.as_ref().unwrap_or(&self.tolerance);
+					match self
+						.compare(tolerance, &case.golden_output, &output, &mut report.comparison_usage)
+						.await
+					{
+						Ok(comparison) => {
+							result.passed = comparison.passed;
+							result.score = comparison.score;
+							result.reason = comparison.reason;
+						}
+						Err(err) => result.reason = Some(format!("comparison error: {err}")),
+					}
+					result.output = Some(output);
+				}
+				Err(err) => result.reason = Some(format!("handler error: {err}")),
+			}
+
+			report.results.push(result);
+		}
+
+		report
+	}
+}
+
+// endregion: --- GoldenRunner
+
+// region:    --- Support
+
+struct Comparison {
+	passed: bool,
+	score: Option<f32>,
+	reason: Option<String>,
+}
+
+impl GoldenRunner {
+	async fn compare(
+		&self,
+		tolerance: &GoldenTolerance,
+		golden_output: &str,
+		output: &str,
+		usage: &mut OperationUsage,
+	) -> Result<Comparison> {
+		match tolerance {
+			GoldenTolerance::Exact => Ok(Comparison::from_equality(golden_output == output)),
+			GoldenTolerance::Normalized => Ok(Comparison::from_equality(
+				normalize_text(golden_output) == normalize_text(output),
+			)),
+			GoldenTolerance::EmbeddingSimilarity { model, threshold } => {
+				let embed_res = self
+					.client
+					.embed_batch(model, vec![golden_output.to_string(), output.to_string()], None)
+					.await?;
+				usage.add_usage(&embed_res.usage);
+				let [golden_vector, output_vector] = embed_res.vectors()[..] else {
+					return Err(Error::InvalidJsonResponseElement {
+						info: "embedding response should have two vectors",
+					});
+				};
+				let similarity = cosine_similarity(golden_vector, output_vector);
+				let passed = similarity >= *threshold;
+				Ok(Comparison {
+					passed,
+					score: Some(similarity),
+					reason: (!passed).then(|| format!("similarity {similarity:.2} below {threshold:.2}")),
+				})
+			}
+			GoldenTolerance::Judge { model, criteria } => {
+				self.judge(model, criteria.as_deref(), golden_output, output, usage).await
+			}
+		}
+	}
+
+	async fn judge(
+		&self,
+		model: &str,
+		criteria: Option<&str>,
+		golden_output: &str,
+		output: &str,
+		usage: &mut OperationUsage,
+	) -> Result<Comparison> {
+		let mut content =
+			format!("<golden_output>\n{golden_output}\n</golden_output>\n\n<new_output>\n{output}\n</new_output>");
+		if let Some(criteria) = criteria {
+			content.push_str(&format!("\n\n<criteria>\n{criteria}\n</criteria>"));
+		}
+		let chat_req = ChatRequest::default()
+			.with_system(JUDGE_SYSTEM)
+			.append_message(ChatMessage::user(content));
+		let verdict_spec = JsonSpec::new(
+			"golden_verdict",
+			json!({
+				"type": "object",
+				"properties": {
+					"equivalent": { "type": "boolean" },
+					"score": { "type": "number", "description": "The similarity, from 0 to 1" },
+					"reason": { "type": "string" }
+				},
+				"required": ["equivalent", "score", "reason"]
+			}),
+		);
+		let options = ChatOptions::default().with_response_format(verdict_spec);
+
+		let chat_res = self.client.exec_chat(model, chat_req, Some(&options)).await?;
+		usage.add_usage(&chat_res.usage);
+
+		let verdict: Value = serde_json::from_str(chat_res.first_text().unwrap_or_default().trim())?;
+		let passed = verdict.get("equivalent").and_then(Value::as_bool).unwrap_or(false);
+		Ok(Comparison {
+			passed,
+			score: verdict.get("score").and_then(Value::as_f64).map(|score| score as f32),
+			reason: verdict.get("reason").and_then(Value::as_str).map(String::from),
+		})
+	}
+}
+
+impl Comparison {
+	fn from_equality(equal: bool) -> Self {
+		Self {
+			passed: equal,
+			score: Some(if equal { 1. } else { 0. }),
+			reason: (!equal).then(|| "output differs from the golden output".to_string()),
+		}
+	}
+}
+
+/// The lowercase text with the whitespace runs collapsed to a single space.
+fn normalize_text(text: &str) -> String {
+	text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+	let dot: f32 = a.iter().zip(b).map(|(a, b)| a * b).sum();
+	let norm_a = a.iter().map(|v| v * v).sum::<f32>().sqrt();
+	let norm_b = b.iter().map(|v| v * v).sum::<f32>().sqrt();
+	if norm_a == 0. || norm_b == 0. {
+		return 0.;
+	}
+	dot / (norm_a * norm_b)
+}
+
+// endregion: --- Support
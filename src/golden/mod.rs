@@ -0,0 +1,20 @@
+//! The genai golden module is a regression suite runner for the downstream apps:
+//! the stored transcripts (`GoldenCase`) are replayed through an app-supplied handler,
+//! and the new outputs are compared to the golden outputs with a `GoldenTolerance`.
+//!
+//! - Record the cases with `GoldenCase::new(..)`, and store them as JSONL (`GoldenCase::to_jsonl_line()`).
+//! - Replay them with `GoldenRunner::run(..)`, which returns a `GoldenReport`.
+//! - Fail the CI with `GoldenReport::is_pass()`, and export the report with `to_summary()` or `to_junit_xml()`.
+
+// region:    --- Modules
+
+mod golden_case;
+mod golden_report;
+mod golden_runner;
+
+// -- Flatten
+pub use golden_case::*;
+pub use golden_report::*;
+pub use golden_runner::*;
+
+// endregion: --- Modules
@@ -18,7 +18,16 @@ pub use error::{Error, Result};
 pub mod adapter;
 pub mod chat;
 pub mod embed;
+pub mod files;
+pub mod finetune;
+pub mod golden;
+pub mod ollama;
+pub mod prompt;
+pub mod realtime;
 pub mod resolver;
+pub mod trace;
+pub mod usage_report;
+pub mod videogen;
 pub mod webc;
 
 // endregion: --- Modules
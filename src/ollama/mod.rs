@@ -0,0 +1,16 @@
+//! The genai ollama module contains the constructs of the Ollama model management API
+//! (pull, show, delete, ps) with the `genai::Client`, so local-model apps can manage their models
+//! with the same client they chat with.
+//!
+//! NOTE: These use the native Ollama API (`/api/...`) of the Ollama endpoint (default `http://localhost:11434/`).
+
+// region:    --- Modules
+
+mod ollama_model;
+mod ollama_pull;
+
+// -- Flatten
+pub use ollama_model::*;
+pub use ollama_pull::*;
+
+// endregion: --- Modules
@@ -0,0 +1,54 @@
+//! This module contains the types of the Ollama model information (`api/show` and `api/ps`).
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// region:    --- OllamaModelInfo
+
+/// The information of a local Ollama model, as returned by `Client::ollama_show(..)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OllamaModelInfo {
+	/// The Modelfile parameters (e.g., `num_ctx 4096`), one per line.
+	pub parameters: Option<String>,
+
+	/// The prompt template of the model.
+	pub template: Option<String>,
+
+	/// The model details (e.g., `family`, `parameter_size`, `quantization_level`).
+	pub details: Option<Value>,
+
+	/// The model architecture information (e.g., `llama.context_length`).
+	pub model_info: Option<Value>,
+
+	/// The model capabilities (e.g., `completion`, `tools`, `vision`, `embedding`).
+	#[serde(default)]
+	pub capabilities: Vec<String>,
+
+	pub modified_at: Option<String>,
+}
+
+// endregion: --- OllamaModelInfo
+
+// region:    --- OllamaRunningModel
+
+/// A model currently loaded in memory by the Ollama server, as returned by `Client::ollama_ps()`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OllamaRunningModel {
+	/// The model name (e.g., `llama3.2:latest`).
+	pub name: String,
+
+	pub digest: Option<String>,
+
+	/// The size in bytes of the loaded model.
+	pub size: Option<u64>,
+
+	/// The size in bytes of the model loaded in the GPU memory.
+	pub size_vram: Option<u64>,
+
+	/// When the model will be unloaded (RFC 3339 time).
+	pub expires_at: Option<String>,
+
+	pub details: Option<Value>,
+}
+
+// endregion: --- OllamaRunningModel
@@ -0,0 +1,138 @@
+//! This module contains the types of the Ollama model pull (`api/pull`) with its progress stream.
+
+use crate::webc::WebStream;
+use crate::{Error, ModelIden, Result};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use value_ext::JsonValueExt;
+
+// region:    --- OllamaPullProgress
+
+/// A progress event of an Ollama model pull.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OllamaPullProgress {
+	/// The pull status (e.g., `pulling manifest`, `pulling <digest>`, `verifying sha256 digest`, `success`).
+	pub status: String,
+
+	/// The digest of the layer being downloaded.
+	pub digest: Option<String>,
+
+	/// The total size in bytes of the layer being downloaded.
+	pub total: Option<u64>,
+
+	/// The size in bytes already downloaded for the layer.
+	pub completed: Option<u64>,
+}
+
+/// Getters
+impl OllamaPullProgress {
+	/// Returns true when this is the final `success` event of the pull.
+	pub fn is_success(&self) -> bool {
+		self.status == "success"
+	}
+
+	/// The completion ratio (0.0 to 1.0) of the layer being downloaded, when known.
+	pub fn ratio(&self) -> Option<f64> {
+		match (self.completed, self.total) {
+			(Some(completed), Some(total)) if total > 0 => Some(completed as f64 / total as f64),
+			_ => None,
+		}
+	}
+}
+
+// endregion: --- OllamaPullProgress
+
+// region:    --- OllamaPullStream
+
+/// The stream of the `OllamaPullProgress` events of a model pull, as returned by `Client::ollama_pull(..)`.
+///
+/// The stream ends after the `success` event, or with an error if the Ollama server reports one.
+pub struct OllamaPullStream {
+	inner: WebStream,
+	model_iden: ModelIden,
+	done: bool,
+}
+
+impl OllamaPullStream {
+	pub(crate) fn new(inner: WebStream, model_iden: ModelIden) -> Self {
+		Self {
+			inner,
+			model_iden,
+			done: false,
+		}
+	}
+}
+
+impl Stream for OllamaPullStream {
+	type Item = Result<OllamaPullProgress>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		if self.done {
+			return Poll::Ready(None);
+		}
+
+		loop {
+			let raw_message = match Pin::new(&mut self.inner).poll_next(cx) {
+				Poll::Ready(Some(Ok(raw_message))) => raw_message,
+				Poll::Ready(Some(Err(err))) => {
+					self.done = true;
+					return Poll::Ready(Some(Err(Error::WebStream {
+						model_iden: self.model_iden.clone(),
+						cause: err.to_string(),
+					})));
+				}
+				Poll::Ready(None) => {
+					self.done = true;
+					return Poll::Ready(None);
+				}
+				Poll::Pending => return Poll::Pending,
+			};
+
+			// -- Skip the eventual empty lines (trailing delimiter)
+			if raw_message.trim().is_empty() {
+				continue;
+			}
+
+			let mut message: Value = match serde_json::from_str(&raw_message) {
+				Ok(message) => message,
+				Err(serde_error) => {
+					self.done = true;
+					return Poll::Ready(Some(Err(Error::StreamParse {
+						model_iden: self.model_iden.clone(),
+						serde_error,
+					})));
+				}
+			};
+
+			// -- The Ollama server reports the pull errors as `{"error": "..."}` messages
+			if let Ok(error) = message.x_take::<String>("error") {
+				self.done = true;
+				return Poll::Ready(Some(Err(Error::WebStream {
+					model_iden: self.model_iden.clone(),
+					cause: error,
+				})));
+			}
+
+			let progress = match serde_json::from_value::<OllamaPullProgress>(message) {
+				Ok(progress) => progress,
+				Err(serde_error) => {
+					self.done = true;
+					return Poll::Ready(Some(Err(Error::StreamParse {
+						model_iden: self.model_iden.clone(),
+						serde_error,
+					})));
+				}
+			};
+			if progress.is_success() {
+				self.done = true;
+			}
+
+			return Poll::Ready(Some(Ok(progress)));
+		}
+	}
+}
+
+// endregion: --- OllamaPullStream
@@ -0,0 +1,16 @@
+//! The genai prompt module renders the chat requests from the prompt templates,
+//! instead of formatting the prompt strings ad hoc in the app code.
+//!
+//! - Create the template with `PromptTemplate::new(..)` (the user message), and add the system
+//!   and the eventual few-shot messages with `with_system(..)`, `append_user(..)`, and `append_assistant(..)`.
+//! - Reference the variables with `{{name}}`, and the shared snippets (`with_partial(..)`) with `{{> name}}`.
+//! - Render the `ChatRequest` with `PromptTemplate::render(..)`.
+
+// region:    --- Modules
+
+mod prompt_template;
+
+// -- Flatten
+pub use prompt_template::*;
+
+// endregion: --- Modules
@@ -0,0 +1,152 @@
+//! The `PromptTemplate`, the system and message templates rendered into a `ChatRequest`.
+
+use crate::chat::{ChatMessage, ChatRequest, ChatRole};
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The max nesting of the partials (a partial can reference other partials), to catch the recursive partials.
+const MAX_PARTIAL_DEPTH: usize = 8;
+
+// region:    --- PromptTemplate
+
+/// The prompt of a chat request, with the `{{name}}` variables and the `{{> name}}` partials
+/// replaced at the `render(..)`.
+///
+/// ```
+/// use genai::prompt::PromptTemplate;
+///
+/// let template = PromptTemplate::new("Translate to {{lang}}:\n{{text}}")
+///     .with_system("You are a translator. {{> tone}}")
+///     .with_partial("tone", "Keep the tone of the original text.");
+/// let chat_req = template.render([("lang", "French"), ("text", "Good morning")])?;
+/// # Ok::<(), genai::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PromptTemplate {
+	/// The eventual system template (rendered as the `ChatRequest.system`).
+	system: Option<String>,
+
+	/// The message templates, in order (rendered as the `ChatRequest.messages`).
+	messages: Vec<PromptMessage>,
+
+	/// The named snippets, referenced with `{{> name}}` in the other templates.
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	partials: HashMap<String, String>,
+}
+
+/// A message template of the `PromptTemplate`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PromptMessage {
+	role: ChatRole,
+	template: String,
+}
+
+/// Constructors & Chainable Setters
+impl PromptTemplate {
+	/// A prompt template with the user message template.
+	pub fn new(user_template: impl Into<String>) -> Self {
+		Self::default().append_user(user_template)
+	}
+
+	/// Set the system template.
+	pub fn with_system(mut self, system_template: impl Into<String>) -> Self {
+		self.system = Some(system_template.into());
+		self
+	}
+
+	/// Append a user message template (e.g., for the few-shot examples).
+	pub fn append_user(self, user_template: impl Into<String>) -> Self {
+		self.append_message(ChatRole::User, user_template)
+	}
+
+	/// Append an assistant message template (e.g., for the few-shot examples).
+	pub fn append_assistant(self, assistant_template: impl Into<String>) -> Self {
+		self.append_message(ChatRole::Assistant, assistant_template)
+	}
+
+	/// Add a named partial, referenced with `{{> name}}` in the system and the message templates.
+	pub fn with_partial(mut self, name: impl Into<String>, template: impl Into<String>) -> Self {
+		self.partials.insert(name.into(), template.into());
+		self
+	}
+
+	fn append_message(mut self, role: ChatRole, template: impl Into<String>) -> Self {
+		self.messages.push(PromptMessage {
+			role,
+			template: template.into(),
+		});
+		self
+	}
+}
+
+/// Renderers
+impl PromptTemplate {
+	/// Render the chat request, with the variables of the `{{name}}` tags.
+	///
+	/// Returns `Error::PromptTemplateRender` when a variable has no value, a partial is not found,
+	/// or a tag is not closed.
+	pub fn render<I, K, V>(&self, vars: I) -> Result<ChatRequest>
+	where
+		I: IntoIterator<Item = (K, V)>,
+		K: Into<String>,
+		V: Into<String>,
+	{
+		let vars: HashMap<String, String> = vars.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+
+		let mut chat_req = ChatRequest::default();
+		if let Some(system) = &self.system {
+			chat_req = chat_req.with_system(self.render_template(system, &vars, 0)?);
+		}
+		for message in &self.messages {
+			chat_req = chat_req.append_message(ChatMessage {
+				role: message.role.clone(),
+				content: self.render_template(&message.template, &vars, 0)?.into(),
+				options: None,
+			});
+		}
+
+		Ok(chat_req)
+	}
+
+	fn render_template(&self, template: &str, vars: &HashMap<String, String>, depth: usize) -> Result<String> {
+		let render_error = |cause: String| Error::PromptTemplateRender { cause };
+
+		let mut rendered = String::with_capacity(template.len());
+		let mut rest = template;
+		while let Some(start) = rest.find("{{") {
+			rendered.push_str(&rest[..start]);
+			let after = &rest[start + 2..];
+			let end = after
+				.find("}}")
+				.ok_or_else(|| render_error(format!("Tag '{{{{' not closed in '{template}'")))?;
+			let tag = after[..end].trim();
+
+			if let Some(partial_name) = tag.strip_prefix('>') {
+				let partial_name = partial_name.trim();
+				if depth >= MAX_PARTIAL_DEPTH {
+					return Err(render_error(format!(
+						"Partial '{partial_name}' nested more than {MAX_PARTIAL_DEPTH} levels (recursive partial?)"
+					)));
+				}
+				let partial = self
+					.partials
+					.get(partial_name)
+					.ok_or_else(|| render_error(format!("Partial '{partial_name}' not found")))?;
+				rendered.push_str(&self.render_template(partial, vars, depth + 1)?);
+			} else {
+				let value = vars
+					.get(tag)
+					.ok_or_else(|| render_error(format!("Variable '{tag}' has no value")))?;
+				rendered.push_str(value);
+			}
+
+			rest = &after[end + 2..];
+		}
+		rendered.push_str(rest);
+
+		Ok(rendered)
+	}
+}
+
+// endregion: --- PromptTemplate
@@ -0,0 +1,22 @@
+//! The genai realtime module contains the constructs to open a bidirectional realtime session
+//! (WebSocket) with the `genai::Client` (OpenAI Realtime API and Gemini Live API), typically for voice agents.
+//!
+//! - `Client::realtime_connect(model, config)` returns a `RealtimeSession`.
+//! - `RealtimeSession::send(RealtimeInput)` sends text, audio chunks, video frames, tool responses, ...
+//! - `RealtimeSession::next_event()` returns the normalized `RealtimeEvent` (text/audio deltas, tool calls, ...).
+//! - `RealtimeSession::split()` allows to send and receive concurrently.
+
+// region:    --- Modules
+
+mod realtime_config;
+mod realtime_event;
+mod realtime_input;
+mod realtime_session;
+
+// -- Flatten
+pub use realtime_config::*;
+pub use realtime_event::*;
+pub use realtime_input::*;
+pub use realtime_session::*;
+
+// endregion: --- Modules
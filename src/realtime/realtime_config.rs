@@ -0,0 +1,122 @@
+//! This module contains the realtime session configuration.
+
+use crate::chat::Tool;
+use serde::{Deserialize, Serialize};
+
+// region:    --- RealtimeConfig
+
+/// The realtime session configuration, sent when connecting with `Client::realtime_connect(..)`,
+/// or later with `RealtimeInput::SessionUpdate(..)`.
+///
+/// All properties are optional, and `None` means the provider default.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RealtimeConfig {
+	/// The system instructions of the session.
+	pub instructions: Option<String>,
+
+	/// The response output modality (audio or text).
+	pub output_modality: Option<RealtimeModality>,
+
+	/// The provider voice name (e.g., `marin` or `alloy` for OpenAI).
+	pub voice: Option<String>,
+
+	/// The input and output audio format.
+	/// NOTE: Ignored for Gemini Live (16kHz PCM input, 24kHz PCM output).
+	pub audio_format: Option<RealtimeAudioFormat>,
+
+	/// The turn detection mode (when `Manual`, the input audio must be committed with `RealtimeInput::AudioCommit`).
+	pub turn_detection: Option<TurnDetection>,
+
+	/// The eventual model used to transcribe the input audio (e.g., `whisper-1` for OpenAI, any value for Gemini).
+	/// When set, the `RealtimeEvent::InputTranscript` events will be received.
+	pub input_transcription_model: Option<String>,
+
+	/// The tools (functions) available to the model.
+	pub tools: Option<Vec<Tool>>,
+
+	/// The maximum number of output tokens of a response.
+	pub max_output_tokens: Option<u32>,
+}
+
+/// Chainable Setters
+impl RealtimeConfig {
+	pub fn with_instructions(mut self, instructions: impl Into<String>) -> Self {
+		self.instructions = Some(instructions.into());
+		self
+	}
+
+	pub fn with_output_modality(mut self, output_modality: RealtimeModality) -> Self {
+		self.output_modality = Some(output_modality);
+		self
+	}
+
+	pub fn with_voice(mut self, voice: impl Into<String>) -> Self {
+		self.voice = Some(voice.into());
+		self
+	}
+
+	pub fn with_audio_format(mut self, audio_format: RealtimeAudioFormat) -> Self {
+		self.audio_format = Some(audio_format);
+		self
+	}
+
+	pub fn with_turn_detection(mut self, turn_detection: TurnDetection) -> Self {
+		self.turn_detection = Some(turn_detection);
+		self
+	}
+
+	pub fn with_input_transcription_model(mut self, model: impl Into<String>) -> Self {
+		self.input_transcription_model = Some(model.into());
+		self
+	}
+
+	pub fn with_tools(mut self, tools: Vec<Tool>) -> Self {
+		self.tools = Some(tools);
+		self
+	}
+
+	pub fn append_tool(mut self, tool: impl Into<Tool>) -> Self {
+		self.tools.get_or_insert_with(Vec::new).push(tool.into());
+		self
+	}
+
+	pub fn with_max_output_tokens(mut self, max_output_tokens: u32) -> Self {
+		self.max_output_tokens = Some(max_output_tokens);
+		self
+	}
+}
+
+// endregion: --- RealtimeConfig
+
+// region:    --- RealtimeConfig Types
+
+/// The realtime response output modality.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RealtimeModality {
+	Audio,
+	Text,
+}
+
+/// The realtime audio format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RealtimeAudioFormat {
+	/// 16-bit PCM, mono, little-endian, with the sample rate (OpenAI supports only 24000).
+	Pcm16 { rate: u32 },
+	/// G.711 μ-law (8kHz)
+	G711Ulaw,
+	/// G.711 A-law (8kHz)
+	G711Alaw,
+}
+
+/// The realtime turn detection mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TurnDetection {
+	/// Voice activity detection based on the audio volume (silence).
+	ServerVad,
+	/// Voice activity detection based on the semantic of the speech (when supported).
+	SemanticVad,
+	/// No turn detection. The input audio must be committed, and the responses created, by the caller.
+	Manual,
+}
+
+// endregion: --- RealtimeConfig Types
@@ -0,0 +1,44 @@
+//! This module contains the `RealtimeEvent`, the normalized events received from a realtime session.
+
+use crate::chat::{ToolCall, Usage};
+use serde_json::Value;
+
+/// The normalized event received from a realtime session.
+#[derive(Debug, Clone)]
+pub enum RealtimeEvent {
+	/// The session was created (the first event).
+	SessionCreated,
+
+	/// The session configuration was updated.
+	SessionUpdated,
+
+	/// The user started speaking (with server turn detection).
+	SpeechStarted,
+
+	/// The user stopped speaking (with server turn detection).
+	SpeechStopped,
+
+	/// The transcript of the user input audio (with `RealtimeConfig.input_transcription_model`).
+	InputTranscript(String),
+
+	/// A text delta of the response (text output modality).
+	TextDelta(String),
+
+	/// An audio delta of the response (raw bytes in the session audio format).
+	AudioDelta(Vec<u8>),
+
+	/// A transcript delta of the response audio.
+	AudioTranscriptDelta(String),
+
+	/// A complete tool call of the response. Answer it with `RealtimeInput::ToolResponse(..)`.
+	ToolCall(ToolCall),
+
+	/// The response is done, with its eventual usage.
+	ResponseDone { usage: Usage },
+
+	/// An error event from the provider (the session stays open).
+	Error { message: String, code: Option<String> },
+
+	/// Any other provider event, not normalized by genai (the raw provider message).
+	Other(Value),
+}
@@ -0,0 +1,53 @@
+//! This module contains the `RealtimeInput`, the normalized messages sent to a realtime session.
+
+use crate::chat::ToolResponse;
+use crate::realtime::RealtimeConfig;
+
+/// The normalized input sent to a realtime session with `RealtimeSession::send(..)`.
+#[derive(Debug, Clone)]
+pub enum RealtimeInput {
+	/// Add a user text message to the conversation.
+	/// NOTE: This does not trigger a response, use `CreateResponse` after it.
+	Text(String),
+
+	/// Append a chunk of input audio (raw bytes in the session audio format) to the input audio buffer.
+	/// NOTE: For Gemini Live, the input audio must be 16-bit PCM at 16kHz.
+	AudioChunk(Vec<u8>),
+
+	/// Send a video frame (e.g., a `image/jpeg` camera or screen frame), when supported (Gemini Live).
+	VideoFrame { content_type: String, data: Vec<u8> },
+
+	/// Commit the input audio buffer as a user message (only needed with `TurnDetection::Manual`).
+	/// NOTE: For Gemini Live, this flushes the cached input audio (e.g., when the microphone is paused).
+	AudioCommit,
+
+	/// Add the response of a `RealtimeEvent::ToolCall` to the conversation.
+	/// NOTE: As for `Text`, use `CreateResponse` after it to get the model answer.
+	ToolResponse(ToolResponse),
+
+	/// Ask the model to create a response from the conversation.
+	CreateResponse,
+
+	/// Cancel the in-progress response (e.g., when the user interrupts).
+	/// NOTE: No-op for Gemini Live, which interrupts the response automatically on user activity.
+	CancelResponse,
+
+	/// Update the session configuration (not supported by Gemini Live, where the config is set on connect).
+	SessionUpdate(RealtimeConfig),
+}
+
+// region:    --- Froms
+
+impl From<ToolResponse> for RealtimeInput {
+	fn from(tool_response: ToolResponse) -> Self {
+		RealtimeInput::ToolResponse(tool_response)
+	}
+}
+
+impl From<RealtimeConfig> for RealtimeInput {
+	fn from(config: RealtimeConfig) -> Self {
+		RealtimeInput::SessionUpdate(config)
+	}
+}
+
+// endregion: --- Froms
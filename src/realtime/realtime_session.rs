@@ -0,0 +1,157 @@
+//! This module contains the `RealtimeSession`, and its `RealtimeSender` and `RealtimeEventStream` halves.
+
+use crate::adapter::AdapterDispatcher;
+use crate::realtime::{RealtimeEvent, RealtimeInput};
+use crate::webc::{WebSocket, WebSocketReceiver, WebSocketSender};
+use crate::{Error, ModelIden, Result};
+use futures::{Stream, StreamExt};
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+// region:    --- RealtimeSession
+
+/// A bidirectional realtime session, returned by `Client::realtime_connect(..)`.
+///
+/// Use `split()` to send the inputs (e.g., microphone audio chunks) and receive the events concurrently.
+pub struct RealtimeSession {
+	model_iden: ModelIden,
+	sender: RealtimeSender,
+	events: RealtimeEventStream,
+}
+
+impl RealtimeSession {
+	pub(crate) fn new(model_iden: ModelIden, web_socket: WebSocket) -> Self {
+		let (ws_sender, ws_receiver) = web_socket.split();
+		Self {
+			model_iden: model_iden.clone(),
+			sender: RealtimeSender {
+				model_iden: model_iden.clone(),
+				ws_sender,
+			},
+			events: RealtimeEventStream {
+				model_iden,
+				ws_receiver,
+				pending: VecDeque::new(),
+			},
+		}
+	}
+}
+
+impl RealtimeSession {
+	/// The resolved model identifier of this session.
+	pub fn model_iden(&self) -> &ModelIden {
+		&self.model_iden
+	}
+
+	/// Send an input (text, audio chunk, tool response, ...) to the session.
+	pub async fn send(&mut self, input: impl Into<RealtimeInput>) -> Result<()> {
+		self.sender.send(input).await
+	}
+
+	/// Send an already provider formatted message (e.g., the session configuration when connecting).
+	pub(crate) async fn send_raw(&mut self, message: &Value) -> Result<()> {
+		self.sender.send_raw(message).await
+	}
+
+	/// Returns the next event of the session, or None when the session is closed.
+	pub async fn next_event(&mut self) -> Option<Result<RealtimeEvent>> {
+		self.events.next().await
+	}
+
+	/// Close the session.
+	pub async fn close(mut self) -> Result<()> {
+		self.sender.close().await
+	}
+
+	/// Split the session into its sender and event stream halves.
+	pub fn split(self) -> (RealtimeSender, RealtimeEventStream) {
+		(self.sender, self.events)
+	}
+}
+
+// endregion: --- RealtimeSession
+
+// region:    --- RealtimeSender
+
+/// The sending half of a `RealtimeSession`.
+pub struct RealtimeSender {
+	model_iden: ModelIden,
+	ws_sender: WebSocketSender,
+}
+
+impl RealtimeSender {
+	/// Send an input (text, audio chunk, tool response, ...) to the session.
+	pub async fn send(&mut self, input: impl Into<RealtimeInput>) -> Result<()> {
+		let messages = AdapterDispatcher::to_realtime_messages(&self.model_iden, input.into())?;
+		for message in messages {
+			self.send_raw(&message).await?;
+		}
+		Ok(())
+	}
+
+	/// Send an already provider formatted message.
+	pub(crate) async fn send_raw(&mut self, message: &Value) -> Result<()> {
+		self.ws_sender
+			.send_json(message)
+			.await
+			.map_err(|webc_error| Error::WebModelCall {
+				model_iden: self.model_iden.clone(),
+				webc_error,
+			})
+	}
+
+	/// Close the session.
+	pub async fn close(&mut self) -> Result<()> {
+		self.ws_sender.close().await.map_err(|webc_error| Error::WebModelCall {
+			model_iden: self.model_iden.clone(),
+			webc_error,
+		})
+	}
+}
+
+// endregion: --- RealtimeSender
+
+// region:    --- RealtimeEventStream
+
+/// The receiving half of a `RealtimeSession`, a Rust Future Stream of `RealtimeEvent`.
+pub struct RealtimeEventStream {
+	model_iden: ModelIden,
+	ws_receiver: WebSocketReceiver,
+	/// Some provider messages contain more than one event (e.g., text and turn complete)
+	pending: VecDeque<RealtimeEvent>,
+}
+
+impl Stream for RealtimeEventStream {
+	type Item = Result<RealtimeEvent>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let this = self.get_mut();
+
+		loop {
+			if let Some(event) = this.pending.pop_front() {
+				return Poll::Ready(Some(Ok(event)));
+			}
+
+			match Pin::new(&mut this.ws_receiver).poll_next(cx) {
+				Poll::Ready(Some(Ok(message))) => {
+					match AdapterDispatcher::to_realtime_events(&this.model_iden, message) {
+						Ok(events) => this.pending.extend(events),
+						Err(err) => return Poll::Ready(Some(Err(err))),
+					}
+				}
+				Poll::Ready(Some(Err(webc_error))) => {
+					return Poll::Ready(Some(Err(Error::WebModelCall {
+						model_iden: this.model_iden.clone(),
+						webc_error,
+					})));
+				}
+				Poll::Ready(None) => return Poll::Ready(None),
+				Poll::Pending => return Poll::Pending,
+			}
+		}
+	}
+}
+
+// endregion: --- RealtimeEventStream
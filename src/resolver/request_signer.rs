@@ -0,0 +1,120 @@
+use crate::resolver::Result;
+use crate::{Headers, ModelIden};
+use std::sync::Arc;
+
+// region:    --- RequestSigner
+
+/// A RequestSigner is called right before a request is sent, with the final url, headers, and body bytes,
+/// and returns the headers to add to the request (e.g., an HMAC body signature required by an enterprise gateway).
+///
+/// NOTE: When a RequestSigner is set, the body is serialized once and the exact signed bytes are sent.
+#[derive(Debug, Clone)]
+pub enum RequestSigner {
+	/// The variant that holds the signer function
+	SignerFn(Arc<Box<dyn RequestSignerFn>>),
+}
+
+impl RequestSigner {
+	/// Create a new `RequestSigner` from a signer function.
+	pub fn from_signer_fn(signer_fn: impl IntoRequestSignerFn) -> Self {
+		RequestSigner::SignerFn(signer_fn.into_signer_fn())
+	}
+}
+
+impl RequestSigner {
+	pub(crate) fn sign(&self, request: SignableRequest<'_>) -> Result<Headers> {
+		match self {
+			RequestSigner::SignerFn(signer_fn) => signer_fn.exec_fn(request),
+		}
+	}
+}
+
+// endregion: --- RequestSigner
+
+// region:    --- SignableRequest
+
+/// The final request data given to the `RequestSigner`.
+#[derive(Debug, Clone, Copy)]
+pub struct SignableRequest<'a> {
+	/// The resolved model identifier of the request.
+	pub model_iden: &'a ModelIden,
+
+	/// The HTTP method (e.g., `POST`).
+	pub method: &'a str,
+
+	/// The final url of the request.
+	pub url: &'a str,
+
+	/// The final headers of the request (without the headers returned by the signer).
+	pub headers: &'a Headers,
+
+	/// The exact body bytes that will be sent.
+	pub body: &'a [u8],
+}
+
+// endregion: --- SignableRequest
+
+// region:    --- RequestSignerFn
+
+/// The `RequestSignerFn` trait object.
+pub trait RequestSignerFn: Send + Sync {
+	/// Execute the `RequestSignerFn` to obtain the headers to add to the request.
+	fn exec_fn(&self, request: SignableRequest<'_>) -> Result<Headers>;
+
+	/// Clone the trait object into a boxed dynamic object.
+	fn clone_box(&self) -> Box<dyn RequestSignerFn>;
+}
+
+// Implement RequestSignerFn for any `Fn`
+impl<F> RequestSignerFn for F
+where
+	F: Fn(SignableRequest<'_>) -> Result<Headers> + Send + Sync + Clone + 'static,
+{
+	fn exec_fn(&self, request: SignableRequest<'_>) -> Result<Headers> {
+		self(request)
+	}
+
+	fn clone_box(&self) -> Box<dyn RequestSignerFn> {
+		Box::new(self.clone())
+	}
+}
+
+// Implement Clone for Box<dyn RequestSignerFn>
+impl Clone for Box<dyn RequestSignerFn> {
+	fn clone(&self) -> Box<dyn RequestSignerFn> {
+		self.clone_box()
+	}
+}
+
+impl std::fmt::Debug for dyn RequestSignerFn {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "RequestSignerFn")
+	}
+}
+
+// endregion: --- RequestSignerFn
+
+// region:    --- IntoRequestSignerFn
+
+/// Implement IntoRequestSignerFn for closures used as arguments in `RequestSigner::from_signer_fn`.
+pub trait IntoRequestSignerFn {
+	/// Convert the given closure into a `RequestSignerFn` trait object.
+	fn into_signer_fn(self) -> Arc<Box<dyn RequestSignerFn>>;
+}
+
+impl IntoRequestSignerFn for Arc<Box<dyn RequestSignerFn>> {
+	fn into_signer_fn(self) -> Arc<Box<dyn RequestSignerFn>> {
+		self
+	}
+}
+
+impl<F> IntoRequestSignerFn for F
+where
+	F: Fn(SignableRequest<'_>) -> Result<Headers> + Send + Sync + Clone + 'static,
+{
+	fn into_signer_fn(self) -> Arc<Box<dyn RequestSignerFn>> {
+		Arc::new(Box::new(self))
+	}
+}
+
+// endregion: --- IntoRequestSignerFn
@@ -0,0 +1,344 @@
+//! The `Anonymizer` replaces the PII of the trace records with stable placeholders (e.g., `<EMAIL_1>`),
+//! so that production conversations can be shared as a dataset (e.g., for eval sets),
+//! while the placeholder-to-original mapping is kept separately.
+
+use crate::trace::{TraceFormat, TraceRecord};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::Write;
+
+/// The minimum and maximum number of digits of a phone number (E.164 max is 15).
+const PHONE_MIN_DIGITS: usize = 9;
+const PHONE_MAX_DIGITS: usize = 15;
+
+// region:    --- Anonymizer
+
+/// Replaces the PII of trace records (message contents, tool call arguments, errors, metadata)
+/// with placeholders which are consistent across the whole dataset (the same email is always `<EMAIL_1>`),
+/// so that the anonymized conversations stay coherent.
+///
+/// Detected:
+/// - Emails and phone numbers (when enabled, default true).
+/// - The registered terms (e.g., customer names, account ids) with `append_term(..)`.
+///
+/// NOTE: This is a best-effort, pattern-based detection. The anonymized dataset should still be reviewed
+///       before being shared outside of the team.
+#[derive(Debug, Clone)]
+pub struct Anonymizer {
+	/// Detect the email addresses (default true).
+	pub detect_emails: bool,
+
+	/// Detect the phone numbers (default true): 9 to 15 digits, starting with `+` or with separators
+	/// (spaces, dashes, dots, parentheses), so that the plain digit runs (e.g., order numbers) and the dates are not matched.
+	pub detect_phones: bool,
+
+	/// The registered terms and their kind (e.g., `("Alice Martin", "NAME")`), replaced verbatim.
+	pub terms: Vec<(String, String)>,
+
+	/// The placeholders assigned so far (original -> entry), to keep them stable across `anonymize(..)` calls.
+	mapping: HashMap<String, AnonymizationEntry>,
+	/// The next placeholder index per kind.
+	counters: HashMap<String, usize>,
+}
+
+impl Default for Anonymizer {
+	fn default() -> Self {
+		Self {
+			detect_emails: true,
+			detect_phones: true,
+			terms: Vec::new(),
+			mapping: HashMap::new(),
+			counters: HashMap::new(),
+		}
+	}
+}
+
+/// Constructors & Chainable Setters
+impl Anonymizer {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn with_detect_emails(mut self, value: bool) -> Self {
+		self.detect_emails = value;
+		self
+	}
+
+	pub fn with_detect_phones(mut self, value: bool) -> Self {
+		self.detect_phones = value;
+		self
+	}
+
+	/// Register a term to replace, with its kind used in the placeholder (e.g., `NAME` for `<NAME_1>`).
+	pub fn append_term(mut self, term: impl Into<String>, kind: impl Into<String>) -> Self {
+		self.terms.push((term.into(), kind.into().to_uppercase()));
+		self
+	}
+}
+
+/// Public Functions
+impl Anonymizer {
+	/// Anonymize the records, and return them with the mapping of the placeholders.
+	///
+	/// NOTE: The placeholders stay stable across calls on the same Anonymizer,
+	///       so a large dataset can be anonymized in batches.
+	pub fn anonymize<'a>(&mut self, records: impl IntoIterator<Item = &'a TraceRecord>) -> AnonymizedExport {
+		let records = records.into_iter().map(|record| self.anonymize_record(record)).collect();
+
+		let mut entries: Vec<AnonymizationEntry> = self.mapping.values().cloned().collect();
+		entries.sort_by(|a, b| a.kind.cmp(&b.kind).then(a.index.cmp(&b.index)));
+
+		AnonymizedExport {
+			records,
+			mapping: AnonymizationMapping { entries },
+		}
+	}
+
+	/// Anonymize a single text.
+	pub fn anonymize_text(&mut self, text: &str) -> String {
+		// -- Collect the (start, end, kind) spans of the PII
+		let mut spans: Vec<(usize, usize, String)> = Vec::new();
+		for (term, kind) in self.terms.iter().filter(|(term, _)| !term.is_empty()) {
+			spans.extend(
+				text.match_indices(term.as_str())
+					.map(|(idx, m)| (idx, idx + m.len(), kind.clone())),
+			);
+		}
+		if self.detect_emails {
+			spans.extend(
+				find_emails(text)
+					.into_iter()
+					.map(|(start, end)| (start, end, "EMAIL".to_string())),
+			);
+		}
+		if self.detect_phones {
+			spans.extend(
+				find_phones(text)
+					.into_iter()
+					.map(|(start, end)| (start, end, "PHONE".to_string())),
+			);
+		}
+
+		// -- Keep the first (then longest) of the overlapping spans
+		spans.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+		let mut result = String::with_capacity(text.len());
+		let mut cursor = 0;
+		for (start, end, kind) in spans {
+			if start < cursor {
+				continue;
+			}
+			result.push_str(&text[cursor..start]);
+			result.push_str(&self.placeholder(&text[start..end], &kind));
+			cursor = end;
+		}
+		result.push_str(&text[cursor..]);
+		result
+	}
+}
+
+/// Support
+impl Anonymizer {
+	fn anonymize_record(&mut self, record: &TraceRecord) -> TraceRecord {
+		let mut record = record.clone();
+
+		for msg in record.input_messages.iter_mut().chain(record.output_messages.iter_mut()) {
+			if let Some(content) = msg.content.as_mut() {
+				*content = self.anonymize_text(content);
+			}
+			for tool_call in msg.tool_calls.iter_mut() {
+				self.anonymize_value(&mut tool_call.fn_arguments);
+			}
+		}
+		if let Some(error) = record.error.as_mut() {
+			*error = self.anonymize_text(error);
+		}
+		if let Some(metadata) = record.metadata.as_mut() {
+			self.anonymize_value(metadata);
+		}
+
+		record
+	}
+
+	/// Anonymize all the string values of a JSON value (e.g., tool call arguments).
+	fn anonymize_value(&mut self, value: &mut Value) {
+		match value {
+			Value::String(text) => *text = self.anonymize_text(text),
+			Value::Array(items) => items.iter_mut().for_each(|item| self.anonymize_value(item)),
+			Value::Object(map) => map.values_mut().for_each(|item| self.anonymize_value(item)),
+			_ => (),
+		}
+	}
+
+	fn placeholder(&mut self, original: &str, kind: &str) -> String {
+		if let Some(entry) = self.mapping.get(original) {
+			return entry.placeholder.clone();
+		}
+
+		let index = self.counters.entry(kind.to_string()).or_insert(0);
+		*index += 1;
+		let entry = AnonymizationEntry {
+			placeholder: format!("<{kind}_{index}>"),
+			kind: kind.to_string(),
+			index: *index,
+			original: original.to_string(),
+		};
+		let placeholder = entry.placeholder.clone();
+		self.mapping.insert(original.to_string(), entry);
+		placeholder
+	}
+}
+
+// endregion: --- Anonymizer
+
+// region:    --- AnonymizedExport
+
+/// The result of `Anonymizer::anonymize(..)`: the shareable records, and the mapping to keep separately.
+#[derive(Debug, Clone)]
+pub struct AnonymizedExport {
+	/// The anonymized records (safe to share).
+	pub records: Vec<TraceRecord>,
+
+	/// The placeholder-to-original mapping (sensitive, must NOT be shared with the records).
+	pub mapping: AnonymizationMapping,
+}
+
+/// Exports
+impl AnonymizedExport {
+	/// Write the anonymized records as JSONL to the writer (see `TraceRecord::write_jsonl(..)`).
+	pub fn write_records_jsonl(&self, format: TraceFormat, writer: impl Write) -> std::io::Result<()> {
+		TraceRecord::write_jsonl(&self.records, format, writer)
+	}
+}
+
+/// The placeholder-to-original mapping of an anonymized dataset.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AnonymizationMapping {
+	pub entries: Vec<AnonymizationEntry>,
+}
+
+/// One placeholder of the mapping (e.g., `<EMAIL_1>` -> `alice@example.com`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnonymizationEntry {
+	pub placeholder: String,
+	pub kind: String,
+	pub index: usize,
+	pub original: String,
+}
+
+/// Exports
+impl AnonymizationMapping {
+	/// Write the mapping as a JSON document to the writer (e.g., a separate, access-restricted file).
+	pub fn write_json(&self, mut writer: impl Write) -> std::io::Result<()> {
+		serde_json::to_writer_pretty(&mut writer, self)?;
+		writer.flush()
+	}
+
+	/// Restore the original values of an anonymized text (e.g., to review a flagged example).
+	pub fn deanonymize_text(&self, text: &str) -> String {
+		self.entries.iter().fold(text.to_string(), |text, entry| {
+			text.replace(&entry.placeholder, &entry.original)
+		})
+	}
+}
+
+// endregion: --- AnonymizedExport
+
+// region:    --- Support
+
+/// Returns the byte ranges of the email addresses (`local@domain.tld`) of the text.
+fn find_emails(text: &str) -> Vec<(usize, usize)> {
+	let is_local_char = |c: char| c.is_ascii_alphanumeric() || "._%+-".contains(c);
+	let is_domain_char = |c: char| c.is_ascii_alphanumeric() || ".-".contains(c);
+
+	let mut ranges: Vec<(usize, usize)> = Vec::new();
+	for (at_idx, _) in text.match_indices('@') {
+		if ranges.last().is_some_and(|(_, end)| at_idx < *end) {
+			continue;
+		}
+
+		let start = text[..at_idx]
+			.char_indices()
+			.rev()
+			.take_while(|(_, c)| is_local_char(*c))
+			.last()
+			.map(|(idx, _)| idx)
+			.unwrap_or(at_idx);
+		let domain = &text[at_idx + 1..];
+		let domain_len = domain.find(|c: char| !is_domain_char(c)).unwrap_or(domain.len());
+		// trailing dots or dashes are punctuation (e.g., end of sentence)
+		let domain = domain[..domain_len].trim_end_matches(['.', '-']);
+
+		let has_tld = domain
+			.rsplit_once('.')
+			.is_some_and(|(name, tld)| !name.is_empty() && tld.len() >= 2);
+		if start < at_idx && has_tld {
+			ranges.push((start, at_idx + 1 + domain.len()));
+		}
+	}
+	ranges
+}
+
+/// Returns the byte ranges of the phone numbers of the text.
+///
+/// A phone number has 9 to 15 digits, and either starts with `+` or has separators between the digits
+/// (e.g., `+33612345678`, `(555) 123-4567`, `06 12 34 56 78`).
+/// The plain digit runs (e.g., order or ticket numbers, timestamps) and the dates (e.g., `2024-01-15 10:30`) are not matched.
+fn find_phones(text: &str) -> Vec<(usize, usize)> {
+	let is_phone_char = |c: char| c.is_ascii_digit() || " -.()+".contains(c);
+	// the characters which make the following digits part of an identifier (e.g., `ORD-2024-000123`, `#1234`)
+	let is_id_char = |c: char| c.is_ascii_alphanumeric() || "-_#/".contains(c);
+	let bytes = text.as_bytes();
+
+	let mut ranges: Vec<(usize, usize)> = Vec::new();
+	let mut idx = 0;
+	while idx < bytes.len() {
+		let c = bytes[idx] as char;
+		// a phone starts with a digit, `+`, or `(`, not preceded by an identifier character
+		let starts = (c.is_ascii_digit() || c == '+' || c == '(') && (idx == 0 || !is_id_char(bytes[idx - 1] as char));
+		if !starts {
+			idx += 1;
+			continue;
+		}
+
+		let len = text[idx..].find(|c: char| !is_phone_char(c)).unwrap_or(text.len() - idx);
+		// trim the trailing separators (e.g., the space before the next word)
+		let candidate = text[idx..idx + len].trim_end_matches(|c: char| !c.is_ascii_digit());
+		let end = idx + candidate.len();
+		let digits = candidate.chars().filter(|c| c.is_ascii_digit()).count();
+		let followed_by_alnum = text[end..].chars().next().is_some_and(|c| c.is_ascii_alphanumeric());
+		let has_separator = candidate.trim_start_matches(['+', '(']).contains(|c: char| !c.is_ascii_digit());
+
+		if (PHONE_MIN_DIGITS..=PHONE_MAX_DIGITS).contains(&digits)
+			&& !followed_by_alnum
+			&& (candidate.starts_with('+') || has_separator)
+			&& !starts_with_date(candidate)
+		{
+			ranges.push((idx, end));
+			idx = end;
+		} else {
+			idx += len.max(1);
+		}
+	}
+	ranges
+}
+
+/// Returns true if the text starts with a date, `YYYY-MM-DD` or `DD-MM-YYYY` (with `-` or `.` as separator).
+fn starts_with_date(text: &str) -> bool {
+	let mut groups = text.split(|c: char| !c.is_ascii_digit());
+	let (Some(a), Some(b), Some(c)) = (groups.next(), groups.next(), groups.next()) else {
+		return false;
+	};
+	// the two separators must be the same single `-` or `.` (e.g., not the spaces of a phone number)
+	let separators = [a.len(), a.len() + 1 + b.len()].map(|sep_idx| text.as_bytes().get(sep_idx).copied());
+	let same_separator = matches!(separators, [Some(s1), Some(s2)] if s1 == s2 && (s1 == b'-' || s1 == b'.'));
+
+	let is_month = |s: &str| s.len() == 2 && s.parse::<u8>().is_ok_and(|n| (1..=12).contains(&n));
+	let is_day = |s: &str| s.len() == 2 && s.parse::<u8>().is_ok_and(|n| (1..=31).contains(&n));
+	let ymd = a.len() == 4 && is_month(b) && is_day(c);
+	let dmy = is_day(a) && is_month(b) && c.len() == 4;
+
+	same_separator && (ymd || dmy)
+}
+
+// endregion: --- Support
@@ -0,0 +1,28 @@
+//! The genai trace module allows exporting chat executions in the common LLM observability
+//! formats (OpenInference / LangSmith), so that genai runs can be ingested by existing tools
+//! (Arize Phoenix, LangSmith, LlamaIndex/LangChain compatible collectors) without a custom collector.
+//!
+//! - Build a `TraceRecord` from the `ChatRequest` / `ChatResponse` pair (and eventual timings).
+//! - Export it with `trace_record.to_json(TraceFormat::..)` or as a JSONL line with `to_jsonl_line(..)`.
+//! - Anonymize the records with the `Anonymizer` to share them as a dataset (the mapping is exported separately).
+//! - Persist every client chat execution in a local SQLite database with the `SqliteLog` (`sqlite` feature).
+//!
+//! NOTE: The OpenInference / LangSmith export is not hooked into the client chat executions (only the `SqliteLog` is),
+//!       so the `TraceRecord`s are built and exported by the app (e.g., `TraceRecord::from_chat(..).with_response(..)`).
+
+// region:    --- Modules
+
+mod anonymizer;
+#[cfg(feature = "sqlite")]
+mod sqlite_log;
+mod trace_format;
+mod trace_record;
+
+// -- Flatten
+pub use anonymizer::*;
+#[cfg(feature = "sqlite")]
+pub use sqlite_log::*;
+pub use trace_format::*;
+pub use trace_record::*;
+
+// endregion: --- Modules
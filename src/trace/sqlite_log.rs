@@ -0,0 +1,325 @@
+//! The `SqliteLog` persists the chat executions (request, response or error, usage, cost) in a local SQLite
+//! database, with the query helpers of a local analytics (top expensive calls, error rates).
+//!
+//! NOTE: Requires the `sqlite` feature.
+
+use crate::adapter::AdapterKind;
+use crate::trace::{Anonymizer, TraceRecord};
+use crate::{ModelIden, ModelPrice, Result};
+use rusqlite::{Connection, OptionalExtension, Row, params};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS genai_log (
+	id                TEXT PRIMARY KEY,
+	time_ms           INTEGER NOT NULL,
+	duration_ms       INTEGER,
+	adapter_kind      TEXT NOT NULL,
+	model             TEXT NOT NULL,
+	tag               TEXT,
+	prompt_tokens     INTEGER,
+	completion_tokens INTEGER,
+	total_tokens      INTEGER,
+	cost              REAL,
+	error             TEXT,
+	record            TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS genai_log_model_idx ON genai_log (model);
+CREATE INDEX IF NOT EXISTS genai_log_tag_idx ON genai_log (tag);
+CREATE INDEX IF NOT EXISTS genai_log_time_idx ON genai_log (time_ms);
+";
+
+const ENTRY_COLUMNS: &str =
+	"id, time_ms, duration_ms, adapter_kind, model, tag, prompt_tokens, completion_tokens, total_tokens, cost, error";
+
+// region:    --- SqliteLog
+
+/// A local SQLite log of the chat executions (table `genai_log`, indexed on model, tag, and time).
+///
+/// When set on the client (`ClientBuilder::with_sqlite_log(..)`), every `Client::exec_chat(..)` is logged
+/// (response or error), with the request tenant id as tag. Records can also be logged directly with `log(..)`.
+///
+/// The records are redacted before being stored (see `LogRedaction`, default `Anonymize`).
+///
+/// The SqliteLog is cheap to clone and all clones share the same connection.
+///
+/// NOTES:
+/// - The writes are synchronous (local file), and a failed write is only logged (`tracing::warn!`)
+///   by the client.
+/// - The chat streams are not logged.
+#[derive(Debug, Clone)]
+pub struct SqliteLog {
+	inner: Arc<Mutex<SqliteLogInner>>,
+}
+
+#[derive(Debug)]
+struct SqliteLogInner {
+	conn: Connection,
+	redaction: LogRedaction,
+	prices: HashMap<String, ModelPrice>,
+}
+
+/// Constructors & Chainable Setters
+impl SqliteLog {
+	/// Open (or create) the log database file.
+	pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+		Self::from_connection(Connection::open(path)?)
+	}
+
+	/// Open an in-memory log database (e.g., for tests).
+	pub fn open_in_memory() -> Result<Self> {
+		Self::from_connection(Connection::open_in_memory()?)
+	}
+
+	pub fn with_redaction(self, redaction: LogRedaction) -> Self {
+		self.lock().redaction = redaction;
+		self
+	}
+
+	/// Set the price of a model name (without namespace, e.g., `gpt-4o-mini`), used for the logged cost.
+	/// The calls of the models without a price have no cost.
+	pub fn with_model_price(self, model_name: impl Into<String>, price: ModelPrice) -> Self {
+		self.lock().prices.insert(model_name.into(), price);
+		self
+	}
+}
+
+/// Public Functions
+impl SqliteLog {
+	/// Log a chat execution record (redacted), with an eventual tag (e.g., a tenant id or a feature name).
+	pub fn log(&self, record: &TraceRecord, tag: Option<&str>) -> Result<()> {
+		let inner = self.lock();
+		let record = inner.redaction.apply(record);
+
+		let (model_name, _) = record.model_iden.model_name.as_model_name_and_namespace();
+		let usage = record.usage.as_ref();
+		let cost = usage.and_then(|usage| inner.prices.get(model_name).map(|price| price.cost(usage)));
+		let duration_ms = record
+			.end_time
+			.and_then(|end_time| end_time.duration_since(record.start_time).ok())
+			.map(|duration| duration.as_millis() as i64);
+
+		inner.conn.execute(
+			&format!(
+				"INSERT OR REPLACE INTO genai_log ({ENTRY_COLUMNS}, record)
+				VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)"
+			),
+			params![
+				record.id,
+				unix_ms(record.start_time),
+				duration_ms,
+				record.model_iden.adapter_kind.as_lower_str(),
+				model_name,
+				tag,
+				usage.and_then(|usage| usage.prompt_tokens),
+				usage.and_then(|usage| usage.completion_tokens),
+				usage.and_then(|usage| usage.total_tokens),
+				cost,
+				record.error,
+				serde_json::to_string(&record)?,
+			],
+		)?;
+
+		Ok(())
+	}
+
+	/// Returns the most expensive calls (by cost, then total tokens), most expensive first.
+	pub fn top_expensive(&self, limit: usize) -> Result<Vec<LogEntry>> {
+		let inner = self.lock();
+		let mut stmt = inner.conn.prepare(&format!(
+			"SELECT {ENTRY_COLUMNS} FROM genai_log ORDER BY cost DESC, total_tokens DESC LIMIT ?1"
+		))?;
+		let entries = stmt
+			.query_map(params![limit as i64], LogEntry::from_row)?
+			.collect::<core::result::Result<Vec<_>, _>>()?;
+		Ok(entries)
+	}
+
+	/// Returns the error rates per model of the calls since the eventual time, highest rate first.
+	pub fn error_rates(&self, since: Option<SystemTime>) -> Result<Vec<ErrorRate>> {
+		let inner = self.lock();
+		let mut stmt = inner.conn.prepare(
+			"SELECT adapter_kind, model, COUNT(*), SUM(error IS NOT NULL) FROM genai_log
+			WHERE time_ms >= ?1
+			GROUP BY adapter_kind, model
+			ORDER BY 1.0 * SUM(error IS NOT NULL) / COUNT(*) DESC, COUNT(*) DESC",
+		)?;
+		let since_ms = since.map(unix_ms).unwrap_or(0);
+		let rates = stmt
+			.query_map(params![since_ms], |row| {
+				Ok(ErrorRate {
+					model_iden: model_iden_from_row(row, 0, 1)?,
+					calls: row.get::<_, i64>(2)? as u64,
+					errors: row.get::<_, i64>(3)? as u64,
+				})
+			})?
+			.collect::<core::result::Result<Vec<_>, _>>()?;
+		Ok(rates)
+	}
+
+	/// Returns the full (redacted) record of a logged call.
+	pub fn record(&self, id: &str) -> Result<Option<TraceRecord>> {
+		let inner = self.lock();
+		let record: Option<String> = inner
+			.conn
+			.query_row("SELECT record FROM genai_log WHERE id = ?1", params![id], |row| {
+				row.get(0)
+			})
+			.optional()?;
+		let record = record.map(|record| serde_json::from_str(&record)).transpose()?;
+		Ok(record)
+	}
+}
+
+/// Support
+impl SqliteLog {
+	fn from_connection(conn: Connection) -> Result<Self> {
+		conn.execute_batch(SCHEMA)?;
+		Ok(Self {
+			inner: Arc::new(Mutex::new(SqliteLogInner {
+				conn,
+				redaction: LogRedaction::default(),
+				prices: HashMap::new(),
+			})),
+		})
+	}
+
+	fn lock(&self) -> MutexGuard<'_, SqliteLogInner> {
+		// NOTE: A poisoned lock still holds a valid connection.
+		self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+}
+
+// endregion: --- SqliteLog
+
+// region:    --- LogRedaction
+
+/// The redaction applied to the records before they are stored in the `SqliteLog`.
+#[derive(Debug, Clone)]
+pub enum LogRedaction {
+	/// Store the records as-is.
+	None,
+
+	/// Replace the PII with placeholders (see `Anonymizer`). The placeholder mapping is not stored.
+	Anonymize(Anonymizer),
+
+	/// Drop the message contents and tool call arguments (keep the model, usage, timings, and error).
+	OmitContent,
+}
+
+impl Default for LogRedaction {
+	fn default() -> Self {
+		Self::Anonymize(Anonymizer::default())
+	}
+}
+
+impl LogRedaction {
+	fn apply(&self, record: &TraceRecord) -> TraceRecord {
+		match self {
+			LogRedaction::None => record.clone(),
+			// NOTE: A fresh clone for each record, so that the mapping (with the originals) is not kept in memory.
+			LogRedaction::Anonymize(anonymizer) => anonymizer
+				.clone()
+				.anonymize(std::iter::once(record))
+				.records
+				.pop()
+				.unwrap_or_else(|| record.clone()),
+			LogRedaction::OmitContent => {
+				let mut record = record.clone();
+				for msg in record.input_messages.iter_mut().chain(record.output_messages.iter_mut()) {
+					msg.content = None;
+					for tool_call in msg.tool_calls.iter_mut() {
+						tool_call.fn_arguments = Value::Null;
+					}
+				}
+				record
+			}
+		}
+	}
+}
+
+// endregion: --- LogRedaction
+
+// region:    --- LogEntry & ErrorRate
+
+/// A logged call, without its record (see `SqliteLog::record(..)`).
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+	pub id: String,
+	pub time: SystemTime,
+	pub duration: Option<Duration>,
+	pub model_iden: ModelIden,
+	pub tag: Option<String>,
+	pub prompt_tokens: Option<i32>,
+	pub completion_tokens: Option<i32>,
+	pub total_tokens: Option<i32>,
+	/// The cost, when the model has a price (see `SqliteLog::with_model_price(..)`).
+	pub cost: Option<f64>,
+	pub error: Option<String>,
+}
+
+impl LogEntry {
+	fn from_row(row: &Row<'_>) -> rusqlite::Result<Self> {
+		Ok(Self {
+			id: row.get(0)?,
+			time: UNIX_EPOCH + Duration::from_millis(row.get::<_, i64>(1)?.max(0) as u64),
+			duration: row
+				.get::<_, Option<i64>>(2)?
+				.map(|duration_ms| Duration::from_millis(duration_ms.max(0) as u64)),
+			model_iden: model_iden_from_row(row, 3, 4)?,
+			tag: row.get(5)?,
+			prompt_tokens: row.get(6)?,
+			completion_tokens: row.get(7)?,
+			total_tokens: row.get(8)?,
+			cost: row.get(9)?,
+			error: row.get(10)?,
+		})
+	}
+}
+
+/// The error rate of the calls of a model.
+#[derive(Debug, Clone)]
+pub struct ErrorRate {
+	pub model_iden: ModelIden,
+	pub calls: u64,
+	pub errors: u64,
+}
+
+impl ErrorRate {
+	/// The ratio of failed calls (0 to 1).
+	pub fn rate(&self) -> f64 {
+		if self.calls == 0 {
+			return 0.;
+		}
+		self.errors as f64 / self.calls as f64
+	}
+}
+
+// endregion: --- LogEntry & ErrorRate
+
+// region:    --- Support
+
+fn unix_ms(time: SystemTime) -> i64 {
+	time.duration_since(UNIX_EPOCH)
+		.map(|duration| duration.as_millis() as i64)
+		.unwrap_or_default()
+}
+
+fn model_iden_from_row(row: &Row<'_>, adapter_kind_idx: usize, model_idx: usize) -> rusqlite::Result<ModelIden> {
+	let adapter_kind: String = row.get(adapter_kind_idx)?;
+	let adapter_kind = AdapterKind::from_lower_str(&adapter_kind).ok_or_else(|| {
+		rusqlite::Error::FromSqlConversionFailure(
+			adapter_kind_idx,
+			rusqlite::types::Type::Text,
+			format!("unknown adapter kind '{adapter_kind}'").into(),
+		)
+	})?;
+	let model: String = row.get(model_idx)?;
+	Ok(ModelIden::new(adapter_kind, model))
+}
+
+// endregion: --- Support
@@ -0,0 +1,257 @@
+//! The trace formats a `TraceRecord` can be exported to.
+//!
+//! - OpenInference: https://github.com/Arize-ai/openinference/blob/main/spec/semantic_conventions.md
+//! - LangSmith run: https://docs.smith.langchain.com/reference/data_formats/run_data_format
+
+use crate::trace::{TraceMessage, TraceRecord};
+use serde_json::{Map, Value, json};
+use std::io::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The supported trace export formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+	/// OpenInference span (flattened `llm.*` attributes), as used by Arize Phoenix and LlamaIndex instrumentation.
+	OpenInference,
+	/// LangSmith run (`run_type: "llm"`), as used by LangChain tooling.
+	LangSmith,
+}
+
+/// Exports
+impl TraceRecord {
+	/// Render the record as a JSON value for the given format.
+	pub fn to_json(&self, format: TraceFormat) -> Value {
+		match format {
+			TraceFormat::OpenInference => self.to_open_inference_json(),
+			TraceFormat::LangSmith => self.to_lang_smith_json(),
+		}
+	}
+
+	/// Render the record as a single JSONL line (with the trailing `\n`).
+	pub fn to_jsonl_line(&self, format: TraceFormat) -> String {
+		let mut line = self.to_json(format).to_string();
+		line.push('\n');
+		line
+	}
+
+	/// Write the records as JSONL to the writer (one record per line).
+	pub fn write_jsonl<'a>(
+		records: impl IntoIterator<Item = &'a TraceRecord>,
+		format: TraceFormat,
+		mut writer: impl Write,
+	) -> std::io::Result<()> {
+		for record in records {
+			writer.write_all(record.to_jsonl_line(format).as_bytes())?;
+		}
+		writer.flush()
+	}
+}
+
+// region:    --- OpenInference
+
+impl TraceRecord {
+	fn to_open_inference_json(&self) -> Value {
+		let mut attributes = Map::new();
+
+		attributes.insert("openinference.span.kind".into(), "LLM".into());
+		attributes.insert("llm.system".into(), self.model_iden.adapter_kind.as_lower_str().into());
+		attributes.insert(
+			"llm.provider".into(),
+			self.model_iden.adapter_kind.as_lower_str().into(),
+		);
+		let model_name = self
+			.provider_model_iden
+			.as_ref()
+			.unwrap_or(&self.model_iden)
+			.model_name
+			.to_string();
+		attributes.insert("llm.model_name".into(), model_name.into());
+
+		insert_open_inference_messages(&mut attributes, "llm.input_messages", &self.input_messages);
+		insert_open_inference_messages(&mut attributes, "llm.output_messages", &self.output_messages);
+
+		if let Some(params) = self.invocation_parameters.as_ref() {
+			// NOTE: OpenInference expects the invocation parameters as a JSON string
+			attributes.insert("llm.invocation_parameters".into(), params.to_string().into());
+		}
+
+		if let Some(usage) = self.usage.as_ref() {
+			if let Some(prompt_tokens) = usage.prompt_tokens {
+				attributes.insert("llm.token_count.prompt".into(), prompt_tokens.into());
+			}
+			if let Some(completion_tokens) = usage.completion_tokens {
+				attributes.insert("llm.token_count.completion".into(), completion_tokens.into());
+			}
+			if let Some(total_tokens) = usage.total_tokens {
+				attributes.insert("llm.token_count.total".into(), total_tokens.into());
+			}
+		}
+
+		if let Some(Value::Object(metadata)) = self.metadata.as_ref() {
+			for (k, v) in metadata {
+				attributes.insert(format!("metadata.{k}"), v.clone());
+			}
+		}
+
+		let (status_code, status_message) = match self.error.as_ref() {
+			Some(error) => ("ERROR", Some(error.to_string())),
+			None => ("OK", None),
+		};
+
+		// NOTE: The span id is the first 16 chars of the trace id (the whole id when shorter, e.g., a custom `id`)
+		let trace_id = self.id.replace('-', "");
+		let span_id = trace_id.get(..16).unwrap_or(&trace_id).to_string();
+
+		json!({
+			"name": self.name,
+			"context": {
+				"trace_id": trace_id,
+				"span_id": span_id,
+			},
+			"span_kind": "LLM",
+			"start_time": format_rfc3339(self.start_time),
+			"end_time": self.end_time.map(format_rfc3339),
+			"status_code": status_code,
+			"status_message": status_message,
+			"attributes": attributes,
+		})
+	}
+}
+
+fn insert_open_inference_messages(attributes: &mut Map<String, Value>, prefix: &str, messages: &[TraceMessage]) {
+	for (idx, msg) in messages.iter().enumerate() {
+		let msg_prefix = format!("{prefix}.{idx}.message");
+		attributes.insert(format!("{msg_prefix}.role"), msg.role.clone().into());
+		if let Some(content) = msg.content.as_ref() {
+			attributes.insert(format!("{msg_prefix}.content"), content.clone().into());
+		}
+		if let Some(tool_call_id) = msg.tool_call_id.as_ref() {
+			attributes.insert(format!("{msg_prefix}.tool_call_id"), tool_call_id.clone().into());
+		}
+		for (tc_idx, tool_call) in msg.tool_calls.iter().enumerate() {
+			let tc_prefix = format!("{msg_prefix}.tool_calls.{tc_idx}.tool_call");
+			attributes.insert(format!("{tc_prefix}.id"), tool_call.call_id.clone().into());
+			attributes.insert(format!("{tc_prefix}.function.name"), tool_call.fn_name.clone().into());
+			attributes.insert(
+				format!("{tc_prefix}.function.arguments"),
+				tool_call.fn_arguments.to_string().into(),
+			);
+		}
+	}
+}
+
+// endregion: --- OpenInference
+
+// region:    --- LangSmith
+
+impl TraceRecord {
+	fn to_lang_smith_json(&self) -> Value {
+		let inputs = json!({
+			"messages": self.input_messages.iter().map(lang_smith_message).collect::<Vec<Value>>(),
+		});
+
+		let outputs = if self.error.is_none() {
+			let mut outputs = json!({
+				"choices": self.output_messages.iter().map(|msg| json!({"message": lang_smith_message(msg)})).collect::<Vec<Value>>(),
+			});
+			if let Some(usage) = self.usage.as_ref() {
+				outputs["usage_metadata"] = json!({
+					"input_tokens": usage.prompt_tokens,
+					"output_tokens": usage.completion_tokens,
+					"total_tokens": usage.total_tokens,
+				});
+			}
+			Some(outputs)
+		} else {
+			None
+		};
+
+		let model_name = self
+			.provider_model_iden
+			.as_ref()
+			.unwrap_or(&self.model_iden)
+			.model_name
+			.to_string();
+		let mut metadata = Map::new();
+		metadata.insert("ls_provider".into(), self.model_iden.adapter_kind.as_lower_str().into());
+		metadata.insert("ls_model_name".into(), model_name.into());
+		if let Some(Value::Object(user_metadata)) = self.metadata.as_ref() {
+			for (k, v) in user_metadata {
+				metadata.insert(k.clone(), v.clone());
+			}
+		}
+
+		json!({
+			"id": self.id,
+			"name": self.name,
+			"run_type": "llm",
+			"start_time": format_rfc3339(self.start_time),
+			"end_time": self.end_time.map(format_rfc3339),
+			"inputs": inputs,
+			"outputs": outputs,
+			"error": self.error,
+			"extra": {
+				"invocation_params": self.invocation_parameters,
+				"metadata": metadata,
+			},
+		})
+	}
+}
+
+fn lang_smith_message(msg: &TraceMessage) -> Value {
+	let mut value = json!({
+		"role": msg.role,
+		"content": msg.content.as_deref().unwrap_or_default(),
+	});
+	if let Some(tool_call_id) = msg.tool_call_id.as_ref() {
+		value["tool_call_id"] = tool_call_id.clone().into();
+	}
+	if !msg.tool_calls.is_empty() {
+		value["tool_calls"] = msg
+			.tool_calls
+			.iter()
+			.map(|tool_call| {
+				json!({
+					"id": tool_call.call_id,
+					"type": "function",
+					"function": {
+						"name": tool_call.fn_name,
+						"arguments": tool_call.fn_arguments.to_string(),
+					}
+				})
+			})
+			.collect::<Vec<Value>>()
+			.into();
+	}
+	value
+}
+
+// endregion: --- LangSmith
+
+// region:    --- Support
+
+/// Format a SystemTime into a RFC 3339 UTC string with microseconds (e.g., `2025-05-08T10:20:30.123456Z`)
+fn format_rfc3339(time: SystemTime) -> String {
+	let duration = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+	let secs = duration.as_secs();
+	let micros = duration.subsec_micros();
+
+	let days = (secs / 86_400) as i64;
+	let secs_of_day = secs % 86_400;
+	let (hour, minute, second) = (secs_of_day / 3600, (secs_of_day % 3600) / 60, secs_of_day % 60);
+
+	// Civil from days (Howard Hinnant's algorithm)
+	let z = days + 719_468;
+	let era = z.div_euclid(146_097);
+	let doe = z.rem_euclid(146_097);
+	let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = doy - (153 * mp + 2) / 5 + 1;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+	format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}.{micros:06}Z")
+}
+
+// endregion: --- Support
@@ -0,0 +1,314 @@
+//! The `TraceRecord` is the normalized representation of one chat execution (request, response, timings)
+//! from which the different trace formats are rendered.
+
+use crate::ModelIden;
+use crate::chat::{
+	ChatOptions, ChatRequest, ChatResponse, ChatRole, ContentBlock, ContentPart, MessageContent, ToolCall, Usage,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// region:    --- TraceRecord
+
+/// One chat execution, ready to be exported in a trace format (see `TraceFormat`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceRecord {
+	/// The trace/run id (UUID v4 format, generated at creation).
+	pub id: String,
+
+	/// The name of the span/run (default `genai.chat`)
+	pub name: String,
+
+	/// The model identifier used for the request.
+	pub model_iden: ModelIden,
+
+	/// The eventual model identifier returned by the provider.
+	pub provider_model_iden: Option<ModelIden>,
+
+	pub start_time: SystemTime,
+	pub end_time: Option<SystemTime>,
+
+	/// The normalized input messages (system included).
+	pub input_messages: Vec<TraceMessage>,
+
+	/// The normalized output messages (one assistant message for now).
+	pub output_messages: Vec<TraceMessage>,
+
+	/// The invocation parameters (temperature, max_tokens, ...) as a JSON object.
+	pub invocation_parameters: Option<Value>,
+
+	pub usage: Option<Usage>,
+
+	/// The eventual error message when the execution failed.
+	pub error: Option<String>,
+
+	/// Free-form metadata, which will be exported as-is (must be a JSON object to be merged).
+	pub metadata: Option<Value>,
+}
+
+/// Constructors
+impl TraceRecord {
+	/// Create a new TraceRecord for a chat request, with the `start_time` set to now.
+	pub fn from_chat(model_iden: ModelIden, chat_req: &ChatRequest) -> Self {
+		let mut input_messages = Vec::new();
+
+		if let Some(system) = chat_req.system.as_ref() {
+			input_messages.push(TraceMessage::new("system", Some(system.to_string())));
+		}
+
+		for msg in chat_req.messages.iter() {
+			let role = match msg.role {
+				ChatRole::System => "system",
+				ChatRole::Developer => "developer",
+				ChatRole::User => "user",
+				ChatRole::Assistant => "assistant",
+				ChatRole::Tool => "tool",
+			};
+			input_messages.extend(TraceMessage::from_content(role, &msg.content));
+		}
+
+		Self {
+			id: new_trace_id(),
+			name: "genai.chat".to_string(),
+			model_iden,
+			provider_model_iden: None,
+			start_time: SystemTime::now(),
+			end_time: None,
+			input_messages,
+			output_messages: Vec::new(),
+			invocation_parameters: None,
+			usage: None,
+			error: None,
+			metadata: None,
+		}
+	}
+}
+
+/// Chainable Setters
+impl TraceRecord {
+	/// Capture the output messages, usage, and provider model of the response, and set the `end_time` to now.
+	pub fn with_response(mut self, chat_res: &ChatResponse) -> Self {
+		let mut output = TraceMessage::new("assistant", None);
+		let mut texts: Vec<String> = Vec::new();
+
+		for content in chat_res.content.iter() {
+			for msg in TraceMessage::from_content("assistant", content) {
+				if let Some(text) = msg.content {
+					texts.push(text);
+				}
+				output.tool_calls.extend(msg.tool_calls);
+			}
+		}
+		if !texts.is_empty() {
+			output.content = Some(texts.join("\n"));
+		}
+
+		self.output_messages = vec![output];
+		self.usage = Some(chat_res.usage.clone());
+		self.provider_model_iden = Some(chat_res.provider_model_iden.clone());
+		self.end_time.get_or_insert_with(SystemTime::now);
+		self
+	}
+
+	/// Capture the error of a failed execution, and set the `end_time` to now.
+	pub fn with_error(mut self, error: impl std::fmt::Display) -> Self {
+		self.error = Some(error.to_string());
+		self.end_time.get_or_insert_with(SystemTime::now);
+		self
+	}
+
+	/// Capture the invocation parameters from the ChatOptions used for the request.
+	pub fn with_options(mut self, options: &ChatOptions) -> Self {
+		let mut params = Map::new();
+		if let Some(temperature) = options.temperature {
+			params.insert("temperature".into(), temperature.into());
+		}
+		if let Some(max_tokens) = options.max_tokens {
+			params.insert("max_tokens".into(), max_tokens.into());
+		}
+		if let Some(top_p) = options.top_p {
+			params.insert("top_p".into(), top_p.into());
+		}
+		if !options.stop_sequences.is_empty() {
+			params.insert("stop".into(), json!(options.stop_sequences));
+		}
+		if let Some(candidate_count) = options.candidate_count {
+			params.insert("n".into(), candidate_count.into());
+		}
+		if let Some(frequency_penalty) = options.frequency_penalty {
+			params.insert("frequency_penalty".into(), frequency_penalty.into());
+		}
+		if let Some(presence_penalty) = options.presence_penalty {
+			params.insert("presence_penalty".into(), presence_penalty.into());
+		}
+		if let Some(top_k) = options.top_k {
+			params.insert("top_k".into(), top_k.into());
+		}
+		if let Some(min_p) = options.min_p {
+			params.insert("min_p".into(), min_p.into());
+		}
+		if let Some(repetition_penalty) = options.repetition_penalty {
+			params.insert("repetition_penalty".into(), repetition_penalty.into());
+		}
+		if let Some(seed) = options.seed {
+			params.insert("seed".into(), seed.into());
+		}
+		if let Some(reasoning_effort) = options.reasoning_effort.as_ref() {
+			params.insert("reasoning_effort".into(), reasoning_effort.to_string().into());
+		}
+		if let Some(reasoning_summary) = options.reasoning_summary {
+			params.insert("reasoning_summary".into(), reasoning_summary.as_str().into());
+		}
+		self.invocation_parameters = Some(Value::Object(params));
+		self
+	}
+
+	pub fn with_name(mut self, name: impl Into<String>) -> Self {
+		self.name = name.into();
+		self
+	}
+
+	pub fn with_metadata(mut self, metadata: Value) -> Self {
+		self.metadata = Some(metadata);
+		self
+	}
+
+	pub fn with_start_time(mut self, start_time: SystemTime) -> Self {
+		self.start_time = start_time;
+		self
+	}
+
+	pub fn with_end_time(mut self, end_time: SystemTime) -> Self {
+		self.end_time = Some(end_time);
+		self
+	}
+}
+
+// endregion: --- TraceRecord
+
+// region:    --- TraceMessage
+
+/// A message flattened in the way most trace formats expect it (role, text content, tool calls).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceMessage {
+	pub role: String,
+	pub content: Option<String>,
+	pub tool_calls: Vec<ToolCall>,
+	/// For `tool` role messages, the tool call id this message responds to.
+	pub tool_call_id: Option<String>,
+}
+
+impl TraceMessage {
+	fn new(role: &str, content: Option<String>) -> Self {
+		Self {
+			role: role.to_string(),
+			content,
+			tool_calls: Vec::new(),
+			tool_call_id: None,
+		}
+	}
+
+	/// Flatten a MessageContent into one or more TraceMessages.
+	/// (multiple only for tool responses, as each response is its own message in those formats)
+	fn from_content(role: &str, content: &MessageContent) -> Vec<TraceMessage> {
+		match content {
+			MessageContent::Text(text) => vec![Self::new(role, Some(text.to_string()))],
+			MessageContent::Parts(parts) => {
+				let texts: Vec<&str> = parts
+					.iter()
+					.filter_map(|part| match part {
+						ContentPart::Text(text) => Some(text.as_str()),
+						ContentPart::Image { .. }
+						| ContentPart::File(_)
+						| ContentPart::Document { .. }
+						| ContentPart::Audio { .. }
+						| ContentPart::Video { .. } => None,
+					})
+					.collect();
+				vec![Self::new(role, Some(texts.join("\n")))]
+			}
+			MessageContent::ToolCalls(tool_calls) => {
+				let mut msg = Self::new(role, None);
+				msg.tool_calls = tool_calls.clone();
+				vec![msg]
+			}
+			MessageContent::ToolResponses(tool_responses) => tool_responses
+				.iter()
+				.map(|tool_response| {
+					let mut msg = Self::new("tool", Some(tool_response.content.to_string()));
+					msg.tool_call_id = Some(tool_response.call_id.to_string());
+					msg
+				})
+				.collect(),
+			MessageContent::Blocks(blocks) => {
+				let mut msg = Self::new(role, None);
+				let mut texts: Vec<&str> = Vec::new();
+				for block in blocks {
+					match block {
+						ContentBlock::Text { text, .. } => texts.push(text),
+						ContentBlock::ToolUse { id, name, input, .. } => msg.tool_calls.push(ToolCall {
+							call_id: id.to_string(),
+							fn_name: name.to_string(),
+							fn_arguments: input.clone(),
+						}),
+						ContentBlock::ToolResult {
+							tool_use_id, content, ..
+						} => {
+							msg.tool_call_id = Some(tool_use_id.to_string());
+							texts.push(content);
+						}
+						// Thinking, image, and code execution blocks are not part of the message content for traces
+						ContentBlock::Thinking { .. }
+						| ContentBlock::RedactedThinking { .. }
+						| ContentBlock::ExecutableCode { .. }
+						| ContentBlock::CodeExecutionResult { .. }
+						| ContentBlock::Image { .. } => (),
+					}
+				}
+				if !texts.is_empty() {
+					msg.content = Some(texts.join("\n"));
+				}
+				vec![msg]
+			}
+		}
+	}
+}
+
+// endregion: --- TraceMessage
+
+// region:    --- Support
+
+/// Generate a random UUID v4 formatted id.
+/// NOTE: Uses the std RandomState as the randomness source (good enough for trace ids, not for crypto).
+fn new_trace_id() -> String {
+	let nanos = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|d| d.as_nanos())
+		.unwrap_or_default();
+
+	let mut bytes = [0u8; 16];
+	for (idx, chunk) in bytes.chunks_mut(8).enumerate() {
+		let mut hasher = RandomState::new().build_hasher();
+		hasher.write_u128(nanos);
+		hasher.write_usize(idx);
+		chunk.copy_from_slice(&hasher.finish().to_le_bytes());
+	}
+	// version 4 and RFC 4122 variant
+	bytes[6] = (bytes[6] & 0x0f) | 0x40;
+	bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+	let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+	format!(
+		"{}-{}-{}-{}-{}",
+		&hex[0..8],
+		&hex[8..12],
+		&hex[12..16],
+		&hex[16..20],
+		&hex[20..32]
+	)
+}
+
+// endregion: --- Support
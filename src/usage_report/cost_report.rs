@@ -0,0 +1,68 @@
+//! This module contains the organization cost report types.
+
+use crate::adapter::AdapterKind;
+use serde::{Deserialize, Serialize};
+
+// region:    --- CostReport
+
+/// The organization cost report, as time buckets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostReport {
+	/// The adapter kind of the provider which reported the cost.
+	pub adapter_kind: AdapterKind,
+
+	/// The time buckets (all pages merged).
+	pub buckets: Vec<CostReportBucket>,
+}
+
+/// Getters
+impl CostReport {
+	/// Sum of the amounts of all the buckets and entries (assumes a single currency, which is USD for Anthropic).
+	pub fn total_amount(&self) -> f64 {
+		self.buckets
+			.iter()
+			.flat_map(|bucket| bucket.results.iter())
+			.map(|entry| entry.amount)
+			.sum()
+	}
+}
+
+/// One time bucket of the cost report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostReportBucket {
+	/// The start of the bucket (RFC 3339).
+	pub starting_at: String,
+
+	/// The end of the bucket (RFC 3339).
+	pub ending_at: String,
+
+	/// The cost entries of the bucket (one per group and cost type).
+	pub results: Vec<CostReportEntry>,
+}
+
+/// The cost of one group of a bucket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostReportEntry {
+	/// The amount in the currency unit (e.g., dollars, not cents).
+	pub amount: f64,
+
+	/// The currency (e.g., `USD`).
+	pub currency: String,
+
+	/// The eventual cost description (e.g., `Claude Sonnet 4 Usage - Input Tokens`).
+	pub description: Option<String>,
+
+	/// The eventual cost type (e.g., `tokens`, `web_search`).
+	pub cost_type: Option<String>,
+
+	/// The eventual token type (e.g., `uncached_input_tokens`, `output_tokens`).
+	pub token_type: Option<String>,
+
+	/// The eventual model of the cost.
+	pub model: Option<String>,
+
+	/// The workspace id, when grouped by workspace.
+	pub workspace_id: Option<String>,
+}
+
+// endregion: --- CostReport
@@ -0,0 +1,17 @@
+//! The genai usage_report module contains the constructs to read the provider organization
+//! usage and cost reports with the `genai::Client` (Anthropic Admin API only for now).
+//!
+//! This allows to reconcile the per-request `Usage` reported by genai with the provider billing data.
+
+// region:    --- Modules
+
+mod cost_report;
+mod token_usage_report;
+mod usage_report_request;
+
+// -- Flatten
+pub use cost_report::*;
+pub use token_usage_report::*;
+pub use usage_report_request::*;
+
+// endregion: --- Modules
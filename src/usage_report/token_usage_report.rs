@@ -0,0 +1,76 @@
+//! This module contains the organization token usage report types.
+
+use crate::adapter::AdapterKind;
+use crate::chat::Usage;
+use serde::{Deserialize, Serialize};
+
+// region:    --- UsageReport
+
+/// The organization token usage report, as time buckets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageReport {
+	/// The adapter kind of the provider which reported the usage.
+	pub adapter_kind: AdapterKind,
+
+	/// The time buckets (all pages merged).
+	pub buckets: Vec<UsageReportBucket>,
+}
+
+/// Getters
+impl UsageReport {
+	/// Sum of the usage of all the buckets and entries (e.g., to compare with the sum of the genai `Usage`).
+	pub fn total_usage(&self) -> Usage {
+		let mut total = Usage::default();
+		for entry in self.buckets.iter().flat_map(|bucket| bucket.results.iter()) {
+			total.prompt_tokens = add_opt(total.prompt_tokens, entry.usage.prompt_tokens);
+			total.completion_tokens = add_opt(total.completion_tokens, entry.usage.completion_tokens);
+			total.total_tokens = add_opt(total.total_tokens, entry.usage.total_tokens);
+		}
+		total
+	}
+}
+
+/// One time bucket of the usage report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageReportBucket {
+	/// The start of the bucket (RFC 3339).
+	pub starting_at: String,
+
+	/// The end of the bucket (RFC 3339).
+	pub ending_at: String,
+
+	/// The usage entries of the bucket (one per group, when grouped).
+	pub results: Vec<UsageReportEntry>,
+}
+
+/// The usage of one group of a bucket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageReportEntry {
+	/// The model, when grouped by model.
+	pub model: Option<String>,
+
+	/// The workspace id, when grouped by workspace.
+	pub workspace_id: Option<String>,
+
+	/// The API key id, when grouped by API key.
+	pub api_key_id: Option<String>,
+
+	/// The service tier, when grouped by service tier.
+	pub service_tier: Option<String>,
+
+	/// The token usage, normalized the same way as the `ChatResponse.usage`.
+	pub usage: Usage,
+}
+
+// endregion: --- UsageReport
+
+// region:    --- Support
+
+fn add_opt(a: Option<i32>, b: Option<i32>) -> Option<i32> {
+	match (a, b) {
+		(None, None) => None,
+		(a, b) => Some(a.unwrap_or(0) + b.unwrap_or(0)),
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,64 @@
+//! This module contains the time range and grouping of a usage or cost report request.
+
+use serde::{Deserialize, Serialize};
+
+// region:    --- UsageReportRequest
+
+/// The time range and grouping of `Client::usage_report(..)` and `Client::cost_report(..)`.
+///
+/// Times are RFC 3339 timestamps (e.g., `2025-06-01T00:00:00Z`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageReportRequest {
+	/// The start of the report (inclusive).
+	pub starting_at: String,
+
+	/// The eventual end of the report (exclusive, provider default when `None`).
+	pub ending_at: Option<String>,
+
+	/// The eventual bucket width (e.g., `1d`, `1h`, `1m` for Anthropic usage; only `1d` for the cost report).
+	pub bucket_width: Option<String>,
+
+	/// The eventual fields to group the results by (e.g., `model`, `workspace_id`, `api_key_id`, `description`).
+	pub group_by: Vec<String>,
+
+	/// The eventual models to restrict the usage report to (ignored by the cost report).
+	pub models: Vec<String>,
+}
+
+/// Constructors
+impl UsageReportRequest {
+	pub fn new(starting_at: impl Into<String>) -> Self {
+		Self {
+			starting_at: starting_at.into(),
+			ending_at: None,
+			bucket_width: None,
+			group_by: Vec::new(),
+			models: Vec::new(),
+		}
+	}
+}
+
+/// Chainable Setters
+impl UsageReportRequest {
+	pub fn with_ending_at(mut self, ending_at: impl Into<String>) -> Self {
+		self.ending_at = Some(ending_at.into());
+		self
+	}
+
+	pub fn with_bucket_width(mut self, bucket_width: impl Into<String>) -> Self {
+		self.bucket_width = Some(bucket_width.into());
+		self
+	}
+
+	pub fn with_group_by(mut self, group_by: impl Into<String>) -> Self {
+		self.group_by.push(group_by.into());
+		self
+	}
+
+	pub fn with_model(mut self, model: impl Into<String>) -> Self {
+		self.models.push(model.into());
+		self
+	}
+}
+
+// endregion: --- UsageReportRequest
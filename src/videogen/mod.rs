@@ -0,0 +1,17 @@
+//! The genai videogen module contains the constructs to generate videos with the `genai::Client`
+//! (Gemini Veo and OpenAI Sora for now).
+//!
+//! Unlike chat, video generation is a long-running operation, so it follows an async job model:
+//! create the job (`Client::create_video_job(..)`), poll it (`Client::get_video_job(..)`) until it is done,
+//! and then fetch the video (`VideoJob.video_uri`, or the bytes with `Client::download_video(..)`).
+
+// region:    --- Modules
+
+mod video_job;
+mod video_request;
+
+// -- Flatten
+pub use video_job::*;
+pub use video_request::*;
+
+// endregion: --- Modules
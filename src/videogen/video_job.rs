@@ -0,0 +1,54 @@
+//! This module contains the types related to a provider video generation job.
+
+use crate::ModelIden;
+use serde::{Deserialize, Serialize};
+
+// region:    --- VideoJob
+
+/// A video generation job as reported by its provider.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VideoJob {
+	/// The model (and adapter kind) generating the video.
+	pub model_iden: ModelIden,
+
+	/// The provider job id
+	/// - OpenAI: `video_...`
+	/// - Gemini: the operation name (e.g., `models/veo-3.0-generate-001/operations/...`)
+	pub id: String,
+
+	/// The status of the job.
+	pub status: VideoJobStatus,
+
+	/// The eventual progress percentage (OpenAI only).
+	pub progress: Option<u32>,
+
+	/// The eventual URI of the generated video, once completed (Gemini only).
+	/// NOTE: Requires the API key to be fetched, see `Client::download_video(..)`.
+	pub video_uri: Option<String>,
+
+	/// The eventual error message when the job failed.
+	pub error_message: Option<String>,
+}
+
+/// Getters
+impl VideoJob {
+	/// Returns true when the job will not change anymore (completed or failed).
+	pub fn is_done(&self) -> bool {
+		matches!(self.status, VideoJobStatus::Completed | VideoJobStatus::Failed)
+	}
+}
+
+// endregion: --- VideoJob
+
+// region:    --- VideoJobStatus
+
+/// The status of a video generation job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VideoJobStatus {
+	Queued,
+	InProgress,
+	Completed,
+	Failed,
+}
+
+// endregion: --- VideoJobStatus
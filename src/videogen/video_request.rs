@@ -0,0 +1,64 @@
+//! This module contains the types related to a video generation request.
+
+use serde::{Deserialize, Serialize};
+
+// region:    --- VideoRequest
+
+/// The video to generate with `Client::create_video_job(..)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VideoRequest {
+	/// The text prompt describing the video.
+	pub prompt: String,
+
+	/// The eventual duration in seconds (provider default when `None`).
+	/// - OpenAI: `4`, `8`, or `12`
+	/// - Gemini: `4` to `8` depending on the model
+	pub duration_seconds: Option<u32>,
+
+	/// The eventual resolution as `WIDTHxHEIGHT` (OpenAI only, e.g., `1280x720`).
+	pub size: Option<String>,
+
+	/// The eventual aspect ratio (Gemini only, e.g., `16:9` or `9:16`).
+	pub aspect_ratio: Option<String>,
+
+	/// The eventual description of what to avoid in the video (Gemini only).
+	pub negative_prompt: Option<String>,
+}
+
+/// Constructors
+impl VideoRequest {
+	pub fn new(prompt: impl Into<String>) -> Self {
+		Self {
+			prompt: prompt.into(),
+			duration_seconds: None,
+			size: None,
+			aspect_ratio: None,
+			negative_prompt: None,
+		}
+	}
+}
+
+/// Chainable Setters
+impl VideoRequest {
+	pub fn with_duration_seconds(mut self, duration_seconds: u32) -> Self {
+		self.duration_seconds = Some(duration_seconds);
+		self
+	}
+
+	pub fn with_size(mut self, size: impl Into<String>) -> Self {
+		self.size = Some(size.into());
+		self
+	}
+
+	pub fn with_aspect_ratio(mut self, aspect_ratio: impl Into<String>) -> Self {
+		self.aspect_ratio = Some(aspect_ratio.into());
+		self
+	}
+
+	pub fn with_negative_prompt(mut self, negative_prompt: impl Into<String>) -> Self {
+		self.negative_prompt = Some(negative_prompt.into());
+		self
+	}
+}
+
+// endregion: --- VideoRequest
@@ -18,6 +18,9 @@ pub enum Error {
 		headers: Box<HeaderMap>,
 	},
 
+	#[display("WebSocket header '{name}' is not a valid header name or value.")]
+	WebSocketInvalidHeader { name: String },
+
 	// -- Utils
 	#[display("JSON value extension error: {_0}")]
 	#[from]
@@ -28,6 +31,13 @@ pub enum Error {
 	#[from]
 	Reqwest(reqwest::Error),
 
+	#[display("Serde JSON error: {_0}")]
+	#[from]
+	SerdeJson(serde_json::Error),
+
+	#[display("WebSocket error: {_0}")]
+	WebSocket(Box<tokio_tungstenite::tungstenite::Error>),
+
 	#[display("Failed to clone EventSource request: {_0}")]
 	#[from]
 	EventSourceClone(reqwest_eventsource::CannotCloneRequestError),
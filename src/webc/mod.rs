@@ -4,11 +4,14 @@
 
 mod error;
 mod web_client;
+// For the bidirectional APIs (e.g., realtime)
+mod web_socket;
 // For when not using `text/event-stream`
 mod web_stream;
 
 pub(crate) use error::Result;
 pub(crate) use web_client::*;
+pub(crate) use web_socket::*;
 pub(crate) use web_stream::*;
 
 // Only public for external use
@@ -1,6 +1,8 @@
 use crate::Headers;
 use crate::webc::{Error, Result};
+use bytes::Bytes;
 use reqwest::header::HeaderMap;
+use reqwest::multipart::Form;
 use reqwest::{Method, RequestBuilder, StatusCode};
 use serde_json::Value;
 
@@ -32,12 +34,9 @@ impl WebClient {
 // region:    --- Web Method Implementation
 
 impl WebClient {
-	pub async fn do_get(&self, url: &str, headers: &[(String, String)]) -> Result<WebResponse> {
-		let mut reqwest_builder = self.reqwest_client.request(Method::GET, url);
+	pub async fn do_get(&self, url: &str, headers: &Headers) -> Result<WebResponse> {
+		let reqwest_builder = self.new_req_builder_for(Method::GET, url, headers);
 
-		for (k, v) in headers.iter() {
-			reqwest_builder = reqwest_builder.header(k, v);
-		}
 		let reqwest_res = reqwest_builder.send().await?;
 
 		let response = WebResponse::from_reqwest_response(reqwest_res).await?;
@@ -55,16 +54,97 @@ impl WebClient {
 		Ok(response)
 	}
 
+	pub async fn do_delete(&self, url: &str, headers: &Headers) -> Result<WebResponse> {
+		let reqwest_builder = self.new_req_builder_for(Method::DELETE, url, headers);
+
+		let reqwest_res = reqwest_builder.send().await?;
+
+		let response = WebResponse::from_reqwest_response(reqwest_res).await?;
+
+		Ok(response)
+	}
+
+	/// Get the raw bytes of a response body (e.g., for the binary media downloads).
+	pub async fn do_get_bytes(&self, url: &str, headers: &Headers) -> Result<Bytes> {
+		let reqwest_builder = self.new_req_builder_for(Method::GET, url, headers);
+
+		let reqwest_res = reqwest_builder.send().await?;
+
+		let status = reqwest_res.status();
+		if !status.is_success() {
+			let headers = reqwest_res.headers().clone();
+			let body = reqwest_res.text().await?;
+			return Err(Error::ResponseFailedStatus {
+				status,
+				body,
+				headers: Box::new(headers),
+			});
+		}
+
+		Ok(reqwest_res.bytes().await?)
+	}
+
+	/// Delete with a JSON body (e.g., Ollama `api/delete`).
+	pub async fn do_delete_json(&self, url: &str, headers: &Headers, content: Value) -> Result<WebResponse> {
+		let reqwest_builder = self.new_req_builder_for(Method::DELETE, url, headers).json(&content);
+
+		let reqwest_res = reqwest_builder.send().await?;
+
+		let response = WebResponse::from_reqwest_response(reqwest_res).await?;
+
+		Ok(response)
+	}
+
+	/// Post a `multipart/form-data` body (e.g., for file uploads).
+	pub async fn do_post_multipart(&self, url: &str, headers: &Headers, form: Form) -> Result<WebResponse> {
+		let reqwest_builder = self.new_req_builder_for(Method::POST, url, headers).multipart(form);
+
+		let reqwest_res = reqwest_builder.send().await?;
+
+		let response = WebResponse::from_reqwest_response(reqwest_res).await?;
+
+		Ok(response)
+	}
+
+	/// Post a raw body with a given content type (e.g., for `multipart/related` uploads).
+	pub async fn do_post_bytes(
+		&self,
+		url: &str,
+		headers: &Headers,
+		content_type: &str,
+		body: Vec<u8>,
+	) -> Result<WebResponse> {
+		let reqwest_builder = self
+			.new_req_builder_for(Method::POST, url, headers)
+			.header("Content-Type", content_type)
+			.body(body);
+
+		let reqwest_res = reqwest_builder.send().await?;
+
+		let response = WebResponse::from_reqwest_response(reqwest_res).await?;
+
+		Ok(response)
+	}
+
 	pub fn new_req_builder(&self, url: &str, headers: &Headers, content: Value) -> Result<RequestBuilder> {
-		let method = Method::POST;
+		let reqwest_builder = self.new_req_builder_for(Method::POST, url, headers).json(&content);
+
+		Ok(reqwest_builder)
+	}
+
+	/// Same as `new_req_builder`, but with an already serialized JSON body (e.g., the signed body bytes).
+	pub fn new_req_builder_json_bytes(&self, url: &str, headers: &Headers, body: Vec<u8>) -> RequestBuilder {
+		self.new_req_builder_for(Method::POST, url, headers)
+			.header("Content-Type", "application/json")
+			.body(body)
+	}
 
+	fn new_req_builder_for(&self, method: Method, url: &str, headers: &Headers) -> RequestBuilder {
 		let mut reqwest_builder = self.reqwest_client.request(method, url);
 		for (k, v) in headers.iter() {
 			reqwest_builder = reqwest_builder.header(k, v);
 		}
-		reqwest_builder = reqwest_builder.json(&content);
-
-		Ok(reqwest_builder)
+		reqwest_builder
 	}
 }
 // endregion: --- Web Method Implementation
@@ -79,6 +159,10 @@ pub struct WebResponse {
 	#[allow(unused)]
 	pub status: StatusCode,
 	pub body: Value,
+	/// The exact bytes of the response body (e.g., for the response digest)
+	pub body_bytes: Bytes,
+	/// The response headers (e.g., for the upload session url of the resumable uploads)
+	pub headers: HeaderMap,
 }
 
 impl WebResponse {
@@ -105,15 +189,25 @@ impl WebResponse {
 
 		// Capture the body
 		let ct = header_map.get("content-type").and_then(|v| v.to_str().ok()).unwrap_or_default();
+		let body_bytes = res.bytes().await?;
 		let body = if ct.starts_with("application/json") {
-			res.json::<Value>().await?
+			serde_json::from_slice::<Value>(&body_bytes)?
+		}
+		// Some APIs return an empty body on success (e.g., Ollama delete)
+		else if body_bytes.is_empty() {
+			Value::Null
 		} else {
 			return Err(Error::ResponseFailedNotJson {
 				content_type: ct.to_string(),
 			});
 		};
 
-		Ok(WebResponse { status, body })
+		Ok(WebResponse {
+			status,
+			body,
+			body_bytes,
+			headers: header_map,
+		})
 	}
 }
 
@@ -0,0 +1,118 @@
+use crate::Headers;
+use crate::webc::{Error, Result};
+use futures::stream::{SplitSink, SplitStream};
+use futures::{SinkExt, Stream, StreamExt};
+use serde_json::Value;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::{HeaderName, HeaderValue};
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// A simple JSON WebSocket transport for the bidirectional APIs (e.g., OpenAI Realtime).
+///
+/// NOTE: All messages are assumed to be JSON (text or binary frames).
+///       Ping/Pong are handled by tungstenite, and a Close frame ends the receiver stream.
+pub struct WebSocket {
+	sender: WebSocketSender,
+	receiver: WebSocketReceiver,
+}
+
+// region:    --- Constructors
+
+impl WebSocket {
+	/// Connect to a `wss://` (or `ws://`) url with the given headers.
+	pub async fn connect(url: &str, headers: &Headers) -> Result<WebSocket> {
+		let mut request = url.into_client_request().map_err(ws_error)?;
+		for (k, v) in headers.iter() {
+			let name =
+				HeaderName::from_bytes(k.as_bytes()).map_err(|_| Error::WebSocketInvalidHeader { name: k.clone() })?;
+			let value = HeaderValue::from_str(v).map_err(|_| Error::WebSocketInvalidHeader { name: k.clone() })?;
+			request.headers_mut().insert(name, value);
+		}
+
+		let (ws_stream, _res) = tokio_tungstenite::connect_async(request).await.map_err(ws_error)?;
+		let (sink, stream) = ws_stream.split();
+
+		Ok(WebSocket {
+			sender: WebSocketSender { sink },
+			receiver: WebSocketReceiver { stream },
+		})
+	}
+}
+
+// endregion: --- Constructors
+
+impl WebSocket {
+	/// Split the WebSocket into its sender and receiver (to send and receive concurrently).
+	pub fn split(self) -> (WebSocketSender, WebSocketReceiver) {
+		(self.sender, self.receiver)
+	}
+}
+
+// region:    --- WebSocketSender
+
+pub struct WebSocketSender {
+	sink: SplitSink<WsStream, Message>,
+}
+
+impl WebSocketSender {
+	/// Send a JSON value as a text message.
+	pub async fn send_json(&mut self, value: &Value) -> Result<()> {
+		self.sink.send(Message::text(value.to_string())).await.map_err(ws_error)
+	}
+
+	/// Send the Close frame.
+	pub async fn close(&mut self) -> Result<()> {
+		self.sink.close().await.map_err(ws_error)
+	}
+}
+
+// endregion: --- WebSocketSender
+
+// region:    --- WebSocketReceiver
+
+/// The JSON messages stream of a WebSocket.
+pub struct WebSocketReceiver {
+	stream: SplitStream<WsStream>,
+}
+
+impl Stream for WebSocketReceiver {
+	type Item = Result<Value>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let this = self.get_mut();
+
+		loop {
+			return match Pin::new(&mut this.stream).poll_next(cx) {
+				Poll::Ready(Some(Ok(Message::Text(text)))) => {
+					Poll::Ready(Some(serde_json::from_str::<Value>(&text).map_err(Error::from)))
+				}
+				// Some providers (e.g., Gemini Live) send the JSON messages as binary frames
+				Poll::Ready(Some(Ok(Message::Binary(bytes)))) => {
+					Poll::Ready(Some(serde_json::from_slice::<Value>(&bytes).map_err(Error::from)))
+				}
+				Poll::Ready(Some(Ok(Message::Close(_)))) => Poll::Ready(None),
+				// Ping/Pong/Frame are handled by tungstenite
+				Poll::Ready(Some(Ok(_))) => continue,
+				Poll::Ready(Some(Err(err))) => Poll::Ready(Some(Err(ws_error(err)))),
+				Poll::Ready(None) => Poll::Ready(None),
+				Poll::Pending => Poll::Pending,
+			};
+		}
+	}
+}
+
+// endregion: --- WebSocketReceiver
+
+// region:    --- Support
+
+fn ws_error(err: tokio_tungstenite::tungstenite::Error) -> Error {
+	Error::WebSocket(Box::new(err))
+}
+
+// endregion: --- Support
@@ -1,19 +1,38 @@
 use crate::get_option_value;
-use crate::support::data::{IMAGE_URL_JPG_DUCK, get_b64_duck};
+use crate::support::data::{IMAGE_URL_JPG_DUCK, VIDEO_URL_YOUTUBE, get_b64_duck, get_b64_small_pdf, get_b64_tone_wav};
 use crate::support::{
 	Check, Result, StreamExtract, assert_contains, contains_checks, extract_stream_end, get_big_content,
 	seed_chat_req_simple, seed_chat_req_tool_simple, validate_checks,
 };
+use futures::StreamExt;
 use genai::adapter::AdapterKind;
 use genai::chat::{
-	CacheControl, ChatMessage, ChatOptions, ChatRequest, ChatResponseFormat, ContentPart, ImageSource, JsonSpec, Tool,
-	ToolResponse,
+	AiDisclosure, AutoContinue, BuiltInTool, CacheControl, CacheTtl, CapabilityFallback, ChatMessage, ChatOptions,
+	ChatRequest, ChatResponseFormat, CitationSource, ContentBlock, ContentPart, FinishReason, GeminiGroundingMetadata,
+	GroqTiming, ImageSource, JsonSpec, MessageContent, OperationUsage, OutputFixer, PromptLeakKind, PromptShield,
+	PromptShieldAction, RagChunk, RagCitations, ReasoningEffort, ReasoningSummary, RegenerationPolicy, SafetyCategory,
+	SafetyThreshold, SpeculativeEvent, TimeContext, Tool, ToolChoice, ToolResponse,
 };
-use genai::embed::EmbedOptions;
-use genai::resolver::{AuthData, AuthResolver, AuthResolverFn, IntoAuthResolverFn};
-use genai::{Client, ClientConfig, ModelIden};
+use genai::embed::{EmbedConcurrency, EmbedOptions, EmbedRequest, EmbeddingVector};
+use genai::files::FileUpload;
+use genai::golden::{GoldenCase, GoldenRunner, GoldenTolerance};
+use genai::prompt::PromptTemplate;
+use genai::realtime::{RealtimeConfig, RealtimeEvent, RealtimeInput, RealtimeModality};
+use genai::resolver::{AuthData, AuthResolver, AuthResolverFn, IntoAuthResolverFn, RequestSigner, SignableRequest};
+use genai::usage_report::UsageReportRequest;
+use genai::{
+	Client, ClientConfig, Headers, ModelIden, ModelPrice, ModelRouter, QuotaIncr, QuotaKind, QuotaManager, QuotaStore,
+	RouteCandidate, RouteStrategy, TenantQuota,
+};
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::Deserialize;
 use serde_json::{Value, json};
-use std::sync::Arc;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use value_ext::JsonValueExt;
 
 // region:    --- Chat
@@ -70,6 +89,49 @@ pub async fn common_test_chat_simple_ok(model: &str, checks: Option<Check>) -> R
 	Ok(())
 }
 
+pub async fn common_test_chat_response_digest_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_simple();
+	let options = ChatOptions::default().with_capture_response_digest(true);
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, Some(&options)).await?;
+
+	// -- Check
+	let digest = chat_res.response_digest.as_ref().ok_or("Should have response_digest")?;
+	assert_eq!(digest.algorithm, "sha256");
+	assert_eq!(digest.hash.len(), 64, "sha256 hex hash should be 64 chars");
+
+	Ok(())
+}
+
+pub async fn common_test_chat_capture_headers_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_simple();
+	let options = ChatOptions::default().with_capture_headers(true);
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req.clone(), None).await?;
+	let captured_res = client.exec_chat(model, chat_req, Some(&options)).await?;
+
+	// -- Check
+	assert!(
+		chat_res.response_headers.is_none(),
+		"Should not capture the headers by default"
+	);
+	let headers = captured_res.response_headers.as_ref().ok_or("Should have response_headers")?;
+	let request_id = headers.request_id.as_deref().ok_or("Should have the request id")?;
+	assert!(!request_id.is_empty(), "request id should not be empty");
+	assert!(
+		headers.ratelimit_remaining_requests.is_some(),
+		"Should have the remaining requests"
+	);
+
+	Ok(())
+}
+
 pub async fn common_test_chat_multi_system_ok(model: &str) -> Result<()> {
 	// -- Setup & Fixtures
 	let client = Client::default();
@@ -100,6 +162,28 @@ pub async fn common_test_chat_multi_system_ok(model: &str) -> Result<()> {
 	Ok(())
 }
 
+/// The developer role message (OpenAI `developer`, a system message for the other adapters).
+pub async fn common_test_chat_developer_role_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = ChatRequest::new(vec![
+		ChatMessage::developer("Answer with the city name only, in uppercase, and nothing else."),
+		ChatMessage::user("What is the capital of England?"),
+	]);
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert!(
+		content.contains("LONDON"),
+		"Content should contain 'LONDON', but was: {content}"
+	);
+
+	Ok(())
+}
+
 /// Test with JSON mode enabled. This is not a structured output test.
 /// - test_token: This is to avoid checking the token (due to an Ollama bug when in JSON mode, no token is returned)
 pub async fn common_test_chat_json_mode_ok(model: &str, checks: Option<Check>) -> Result<()> {
@@ -217,6 +301,49 @@ Reply in a JSON format."#,
 	Ok(())
 }
 
+pub async fn common_test_chat_structured_typed_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	// NOTE: The JsonSchema is implemented by hand (as `#[derive(JsonSchema)]` would), with an optional property.
+	#[derive(Debug, Deserialize)]
+	struct CountryInfo {
+		country: String,
+		capital: String,
+		population_millions: Option<f64>,
+	}
+	impl JsonSchema for CountryInfo {
+		fn schema_name() -> Cow<'static, str> {
+			"CountryInfo".into()
+		}
+		fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+			json_schema!({
+				"type": "object",
+				"properties": {
+					"country": generator.subschema_for::<String>(),
+					"capital": generator.subschema_for::<String>(),
+					"population_millions": generator.subschema_for::<Option<f64>>(),
+				},
+				"required": ["country", "capital"]
+			})
+		}
+	}
+
+	let client = Client::default();
+	let chat_req = ChatRequest::from_user("Give the info of France.");
+
+	// -- Exec
+	let res = client.exec_chat_structured::<CountryInfo>(model, chat_req, None).await?;
+
+	// -- Check
+	assert_eq!(res.value.country, "France");
+	assert_eq!(res.value.capital, "Paris");
+	if let Some(population_millions) = res.value.population_millions {
+		assert!(population_millions > 50., "population_millions should be > 50");
+	}
+	assert!(res.chat_response.first_text().is_some(), "Should have the raw text");
+
+	Ok(())
+}
+
 pub async fn common_test_chat_temperature_ok(model: &str) -> Result<()> {
 	// -- Setup & Fixtures
 	let client = Client::default();
@@ -235,493 +362,2575 @@ pub async fn common_test_chat_temperature_ok(model: &str) -> Result<()> {
 	Ok(())
 }
 
-pub async fn common_test_chat_stop_sequences_ok(model: &str) -> Result<()> {
+/// `check_fingerprint` for the providers returning the `system_fingerprint` (e.g., OpenAI).
+pub async fn common_test_chat_seed_ok(model: &str, check_fingerprint: bool) -> Result<()> {
 	// -- Setup & Fixtures
 	let client = Client::default();
-	let chat_req = ChatRequest::from_user("What is the capital of England?");
-	let chat_options = ChatOptions::default().with_stop_sequences(vec!["London".to_string()]);
+	let chat_req = seed_chat_req_simple();
+	let chat_options = ChatOptions::default().with_seed(42).with_temperature(0.);
 
 	// -- Exec
 	let chat_res = client.exec_chat(model, chat_req, Some(&chat_options)).await?;
 
-	let ai_content_lower = chat_res.first_text().ok_or("Should have a AI response")?.to_lowercase();
-
 	// -- Check
 	assert!(
-		!ai_content_lower.contains("london"),
-		"Content should not contain 'London'"
+		!chat_res.first_text().unwrap_or("").is_empty(),
+		"Content should not be empty"
 	);
+	if check_fingerprint {
+		assert!(chat_res.system_fingerprint.is_some(), "Should have a system_fingerprint");
+	}
 
 	Ok(())
 }
 
-pub async fn common_test_chat_reasoning_normalize_ok(model: &str) -> Result<()> {
+pub async fn common_test_chat_penalties_ok(model: &str) -> Result<()> {
 	// -- Setup & Fixtures
-	let client = Client::builder()
-		.with_chat_options(ChatOptions::default().with_normalize_reasoning_content(true))
-		.build();
+	let client = Client::default();
 	let chat_req = seed_chat_req_simple();
+	let chat_options = ChatOptions::default().with_frequency_penalty(0.5).with_presence_penalty(0.5);
 
 	// -- Exec
-	let chat_res = client.exec_chat(model, chat_req, None).await?;
-
-	// -- Check Content
-	chat_res.first_text();
-	let content = chat_res.first_text().ok_or("Should have content")?;
-	assert!(!content.trim().is_empty(), "Content should not be empty");
-
-	// -- Check Reasoning Content
-	let reasoning_content = chat_res.reasoning_content.as_deref().ok_or("Should have reasoning_content")?;
-	// Because the test is so simple, sometime ollama for example, will have `<think>\n\n</think>`
-	// So we are ok if is_empty() for now
-	// assert!(!reasoning_content.is_empty(), "reasoning_content should not be empty");
-	assert!(
-		reasoning_content.len() > content.len(),
-		"reasoning_content should be > than the content"
-	);
+	let chat_res = client.exec_chat(model, chat_req, Some(&chat_options)).await?;
 
-	// -- Check Usage
-	let usage = chat_res.usage;
-	let prompt_tokens = get_option_value!(usage.prompt_tokens);
-	let completion_tokens = get_option_value!(usage.completion_tokens);
-	let total_tokens = get_option_value!(usage.total_tokens);
-	assert!(total_tokens > 0, "total_tokens should be > 0");
+	// -- Check
 	assert!(
-		total_tokens == prompt_tokens + completion_tokens,
-		"total_tokens should be equal to prompt_token + completion_tokens"
+		!chat_res.first_text().unwrap_or("").is_empty(),
+		"Content should not be empty"
 	);
 
 	Ok(())
 }
 
-// endregion: --- Chat
-
-// region:    --- Chat Implicit Cache
-
-pub async fn common_test_chat_cache_implicit_simple_ok(model: &str) -> Result<()> {
+pub async fn common_test_chat_sampling_extras_ok(model: &str) -> Result<()> {
 	// -- Setup & Fixtures
 	let client = Client::default();
-	let big_content = get_big_content()?;
-	let chat_req = ChatRequest::new(vec![
-		// -- Messages (deactivate to see the differences)
-		ChatMessage::user(big_content),
-		ChatMessage::user("Give a very short summary of what each of those files are about."),
-	]);
+	let chat_req = seed_chat_req_simple();
+	let chat_options = ChatOptions::default()
+		.with_top_k(40)
+		.with_min_p(0.05)
+		.with_repetition_penalty(1.1);
 
 	// -- Exec
-	// Execute twice
-	let chat_res = client.exec_chat(model, chat_req.clone(), None).await?;
-	// sleep 500ms
-	tokio::time::sleep(std::time::Duration::from_millis(500)).await;
-	let chat_res = client.exec_chat(model, chat_req, None).await?;
+	let chat_res = client.exec_chat(model, chat_req, Some(&chat_options)).await?;
 
-	// -- Check Content
-	let content = chat_res.first_text().ok_or("Should have content")?;
-	assert!(!content.trim().is_empty(), "Content should not be empty");
+	// -- Check
+	assert!(
+		!chat_res.first_text().unwrap_or("").is_empty(),
+		"Content should not be empty"
+	);
 
-	// -- Check Usage
-	let usage = &chat_res.usage;
+	Ok(())
+}
 
-	let prompt_tokens = get_option_value!(usage.prompt_tokens);
-	let completion_tokens = get_option_value!(usage.completion_tokens);
-	let total_tokens = get_option_value!(usage.total_tokens);
-	let prompt_tokens_details = usage
-		.prompt_tokens_details
-		.as_ref()
-		.ok_or("Should have prompt_tokens_details")?;
-	let cached_tokens = get_option_value!(prompt_tokens_details.cached_tokens);
+pub async fn common_test_chat_logprobs_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_simple();
+	let chat_options = ChatOptions::default().with_logprobs(true);
 
-	assert!(cached_tokens > 0, " cached_tokens should be greater than 0");
-	assert!(total_tokens > 0, "total_tokens should be > 0");
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, Some(&chat_options)).await?;
+
+	// -- Check
+	assert!(
+		chat_res.choices.is_empty(),
+		"Should not have choices for a single candidate"
+	);
+	assert!(chat_res.logprobs.is_some(), "Should have the first choice logprobs");
 
 	Ok(())
 }
 
-// endregion: --- Chat Implicit Cache
-
-// region:    --- Chat Explicit Cache
-
-pub async fn common_test_chat_cache_explicit_user_ok(model: &str) -> Result<()> {
+pub async fn common_test_chat_candidate_count_ok(model: &str) -> Result<()> {
 	// -- Setup & Fixtures
 	let client = Client::default();
-	let big_content = get_big_content()?;
-	let chat_req = ChatRequest::new(vec![
-		// -- Messages (deactivate to see the differences)
-		ChatMessage::system("Give a very short summary of what each of those files are about"),
-		ChatMessage::user(big_content).with_options(CacheControl::Ephemeral),
-	]);
+	let chat_req = seed_chat_req_simple();
+	let chat_options = ChatOptions::default()
+		.with_candidate_count(2)
+		.with_logprobs(true)
+		.with_temperature(1.);
 
 	// -- Exec
-	let chat_res = client.exec_chat(model, chat_req, None).await?;
+	let chat_res = client.exec_chat(model, chat_req, Some(&chat_options)).await?;
 
-	// -- Check Content
-	let content = chat_res.first_text().ok_or("Should have content")?;
-	assert!(!content.trim().is_empty(), "Content should not be empty");
+	// -- Check
+	assert_eq!(chat_res.choices.len(), 2, "Should have 2 choices");
+	for choice in chat_res.choices.iter() {
+		assert!(
+			!choice.first_text().unwrap_or("").is_empty(),
+			"Choice content should not be empty"
+		);
+		assert!(choice.finish_reason.is_some(), "Choice should have a finish_reason");
+		assert!(choice.logprobs.is_some(), "Choice should have logprobs");
+	}
+	assert_eq!(chat_res.first_text(), chat_res.choices[0].first_text());
+	assert_eq!(chat_res.logprobs, chat_res.choices[0].logprobs);
 
-	// -- Check Usage
-	let usage = &chat_res.usage;
+	Ok(())
+}
 
-	let prompt_tokens = get_option_value!(usage.prompt_tokens);
-	let completion_tokens = get_option_value!(usage.completion_tokens);
-	let total_tokens = get_option_value!(usage.total_tokens);
-	let prompt_tokens_details = usage
-		.prompt_tokens_details
-		.as_ref()
-		.ok_or("Should have prompt_tokens_details")?;
-	let cache_creation_tokens = get_option_value!(prompt_tokens_details.cache_creation_tokens);
-	let cached_tokens = get_option_value!(prompt_tokens_details.cached_tokens);
+pub async fn common_test_chat_logit_bias_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_simple();
+	// NOTE: A light bias on a few token ids, just to check that the option is accepted.
+	let chat_options = ChatOptions::default().with_logit_bias([(15339, -10), (1820, -10)]);
 
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, Some(&chat_options)).await?;
+
+	// -- Check
 	assert!(
-		cache_creation_tokens > 0 || cached_tokens > 0,
-		"one of cache_creation_tokens or cached_tokens should be greater than 0"
+		!chat_res.first_text().unwrap_or("").is_empty(),
+		"Content should not be empty"
 	);
-	assert!(total_tokens > 0, "total_tokens should be > 0");
 
 	Ok(())
 }
 
-pub async fn common_test_chat_cache_explicit_system_ok(model: &str) -> Result<()> {
+pub async fn common_test_chat_logit_bias_not_supported_should_fail(model: &str) -> Result<()> {
 	// -- Setup & Fixtures
 	let client = Client::default();
-	let big_content = get_big_content()?;
-	let chat_req = ChatRequest::new(vec![
-		// -- Messages (deactivate to see the differences)
-		ChatMessage::system("You are a senior developer which has the following code base:"),
-		ChatMessage::system(big_content).with_options(CacheControl::Ephemeral),
-		ChatMessage::user("can you give a summary of each file (very concise)"),
-	]);
+	let chat_req = seed_chat_req_simple();
+	let chat_options = ChatOptions::default().with_logit_bias([(15339, -100)]);
 
 	// -- Exec
-	let chat_res = client.exec_chat(model, chat_req, None).await?;
+	let result = client.exec_chat(model, chat_req, Some(&chat_options)).await;
 
-	// -- Check Content
-	let content = chat_res.first_text().ok_or("Should have content")?;
-	assert!(!content.trim().is_empty(), "Content should not be empty");
+	// -- Check
+	match result {
+		Err(genai::Error::AdapterNotSupported { feature, .. }) if feature == "logit_bias" => Ok(()),
+		other => Err(format!("Should be AdapterNotSupported for logit_bias, but was: {other:?}").into()),
+	}
+}
 
-	// -- Check Usage
-	let usage = &chat_res.usage;
+pub async fn common_test_chat_stop_sequences_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = ChatRequest::from_user("What is the capital of England?");
+	let chat_options = ChatOptions::default().with_stop_sequences(vec!["London".to_string()]);
 
-	let prompt_tokens = get_option_value!(usage.prompt_tokens);
-	let completion_tokens = get_option_value!(usage.completion_tokens);
-	let total_tokens = get_option_value!(usage.total_tokens);
-	let prompt_tokens_details = usage
-		.prompt_tokens_details
-		.as_ref()
-		.ok_or("Should have prompt_tokens_details")?;
-	let cache_creation_tokens = get_option_value!(prompt_tokens_details.cache_creation_tokens);
-	let cached_tokens = get_option_value!(prompt_tokens_details.cached_tokens);
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, Some(&chat_options)).await?;
+
+	let ai_content_lower = chat_res.first_text().ok_or("Should have a AI response")?.to_lowercase();
 
+	// -- Check
 	assert!(
-		cache_creation_tokens > 0 || cached_tokens > 0,
-		"one of cache_creation_tokens or cached_tokens should be greater than 0"
+		!ai_content_lower.contains("london"),
+		"Content should not contain 'London'"
 	);
-	assert!(total_tokens > 0, "total_tokens should be > 0");
 
 	Ok(())
 }
 
-// endregion: --- Chat Explicit Cache
+/// The `stop_sequence` is the one of the stop sequences which ended the generation (chat and stream).
+pub async fn common_test_chat_stop_sequence_matched_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = ChatRequest::from_user("What is the capital of England?");
+	let chat_options = ChatOptions::default().with_stop_sequences(vec!["Paris".to_string(), "London".to_string()]);
 
-// region:    --- Chat Stream Tests
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req.clone(), Some(&chat_options)).await?;
+	let stream_res = client.exec_chat_stream(model, chat_req, Some(&chat_options)).await?;
+	let StreamExtract { stream_end, .. } = extract_stream_end(stream_res.stream).await?;
 
-pub async fn common_test_chat_stream_simple_ok(model: &str, checks: Option<Check>) -> Result<()> {
-	validate_checks(checks.clone(), Check::REASONING)?;
+	// -- Check
+	assert_eq!(chat_res.finish_reason, Some(FinishReason::Stop));
+	assert_eq!(chat_res.stop_sequence.as_deref(), Some("London"));
+	assert_eq!(stream_end.stop_sequence.as_deref(), Some("London"));
 
+	Ok(())
+}
+
+pub async fn common_test_chat_user_metadata_ok(model: &str) -> Result<()> {
 	// -- Setup & Fixtures
 	let client = Client::default();
-	let chat_req = seed_chat_req_simple();
+	let chat_req = ChatRequest::from_user("What is the capital of England?");
+	let chat_options = ChatOptions::default()
+		.with_user("test-user-123")
+		.with_metadata([("feature", "capital-quiz")]);
 
 	// -- Exec
-	let chat_res = client.exec_chat_stream(model, chat_req.clone(), None).await?;
+	let chat_res = client.exec_chat(model, chat_req, Some(&chat_options)).await?;
 
-	// -- Extract Stream content
-	let StreamExtract {
-		stream_end,
-		content,
-		reasoning_content,
-	} = extract_stream_end(chat_res.stream).await?;
-	let content = content.ok_or("extract_stream_end SHOULD have extracted some content")?;
-
-	// -- Check no meta_usage and captured_content
-	assert!(!content.is_empty(), "Content streamed should not be empty");
+	// -- Check
+	let content = chat_res.first_text().ok_or("Should have content")?;
 	assert!(
-		stream_end.captured_usage.is_none(),
-		"StreamEnd should not have any meta_usage"
+		content.to_lowercase().contains("london"),
+		"Content should contain 'London'"
 	);
+
+	Ok(())
+}
+
+/// The stored completion (with its metadata) is accepted by the provider.
+pub async fn common_test_chat_store_metadata_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = ChatRequest::from_user("What is the capital of England?");
+	let chat_options = ChatOptions::default()
+		.with_store(true)
+		.with_metadata([("feature", "capital-quiz"), ("suite", "genai-tests")]);
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, Some(&chat_options)).await?;
+
+	// -- Check
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert_contains(&content.to_lowercase(), "london");
+
+	Ok(())
+}
+
+/// The `PromptTemplate` renders the system (with a partial) and the user message, and a missing variable is an error.
+pub async fn common_test_chat_prompt_template_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let template = PromptTemplate::new("What is the capital of {{ country }}?")
+		.with_system("{{> persona}} Answer in one sentence.")
+		.with_partial("persona", "You are a geography teacher.");
+
+	// -- Exec
+	let chat_req = template.render([("country", "England")])?;
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert_contains(&content.to_lowercase(), "london");
+	let render_res = template.render(Vec::<(String, String)>::new());
 	assert!(
-		stream_end.captured_content.is_none(),
-		"StreamEnd should not have any captured_content"
+		matches!(render_res, Err(genai::Error::PromptTemplateRender { .. })),
+		"Missing variable should be a PromptTemplateRender error"
 	);
 
-	// -- Check Reasoning Content
-	if contains_checks(checks, Check::REASONING) {
-		let reasoning_content =
-			reasoning_content.ok_or("extract_stream_end SHOULD have extracted some reasoning_content")?;
-		assert!(!reasoning_content.is_empty(), "reasoning_content should not be empty");
-		// We can assume that the reasoning content should be bigger than the content given the prompt to keep content very concise.
-		assert!(
-			reasoning_content.len() > content.len(),
-			"Reasoning content should be > than the content"
-		);
+	Ok(())
+}
+
+/// The `safety_settings` are accepted by the provider, and a harmless answer is not a refusal.
+pub async fn common_test_chat_safety_settings_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = ChatRequest::from_user("What is the capital of England?");
+	let chat_options = ChatOptions::default().with_safety_settings([
+		(SafetyCategory::Harassment, SafetyThreshold::BlockLowAndAbove),
+		(SafetyCategory::HateSpeech, SafetyThreshold::BlockOnlyHigh),
+		(SafetyCategory::DangerousContent, SafetyThreshold::BlockNone),
+	]);
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, Some(&chat_options)).await?;
+
+	// -- Check
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert_contains(&content.to_lowercase(), "london");
+	assert!(!chat_res.is_refusal(), "Should not be a refusal");
+
+	Ok(())
+}
+
+/// The `prediction` (the current code to edit) is used, with the prediction tokens in the usage details.
+pub async fn common_test_chat_prediction_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let code = r#"
+fn total_price(items: &[Item]) -> f64 {
+	let mut total = 0.0;
+	for item in items {
+		total += item.price * item.quantity as f64;
 	}
+	total
+}
+"#;
+	let chat_req = ChatRequest::new(vec![
+		ChatMessage::system("Rewrite the given Rust code with the requested change. Answer with the code only."),
+		ChatMessage::user(format!("Rename the `total` variable to `sum`.\n\n{code}")),
+	]);
+	let chat_options = ChatOptions::default().with_prediction(code);
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, Some(&chat_options)).await?;
+
+	// -- Check
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert_contains(content, "sum +=");
+	let details = chat_res
+		.usage
+		.completion_tokens_details
+		.as_ref()
+		.ok_or("Should have completion_tokens_details")?;
+	let prediction_tokens =
+		details.accepted_prediction_tokens.unwrap_or_default() + details.rejected_prediction_tokens.unwrap_or_default();
+	assert!(
+		prediction_tokens > 0,
+		"Should have accepted or rejected prediction tokens"
+	);
 
 	Ok(())
 }
 
-/// Check that the capture content flag does the capture
-/// NOTE: When checking for reasoning, the captured_reasoning_content should be None in this function
-pub async fn common_test_chat_stream_capture_content_ok(model: &str) -> Result<()> {
+/// Merge the provider max tokens parameter with the `extra_body` (the typed max tokens is not set).
+pub async fn common_test_chat_extra_body_ok(model: &str, max_tokens_key: &str) -> Result<()> {
 	// -- Setup & Fixtures
-	let client = Client::builder()
-		.with_chat_options(ChatOptions::default().with_capture_content(true))
-		.build();
-	let chat_req = seed_chat_req_simple();
+	let client = Client::default();
+	let chat_req = ChatRequest::from_user("Write the numbers from 1 to 60 in words, separated by commas.");
+	let chat_options = ChatOptions::default().with_extra_body(json!({ max_tokens_key: 16 }));
 
 	// -- Exec
-	let chat_res = client.exec_chat_stream(model, chat_req.clone(), None).await?;
+	let chat_res = client.exec_chat(model, chat_req, Some(&chat_options)).await?;
 
-	// -- Extract Stream content
-	let StreamExtract {
-		stream_end,
-		content,
-		reasoning_content,
-	} = extract_stream_end(chat_res.stream).await?;
+	// -- Check
+	assert_eq!(chat_res.finish_reason, Some(FinishReason::Length));
 
-	// -- Check meta_usage
-	// Should be None as not captured
+	Ok(())
+}
+
+/// The extra headers are sent, and are merged after the adapter auth header (so a bad key override fails).
+pub async fn common_test_chat_extra_headers_ok(model: &str, auth_header: &str, bad_auth_value: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = ChatRequest::from_user("What is the capital of England?");
+	let ok_options = ChatOptions::default().with_extra_headers(("x-genai-test", "extra-headers"));
+	let bad_auth_options = ChatOptions::default().with_extra_headers((auth_header, bad_auth_value));
+
+	// -- Exec
+	let ok_res = client.exec_chat(model, chat_req.clone(), Some(&ok_options)).await?;
+	let bad_auth_res = client.exec_chat(model, chat_req, Some(&bad_auth_options)).await;
+
+	// -- Check
+	assert!(ok_res.first_text().is_some(), "Should have content");
 	assert!(
-		stream_end.captured_usage.is_none(),
-		"StreamEnd should not have any meta_usage"
+		bad_auth_res.is_err(),
+		"The overridden auth header should fail the request"
 	);
 
-	// -- Check captured_content
-	let captured_content = get_option_value!(stream_end.captured_content);
-	assert!(!captured_content.is_empty(), "captured_content.length should be > 0");
+	Ok(())
+}
 
-	// -- Check Reasoning Content
-	// Should always be none, as it was not instructed to be captured.
+/// Truncate the output with a small max tokens, and check the continuations complete it.
+pub async fn common_test_chat_auto_continue_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req =
+		ChatRequest::from_user("Write the numbers from 1 to 60 in words, separated by commas, and nothing else.");
+	let truncate_options = ChatOptions::default().with_max_tokens(40);
+	let continue_options = truncate_options.clone().with_auto_continue(AutoContinue::new(8));
+
+	// -- Exec
+	let truncated_res = client.exec_chat(model, chat_req.clone(), Some(&truncate_options)).await?;
+	let chat_res = client.exec_chat(model, chat_req, Some(&continue_options)).await?;
+
+	// -- Check
+	assert_eq!(truncated_res.finish_reason, Some(FinishReason::Length));
+	assert_eq!(chat_res.finish_reason, Some(FinishReason::Stop));
+	let content = chat_res.first_text().ok_or("Should have text result")?.to_lowercase();
+	assert_contains(&content, "sixty");
+	let completion_tokens = chat_res.usage.completion_tokens.ok_or("Should have completion_tokens")?;
 	assert!(
-		stream_end.captured_reasoning_content.is_none(),
-		"The captured_reasoning_content should be None"
+		completion_tokens > 40,
+		"completion_tokens should include the continuations"
 	);
 
 	Ok(())
 }
 
-pub async fn common_test_chat_stream_capture_all_ok(model: &str, checks: Option<Check>) -> Result<()> {
-	validate_checks(checks.clone(), Check::REASONING)?;
+/// Inject retrieved chunks, and check the markers of the answer are resolved into citations.
+pub async fn common_test_chat_rag_citations_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let rag = RagCitations::new(vec![
+		RagChunk::new("doc1-1", "The Zorblat company was founded in 1987 in Lyon.").with_title("Zorblat history"),
+		RagChunk::new("doc2-4", "The Zorblat mascot is a blue heron named Pim."),
+	]);
+	let chat_req = rag.apply(ChatRequest::from_user(
+		"In which city was Zorblat founded? Answer in one sentence.",
+	));
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let answer = rag.resolve_response(&chat_res).ok_or("Should have text result")?;
+	assert_contains(&answer.text, "Lyon");
+	assert!(!answer.text.contains("[doc1-1"), "markers should be removed");
+	let citation = answer
+		.citations
+		.iter()
+		.find(|citation| citation.id == "doc1-1")
+		.ok_or("Should cite doc1-1")?;
+	assert_eq!(citation.title.as_deref(), Some("Zorblat history"));
+	let span = citation.span.clone().ok_or("Should have a span")?;
+	let cited: String = answer.text.chars().skip(span.start).take(span.len()).collect();
+	assert_contains(&cited, "Lyon");
+
+	Ok(())
+}
 
+/// The `ReasoningEffort::Disabled` turns the thinking off (for the models which allow it).
+pub async fn common_test_chat_reasoning_disabled_ok(model: &str) -> Result<()> {
 	// -- Setup & Fixtures
-	let client = Client::builder()
-		.with_chat_options(
-			ChatOptions::default()
-				.with_capture_usage(true)
-				.with_capture_content(true)
-				.with_capture_reasoning_content(true),
-		)
-		.build();
+	let client = Client::default();
 	let chat_req = seed_chat_req_simple();
+	let chat_options = ChatOptions::default().with_reasoning_effort(ReasoningEffort::Disabled);
 
 	// -- Exec
-	let chat_res = client.exec_chat_stream(model, chat_req.clone(), None).await?;
+	let chat_res = client.exec_chat(model, chat_req, Some(&chat_options)).await?;
 
-	// -- Extract Stream content
+	// -- Check
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert!(!content.trim().is_empty(), "Content should not be empty");
+	let reasoning_tokens = chat_res
+		.usage
+		.completion_tokens_details
+		.as_ref()
+		.and_then(|v| v.reasoning_tokens)
+		.unwrap_or_default();
+	assert_eq!(reasoning_tokens, 0, "Should have no reasoning tokens");
+
+	Ok(())
+}
+
+/// The `ChatOptions::reasoning_summary` returns the reasoning summary, both for the chat and the stream.
+pub async fn common_test_chat_reasoning_summary_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req =
+		ChatRequest::from_user("How many prime numbers are there between 10 and 50? Answer with the number only.");
+	let chat_options = ChatOptions::default()
+		.with_reasoning_summary(ReasoningSummary::Auto)
+		.with_capture_reasoning_content(true);
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req.clone(), Some(&chat_options)).await?;
+
+	// -- Check
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert_contains(content, "11");
+	let reasoning_content = chat_res.reasoning_content.as_deref().ok_or("Should have reasoning_content")?;
+	assert!(
+		!reasoning_content.trim().is_empty(),
+		"reasoning_content should not be empty"
+	);
+
+	// -- Exec Stream
+	let chat_res = client.exec_chat_stream(model, chat_req, Some(&chat_options)).await?;
 	let StreamExtract {
 		stream_end,
 		content,
 		reasoning_content,
 	} = extract_stream_end(chat_res.stream).await?;
 
-	// -- Check meta_usage
-	let meta_usage = stream_end.captured_usage.as_ref().ok_or("Should have usage")?;
+	// -- Check Stream
+	let content = content.ok_or("Should have streamed content")?;
+	assert_contains(&content, "11");
+	let reasoning_content = reasoning_content.ok_or("Should have streamed reasoning_content")?;
+	assert!(
+		!reasoning_content.trim().is_empty(),
+		"reasoning_content should not be empty"
+	);
+	assert_eq!(
+		stream_end.captured_reasoning_content.as_deref(),
+		Some(reasoning_content.as_str()),
+		"captured_reasoning_content should be the streamed reasoning_content"
+	);
+
+	Ok(())
+}
+
+pub async fn common_test_chat_reasoning_normalize_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::builder()
+		.with_chat_options(ChatOptions::default().with_normalize_reasoning_content(true))
+		.build();
+	let chat_req = seed_chat_req_simple();
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check Content
+	chat_res.first_text();
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert!(!content.trim().is_empty(), "Content should not be empty");
 
+	// -- Check Reasoning Content
+	let reasoning_content = chat_res.reasoning_content.as_deref().ok_or("Should have reasoning_content")?;
+	// Because the test is so simple, sometime ollama for example, will have `<think>\n\n</think>`
+	// So we are ok if is_empty() for now
+	// assert!(!reasoning_content.is_empty(), "reasoning_content should not be empty");
 	assert!(
-		get_option_value!(meta_usage.prompt_tokens) > 0,
-		"prompt_token should be > 0"
+		reasoning_content.len() > content.len(),
+		"reasoning_content should be > than the content"
 	);
+
+	// -- Check Usage
+	let usage = chat_res.usage;
+	let prompt_tokens = get_option_value!(usage.prompt_tokens);
+	let completion_tokens = get_option_value!(usage.completion_tokens);
+	let total_tokens = get_option_value!(usage.total_tokens);
+	assert!(total_tokens > 0, "total_tokens should be > 0");
 	assert!(
-		get_option_value!(meta_usage.completion_tokens) > 0,
-		"completion_tokens should be > 0"
+		total_tokens == prompt_tokens + completion_tokens,
+		"total_tokens should be equal to prompt_token + completion_tokens"
 	);
+
+	Ok(())
+}
+
+// endregion: --- Chat
+
+// region:    --- Chat Implicit Cache
+
+pub async fn common_test_chat_cache_implicit_simple_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let big_content = get_big_content()?;
+	let chat_req = ChatRequest::new(vec![
+		// -- Messages (deactivate to see the differences)
+		ChatMessage::user(big_content),
+		ChatMessage::user("Give a very short summary of what each of those files are about."),
+	]);
+
+	// -- Exec
+	// Execute twice
+	let chat_res = client.exec_chat(model, chat_req.clone(), None).await?;
+	// sleep 500ms
+	tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check Content
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert!(!content.trim().is_empty(), "Content should not be empty");
+
+	// -- Check Usage
+	let usage = &chat_res.usage;
+
+	let prompt_tokens = get_option_value!(usage.prompt_tokens);
+	let completion_tokens = get_option_value!(usage.completion_tokens);
+	let total_tokens = get_option_value!(usage.total_tokens);
+	let prompt_tokens_details = usage
+		.prompt_tokens_details
+		.as_ref()
+		.ok_or("Should have prompt_tokens_details")?;
+	let cached_tokens = get_option_value!(prompt_tokens_details.cached_tokens);
+
+	assert!(cached_tokens > 0, " cached_tokens should be greater than 0");
+	assert!(total_tokens > 0, "total_tokens should be > 0");
+
+	Ok(())
+}
+
+// endregion: --- Chat Implicit Cache
+
+// region:    --- Chat Explicit Cache
+
+pub async fn common_test_chat_cache_explicit_user_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let big_content = get_big_content()?;
+	let chat_req = ChatRequest::new(vec![
+		// -- Messages (deactivate to see the differences)
+		ChatMessage::system("Give a very short summary of what each of those files are about"),
+		ChatMessage::user(big_content).with_options(CacheControl::Ephemeral),
+	]);
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check Content
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert!(!content.trim().is_empty(), "Content should not be empty");
+
+	// -- Check Usage
+	let usage = &chat_res.usage;
+
+	let prompt_tokens = get_option_value!(usage.prompt_tokens);
+	let completion_tokens = get_option_value!(usage.completion_tokens);
+	let total_tokens = get_option_value!(usage.total_tokens);
+	let prompt_tokens_details = usage
+		.prompt_tokens_details
+		.as_ref()
+		.ok_or("Should have prompt_tokens_details")?;
+	let cache_creation_tokens = get_option_value!(prompt_tokens_details.cache_creation_tokens);
+	let cached_tokens = get_option_value!(prompt_tokens_details.cached_tokens);
+
+	assert!(
+		cache_creation_tokens > 0 || cached_tokens > 0,
+		"one of cache_creation_tokens or cached_tokens should be greater than 0"
+	);
+	assert!(total_tokens > 0, "total_tokens should be > 0");
+
+	Ok(())
+}
+
+pub async fn common_test_chat_cache_explicit_system_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let big_content = get_big_content()?;
+	let chat_req = ChatRequest::new(vec![
+		// -- Messages (deactivate to see the differences)
+		ChatMessage::system("You are a senior developer which has the following code base:"),
+		ChatMessage::system(big_content).with_options(CacheControl::Ephemeral),
+		ChatMessage::user("can you give a summary of each file (very concise)"),
+	]);
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check Content
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert!(!content.trim().is_empty(), "Content should not be empty");
+
+	// -- Check Usage
+	let usage = &chat_res.usage;
+
+	let prompt_tokens = get_option_value!(usage.prompt_tokens);
+	let completion_tokens = get_option_value!(usage.completion_tokens);
+	let total_tokens = get_option_value!(usage.total_tokens);
+	let prompt_tokens_details = usage
+		.prompt_tokens_details
+		.as_ref()
+		.ok_or("Should have prompt_tokens_details")?;
+	let cache_creation_tokens = get_option_value!(prompt_tokens_details.cache_creation_tokens);
+	let cached_tokens = get_option_value!(prompt_tokens_details.cached_tokens);
+
+	assert!(
+		cache_creation_tokens > 0 || cached_tokens > 0,
+		"one of cache_creation_tokens or cached_tokens should be greater than 0"
+	);
+	assert!(total_tokens > 0, "total_tokens should be > 0");
+
+	Ok(())
+}
+
+/// Explicit cache with a 5m TTL message, and the 1h TTL default of the client chat options
+pub async fn common_test_chat_cache_explicit_ttl_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::builder()
+		.with_chat_options(ChatOptions::default().with_cache_ttl(CacheTtl::OneHour))
+		.build();
+	let big_content = get_big_content()?;
+	let chat_req = ChatRequest::new(vec![
+		// -- Messages (deactivate to see the differences)
+		ChatMessage::system("You are a senior developer which has the following code base:"),
+		ChatMessage::system(big_content).with_options(CacheControl::Ephemeral),
+		ChatMessage::user("can you give a summary of each file (very concise)")
+			.with_options(CacheControl::EphemeralWithTtl(CacheTtl::FiveMinutes)),
+	]);
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check Content
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert!(!content.trim().is_empty(), "Content should not be empty");
+
+	// -- Check Usage
+	let prompt_tokens_details = chat_res
+		.usage
+		.prompt_tokens_details
+		.as_ref()
+		.ok_or("Should have prompt_tokens_details")?;
+	let cache_creation_tokens = get_option_value!(prompt_tokens_details.cache_creation_tokens);
+	let cached_tokens = get_option_value!(prompt_tokens_details.cached_tokens);
+
+	assert!(
+		cache_creation_tokens > 0 || cached_tokens > 0,
+		"one of cache_creation_tokens or cached_tokens should be greater than 0"
+	);
+
+	Ok(())
+}
+
+/// Explicit cache breakpoints on the first `.system` content and on a message
+pub async fn common_test_chat_cache_point_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let big_content = get_big_content()?;
+	let chat_req = ChatRequest::from_system(format!(
+		"You are a senior developer which has the following code base:\n{big_content}"
+	))
+	.with_system_cache_point()
+	.append_message(ChatMessage::user("can you give a summary of each file (very concise)").with_cache_point());
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check Content
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert!(!content.trim().is_empty(), "Content should not be empty");
+
+	// -- Check Usage
+	let prompt_tokens_details = chat_res
+		.usage
+		.prompt_tokens_details
+		.as_ref()
+		.ok_or("Should have prompt_tokens_details")?;
+	let cache_creation_tokens = get_option_value!(prompt_tokens_details.cache_creation_tokens);
+	let cached_tokens = get_option_value!(prompt_tokens_details.cached_tokens);
+
+	assert!(
+		cache_creation_tokens > 0 || cached_tokens > 0,
+		"one of cache_creation_tokens or cached_tokens should be greater than 0"
+	);
+
+	Ok(())
+}
+
+/// Explicit provider cache with `Client::create_cached_content(..)` (Gemini `cachedContents`)
+pub async fn common_test_chat_cached_content_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let big_content = get_big_content()?;
+	let cache_req = ChatRequest::new(vec![ChatMessage::user(big_content)])
+		.with_system("Give a very short summary of what each of those files are about");
+	let cached_content = client
+		.create_cached_content(model, cache_req, Some(Duration::from_secs(300)))
+		.await?;
+
+	// -- Exec
+	let chat_req = ChatRequest::new(vec![ChatMessage::user(
+		"What are those files about? (very short answer)",
+	)]);
+	let options = ChatOptions::default().with_cached_content(&cached_content.name);
+	let chat_res = client.exec_chat(model, chat_req, Some(&options)).await;
+	// delete before the checks, so that the cache is always cleaned up
+	client.delete_cached_content(&cached_content).await?;
+
+	// -- Check
+	let chat_res = chat_res?;
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert!(!content.trim().is_empty(), "Content should not be empty");
+	let prompt_tokens_details = chat_res
+		.usage
+		.prompt_tokens_details
+		.as_ref()
+		.ok_or("Should have prompt_tokens_details")?;
+	let cached_tokens = get_option_value!(prompt_tokens_details.cached_tokens);
+	assert!(cached_tokens > 0, "cached_tokens should be greater than 0");
+
+	Ok(())
+}
+
+// endregion: --- Chat Explicit Cache
+
+// region:    --- Chat Stream Tests
+
+pub async fn common_test_chat_stream_simple_ok(model: &str, checks: Option<Check>) -> Result<()> {
+	validate_checks(checks.clone(), Check::REASONING)?;
+
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_simple();
+
+	// -- Exec
+	let chat_res = client.exec_chat_stream(model, chat_req.clone(), None).await?;
+
+	// -- Extract Stream content
+	let StreamExtract {
+		stream_end,
+		content,
+		reasoning_content,
+	} = extract_stream_end(chat_res.stream).await?;
+	let content = content.ok_or("extract_stream_end SHOULD have extracted some content")?;
+
+	// -- Check no meta_usage and captured_content
+	assert!(!content.is_empty(), "Content streamed should not be empty");
+	assert!(
+		stream_end.captured_usage.is_none(),
+		"StreamEnd should not have any meta_usage"
+	);
+	assert!(
+		stream_end.captured_content.is_none(),
+		"StreamEnd should not have any captured_content"
+	);
+
+	// -- Check Reasoning Content
+	if contains_checks(checks, Check::REASONING) {
+		let reasoning_content =
+			reasoning_content.ok_or("extract_stream_end SHOULD have extracted some reasoning_content")?;
+		assert!(!reasoning_content.is_empty(), "reasoning_content should not be empty");
+		// We can assume that the reasoning content should be bigger than the content given the prompt to keep content very concise.
+		assert!(
+			reasoning_content.len() > content.len(),
+			"Reasoning content should be > than the content"
+		);
+	}
+
+	Ok(())
+}
+
+/// Check that the capture content flag does the capture
+/// NOTE: When checking for reasoning, the captured_reasoning_content should be None in this function
+pub async fn common_test_chat_stream_finish_reason_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_simple();
+	let truncate_options = ChatOptions::default().with_max_tokens(5);
+
+	// -- Exec
+	let chat_res = client.exec_chat_stream(model, chat_req.clone(), None).await?;
+	let StreamExtract { stream_end, .. } = extract_stream_end(chat_res.stream).await?;
+	let truncated_res = client.exec_chat_stream(model, chat_req, Some(&truncate_options)).await?;
+	let StreamExtract {
+		stream_end: truncated_stream_end,
+		..
+	} = extract_stream_end(truncated_res.stream).await?;
+
+	// -- Check
+	assert_eq!(stream_end.finish_reason, Some(FinishReason::Stop));
+	assert_eq!(truncated_stream_end.finish_reason, Some(FinishReason::Length));
+
+	Ok(())
+}
+
+pub async fn common_test_chat_stream_capture_content_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::builder()
+		.with_chat_options(ChatOptions::default().with_capture_content(true))
+		.build();
+	let chat_req = seed_chat_req_simple();
+
+	// -- Exec
+	let chat_res = client.exec_chat_stream(model, chat_req.clone(), None).await?;
+
+	// -- Extract Stream content
+	let StreamExtract {
+		stream_end,
+		content,
+		reasoning_content,
+	} = extract_stream_end(chat_res.stream).await?;
+
+	// -- Check meta_usage
+	// Should be None as not captured
+	assert!(
+		stream_end.captured_usage.is_none(),
+		"StreamEnd should not have any meta_usage"
+	);
+
+	// -- Check captured_content
+	let captured_content = get_option_value!(stream_end.captured_content);
+	assert!(!captured_content.is_empty(), "captured_content.length should be > 0");
+
+	// -- Check Reasoning Content
+	// Should always be none, as it was not instructed to be captured.
+	assert!(
+		stream_end.captured_reasoning_content.is_none(),
+		"The captured_reasoning_content should be None"
+	);
+
+	Ok(())
+}
+
+pub async fn common_test_chat_stream_capture_all_ok(model: &str, checks: Option<Check>) -> Result<()> {
+	validate_checks(checks.clone(), Check::REASONING)?;
+
+	// -- Setup & Fixtures
+	let client = Client::builder()
+		.with_chat_options(
+			ChatOptions::default()
+				.with_capture_usage(true)
+				.with_capture_content(true)
+				.with_capture_reasoning_content(true),
+		)
+		.build();
+	let chat_req = seed_chat_req_simple();
+
+	// -- Exec
+	let chat_res = client.exec_chat_stream(model, chat_req.clone(), None).await?;
+
+	// -- Extract Stream content
+	let StreamExtract {
+		stream_end,
+		content,
+		reasoning_content,
+	} = extract_stream_end(chat_res.stream).await?;
+
+	// -- Check meta_usage
+	let meta_usage = stream_end.captured_usage.as_ref().ok_or("Should have usage")?;
+
+	assert!(
+		get_option_value!(meta_usage.prompt_tokens) > 0,
+		"prompt_token should be > 0"
+	);
+	assert!(
+		get_option_value!(meta_usage.completion_tokens) > 0,
+		"completion_tokens should be > 0"
+	);
+	assert!(
+		get_option_value!(meta_usage.total_tokens) > 0,
+		"total_tokens should be > 0"
+	);
+
+	// -- Check captured_content
+	let captured_content = stream_end.captured_first_text();
+	let captured_content = captured_content.ok_or("Captured content should have a text")?;
+	assert!(!captured_content.is_empty(), "captured_content.length should be > 0");
+
+	// -- Check Reasoning Content
+	if contains_checks(checks, Check::REASONING) {
+		let reasoning_content = stream_end
+			.captured_reasoning_content
+			.as_ref()
+			.ok_or("captured_reasoning_content SHOULD have extracted some reasoning_content")?;
+		assert!(!reasoning_content.is_empty(), "reasoning_content should not be empty");
+		// We can assume that the reasoning content should be bigger than the content given the prompt to keep content very concise.
+		assert!(
+			reasoning_content.len() > captured_content.len(),
+			"Reasoning content should be > than the content"
+		);
+	}
+
+	Ok(())
+}
+
+// endregion: --- Chat Stream Tests
+
+// region:    --- Chat Prompt Shield
+
+pub async fn common_test_chat_prompt_shield_redact_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let secret = "PELICAN-7731";
+	let chat_req = ChatRequest::new(vec![ChatMessage::user(
+		"What is the access code? Answer with only the access code.",
+	)])
+	.with_system(format!("You are a helpful assistant. The access code is {secret}."));
+	let shield = PromptShield::default()
+		.append_secret(secret)
+		.with_action(PromptShieldAction::Redact);
+	let options = ChatOptions::default().with_prompt_shield(shield);
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, Some(&options)).await?;
+
+	// -- Check
+	let prompt_leaks = chat_res.prompt_leaks.as_ref().ok_or("Should have prompt_leaks")?;
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert!(!content.contains(secret), "secret should have been redacted");
+	if content.contains("[REDACTED]") {
+		assert!(
+			prompt_leaks
+				.iter()
+				.any(|leak| leak.kind == PromptLeakKind::Secret && leak.redacted),
+			"should have a redacted secret leak"
+		);
+	}
+
+	Ok(())
+}
+
+// endregion: --- Chat Prompt Shield
+
+// region:    --- Chat AI Disclosure
+
+pub async fn common_test_chat_ai_disclosure_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let disclosure_text = "[AI-generated]";
+	let client = Client::builder()
+		.with_chat_options(ChatOptions::default().with_ai_disclosure(AiDisclosure::new(disclosure_text)))
+		.build();
+	let opt_out_options = ChatOptions::default().with_ai_disclosure(AiDisclosure::disabled());
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, seed_chat_req_simple(), None).await?;
+	let opt_out_res = client.exec_chat(model, seed_chat_req_simple(), Some(&opt_out_options)).await?;
+
+	// -- Check
+	let tag = chat_res.ai_disclosure.as_ref().ok_or("Should have ai_disclosure")?;
+	assert_eq!(tag.text, disclosure_text);
+	assert!(tag.in_text, "disclosure should be in the text");
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert!(
+		content.ends_with(disclosure_text),
+		"content should end with the disclosure"
+	);
+	assert!(
+		opt_out_res.ai_disclosure.is_none(),
+		"opt-out should not have ai_disclosure"
+	);
+	let opt_out_content = opt_out_res.first_text().ok_or("Should have content")?;
+	assert!(!opt_out_content.contains(disclosure_text));
+
+	Ok(())
+}
+
+// endregion: --- Chat AI Disclosure
+
+// region:    --- Chat Refusal
+
+pub async fn common_test_chat_not_refusal_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, seed_chat_req_simple(), None).await?;
+
+	// -- Check
+	assert!(chat_res.first_text().is_some(), "Should have content");
+	assert!(!chat_res.is_refusal(), "a simple answer should not be a refusal");
+
+	Ok(())
+}
+
+// endregion: --- Chat Refusal
+
+// region:    --- Chat Provider Ext
+
+pub async fn common_test_chat_provider_ext_groq_timing_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, seed_chat_req_simple(), None).await?;
+
+	// -- Check
+	let timing = chat_res.provider_ext::<GroqTiming>().ok_or("Should have the GroqTiming")?;
+	let total_time = timing.total_time.ok_or("Should have total_time")?;
+	assert!(total_time > 0.0, "total_time should be > 0");
+	assert!(
+		chat_res.provider_ext::<GeminiGroundingMetadata>().is_none(),
+		"Should not have a Gemini extra"
+	);
+
+	Ok(())
+}
+
+pub async fn common_test_chat_provider_ext_grounding_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req =
+		ChatRequest::from_user("Who won the most recent Formula 1 World Championship? Answer in one sentence.")
+			.append_tool(Tool::new("googleSearch").with_config(json!({})));
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let grounding = chat_res
+		.provider_ext::<GeminiGroundingMetadata>()
+		.ok_or("Should have the GeminiGroundingMetadata")?;
+	assert!(
+		!grounding.web_search_queries.is_empty(),
+		"Should have web search queries"
+	);
+	assert!(!grounding.grounding_chunks.is_empty(), "Should have grounding chunks");
+	let text = chat_res.first_text().ok_or("Should have content")?;
+	for support in &grounding.grounding_supports {
+		let (Some(start), Some(end)) = (support.start_index, support.end_index) else {
+			return Err("Grounding support should have a segment".into());
+		};
+		if support.part_index == Some(0) {
+			assert!(
+				start < end && end <= text.len(),
+				"Segment should be in the response text"
+			);
+		}
+		for index in &support.grounding_chunk_indices {
+			assert!(*index < grounding.grounding_chunks.len(), "Chunk index should be valid");
+		}
+	}
+
+	Ok(())
+}
+
+pub async fn common_test_chat_builtin_web_search_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req =
+		ChatRequest::from_user("Who won the most recent Formula 1 World Championship? Answer in one sentence.")
+			.append_tool(Tool::builtin(BuiltInTool::web_search()));
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let citations = chat_res.citations();
+	let citation = citations
+		.iter()
+		.find(|citation| citation.source == CitationSource::Web)
+		.ok_or("Should have a web citation")?;
+	assert!(
+		citation.id.starts_with("http"),
+		"Web citation id should be an url: {}",
+		citation.id
+	);
+
+	Ok(())
+}
+
+pub async fn common_test_chat_stream_builtin_web_search_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req =
+		ChatRequest::from_user("Who won the most recent Formula 1 World Championship? Answer in one sentence.")
+			.append_tool(Tool::builtin(BuiltInTool::web_search()));
+
+	// -- Exec
+	let chat_res = client.exec_chat_stream(model, chat_req, None).await?;
+
+	// -- Extract Stream content
+	let StreamExtract { content, .. } = extract_stream_end(chat_res.stream).await?;
+	let content = content.ok_or("extract_stream_end SHOULD have extracted some content")?;
+
+	// -- Check
+	assert!(!content.is_empty(), "Content streamed should not be empty");
+
+	Ok(())
+}
+
+pub async fn common_test_chat_builtin_code_execution_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = ChatRequest::from_user("Run code to compute the sum of the first 50 prime numbers.")
+		.append_tool(Tool::builtin(BuiltInTool::CodeExecution));
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let blocks: Vec<&ContentBlock> = chat_res
+		.content
+		.iter()
+		.filter_map(|content| match content {
+			MessageContent::Blocks(blocks) => Some(blocks.iter()),
+			_ => None,
+		})
+		.flatten()
+		.collect();
+	assert!(
+		blocks.iter().any(|block| matches!(block, ContentBlock::ExecutableCode { .. })),
+		"Should have an executable code block"
+	);
+	let output = blocks
+		.iter()
+		.find_map(|block| match block {
+			ContentBlock::CodeExecutionResult { output, is_error } if !is_error => Some(output),
+			_ => None,
+		})
+		.ok_or("Should have a successful code execution result")?;
+	assert_contains(output, "5117");
+
+	Ok(())
+}
+
+pub async fn common_test_chat_stream_builtin_code_execution_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = ChatRequest::from_user("Run code to compute the sum of the first 50 prime numbers.")
+		.append_tool(Tool::builtin(BuiltInTool::CodeExecution));
+
+	// -- Exec
+	let chat_res = client.exec_chat_stream(model, chat_req, None).await?;
+
+	// -- Extract Stream content
+	let StreamExtract { content, .. } = extract_stream_end(chat_res.stream).await?;
+	let content = content.ok_or("extract_stream_end SHOULD have extracted some content")?;
+
+	// -- Check
+	assert_contains(&content, "5117");
+
+	Ok(())
+}
+
+// endregion: --- Chat Provider Ext
+
+// region:    --- Chat Time Context
+
+pub async fn common_test_chat_time_context_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let time_context = TimeContext::new().with_timezone("Europe/Paris", 120).with_locale("fr-FR");
+	let client = Client::builder()
+		.with_chat_options(ChatOptions::default().with_time_context(time_context.clone()))
+		.build();
+	let block = time_context.context_block_at(std::time::SystemTime::now());
+	let current_year = block
+		.lines()
+		.find_map(|line| line.strip_prefix("Current date: "))
+		.and_then(|date| date.get(..4))
+		.ok_or("Should have a current date")?;
+	let chat_req = ChatRequest::from_system("Answer only with the requested value.")
+		.append_message(ChatMessage::user("What is the current year?"));
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert_contains(content, current_year);
+
+	Ok(())
+}
+
+// endregion: --- Chat Time Context
+
+// region:    --- Chat Regeneration
+
+pub async fn common_test_chat_regenerate_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let policy = RegenerationPolicy::new(3)
+		.with_temperature_step(0.3)
+		.with_temperature_jitter(0.1);
+	let attempts = Arc::new(Mutex::new(0));
+
+	// -- Exec
+	let validator_attempts = attempts.clone();
+	let chat_res = client
+		.exec_chat_regenerate(model, seed_chat_req_simple(), None, &policy, |_chat_res| {
+			let mut attempts = validator_attempts.lock().unwrap();
+			*attempts += 1;
+			// reject the first response
+			if *attempts == 1 {
+				Err("first attempt rejected".to_string())
+			} else {
+				Ok(())
+			}
+		})
+		.await?;
+	let exhausted_res = client
+		.exec_chat_regenerate(
+			model,
+			seed_chat_req_simple(),
+			None,
+			&policy.clone().with_model_from_attempt(2, model),
+			|_| Err("always rejected".to_string()),
+		)
+		.await;
+
+	// -- Check
+	assert!(chat_res.first_text().is_some(), "Should have content");
+	assert_eq!(*attempts.lock().unwrap(), 2);
+	match exhausted_res {
+		Err(genai::Error::RegenerationExhausted { attempts, reason, .. }) => {
+			assert_eq!(attempts, 3);
+			assert_eq!(reason, "always rejected");
+		}
+		other => return Err(format!("Should be RegenerationExhausted, but was: {other:?}").into()),
+	}
+
+	Ok(())
+}
+
+// endregion: --- Chat Regeneration
+
+// region:    --- Chat Routing
+
+pub async fn common_test_chat_routed_ok(cheap_model: &str, capable_model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let router = ModelRouter::new(RouteStrategy::LeastCost)
+		.with_candidate(RouteCandidate::new(capable_model, 2).with_price(ModelPrice::new(2.5, 10.)))
+		.with_candidate(RouteCandidate::new(cheap_model, 1).with_price(ModelPrice::new(0.15, 0.6)));
+
+	// -- Exec
+	let cheap_res = client.exec_chat_routed(&router, 1, seed_chat_req_simple(), None).await?;
+	let capable_res = client.exec_chat_routed(&router, 2, seed_chat_req_simple(), None).await?;
+	let no_route_res = client.exec_chat_routed(&router, 3, seed_chat_req_simple(), None).await;
+
+	// -- Check
+	assert_eq!(&*cheap_res.model_iden.model_name, cheap_model);
+	assert_eq!(&*capable_res.model_iden.model_name, capable_model);
+	assert!(
+		matches!(no_route_res, Err(genai::Error::NoRouteCandidate { min_tier: 3 })),
+		"should be NoRouteCandidate, but was {no_route_res:?}"
+	);
+	let cheap_stats = router.stats(cheap_model).ok_or("Should have the cheap model stats")?;
+	assert_eq!(cheap_stats.success_count, 1);
+	assert!(cheap_stats.avg_latency.is_some(), "Should have the latency");
+
+	Ok(())
+}
+
+// endregion: --- Chat Routing
+
+// region:    --- Chat Map-Reduce
+
+pub async fn common_test_chat_map_reduce_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chunks = [
+		"The Eiffel Tower is in Paris.",
+		"The Colosseum is in Rome.",
+		"The Sagrada Familia is in Barcelona.",
+	];
+	let chat_reqs = chunks
+		.iter()
+		.map(|chunk| ChatRequest::from_user(format!("Summarize in a few words: {chunk}")));
+	let mut op_usage = OperationUsage::new();
+
+	// -- Exec
+	let results = client.map_concurrent(model, chat_reqs, None, 2, &mut op_usage).await;
+	let partials = results
+		.into_iter()
+		.map(|res| res.map(|chat_res| chat_res.into_first_text().unwrap_or_default()))
+		.collect::<genai::Result<Vec<_>>>()?;
+	let map_prompt_tokens = op_usage.usage.prompt_tokens.ok_or("Should have prompt_tokens")?;
+	let chat_res = client
+		.reduce_with_model(
+			model,
+			partials,
+			"List the cities of the parts, comma separated.",
+			None,
+			&mut op_usage,
+		)
+		.await?;
+
+	// -- Check
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert_contains(content, "Paris");
+	assert_eq!(op_usage.request_count, 4);
+	assert_eq!(op_usage.error_count, 0);
+	let total_prompt_tokens = op_usage.usage.prompt_tokens.ok_or("Should have prompt_tokens")?;
+	assert!(
+		total_prompt_tokens > map_prompt_tokens,
+		"reduce usage should be added to the operation usage"
+	);
+
+	Ok(())
+}
+
+// endregion: --- Chat Map-Reduce
+
+// region:    --- Chat Quota
+
+pub async fn common_test_chat_quota_rpm_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let quota_manager = QuotaManager::new().with_tenant_quota("tenant-a", TenantQuota::default().with_rpm(1));
+	let client = Client::builder().with_quota_manager(quota_manager.clone()).build();
+	let options = ChatOptions::default().with_tenant_id("tenant-a");
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, seed_chat_req_simple(), Some(&options)).await?;
+	let second_res = client.exec_chat(model, seed_chat_req_simple(), Some(&options)).await;
+
+	// -- Check
+	assert!(!chat_res.content.is_empty(), "Content should not be empty");
+	let Err(genai::Error::QuotaExceeded { quota_kind, .. }) = second_res else {
+		return Err("Second request should have exceeded the rpm quota".into());
+	};
+	assert_eq!(quota_kind, QuotaKind::Rpm);
+	let remaining = quota_manager.remaining("tenant-a");
+	assert_eq!(remaining.requests, Some(0));
+	// Other tenants are not limited
+	assert_eq!(quota_manager.remaining("tenant-b").requests, None);
+
+	Ok(())
+}
+
+/// NOTE: Two clients (as two service instances) with their own QuotaManager, sharing the same (in-memory) store.
+pub async fn common_test_chat_quota_shared_store_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let counters: Arc<Mutex<HashMap<String, u64>>> = Arc::default();
+	let store = QuotaStore::from_incr_fn(move |incr: QuotaIncr| -> Pin<Box<dyn Future<Output = _> + Send>> {
+		let counters = counters.clone();
+		Box::pin(async move {
+			let mut counters = counters.lock().map_err(|err| err.to_string())?;
+			let counter = counters.entry(incr.key).or_default();
+			*counter += incr.amount;
+			Ok(*counter)
+		})
+	});
+	let new_client = || {
+		let quota_manager = QuotaManager::new()
+			.with_tenant_quota("tenant-a", TenantQuota::default().with_rpm(1))
+			.with_store(store.clone());
+		Client::builder().with_quota_manager(quota_manager).build()
+	};
+	let (client_a, client_b) = (new_client(), new_client());
+	let options = ChatOptions::default().with_tenant_id("tenant-a");
+
+	// -- Exec
+	let chat_res = client_a.exec_chat(model, seed_chat_req_simple(), Some(&options)).await?;
+	let second_res = client_b.exec_chat(model, seed_chat_req_simple(), Some(&options)).await;
+
+	// -- Check
+	assert!(!chat_res.content.is_empty(), "Content should not be empty");
+	let Err(genai::Error::QuotaExceeded { quota_kind, .. }) = second_res else {
+		return Err("Second instance request should have exceeded the shared rpm quota".into());
+	};
+	assert_eq!(quota_kind, QuotaKind::Rpm);
+
+	Ok(())
+}
+
+// endregion: --- Chat Quota
+
+// region:    --- Chat Output Fixer
+
+/// NOTE: For a provider which does not enforce the response format (e.g., Anthropic), so that the output is invalid JSON.
+pub async fn common_test_chat_output_fixer_ok(model: &str, fixer_model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req =
+		ChatRequest::from_user("Reply exactly with this plain text line (not JSON): name is Alice, age is 30");
+	let json_spec = JsonSpec::new(
+		"person",
+		json!({
+			"type": "object",
+			"properties": {
+				"name": { "type": "string" },
+				"age": { "type": "number" },
+			},
+			"required": ["name", "age"],
+		}),
+	);
+	let options = ChatOptions::default()
+		.with_response_format(json_spec)
+		.with_output_fixer(OutputFixer::new(fixer_model));
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, Some(&options)).await?;
+
+	// -- Check
+	let output_repair = chat_res.output_repair.as_ref().ok_or("Should have an output_repair")?;
+	assert!(
+		!output_repair.original_output.is_empty(),
+		"original_output should not be empty"
+	);
+	if output_repair.repaired {
+		let text = chat_res.first_text().ok_or("Should have text result")?;
+		let value: Value = serde_json::from_str(text)?;
+		assert_eq!(value.x_get_as::<&str>("name")?, "Alice");
+	}
+
+	Ok(())
+}
+
+// endregion: --- Chat Output Fixer
+
+// region:    --- Chat Speculative
+
+pub async fn common_test_chat_speculative_ok(draft_model: &str, verify_model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = ChatRequest::new(vec![ChatMessage::user(
+		"What is the capital of France? Answer with only the city name.",
+	)]);
+
+	// -- Exec
+	let mut speculative_stream = client.exec_chat_speculative(draft_model, verify_model, chat_req, None).await?;
+	let mut draft_events_count = 0;
+	let mut last_event = None;
+	while let Some(event) = speculative_stream.next().await {
+		match event? {
+			SpeculativeEvent::Draft(_) => draft_events_count += 1,
+			event => last_event = Some(event),
+		}
+	}
+
+	// -- Check
+	assert!(draft_events_count > 0, "Should have draft events");
+	let response = match last_event.ok_or("Should have a Confirmed or Correction event")? {
+		SpeculativeEvent::Confirmed(response) => response,
+		SpeculativeEvent::Correction { response, .. } => response,
+		SpeculativeEvent::Draft(_) => return Err("Last event should not be a draft".into()),
+	};
+	let content = response.first_text().ok_or("Should have verify content")?;
+	assert_contains(content, "Paris");
+
+	Ok(())
+}
+
+// endregion: --- Chat Speculative
+
+// region:    --- Images
+
+pub async fn common_test_chat_image_url_ok(model: &str) -> Result<()> {
+	// -- Setup
+	let client = Client::default();
+
+	// -- Build & Exec
+	let mut chat_req = ChatRequest::default().with_system("Answer in one sentence");
+	// This is similar to sending initial system chat messages (which will be cumulative with system chat messages)
+	chat_req = chat_req.append_message(ChatMessage::user(vec![
+		ContentPart::from_text("What is in this picture?"),
+		ContentPart::from_image_url("image/jpeg", IMAGE_URL_JPG_DUCK),
+	]));
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let res = chat_res.first_text().ok_or("Should have text result")?;
+	assert_contains(res, "duck");
+
+	Ok(())
+}
+
+pub async fn common_test_chat_image_b64_ok(model: &str) -> Result<()> {
+	// -- Setup
+	let client = Client::default();
+
+	// -- Build & Exec
+	let mut chat_req = ChatRequest::default().with_system("Answer in one sentence");
+	// This is similar to sending initial system chat messages (which will be cumulative with system chat messages)
+	chat_req = chat_req.append_message(ChatMessage::user(vec![
+		ContentPart::from_text("What is in this picture?"),
+		ContentPart::from_image_base64("image/jpeg", get_b64_duck()?),
+	]));
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let res = chat_res.first_text().ok_or("Should have text result")?;
+	assert_contains(res, "duck");
+
+	Ok(())
+}
+
+pub async fn common_test_chat_document_b64_ok(model: &str) -> Result<()> {
+	// -- Setup
+	let client = Client::default();
+
+	// -- Build & Exec
+	let chat_req = ChatRequest::default()
+		.with_system("Answer in one sentence")
+		.append_message(ChatMessage::user(vec![
+			ContentPart::from_text("What is the secret fruit in this document?"),
+			ContentPart::from_document_base64("application/pdf", get_b64_small_pdf()?),
+		]));
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let res = chat_res.first_text().ok_or("Should have text result")?;
+	assert_contains(res, "banana");
+
+	Ok(())
+}
+
+pub async fn common_test_chat_document_citations_ok(model: &str) -> Result<()> {
+	// -- Setup
+	let client = Client::default();
+	let options = ChatOptions::default().with_citations(true);
+
+	// -- Build & Exec
+	let chat_req = ChatRequest::default().append_message(ChatMessage::user(vec![
+		ContentPart::from_text("What is the secret fruit in this document? Cite the document."),
+		ContentPart::from_document_base64("application/pdf", get_b64_small_pdf()?),
+	]));
+	let chat_res = client.exec_chat(model, chat_req, Some(&options)).await?;
+
+	// -- Check
+	let citations = chat_res.citations();
+	let citation = citations
+		.iter()
+		.find(|citation| citation.source == CitationSource::Document)
+		.ok_or("Should have a document citation")?;
+	assert_eq!(citation.id, "0");
+	let cited_text = citation.cited_text.as_deref().ok_or("Should have a cited_text")?;
+	assert_contains(cited_text, "banana");
+
+	Ok(())
+}
+
+pub async fn common_test_chat_stream_document_citations_ok(model: &str) -> Result<()> {
+	// -- Setup
+	let client = Client::default();
+	let options = ChatOptions::default().with_citations(true);
+
+	// -- Build & Exec
+	let chat_req = ChatRequest::default().append_message(ChatMessage::user(vec![
+		ContentPart::from_text("What is the secret fruit in this document? Cite the document."),
+		ContentPart::from_document_base64("application/pdf", get_b64_small_pdf()?),
+	]));
+	let chat_res = client.exec_chat_stream(model, chat_req, Some(&options)).await?;
+
+	// -- Extract Stream content
+	let StreamExtract { content, .. } = extract_stream_end(chat_res.stream).await?;
+	let content = content.ok_or("extract_stream_end SHOULD have extracted some content")?;
+
+	// -- Check
+	assert_contains(&content.to_lowercase(), "banana");
+
+	Ok(())
+}
+
+pub async fn common_test_chat_audio_b64_ok(model: &str) -> Result<()> {
+	// -- Setup
+	let client = Client::default();
+
+	// -- Build & Exec
+	let chat_req = ChatRequest::default()
+		.with_system("Answer in one sentence")
+		.append_message(ChatMessage::user(vec![
+			ContentPart::from_text("Is this audio a musical tone, speech, or silence?"),
+			ContentPart::from_audio_base64("audio/wav", get_b64_tone_wav()?),
+		]));
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let res = chat_res.first_text().ok_or("Should have text result")?;
+	assert_contains(&res.to_lowercase(), "tone");
+
+	Ok(())
+}
+
+pub async fn common_test_chat_video_url_ok(model: &str) -> Result<()> {
+	// -- Setup
+	let client = Client::default();
+
+	// -- Build & Exec
+	let video = ContentPart::from_video_url("video/mp4", VIDEO_URL_YOUTUBE)
+		.with_video_offsets(Some(Duration::from_secs(0)), Some(Duration::from_secs(10)));
+	let chat_req = ChatRequest::default()
+		.with_system("Answer in one sentence")
+		.append_message(ChatMessage::user(vec![
+			ContentPart::from_text("What is shown in this video?"),
+			video,
+		]));
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let res = chat_res.first_text().ok_or("Should have text result")?;
+	assert!(!res.trim().is_empty(), "Should have a non-empty video description");
+
+	Ok(())
+}
+
+/// NOTE: The `tools` are the eventual tools enabling the image output (e.g., OpenAI `Tool::image_generation(..)`).
+pub async fn common_test_chat_image_output_ok(model: &str, tools: Option<Vec<Tool>>) -> Result<()> {
+	// -- Setup
+	let client = Client::default();
+
+	// -- Build & Exec
+	let mut chat_req = ChatRequest::new(vec![ChatMessage::user("Generate a small image of a yellow duck.")]);
+	if let Some(tools) = tools {
+		chat_req = chat_req.with_tools(tools);
+	}
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let images = chat_res.images();
+	let (content_type, source) = images.first().ok_or("Should have an image output")?;
+	assert!(
+		content_type.starts_with("image/"),
+		"Wrong image content type: {content_type}"
+	);
+	assert!(
+		matches!(source, ImageSource::Base64(data) if !data.is_empty()),
+		"Should have a base64 image"
+	);
+
+	Ok(())
+}
+
+/// Send an image request with a model declared text-only, and check the vision fallback model was used.
+pub async fn common_test_chat_capability_fallback_ok(text_only_model: &str, vision_model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let options = ChatOptions::default()
+		.with_capability_fallback(CapabilityFallback::new().with_vision_fallback(vision_model, [text_only_model]));
+	let chat_req = ChatRequest::default()
+		.with_system("Answer in one sentence")
+		.append_message(ChatMessage::user(vec![
+			ContentPart::from_text("What is in this picture?"),
+			ContentPart::from_image_url("image/jpeg", IMAGE_URL_JPG_DUCK),
+		]));
+
+	// -- Exec
+	let chat_res = client.exec_chat(text_only_model, chat_req, Some(&options)).await?;
+
+	// -- Check
+	assert_eq!(&*chat_res.model_iden.model_name, vision_model);
+	let res = chat_res.first_text().ok_or("Should have text result")?;
+	assert_contains(res, "duck");
+
+	Ok(())
+}
+
+// endregion: --- Images
+
+// region:    --- Tools
+
+/// Just making the tool request, and checking the tool call response
+/// `complete_check` if for LLMs that are better at giving back the unit and weather.
+pub async fn common_test_tool_simple_ok(model: &str, complete_check: bool) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_tool_simple();
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let mut tool_calls = chat_res.tool_calls();
+	if tool_calls.is_empty() {
+		return Err("Should have tool calls in chat_res".into());
+	}
+	let tool_call = tool_calls.pop().ok_or("Should have at least one tool call")?;
+	assert_eq!(tool_call.fn_arguments.x_get_as::<&str>("city")?, "Paris");
+	assert_eq!(tool_call.fn_arguments.x_get_as::<&str>("country")?, "France");
+	if complete_check {
+		// Note: Not all LLM will output the weather (e.g. Anthropic Haiku)
+		assert_eq!(tool_call.fn_arguments.x_get_as::<&str>("unit")?, "C");
+	}
+
+	Ok(())
+}
+
+/// The tools cache breakpoint options (no automatic breakpoint, and a 5m one)
+pub async fn common_test_tool_cache_control_options_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let no_cache_options = ChatOptions::default().with_tools_cache_control(false);
+	let ttl_options = ChatOptions::default().with_tools_cache_ttl(CacheTtl::FiveMinutes);
+
+	// -- Exec & Check
+	for options in [no_cache_options, ttl_options] {
+		let chat_res = client.exec_chat(model, seed_chat_req_tool_simple(), Some(&options)).await?;
+		let tool_call = chat_res.tool_calls().pop().ok_or("Should have a tool call")?;
+		assert_eq!(tool_call.fn_arguments.x_get_as::<&str>("city")?, "Paris");
+	}
+
+	Ok(())
+}
+
+pub async fn common_test_tool_choice_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let tools = seed_chat_req_tool_simple().tools;
+	let mut chat_req = ChatRequest::from_user("Say hello. The weather is not needed, but please report it for Lyon.");
+	chat_req.tools = tools;
+	let named_options = ChatOptions::default().with_tool_choice(ToolChoice::named("get_weather"));
+	let none_options = ChatOptions::default().with_tool_choice(ToolChoice::None);
+
+	// -- Exec
+	let named_res = client.exec_chat(model, chat_req.clone(), Some(&named_options)).await?;
+	let none_res = client
+		.exec_chat(model, seed_chat_req_tool_simple(), Some(&none_options))
+		.await?;
+
+	// -- Check
+	let tool_call = named_res.tool_calls().pop().ok_or("Should have a forced tool call")?;
+	assert_eq!(tool_call.fn_name, "get_weather");
+	assert!(
+		none_res.tool_calls().is_empty(),
+		"Should have no tool calls with ToolChoice::None"
+	);
+
+	Ok(())
+}
+
+/// The forced tool choice with a reasoning effort (Anthropic disables the thinking instead of failing).
+pub async fn common_test_tool_choice_with_reasoning_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_tool_simple();
+	let options = ChatOptions::default()
+		.with_reasoning_effort(ReasoningEffort::Low)
+		.with_tool_choice(ToolChoice::Required);
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, Some(&options)).await?;
+
+	// -- Check
+	let tool_call = chat_res.tool_calls().pop().ok_or("Should have a forced tool call")?;
+	assert_eq!(tool_call.fn_name, "get_weather");
+
+	Ok(())
+}
+
+pub async fn common_test_tool_no_parallel_calls_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let tools = seed_chat_req_tool_simple().tools;
+	let mut chat_req = ChatRequest::from_user("What is the temperature in C, in Paris, in Lyon, and in Nice (France)?");
+	chat_req.tools = tools;
+	let options = ChatOptions::default().with_parallel_tool_calls(false);
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, Some(&options)).await?;
+
+	// -- Check
+	assert_eq!(chat_res.tool_calls().len(), 1, "Should have a single tool call");
+
+	Ok(())
+}
+
+pub async fn common_test_tool_strict_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	// NOTE: `unit` is optional, so it becomes nullable (and required) in the strict schema.
+	let tool = Tool::new("get_weather")
+		.with_schema(json!({
+			"type": "object",
+			"properties": {
+				"city": { "type": "string", "description": "The city name" },
+				"country": { "type": "string", "description": "The most likely country of this city name" },
+				"unit": { "type": "string", "enum": ["C", "F"], "description": "The temperature unit" }
+			},
+			"required": ["city", "country"],
+		}))
+		.with_strict(true);
+	let strict_schema = tool.strict_schema()?;
+	assert!(!strict_schema.x_get::<bool>("additionalProperties")?);
+	assert_eq!(strict_schema.x_get::<Vec<String>>("required")?.len(), 3);
+	assert_eq!(strict_schema.x_get::<Value>("/properties/unit/type")?, json!(["string", "null"]));
+	let chat_req = ChatRequest::from_user("What is the temperature in C, in Paris, France").append_tool(tool);
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let tool_call = chat_res.tool_calls().pop().ok_or("Should have at least one tool call")?;
+	assert_eq!(tool_call.fn_arguments.x_get_as::<&str>("city")?, "Paris");
+	assert_eq!(tool_call.fn_arguments.x_get_as::<&str>("country")?, "France");
+	assert!(tool_call.fn_arguments.get("unit").is_some(), "Strict tool call should have all properties");
+
+	// -- Check invalid schema
+	let invalid_tool = Tool::new("get_weather")
+		.with_schema(json!({"type": "object", "properties": {}, "additionalProperties": true}))
+		.with_strict(true);
+	assert!(invalid_tool.strict_schema().is_err(), "Should fail for additionalProperties true");
+
+	Ok(())
+}
+
+/// `complete_check` if for LLMs that are better at giving back the unit and weather.
+pub async fn common_test_tool_full_flow_ok(model: &str, complete_check: bool) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let mut chat_req = seed_chat_req_tool_simple();
+
+	// -- Exec first request to get the tool calls
+	let chat_res = client.exec_chat(model, chat_req.clone(), None).await?;
+	let tool_calls = chat_res.into_tool_calls();
+
+	if tool_calls.is_empty() {
+		return Err("Should have tool calls in chat_res".into());
+	}
+
+	// -- Exec the second request
+	// get the tool call id (first one)
+	let first_tool_call = tool_calls.first().ok_or("Should have at least one tool call")?;
+	let first_tool_call_id = &first_tool_call.call_id;
+	// simulate the response
+	let tool_response = ToolResponse::new(first_tool_call_id, r#"{"weather": "Sunny", "temperature": "32C"}"#);
+
+	// Add the tool_calls, tool_response
+	let chat_req = chat_req.append_message(tool_calls).append_message(tool_response);
+
+	let chat_res = client.exec_chat(model, chat_req.clone(), None).await?;
+
+	// -- Check
+	let content = chat_res.first_text().ok_or("Last response should be message")?.to_lowercase(); // lowercase because some models send "Sunny" and not "sunny"
+
+	assert!(content.contains("paris"), "Should contain 'Paris'");
+	assert!(content.contains("32"), "Should contain '32'");
+	if complete_check {
+		// Note: Not all LLM will output the weather (e.g. Anthropic Haiku)
+		assert!(content.contains("sunny"), "Should contain 'sunny'");
+	}
+
+	Ok(())
+}
+
+/// The interleaved thinking tool flow, with the assistant blocks (thinking and tool use) sent back as is.
+pub async fn common_test_tool_interleaved_thinking_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_tool_simple();
+	let chat_options = ChatOptions::default()
+		.with_reasoning_effort(ReasoningEffort::Low)
+		.with_interleaved_thinking(true);
+
+	// -- Exec first request to get the thinking and the tool use
+	let chat_res = client.exec_chat(model, chat_req.clone(), Some(&chat_options)).await?;
+	let blocks = chat_res
+		.content
+		.into_iter()
+		.find_map(|content| match content {
+			MessageContent::Blocks(blocks) => Some(blocks),
+			_ => None,
+		})
+		.ok_or("Should have content blocks")?;
+	assert!(
+		blocks.iter().any(|block| matches!(block, ContentBlock::Thinking { .. })),
+		"Should have a thinking block"
+	);
+	let tool_use_id = blocks
+		.iter()
+		.find_map(|block| match block {
+			ContentBlock::ToolUse { id, .. } => Some(id.clone()),
+			_ => None,
+		})
+		.ok_or("Should have a tool use block")?;
+
+	// -- Exec the second request (the thinking blocks must be sent back)
+	let tool_response = ToolResponse::new(tool_use_id, r#"{"weather": "Sunny", "temperature": "32C"}"#);
+	let chat_req = chat_req
+		.append_message(ChatMessage::assistant(MessageContent::Blocks(blocks)))
+		.append_message(tool_response);
+	let chat_res = client.exec_chat(model, chat_req, Some(&chat_options)).await?;
+
+	// -- Check
+	let content: String = chat_res
+		.content
+		.iter()
+		.flat_map(|content| match content {
+			MessageContent::Text(text) => vec![text.as_str()],
+			MessageContent::Blocks(blocks) => blocks
+				.iter()
+				.filter_map(|block| match block {
+					ContentBlock::Text { text, .. } => Some(text.as_str()),
+					_ => None,
+				})
+				.collect(),
+			_ => Vec::new(),
+		})
+		.collect();
+	assert!(content.contains("32"), "Should contain '32'");
+
+	Ok(())
+}
+
+/// The thinking history validation (an unsigned thinking block, and a tool use turn without its thinking).
+pub async fn common_test_tool_thinking_history_invalid_should_fail(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_options = ChatOptions::default().with_reasoning_effort(ReasoningEffort::Low);
+	let tool_use = ContentBlock::ToolUse {
+		id: "toolu_01".to_string(),
+		name: "get_weather".to_string(),
+		input: json!({"city": "Paris", "country": "France"}),
+		thought_signature: None,
+	};
+	let tool_response = ToolResponse::new("toolu_01", r#"{"weather": "Sunny", "temperature": "32C"}"#);
+	let unsigned_req = seed_chat_req_tool_simple()
+		.append_message(ChatMessage::assistant(MessageContent::Blocks(vec![
+			ContentBlock::Thinking {
+				text: "I need the weather of Paris.".to_string(),
+				signature: None,
+			},
+			tool_use.clone(),
+		])))
+		.append_message(tool_response.clone());
+	let no_thinking_req = seed_chat_req_tool_simple()
+		.append_message(ChatMessage::assistant(MessageContent::Blocks(vec![tool_use])))
+		.append_message(tool_response);
+
+	// -- Exec & Check
+	for chat_req in [unsigned_req, no_thinking_req] {
+		match client.exec_chat(model, chat_req, Some(&chat_options)).await {
+			Err(genai::Error::ThinkingHistoryInvalid { message_index, .. }) => assert_eq!(message_index, 1),
+			other => return Err(format!("Should be ThinkingHistoryInvalid, but was: {other:?}").into()),
+		}
+	}
+
+	Ok(())
+}
+
+/// Without thinking, an unsigned thinking block (e.g., of a transcript from another provider) is dropped, not rejected.
+pub async fn common_test_tool_thinking_history_unsigned_without_thinking_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_tool_simple()
+		.append_message(ChatMessage::assistant(MessageContent::Blocks(vec![
+			ContentBlock::Thinking {
+				text: "I need the weather of Paris.".to_string(),
+				signature: None,
+			},
+			ContentBlock::ToolUse {
+				id: "toolu_01".to_string(),
+				name: "get_weather".to_string(),
+				input: json!({"city": "Paris", "country": "France"}),
+				thought_signature: None,
+			},
+		])))
+		.append_message(ToolResponse::new(
+			"toolu_01",
+			r#"{"weather": "Sunny", "temperature": "32C"}"#,
+		));
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let content = chat_res.first_text().ok_or("Should have content")?;
+	assert_contains(&content.to_lowercase(), "sunny");
+
+	Ok(())
+}
+
+pub async fn common_test_tool_computer_use_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = ChatRequest::from_user("Take a screenshot, then tell me what animal is on the screen.")
+		.append_tool(Tool::builtin(BuiltInTool::computer(1024, 768)));
+
+	// -- Exec first request to get the screenshot action
+	let chat_res = client.exec_chat(model, chat_req.clone(), None).await?;
+	let tool_calls = chat_res.into_tool_calls();
+	let tool_call = tool_calls.first().ok_or("Should have a computer tool call")?;
+	assert_eq!(tool_call.fn_name, "computer");
+	assert_eq!(tool_call.fn_arguments.x_get_as::<&str>("action")?, "screenshot");
+
+	// -- Exec the second request with the screenshot
+	let tool_response = ToolResponse::new(&tool_call.call_id, "").with_image_base64("image/jpeg", get_b64_duck()?);
+	let chat_req = chat_req.append_message(tool_calls).append_message(tool_response);
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let content = chat_res.first_text().ok_or("Last response should be message")?.to_lowercase();
+	assert_contains(&content, "duck");
+
+	Ok(())
+}
+
+pub async fn common_test_tool_builtin_bash_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = ChatRequest::from_user("List the files of the current directory.")
+		.append_tool(Tool::builtin(BuiltInTool::Bash))
+		.append_tool(Tool::builtin(BuiltInTool::text_editor()));
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let tool_call = chat_res.tool_calls().pop().ok_or("Should have a bash tool call")?;
+	assert_eq!(tool_call.fn_name, "bash");
+	assert_contains(tool_call.fn_arguments.x_get_as::<&str>("command")?, "ls");
+
+	Ok(())
+}
+
+pub async fn common_test_tool_history_edit_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_tool_simple();
+	let chat_res = client.exec_chat(model, chat_req.clone(), None).await?;
+	let tool_calls = chat_res.into_tool_calls();
+	if tool_calls.is_empty() {
+		return Err("Should have tool calls in chat_res".into());
+	}
+	// simulate long tool responses (the weather first, then a long irrelevant log)
+	let long_content = format!(
+		r#"{{"weather": "Sunny", "temperature": "32C"}} {}"#,
+		"log line ".repeat(500)
+	);
+	let tool_responses: Vec<ChatMessage> = tool_calls
+		.iter()
+		.map(|tool_call| ToolResponse::new(&tool_call.call_id, &long_content).into())
+		.collect();
+	let mut chat_req = chat_req.append_message(tool_calls);
+	let tool_responses_idx = chat_req.messages.len();
+	let mut chat_req = chat_req.append_messages(tool_responses);
+	let messages_len = chat_req.messages.len();
+
+	// -- Exec
+	let truncated_count = chat_req.truncate_tool_results(60);
+	let splice_res = chat_req.splice_messages(tool_responses_idx.., Vec::new());
+	let chat_res = client.exec_chat(model, chat_req.clone(), None).await?;
+
+	// -- Check
+	assert!(truncated_count > 0, "Should have truncated the tool responses");
+	assert!(
+		splice_res.is_err(),
+		"Removing the tool responses should break the tool pairs"
+	);
+	assert_eq!(
+		chat_req.messages.len(),
+		tool_responses_idx + 1,
+		"Failed splice should not change the messages"
+	);
+	chat_req.validate_tool_pairs()?;
+	let content = chat_res.first_text().ok_or("Last response should be message")?.to_lowercase();
+	assert!(content.contains("32"), "Should contain '32'");
+
+	Ok(())
+}
+
+// endregion: --- Tools
+
+// region:    --- With Resolvers
+
+pub async fn common_test_resolver_auth_ok(model: &str, auth_data: AuthData) -> Result<()> {
+	// -- Setup & Fixtures
+	let auth_resolver = AuthResolver::from_resolver_fn(move |model_iden: ModelIden| Ok(Some(auth_data)));
+	let client = Client::builder().with_auth_resolver(auth_resolver).build();
+	let chat_req = seed_chat_req_simple();
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	assert!(!chat_res.content.is_empty(), "Content should not be empty");
+	let usage = chat_res.usage;
+	let total_tokens = get_option_value!(usage.total_tokens);
+	assert!(total_tokens > 0, "total_tokens should be > 0");
+
+	Ok(())
+}
+
+pub async fn common_test_resolver_request_signer_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let signed_body: Arc<Mutex<Option<Vec<u8>>>> = Arc::default();
+	let signed_body_ref = signed_body.clone();
+	let request_signer = RequestSigner::from_signer_fn(move |req: SignableRequest<'_>| {
+		*signed_body_ref.lock().unwrap() = Some(req.body.to_vec());
+		Ok(Headers::from(("X-Body-Length", req.body.len().to_string())))
+	});
+	let client = Client::builder().with_request_signer(request_signer).build();
+	let chat_req = seed_chat_req_simple();
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	assert!(!chat_res.content.is_empty(), "Content should not be empty");
+	let signed_body = signed_body
+		.lock()
+		.unwrap()
+		.take()
+		.ok_or("Should have signed the request body")?;
+	let signed_body: Value = serde_json::from_slice(&signed_body)?;
+	assert!(
+		signed_body.get("model").is_some(),
+		"Signed body should be the request payload"
+	);
+
+	Ok(())
+}
+
+// endregion: --- With Resolvers
+
+// region:    --- List
+
+pub async fn common_test_list_models(adapter_kind: AdapterKind, contains: &str) -> Result<()> {
+	let client = Client::default();
+
+	// -- Exec
+	let models = client.all_model_names(adapter_kind).await?;
+
+	// -- Check
+	assert_contains(&models, contains);
+
+	Ok(())
+}
+
+// endregion: --- List
+
+// region:    --- Ping
+
+pub async fn common_test_ping_ok(adapter_kind: AdapterKind) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+
+	// -- Exec
+	let report = client.ping(adapter_kind).await?;
+
+	// -- Check
+	assert_eq!(report.adapter_kind, adapter_kind);
+	assert!(report.reachable, "provider should be reachable");
+	assert!(report.ok, "ping should be ok, error: {:?}", report.error);
+	assert!(report.latency.as_nanos() > 0, "latency should be > 0");
+
+	Ok(())
+}
+
+// endregion: --- Ping
+
+// region:    --- Model Info
+
+/// NOTE: `has_limits` for the providers returning the token limits (e.g., Gemini), not OpenAI.
+pub async fn common_test_model_info_ok(model: &str, has_limits: bool) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+
+	// -- Exec
+	let info = client.model_info(model).await?;
+
+	// -- Check
+	assert_eq!(&*info.model_iden.model_name, model);
+	assert!(!info.captured_raw_body.is_null(), "should have captured_raw_body");
+	if has_limits {
+		let context_window = info.context_window.ok_or("Should have context_window")?;
+		assert!(context_window > 0, "context_window should be > 0");
+		let max_output_tokens = info.max_output_tokens.ok_or("Should have max_output_tokens")?;
+		assert!(max_output_tokens > 0, "max_output_tokens should be > 0");
+	}
+
+	Ok(())
+}
+
+// endregion: --- Model Info
+
+// region:    --- Count Tokens
+
+pub async fn common_test_count_tokens_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_simple();
+
+	// -- Exec
+	let token_count = client.exec_count_tokens(model, chat_req).await?;
+
+	// -- Check
+	assert!(token_count.input_tokens > 0, "input_tokens should be > 0");
+
+	Ok(())
+}
+
+pub async fn common_test_pinned_budget_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let mut chat_req = ChatRequest::new(vec![
+		ChatMessage::user("Task: answer in one word, and always in French.").with_pinned(true),
+		ChatMessage::assistant("D'accord."),
+		ChatMessage::user("What is the color of the sky?"),
+	]);
+
+	// -- Exec
+	let token_count = client.check_pinned_budget(model, &chat_req, 10_000).await?;
+	let over_budget_res = client.check_pinned_budget(model, &chat_req, 1).await;
+	let splice_res = chat_req.splice_messages(0..2, Vec::new());
+
+	// -- Check
+	assert!(token_count.input_tokens > 0, "input_tokens should be > 0");
 	assert!(
-		get_option_value!(meta_usage.total_tokens) > 0,
-		"total_tokens should be > 0"
+		matches!(over_budget_res, Err(genai::Error::PinnedBudgetExceeded { .. })),
+		"should be PinnedBudgetExceeded, but was {over_budget_res:?}"
 	);
+	assert!(splice_res.is_err(), "pinned message should not be removed");
+	assert_eq!(chat_req.messages.len(), 3);
+	assert_eq!(chat_req.iter_pinned().count(), 1);
 
-	// -- Check captured_content
-	let captured_content = stream_end.captured_first_text();
-	let captured_content = captured_content.ok_or("Captured content should have a text")?;
-	assert!(!captured_content.is_empty(), "captured_content.length should be > 0");
+	Ok(())
+}
 
-	// -- Check Reasoning Content
-	if contains_checks(checks, Check::REASONING) {
-		let reasoning_content = stream_end
-			.captured_reasoning_content
-			.as_ref()
-			.ok_or("captured_reasoning_content SHOULD have extracted some reasoning_content")?;
-		assert!(!reasoning_content.is_empty(), "reasoning_content should not be empty");
-		// We can assume that the reasoning content should be bigger than the content given the prompt to keep content very concise.
-		assert!(
-			reasoning_content.len() > captured_content.len(),
-			"Reasoning content should be > than the content"
-		);
-	}
+// endregion: --- Count Tokens
+
+// region:    --- Files
+
+pub async fn common_test_files_upload_list_delete_ok(adapter_kind: AdapterKind) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let file = FileUpload::new("genai-test.txt", "text/plain", "Hello from genai".as_bytes());
+
+	// -- Exec
+	let file_ref = client.upload_file(adapter_kind, file).await?;
+	let file_ref_got = client.get_file(adapter_kind, &file_ref.id).await?;
+	let files = client.list_files(adapter_kind).await?;
+	client.delete_file(&file_ref).await?;
+
+	// -- Check
+	assert_eq!(file_ref_got.id, file_ref.id);
+	assert!(
+		files.iter().any(|f| f.id == file_ref.id),
+		"list_files should contain the uploaded file"
+	);
 
 	Ok(())
 }
 
-// endregion: --- Chat Stream Tests
+/// NOTE: Uploads a file large enough to use the resumable upload protocol (Gemini).
+pub async fn common_test_files_upload_large_ok(adapter_kind: AdapterKind) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let content = "genai large file upload test line.\n".repeat(300_000);
+	let file = FileUpload::new("genai-test-large.txt", "text/plain", content.as_bytes());
 
-// region:    --- Images
+	// -- Exec
+	let file_ref = client.upload_file(adapter_kind, file).await?;
+	let file_ref_got = client.get_file(adapter_kind, &file_ref.id).await;
+	client.delete_file(&file_ref).await?;
 
-pub async fn common_test_chat_image_url_ok(model: &str) -> Result<()> {
-	// -- Setup
+	// -- Check
+	let file_ref_got = file_ref_got?;
+	assert_eq!(file_ref_got.id, file_ref.id);
+	assert_eq!(file_ref_got.size, Some(content.len() as u64));
+	assert!(file_ref_got.uri.is_some(), "uploaded file should have an uri");
+	assert!(!file_ref_got.is_failed(), "uploaded file should not have failed");
+
+	Ok(())
+}
+
+/// NOTE: Uses a `text/plain` file (supported as chat reference by Anthropic and Gemini, OpenAI supports only PDFs)
+pub async fn common_test_files_chat_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
 	let client = Client::default();
+	let adapter_kind = client.default_model(model)?.adapter_kind;
+	let content = "The secret code word of this document is 'blue pelican'.";
+	let file = FileUpload::new("genai-secret.txt", "text/plain", content.as_bytes());
+	let file_ref = client.upload_file(adapter_kind, file).await?;
 
-	// -- Build & Exec
-	let mut chat_req = ChatRequest::default().with_system("Answer in one sentence");
-	// This is similar to sending initial system chat messages (which will be cumulative with system chat messages)
-	chat_req = chat_req.append_message(ChatMessage::user(vec![
-		ContentPart::from_text("What is in this picture?"),
-		ContentPart::from_image_url("image/jpeg", IMAGE_URL_JPG_DUCK),
-	]));
-	let chat_res = client.exec_chat(model, chat_req, None).await?;
+	// -- Exec
+	let chat_req = ChatRequest::new(vec![ChatMessage::user(vec![
+		ContentPart::from_file(file_ref.clone()),
+		ContentPart::from_text("What is the secret code word of this document? Answer only with the code word."),
+	])]);
+	let chat_res = client.exec_chat(model, chat_req, None).await;
+	// delete before the checks, so that the file is always cleaned up
+	client.delete_file(&file_ref).await?;
 
 	// -- Check
+	let chat_res = chat_res?;
 	let res = chat_res.first_text().ok_or("Should have text result")?;
-	assert_contains(res, "duck");
+	assert_contains(res.to_lowercase().as_str(), "pelican");
 
 	Ok(())
 }
 
-pub async fn common_test_chat_image_b64_ok(model: &str) -> Result<()> {
-	// -- Setup
+/// NOTE: Creates a vector store with one file, waits for it to be processed, and searches it with `Tool::file_search(..)`.
+pub async fn common_test_chat_file_search_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
 	let client = Client::default();
+	let adapter_kind = client.default_model(model)?.adapter_kind;
+	let content = "The secret code word of the genai project is 'blue pelican'.";
+	let file = FileUpload::new("genai-secret.txt", "text/plain", content.as_bytes()).with_purpose("assistants");
+	let file_ref = client.upload_file(adapter_kind, file).await?;
+	let mut vector_store = client
+		.create_vector_store(adapter_kind, "genai-test", vec![file_ref.id.clone()])
+		.await?;
+	for _ in 0..30 {
+		if vector_store.is_ready() {
+			break;
+		}
+		tokio::time::sleep(Duration::from_secs(1)).await;
+		vector_store = client.get_vector_store(adapter_kind, &vector_store.id).await?;
+	}
 
-	// -- Build & Exec
-	let mut chat_req = ChatRequest::default().with_system("Answer in one sentence");
-	// This is similar to sending initial system chat messages (which will be cumulative with system chat messages)
-	chat_req = chat_req.append_message(ChatMessage::user(vec![
-		ContentPart::from_text("What is in this picture?"),
-		ContentPart::from_image_base64("image/jpeg", get_b64_duck()?),
-	]));
-	let chat_res = client.exec_chat(model, chat_req, None).await?;
+	// -- Exec
+	let chat_req =
+		ChatRequest::from_user("What is the secret code word of the genai project? Answer only with the code word.")
+			.with_tools(vec![Tool::file_search([vector_store.id.clone()])]);
+	let chat_res = client.exec_chat(model, chat_req, None).await;
+	// delete before the checks, so that the vector store and file are always cleaned up
+	client.delete_vector_store(&vector_store).await?;
+	client.delete_file(&file_ref).await?;
 
 	// -- Check
+	let chat_res = chat_res?;
 	let res = chat_res.first_text().ok_or("Should have text result")?;
-	assert_contains(res, "duck");
+	assert_contains(res.to_lowercase().as_str(), "pelican");
+	let file_citations = chat_res.file_citations.ok_or("Should have file_citations")?;
+	for file_citation in file_citations.iter() {
+		assert_eq!(file_citation.file_id, file_ref.id);
+	}
 
 	Ok(())
 }
 
-// endregion: --- Images
+// endregion: --- Files
 
-// region:    --- Tools
+// region:    --- Fine-tuning
 
-/// Just making the tool request, and checking the tool call response
-/// `complete_check` if for LLMs that are better at giving back the unit and weather.
-pub async fn common_test_tool_simple_ok(model: &str, complete_check: bool) -> Result<()> {
+pub async fn common_test_finetune_list_ok(adapter_kind: AdapterKind) -> Result<()> {
 	// -- Setup & Fixtures
 	let client = Client::default();
-	let chat_req = seed_chat_req_tool_simple();
 
 	// -- Exec
-	let chat_res = client.exec_chat(model, chat_req, None).await?;
+	let jobs = client.list_finetune_jobs(adapter_kind).await?;
+	let model_names = client.list_finetuned_model_names(adapter_kind).await?;
 
 	// -- Check
-	let mut tool_calls = chat_res.tool_calls();
-	if tool_calls.is_empty() {
-		return Err("Should have tool calls in chat_res".into());
+	for job in jobs.iter() {
+		assert!(!job.id.is_empty(), "job id should not be empty");
+		assert_eq!(job.adapter_kind, adapter_kind);
 	}
-	let tool_call = tool_calls.pop().ok_or("Should have at least one tool call")?;
-	assert_eq!(tool_call.fn_arguments.x_get_as::<&str>("city")?, "Paris");
-	assert_eq!(tool_call.fn_arguments.x_get_as::<&str>("country")?, "France");
-	if complete_check {
-		// Note: Not all LLM will output the weather (e.g. Anthropic Haiku)
-		assert_eq!(tool_call.fn_arguments.x_get_as::<&str>("unit")?, "C");
+	for model_name in model_names.iter() {
+		let model_iden = client.default_model(model_name)?;
+		assert_eq!(model_iden.adapter_kind, adapter_kind);
 	}
 
 	Ok(())
 }
 
-/// `complete_check` if for LLMs that are better at giving back the unit and weather.
-pub async fn common_test_tool_full_flow_ok(model: &str, complete_check: bool) -> Result<()> {
+// endregion: --- Fine-tuning
+
+// region:    --- Golden
+
+pub async fn common_test_golden_runner_ok(model: &str) -> Result<()> {
 	// -- Setup & Fixtures
 	let client = Client::default();
-	let mut chat_req = seed_chat_req_tool_simple();
+	let cases = GoldenCase::from_jsonl(&format!(
+		"{}\n{}",
+		GoldenCase::new(
+			"capital",
+			ChatRequest::from_user("What is the capital of France? Answer with one word."),
+			"Paris"
+		)
+		.to_jsonl_line()?,
+		GoldenCase::new(
+			"sum",
+			ChatRequest::from_user("What is 2 + 3? Answer with the number only."),
+			"5"
+		)
+		.with_tolerance(GoldenTolerance::Judge {
+			model: model.to_string(),
+			criteria: Some("Only the numeric value matters.".to_string()),
+		})
+		.to_jsonl_line()?
+	))?;
+	let runner = GoldenRunner::new(client.clone());
 
-	// -- Exec first request to get the tool calls
-	let chat_res = client.exec_chat(model, chat_req.clone(), None).await?;
-	let tool_calls = chat_res.into_tool_calls();
+	// -- Exec
+	let report = runner
+		.run(&cases, |case| {
+			let client = client.clone();
+			let chat_req = case.chat_req.clone();
+			async move {
+				let chat_res = client.exec_chat(model, chat_req, None).await?;
+				let output = chat_res
+					.first_text()
+					.unwrap_or_default()
+					.trim()
+					.trim_end_matches('.')
+					.to_string();
+				Ok::<_, genai::Error>(output)
+			}
+		})
+		.await;
 
-	if tool_calls.is_empty() {
-		return Err("Should have tool calls in chat_res".into());
-	}
+	// -- Check
+	assert!(report.is_pass(), "report should pass:\n{}", report.to_summary());
+	assert_eq!(report.passed_count(), 2);
+	assert_contains(&report.to_junit_xml("golden"), r#"<testcase name="capital""#);
 
-	// -- Exec the second request
-	// get the tool call id (first one)
-	let first_tool_call = tool_calls.first().ok_or("Should have at least one tool call")?;
-	let first_tool_call_id = &first_tool_call.call_id;
-	// simulate the response
-	let tool_response = ToolResponse::new(first_tool_call_id, r#"{"weather": "Sunny", "temperature": "32C"}"#);
+	Ok(())
+}
 
-	// Add the tool_calls, tool_response
-	let chat_req = chat_req.append_message(tool_calls).append_message(tool_response);
+// endregion: --- Golden
 
-	let chat_res = client.exec_chat(model, chat_req.clone(), None).await?;
+// region:    --- Sqlite Log
+
+#[cfg(feature = "sqlite")]
+pub async fn common_test_sqlite_log_ok(model: &str) -> Result<()> {
+	use genai::trace::SqliteLog;
+
+	// -- Setup & Fixtures
+	let model_name = model.split_once("::").map(|(_, name)| name).unwrap_or(model);
+	let sqlite_log = SqliteLog::open_in_memory()?.with_model_price(model_name, ModelPrice::new(0.15, 0.6));
+	let client = Client::builder().with_sqlite_log(sqlite_log.clone()).build();
+	let chat_req = ChatRequest::from_user("My email is jane.doe@example.com. Reply with 'ok' only.");
+	let options = ChatOptions::default().with_tenant_id("tenant-a");
+
+	// -- Exec
+	client.exec_chat(model, chat_req.clone(), Some(&options)).await?;
+	let failed = client.exec_chat(&format!("{model}-not-a-model"), chat_req, None).await;
 
 	// -- Check
-	let content = chat_res.first_text().ok_or("Last response should be message")?.to_lowercase(); // lowercase because some models send "Sunny" and not "sunny"
+	assert!(failed.is_err(), "the unknown model call should fail");
+	let entries = sqlite_log.top_expensive(10)?;
+	assert_eq!(entries.len(), 2);
+	let entry = &entries[0];
+	assert_eq!(entry.tag.as_deref(), Some("tenant-a"));
+	assert!(
+		entry.cost.is_some_and(|cost| cost > 0.),
+		"the priced call should have a cost"
+	);
+	assert!(entries[1].error.is_some(), "the failed call should have an error");
+	let record = sqlite_log.record(&entry.id)?.ok_or("Should have the logged record")?;
+	let input = serde_json::to_string(&record.input_messages)?;
+	assert!(!input.contains("jane.doe@example.com"), "the email should be redacted");
+	assert_contains(&input, "<EMAIL_1>");
+	let error_rates = sqlite_log.error_rates(None)?;
+	assert_eq!(error_rates.len(), 2);
+	assert_eq!(error_rates[0].rate(), 1.);
 
-	assert!(content.contains("paris"), "Should contain 'Paris'");
-	assert!(content.contains("32"), "Should contain '32'");
-	if complete_check {
-		// Note: Not all LLM will output the weather (e.g. Anthropic Haiku)
-		assert!(content.contains("sunny"), "Should contain 'sunny'");
+	Ok(())
+}
+
+// endregion: --- Sqlite Log
+
+// region:    --- Usage Report
+
+pub async fn common_test_usage_report_ok(adapter_kind: AdapterKind) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let req = UsageReportRequest::new("2025-06-01T00:00:00Z")
+		.with_ending_at("2025-06-08T00:00:00Z")
+		.with_bucket_width("1d")
+		.with_group_by("model");
+
+	// -- Exec
+	let usage_report = client.usage_report(adapter_kind, req.clone()).await?;
+	let cost_report = client.cost_report(adapter_kind, req).await?;
+
+	// -- Check
+	assert_eq!(usage_report.adapter_kind, adapter_kind);
+	assert!(!usage_report.buckets.is_empty(), "usage report should have buckets");
+	let total_usage = usage_report.total_usage();
+	if let Some(total_tokens) = total_usage.total_tokens {
+		assert!(total_tokens >= 0, "total_tokens should be >= 0");
 	}
+	assert!(cost_report.total_amount() >= 0., "total amount should be >= 0");
 
 	Ok(())
 }
 
-// endregion: --- Tools
+// endregion: --- Usage Report
 
-// region:    --- With Resolvers
+// region:    --- Ollama Management
 
-pub async fn common_test_resolver_auth_ok(model: &str, auth_data: AuthData) -> Result<()> {
+/// NOTE: Assumes the model is already pulled (so the pull only verifies it).
+pub async fn common_test_ollama_manage_ok(model: &str) -> Result<()> {
 	// -- Setup & Fixtures
-	let auth_resolver = AuthResolver::from_resolver_fn(move |model_iden: ModelIden| Ok(Some(auth_data)));
-	let client = Client::builder().with_auth_resolver(auth_resolver).build();
-	let chat_req = seed_chat_req_simple();
+	let client = Client::default();
 
 	// -- Exec
-	let chat_res = client.exec_chat(model, chat_req, None).await?;
+	let mut pull_stream = client.ollama_pull(model).await?;
+	let mut last_progress = None;
+	while let Some(progress) = pull_stream.next().await {
+		last_progress = Some(progress?);
+	}
+	let model_info = client.ollama_show(model).await?;
+	let _running_models = client.ollama_ps().await?;
 
 	// -- Check
-	assert!(!chat_res.content.is_empty(), "Content should not be empty");
-	let usage = chat_res.usage;
-	let total_tokens = get_option_value!(usage.total_tokens);
-	assert!(total_tokens > 0, "total_tokens should be > 0");
+	let last_progress = last_progress.ok_or("Should have pull progress events")?;
+	assert!(last_progress.is_success(), "last pull progress should be 'success'");
+	assert!(
+		model_info.capabilities.iter().any(|c| c == "completion"),
+		"model should have the 'completion' capability"
+	);
 
 	Ok(())
 }
 
-// endregion: --- With Resolvers
+// endregion: --- Ollama Management
 
-// region:    --- List
+// region:    --- Realtime
 
-pub async fn common_test_list_models(adapter_kind: AdapterKind, contains: &str) -> Result<()> {
+pub async fn common_test_realtime_text_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
 	let client = Client::default();
+	let config = RealtimeConfig::default()
+		.with_instructions("Answer in one short sentence.")
+		.with_output_modality(RealtimeModality::Text);
 
 	// -- Exec
-	let models = client.all_model_names(adapter_kind).await?;
+	let mut session = client.realtime_connect(model, Some(&config)).await?;
+	session
+		.send(RealtimeInput::Text("Why is the sky red? (be concise)".to_string()))
+		.await?;
+	session.send(RealtimeInput::CreateResponse).await?;
+
+	let mut text = String::new();
+	let mut usage = None;
+	while let Some(event) = session.next_event().await {
+		match event? {
+			RealtimeEvent::TextDelta(delta) => text.push_str(&delta),
+			RealtimeEvent::ResponseDone { usage: done_usage } => {
+				usage = Some(done_usage);
+				break;
+			}
+			RealtimeEvent::Error { message, .. } => return Err(format!("Realtime error event: {message}").into()),
+			_ => (),
+		}
+	}
+	session.close().await?;
 
 	// -- Check
-	assert_contains(&models, contains);
+	assert!(!text.trim().is_empty(), "text should not be empty");
+	let usage = usage.ok_or("Should have a ResponseDone event")?;
+	let total_tokens = get_option_value!(usage.total_tokens);
+	assert!(total_tokens > 0, "total_tokens should be > 0");
 
 	Ok(())
 }
 
-// endregion: --- List
+// endregion: --- Realtime
 
 // region:    --- Embeddings
 
@@ -754,7 +2963,7 @@ pub async fn common_test_embed_single_simple_ok_with_usage_check(model: &str, ex
 	if expect_usage {
 		assert!(response.usage.prompt_tokens.is_some());
 		assert!(response.usage.prompt_tokens.unwrap() > 0);
-		assert!(response.usage.total_tokens.is_some());
+		assert_eq!(response.usage.total_tokens, response.usage.prompt_tokens);
 		println!(
 			"✓ Single embedding: {} dimensions, {} tokens",
 			embedding.dimensions(),
@@ -937,4 +3146,182 @@ pub async fn common_test_embed_empty_batch_should_fail(model: &str) -> Result<()
 	Ok(())
 }
 
+pub async fn common_test_embed_base64_encoding_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let text = "Test with the base64 encoding format";
+	let float_options = EmbedOptions::new().with_encoding_format("float");
+	let base64_options = EmbedOptions::new().with_encoding_format("base64");
+
+	// -- Exec
+	let float_res = client.embed(model, text, Some(&float_options)).await?;
+	let base64_res = client.embed(model, text, Some(&base64_options)).await?;
+
+	// -- Check
+	let float_vector = float_res.first_vector().ok_or("Should have a float vector")?;
+	let base64_vector = base64_res.first_vector().ok_or("Should have a base64 vector")?;
+	assert_eq!(float_vector.len(), base64_vector.len());
+	// embeddings are not fully deterministic, but should be very close
+	let max_diff = float_vector
+		.iter()
+		.zip(base64_vector.iter())
+		.map(|(a, b)| (a - b).abs())
+		.fold(0.0f32, f32::max);
+	assert!(
+		max_diff < 0.01,
+		"base64 vector should match the float vector (max diff {max_diff})"
+	);
+
+	Ok(())
+}
+
+pub async fn common_test_embed_billed_units_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let texts = vec!["First billed text".to_string(), "Second billed text".to_string()];
+
+	// -- Exec
+	let response = client.embed_batch(model, texts, None).await?;
+
+	// -- Check
+	let billed_units = response.billed_units.as_ref().ok_or("Should have billed units")?;
+	let input_tokens = billed_units.input_tokens.ok_or("Should have billed input tokens")?;
+	assert!(input_tokens > 0);
+	assert_eq!(response.usage.prompt_tokens, Some(input_tokens));
+	assert_eq!(response.usage.total_tokens, Some(input_tokens));
+
+	Ok(())
+}
+
+pub async fn common_test_embed_normalize_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let options = EmbedOptions::new().with_normalize(true);
+	let texts = vec!["First text to normalize".to_string(), "Second text to normalize".to_string()];
+
+	// -- Exec
+	let response = client.embed_batch(model, texts, Some(&options)).await?;
+
+	// -- Check
+	assert_eq!(response.embedding_count(), 2);
+	for vector in response.vectors() {
+		let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+		assert!(
+			(norm - 1.0).abs() < 1e-4,
+			"vector should be a unit vector (norm {norm})"
+		);
+	}
+
+	Ok(())
+}
+
+pub async fn common_test_embed_dimensions_not_supported_should_fail(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let options = EmbedOptions::new().with_dimensions(256);
+
+	// -- Exec
+	let result = client.embed(model, "Test with unsupported dimensions", Some(&options)).await;
+
+	// -- Check
+	match result {
+		Err(genai::Error::EmbedDimensionsNotSupported { .. }) => Ok(()),
+		other => Err(format!("Should be EmbedDimensionsNotSupported, but was: {other:?}").into()),
+	}
+}
+
+pub async fn common_test_embed_native_int8_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let options = EmbedOptions::new().with_encoding_format("int8");
+
+	// -- Exec
+	let response = client
+		.embed(model, "Test with the int8 encoding format", Some(&options))
+		.await?;
+
+	// -- Check
+	let embedding = response.first_embedding().ok_or("Should have an embedding")?;
+	let Some(EmbeddingVector::I8(native)) = embedding.native_vector() else {
+		return Err(format!(
+			"Should have an I8 native vector, but was: {:?}",
+			embedding.native_vector()
+		)
+		.into());
+	};
+	assert_eq!(native.len(), embedding.dimensions());
+	assert_eq!(native[0] as f32, embedding.vector()[0]);
+
+	Ok(())
+}
+
+pub async fn common_test_embed_image_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let embed_req = EmbedRequest::new_parts(vec![
+		vec![ContentPart::from_image_base64("image/jpeg", get_b64_duck()?)],
+		vec![
+			ContentPart::from_text("A small yellow duck"),
+			ContentPart::from_image_base64("image/jpeg", get_b64_duck()?),
+		],
+	]);
+
+	// -- Exec
+	let response = client.exec_embed(model, embed_req, None).await?;
+
+	// -- Check
+	assert_eq!(response.embedding_count(), 2);
+	let first = response.embeddings.first().ok_or("Should have a first embedding")?;
+	let second = response.embeddings.get(1).ok_or("Should have a second embedding")?;
+	assert!(first.dimensions() > 0);
+	assert_eq!(first.dimensions(), second.dimensions());
+
+	Ok(())
+}
+
+pub async fn common_test_embed_batch_concurrent_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let inputs: Vec<String> = (0..25).map(|idx| format!("Concurrent embedding input number {idx}")).collect();
+	let concurrency = EmbedConcurrency::default().with_chunk_size(10).with_max_concurrent(2);
+
+	// -- Exec
+	let chunk_results = client.embed_batch_concurrent(model, inputs, None, &concurrency).await?;
+
+	// -- Check
+	assert_eq!(chunk_results.len(), 3);
+	let mut expected_index = 0;
+	for chunk_result in chunk_results {
+		let response = chunk_result.result?;
+		assert_eq!(response.embedding_count(), chunk_result.inputs_range.len());
+		for embedding in response.embeddings {
+			assert_eq!(embedding.index(), expected_index);
+			expected_index += 1;
+		}
+	}
+	assert_eq!(expected_index, 25);
+
+	Ok(())
+}
+
+pub async fn common_test_embed_many_ok(model: &str, input_count: usize) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let texts: Vec<String> = (0..input_count).map(|idx| format!("Document number {idx}")).collect();
+
+	// -- Exec
+	let response = client.embed_many(model, texts, None, 2).await?;
+
+	// -- Check
+	assert_eq!(response.embedding_count(), input_count);
+	for (idx, embedding) in response.embeddings.iter().enumerate() {
+		assert_eq!(embedding.index(), idx);
+		assert!(embedding.dimensions() > 0);
+	}
+	let prompt_tokens = response.usage.prompt_tokens.ok_or("Should have prompt_tokens")?;
+	assert!(prompt_tokens >= input_count as i32, "usage should be aggregated");
+
+	Ok(())
+}
+
 // endregion: --- Embeddings
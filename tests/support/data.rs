@@ -7,6 +7,9 @@ use std::io::Read;
 
 pub const IMAGE_URL_JPG_DUCK: &str = "https://upload.wikimedia.org/wikipedia/commons/thumb/b/bf/Bucephala-albeola-010.jpg/440px-Bucephala-albeola-010.jpg";
 
+/// A public YouTube video (for the Gemini video understanding)
+pub const VIDEO_URL_YOUTUBE: &str = "https://www.youtube.com/watch?v=9hE5-98ZeCg";
+
 /// Get the base64 of the image above (but resized/lower to fit 5kb)
 pub fn get_b64_duck() -> Result<String, Box<dyn std::error::Error>> {
 	// Path to the local image file
@@ -22,3 +25,15 @@ pub fn get_b64_duck() -> Result<String, Box<dyn std::error::Error>> {
 
 	Ok(base64_encoded)
 }
+
+/// Get the base64 of a small one page PDF document (with the text "The secret fruit is banana.")
+pub fn get_b64_small_pdf() -> Result<String, Box<dyn std::error::Error>> {
+	let buffer = std::fs::read("./tests/data/small-doc.pdf")?;
+	Ok(general_purpose::STANDARD.encode(&buffer))
+}
+
+/// Get the base64 of a one second 440 Hz tone (wav, 8 kHz mono)
+pub fn get_b64_tone_wav() -> Result<String, Box<dyn std::error::Error>> {
+	let buffer = std::fs::read("./tests/data/tone-440hz.wav")?;
+	Ok(general_purpose::STANDARD.encode(&buffer))
+}
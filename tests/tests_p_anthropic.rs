@@ -29,24 +29,84 @@ async fn test_chat_namespaced_ok() -> Result<()> {
 	common_tests::common_test_chat_simple_ok(MODEL_NS, None).await
 }
 
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_capture_headers_ok() -> Result<()> {
+	common_tests::common_test_chat_capture_headers_ok(MODEL).await
+}
+
 #[tokio::test]
 #[serial(anthropic)]
 async fn test_chat_multi_system_ok() -> Result<()> {
 	common_tests::common_test_chat_multi_system_ok(MODEL).await
 }
 
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_developer_role_ok() -> Result<()> {
+	common_tests::common_test_chat_developer_role_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_json_structured_ok() -> Result<()> {
+	common_tests::common_test_chat_json_structured_ok(MODEL, Some(Check::USAGE)).await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_structured_typed_ok() -> Result<()> {
+	common_tests::common_test_chat_structured_typed_ok(MODEL).await
+}
+
 #[tokio::test]
 #[serial(anthropic)]
 async fn test_chat_temperature_ok() -> Result<()> {
 	common_tests::common_test_chat_temperature_ok(MODEL).await
 }
 
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_logit_bias_not_supported_should_fail() -> Result<()> {
+	common_tests::common_test_chat_logit_bias_not_supported_should_fail(MODEL).await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_penalties_ok() -> Result<()> {
+	common_tests::common_test_chat_penalties_ok(MODEL).await
+}
+
 #[tokio::test]
 #[serial(anthropic)]
 async fn test_chat_stop_sequences_ok() -> Result<()> {
 	common_tests::common_test_chat_stop_sequences_ok(MODEL).await
 }
 
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_stop_sequence_matched_ok() -> Result<()> {
+	common_tests::common_test_chat_stop_sequence_matched_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_user_metadata_ok() -> Result<()> {
+	common_tests::common_test_chat_user_metadata_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_extra_body_ok() -> Result<()> {
+	common_tests::common_test_chat_extra_body_ok(MODEL, "max_tokens").await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_extra_headers_ok() -> Result<()> {
+	common_tests::common_test_chat_extra_headers_ok(MODEL, "x-api-key", "bad-key").await
+}
+
 /// TODO: Fix/Workaround - This test for "claude-3-7-sonnet-latest" (works for other models)
 #[tokio::test]
 #[serial(anthropic)]
@@ -70,8 +130,39 @@ async fn test_chat_cache_explicit_system_ok() -> Result<()> {
 	common_tests::common_test_chat_cache_explicit_system_ok(MODEL).await
 }
 
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_cache_explicit_ttl_ok() -> Result<()> {
+	common_tests::common_test_chat_cache_explicit_ttl_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_cache_point_ok() -> Result<()> {
+	common_tests::common_test_chat_cache_point_ok(MODEL).await
+}
+
 // endregion: --- Chat Explicit Cache
 
+// region:    --- Chat Auto Continue
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_auto_continue_ok() -> Result<()> {
+	common_tests::common_test_chat_auto_continue_ok(MODEL).await
+}
+
+// endregion: --- Chat Auto Continue
+
+// region:    --- Chat Refusal
+
+#[tokio::test]
+async fn test_chat_not_refusal_ok() -> Result<()> {
+	common_tests::common_test_chat_not_refusal_ok(MODEL).await
+}
+
+// endregion: --- Chat Refusal
+
 // region:    --- Chat Stream Tests
 
 #[tokio::test]
@@ -86,6 +177,12 @@ async fn test_chat_stream_capture_content_ok() -> Result<()> {
 	common_tests::common_test_chat_stream_capture_content_ok(MODEL).await
 }
 
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_stream_finish_reason_ok() -> Result<()> {
+	common_tests::common_test_chat_stream_finish_reason_ok(MODEL).await
+}
+
 #[tokio::test]
 #[serial(anthropic)]
 async fn test_chat_stream_capture_all_ok() -> Result<()> {
@@ -93,6 +190,15 @@ async fn test_chat_stream_capture_all_ok() -> Result<()> {
 }
 // endregion: --- Chat Stream Tests
 
+// region:    --- Chat Output Fixer
+
+#[tokio::test]
+async fn test_chat_output_fixer_ok() -> Result<()> {
+	common_tests::common_test_chat_output_fixer_ok(MODEL, MODEL_NS).await
+}
+
+// endregion: --- Chat Output Fixer
+
 // region:    --- Image Tests
 
 // NOTE: For now disable these tests as they failed. Needs to be resolved.
@@ -108,6 +214,21 @@ async fn test_chat_image_b64_ok() -> Result<()> {
 	common_tests::common_test_chat_image_b64_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_document_b64_ok() -> Result<()> {
+	common_tests::common_test_chat_document_b64_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_document_citations_ok() -> Result<()> {
+	common_tests::common_test_chat_document_citations_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_stream_document_citations_ok() -> Result<()> {
+	common_tests::common_test_chat_stream_document_citations_ok(MODEL).await
+}
+
 // endregion: --- Image Test
 
 // region:    --- Tool Tests
@@ -118,12 +239,99 @@ async fn test_tool_simple_ok() -> Result<()> {
 	common_tests::common_test_tool_simple_ok(MODEL, false).await
 }
 
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_tool_choice_ok() -> Result<()> {
+	common_tests::common_test_tool_choice_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_tool_choice_with_reasoning_ok() -> Result<()> {
+	// NOTE: The thinking requires a Claude 4 (or 3.7) model.
+	common_tests::common_test_tool_choice_with_reasoning_ok("claude-sonnet-4-20250514").await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_tool_cache_control_options_ok() -> Result<()> {
+	common_tests::common_test_tool_cache_control_options_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_tool_no_parallel_calls_ok() -> Result<()> {
+	common_tests::common_test_tool_no_parallel_calls_ok(MODEL).await
+}
+
 #[tokio::test]
 // #[serial(anthropic)]
 async fn test_tool_full_flow_ok() -> Result<()> {
 	common_tests::common_test_tool_full_flow_ok(MODEL, false).await
 }
 
+#[tokio::test]
+async fn test_tool_history_edit_ok() -> Result<()> {
+	common_tests::common_test_tool_history_edit_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_tool_interleaved_thinking_ok() -> Result<()> {
+	// NOTE: The interleaved thinking requires a Claude 4 model.
+	common_tests::common_test_tool_interleaved_thinking_ok("claude-sonnet-4-20250514").await
+}
+
+#[tokio::test]
+async fn test_tool_thinking_history_invalid_should_fail() -> Result<()> {
+	// NOTE: The thinking requires a Claude 4 (or 3.7) model.
+	common_tests::common_test_tool_thinking_history_invalid_should_fail("claude-sonnet-4-20250514").await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_tool_thinking_history_unsigned_without_thinking_ok() -> Result<()> {
+	common_tests::common_test_tool_thinking_history_unsigned_without_thinking_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_builtin_web_search_ok() -> Result<()> {
+	common_tests::common_test_chat_builtin_web_search_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_stream_builtin_web_search_ok() -> Result<()> {
+	common_tests::common_test_chat_stream_builtin_web_search_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_tool_computer_use_ok() -> Result<()> {
+	// NOTE: The `computer_20250124` tool requires a Claude 4 (or 3.7) model.
+	common_tests::common_test_tool_computer_use_ok("claude-sonnet-4-20250514").await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_builtin_code_execution_ok() -> Result<()> {
+	common_tests::common_test_chat_builtin_code_execution_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_chat_stream_builtin_code_execution_ok() -> Result<()> {
+	common_tests::common_test_chat_stream_builtin_code_execution_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_tool_builtin_bash_ok() -> Result<()> {
+	// NOTE: The `bash_20250124` and `text_editor_20250728` tools require a Claude 4 model.
+	common_tests::common_test_tool_builtin_bash_ok("claude-sonnet-4-20250514").await
+}
+
 // endregion: --- Tool Tests
 
 // region:    --- Resolver Tests
@@ -136,6 +344,46 @@ async fn test_resolver_auth_ok() -> Result<()> {
 
 // endregion: --- Resolver Tests
 
+// region:    --- Count Tokens
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_count_tokens_ok() -> Result<()> {
+	common_tests::common_test_count_tokens_ok(MODEL).await
+}
+
+#[tokio::test]
+#[serial(anthropic)]
+async fn test_pinned_budget_ok() -> Result<()> {
+	common_tests::common_test_pinned_budget_ok(MODEL).await
+}
+
+// endregion: --- Count Tokens
+
+// region:    --- Files
+
+#[tokio::test]
+async fn test_files_upload_list_delete_ok() -> Result<()> {
+	common_tests::common_test_files_upload_list_delete_ok(AdapterKind::Anthropic).await
+}
+
+#[tokio::test]
+async fn test_files_chat_ok() -> Result<()> {
+	common_tests::common_test_files_chat_ok(MODEL).await
+}
+
+// endregion: --- Files
+
+// region:    --- Usage Report
+
+/// NOTE: Requires an Anthropic Admin API key in the `ANTHROPIC_ADMIN_KEY` environment variable.
+#[tokio::test]
+async fn test_usage_report_ok() -> Result<()> {
+	common_tests::common_test_usage_report_ok(AdapterKind::Anthropic).await
+}
+
+// endregion: --- Usage Report
+
 // region:    --- List
 
 #[tokio::test]
@@ -144,3 +392,12 @@ async fn test_list_models() -> Result<()> {
 }
 
 // endregion: --- List
+
+// region:    --- Ping
+
+#[tokio::test]
+async fn test_ping_ok() -> Result<()> {
+	common_tests::common_test_ping_ok(AdapterKind::Anthropic).await
+}
+
+// endregion: --- Ping
@@ -1,6 +1,8 @@
 mod support;
 
 use crate::support::{Result, common_tests};
+use genai::Client;
+use genai::embed::EmbedOptions;
 
 const MODEL: &str = "embed-english-v3.0";
 const MODEL_V4: &str = "embed-v4.0";
@@ -37,6 +39,17 @@ async fn test_cohere_embed_batch_empty_should_fail() -> Result<()> {
 	common_tests::common_test_embed_empty_batch_should_fail(MODEL).await
 }
 
+#[tokio::test]
+async fn test_cohere_embed_many_ok() -> Result<()> {
+	// more than the Cohere batch limit (96)
+	common_tests::common_test_embed_many_ok(MODEL, 150).await
+}
+
+#[tokio::test]
+async fn test_cohere_embed_billed_units_ok() -> Result<()> {
+	common_tests::common_test_embed_billed_units_ok(MODEL).await
+}
+
 // endregion: --- Batch Embedding Tests
 
 // region:    --- Provider-Specific Tests
@@ -46,4 +59,43 @@ async fn test_cohere_embed_with_provider_specific_options_ok() -> Result<()> {
 	common_tests::common_test_embed_provider_specific_options_ok(MODEL_V4, "search_query", Some("START")).await
 }
 
+#[tokio::test]
+async fn test_cohere_embed_image_ok() -> Result<()> {
+	common_tests::common_test_embed_image_ok(MODEL_V4).await
+}
+
+#[tokio::test]
+async fn test_cohere_embed_native_int8_ok() -> Result<()> {
+	common_tests::common_test_embed_native_int8_ok(MODEL_V4).await
+}
+
+#[tokio::test]
+async fn test_cohere_embed_multiple_embedding_types_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let texts = vec!["First document".to_string(), "Second document".to_string()];
+	let options = EmbedOptions::new()
+		.with_embedding_type("classification")
+		.with_embedding_types(["int8", "float", "binary"]);
+
+	// -- Exec
+	let response = client.embed_batch(MODEL_V4, texts, Some(&options)).await?;
+
+	// -- Check
+	assert_eq!(response.embedding_count(), 2);
+	for embedding in response.embeddings.iter() {
+		let typed = embedding.typed_vectors().ok_or("Should have typed vectors")?;
+		let int8 = typed.int8.as_ref().ok_or("Should have int8 vector")?;
+		let float = typed.float.as_ref().ok_or("Should have float vector")?;
+		let binary = typed.binary.as_ref().ok_or("Should have binary vector")?;
+		assert_eq!(int8.len(), float.len());
+		assert_eq!(binary.len() * 8, float.len());
+		// the main vector is the first requested type
+		assert_eq!(embedding.dimensions(), int8.len());
+		assert!(typed.uint8.is_none());
+	}
+
+	Ok(())
+}
+
 // endregion: --- Provider-Specific Tests
@@ -28,21 +28,61 @@ async fn test_chat_multi_system_ok() -> Result<()> {
 	common_tests::common_test_chat_multi_system_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_developer_role_ok() -> Result<()> {
+	common_tests::common_test_chat_developer_role_ok(MODEL).await
+}
+
 #[tokio::test]
 async fn test_chat_json_structured_ok() -> Result<()> {
 	common_tests::common_test_chat_json_structured_ok(MODEL, Some(Check::USAGE)).await
 }
 
+#[tokio::test]
+async fn test_chat_structured_typed_ok() -> Result<()> {
+	common_tests::common_test_chat_structured_typed_ok(MODEL).await
+}
+
 #[tokio::test]
 async fn test_chat_temperature_ok() -> Result<()> {
 	common_tests::common_test_chat_temperature_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_logprobs_ok() -> Result<()> {
+	common_tests::common_test_chat_logprobs_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_candidate_count_ok() -> Result<()> {
+	common_tests::common_test_chat_candidate_count_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_sampling_extras_ok() -> Result<()> {
+	common_tests::common_test_chat_sampling_extras_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_seed_ok() -> Result<()> {
+	common_tests::common_test_chat_seed_ok(MODEL, false).await
+}
+
 #[tokio::test]
 async fn test_chat_stop_sequences_ok() -> Result<()> {
 	common_tests::common_test_chat_stop_sequences_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_safety_settings_ok() -> Result<()> {
+	common_tests::common_test_chat_safety_settings_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_extra_headers_ok() -> Result<()> {
+	common_tests::common_test_chat_extra_headers_ok(MODEL, "x-goog-api-key", "bad-key").await
+}
+
 // endregion: --- Chat
 
 // region:    --- Chat Implicit Cache
@@ -56,6 +96,15 @@ async fn test_chat_stop_sequences_ok() -> Result<()> {
 
 // endregion: --- Chat Implicit Cache
 
+// region:    --- Chat Explicit Cache
+
+#[tokio::test]
+async fn test_chat_cached_content_ok() -> Result<()> {
+	common_tests::common_test_chat_cached_content_ok(MODEL).await
+}
+
+// endregion: --- Chat Explicit Cache
+
 // region:    --- Chat Stream Tests
 
 #[tokio::test]
@@ -68,6 +117,11 @@ async fn test_chat_stream_capture_content_ok() -> Result<()> {
 	common_tests::common_test_chat_stream_capture_content_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_stream_finish_reason_ok() -> Result<()> {
+	common_tests::common_test_chat_stream_finish_reason_ok(MODEL).await
+}
+
 #[tokio::test]
 async fn test_chat_stream_capture_all_ok() -> Result<()> {
 	common_tests::common_test_chat_stream_capture_all_ok(MODEL, None).await
@@ -87,6 +141,27 @@ async fn test_chat_stream_capture_all_ok() -> Result<()> {
 async fn test_chat_image_b64_ok() -> Result<()> {
 	common_tests::common_test_chat_image_b64_ok(MODEL).await
 }
+
+#[tokio::test]
+async fn test_chat_document_b64_ok() -> Result<()> {
+	common_tests::common_test_chat_document_b64_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_audio_b64_ok() -> Result<()> {
+	common_tests::common_test_chat_audio_b64_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_image_output_ok() -> Result<()> {
+	common_tests::common_test_chat_image_output_ok("gemini-2.5-flash-image", None).await
+}
+
+#[tokio::test]
+async fn test_chat_video_url_ok() -> Result<()> {
+	common_tests::common_test_chat_video_url_ok(MODEL).await
+}
+
 // endregion: --- Image Test
 
 // region:    --- Tool Tests
@@ -96,10 +171,31 @@ async fn test_tool_simple_ok() -> Result<()> {
 	common_tests::common_test_tool_simple_ok(MODEL, true).await
 }
 
+#[tokio::test]
+async fn test_tool_choice_ok() -> Result<()> {
+	common_tests::common_test_tool_choice_ok(MODEL).await
+}
+
 #[tokio::test]
 async fn test_tool_full_flow_ok() -> Result<()> {
 	common_tests::common_test_tool_full_flow_ok(MODEL, true).await
 }
+
+#[tokio::test]
+async fn test_chat_provider_ext_grounding_ok() -> Result<()> {
+	common_tests::common_test_chat_provider_ext_grounding_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_builtin_web_search_ok() -> Result<()> {
+	common_tests::common_test_chat_builtin_web_search_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_builtin_code_execution_ok() -> Result<()> {
+	common_tests::common_test_chat_builtin_code_execution_ok(MODEL).await
+}
+
 // endregion: --- Tool Tests
 
 // region:    --- Resolver Tests
@@ -111,6 +207,39 @@ async fn test_resolver_auth_ok() -> Result<()> {
 
 // endregion: --- Resolver Tests
 
+// region:    --- Count Tokens
+
+#[tokio::test]
+async fn test_count_tokens_ok() -> Result<()> {
+	common_tests::common_test_count_tokens_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_pinned_budget_ok() -> Result<()> {
+	common_tests::common_test_pinned_budget_ok(MODEL).await
+}
+
+// endregion: --- Count Tokens
+
+// region:    --- Files
+
+#[tokio::test]
+async fn test_files_upload_list_delete_ok() -> Result<()> {
+	common_tests::common_test_files_upload_list_delete_ok(AdapterKind::Gemini).await
+}
+
+#[tokio::test]
+async fn test_files_upload_large_ok() -> Result<()> {
+	common_tests::common_test_files_upload_large_ok(AdapterKind::Gemini).await
+}
+
+#[tokio::test]
+async fn test_files_chat_ok() -> Result<()> {
+	common_tests::common_test_files_chat_ok(MODEL).await
+}
+
+// endregion: --- Files
+
 // region:    --- List
 
 #[tokio::test]
@@ -119,3 +248,21 @@ async fn test_list_models() -> Result<()> {
 }
 
 // endregion: --- List
+
+// region:    --- Ping
+
+#[tokio::test]
+async fn test_ping_ok() -> Result<()> {
+	common_tests::common_test_ping_ok(AdapterKind::Gemini).await
+}
+
+// endregion: --- Ping
+
+// region:    --- Model Info
+
+#[tokio::test]
+async fn test_model_info_ok() -> Result<()> {
+	common_tests::common_test_model_info_ok(MODEL, true).await
+}
+
+// endregion: --- Model Info
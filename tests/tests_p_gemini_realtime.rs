@@ -0,0 +1,14 @@
+mod support;
+
+use crate::support::{Result, common_tests};
+
+const MODEL: &str = "gemini-2.0-flash-live-001";
+
+// region:    --- Realtime
+
+#[tokio::test]
+async fn test_realtime_text_ok() -> Result<()> {
+	common_tests::common_test_realtime_text_ok(MODEL).await
+}
+
+// endregion: --- Realtime
@@ -15,3 +15,21 @@ async fn test_chat_simple_ok() -> Result<()> {
 	//       But it should have REASONING_USAGE
 	common_tests::common_test_chat_simple_ok(MODEL, Some(Check::REASONING_USAGE)).await
 }
+
+#[tokio::test]
+async fn test_chat_reasoning_ok() -> Result<()> {
+	// NOTE: The `-medium` suffix sets the `thinkingBudget` with `includeThoughts`, so the thoughts are returned.
+	let model = format!("{MODEL}-medium");
+	common_tests::common_test_chat_simple_ok(&model, Some(Check::REASONING | Check::REASONING_USAGE)).await
+}
+
+#[tokio::test]
+async fn test_chat_stream_reasoning_ok() -> Result<()> {
+	let model = format!("{MODEL}-medium");
+	common_tests::common_test_chat_stream_simple_ok(&model, Some(Check::REASONING)).await
+}
+
+#[tokio::test]
+async fn test_chat_reasoning_disabled_ok() -> Result<()> {
+	common_tests::common_test_chat_reasoning_disabled_ok(MODEL).await
+}
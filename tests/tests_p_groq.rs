@@ -31,6 +31,11 @@ async fn test_chat_multi_system_ok() -> Result<()> {
 	common_tests::common_test_chat_multi_system_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_developer_role_ok() -> Result<()> {
+	common_tests::common_test_chat_developer_role_ok(MODEL).await
+}
+
 #[tokio::test]
 async fn test_chat_json_mode_ok() -> Result<()> {
 	common_tests::common_test_chat_json_mode_ok(MODEL, Some(Check::USAGE)).await
@@ -46,6 +51,11 @@ async fn test_chat_stop_sequences_ok() -> Result<()> {
 	common_tests::common_test_chat_stop_sequences_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_provider_ext_groq_timing_ok() -> Result<()> {
+	common_tests::common_test_chat_provider_ext_groq_timing_ok(MODEL).await
+}
+
 // endregion: --- Chat
 
 // region:    --- Chat Stream Tests
@@ -32,11 +32,21 @@ async fn test_chat_json_mode_ok() -> Result<()> {
 	common_tests::common_test_chat_json_mode_ok(MODEL, None).await
 }
 
+#[tokio::test]
+async fn test_chat_json_structured_ok() -> Result<()> {
+	common_tests::common_test_chat_json_structured_ok(MODEL, None).await
+}
+
 #[tokio::test]
 async fn test_chat_temperature_ok() -> Result<()> {
 	common_tests::common_test_chat_temperature_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_sampling_extras_ok() -> Result<()> {
+	common_tests::common_test_chat_sampling_extras_ok(MODEL).await
+}
+
 #[tokio::test]
 async fn test_chat_stop_sequences_ok() -> Result<()> {
 	common_tests::common_test_chat_stop_sequences_ok(MODEL).await
@@ -83,3 +93,12 @@ async fn test_list_models() -> Result<()> {
 }
 
 // endregion: --- List
+
+// region:    --- Ollama Management
+
+#[tokio::test]
+async fn test_ollama_manage_ok() -> Result<()> {
+	common_tests::common_test_ollama_manage_ok(MODEL).await
+}
+
+// endregion: --- Ollama Management
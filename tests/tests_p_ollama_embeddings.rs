@@ -0,0 +1,58 @@
+mod support;
+
+use crate::support::{Result, common_tests};
+use genai::Client;
+use genai::embed::EmbedOptions;
+
+// NOTE: Requires `ollama pull nomic-embed-text`
+const MODEL: &str = "nomic-embed-text";
+const MODEL_NS: &str = "ollama::nomic-embed-text";
+
+// region:    --- Single Embedding Tests
+
+#[tokio::test]
+async fn test_embed_single_simple_ok() -> Result<()> {
+	common_tests::common_test_embed_single_simple_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_embed_single_namespaced_ok() -> Result<()> {
+	common_tests::common_test_embed_single_simple_ok(MODEL_NS).await
+}
+
+// endregion: --- Single Embedding Tests
+
+// region:    --- Batch Embedding Tests
+
+#[tokio::test]
+async fn test_embed_batch_simple_ok() -> Result<()> {
+	common_tests::common_test_embed_batch_simple_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_embed_normalize_ok() -> Result<()> {
+	common_tests::common_test_embed_normalize_ok(MODEL).await
+}
+
+// endregion: --- Batch Embedding Tests
+
+// region:    --- Provider-Specific Tests
+
+#[tokio::test]
+async fn test_embed_with_ollama_truncate_ok() -> Result<()> {
+	// Ollama supports enabling/disabling the truncation ("NONE" disables it)
+	let client = Client::default();
+	let text = "Test with Ollama-specific options";
+
+	let options = EmbedOptions::new().with_truncate("END").with_capture_raw_body(true);
+
+	let response = client.embed(MODEL, text, Some(&options)).await?;
+
+	let embedding = response.first_embedding().ok_or("Should have an embedding")?;
+	assert!(embedding.dimensions() > 0);
+	assert!(response.captured_raw_body.is_some());
+
+	Ok(())
+}
+
+// endregion: --- Provider-Specific Tests
@@ -2,6 +2,7 @@ mod support;
 
 use crate::support::{Check, common_tests};
 use genai::adapter::AdapterKind;
+use genai::chat::Tool;
 use genai::resolver::AuthData;
 
 type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
@@ -21,11 +22,26 @@ async fn test_chat_namespaced_ok() -> Result<()> {
 	common_tests::common_test_chat_simple_ok(MODEL_NS, None).await
 }
 
+#[tokio::test]
+async fn test_chat_response_digest_ok() -> Result<()> {
+	common_tests::common_test_chat_response_digest_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_capture_headers_ok() -> Result<()> {
+	common_tests::common_test_chat_capture_headers_ok(MODEL).await
+}
+
 #[tokio::test]
 async fn test_chat_multi_system_ok() -> Result<()> {
 	common_tests::common_test_chat_multi_system_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_developer_role_ok() -> Result<()> {
+	common_tests::common_test_chat_developer_role_ok(MODEL).await
+}
+
 #[tokio::test]
 async fn test_chat_json_mode_ok() -> Result<()> {
 	common_tests::common_test_chat_json_mode_ok(MODEL, Some(Check::USAGE)).await
@@ -36,16 +52,76 @@ async fn test_chat_json_structured_ok() -> Result<()> {
 	common_tests::common_test_chat_json_structured_ok(MODEL, Some(Check::USAGE)).await
 }
 
+#[tokio::test]
+async fn test_chat_structured_typed_ok() -> Result<()> {
+	common_tests::common_test_chat_structured_typed_ok(MODEL).await
+}
+
 #[tokio::test]
 async fn test_chat_temperature_ok() -> Result<()> {
 	common_tests::common_test_chat_temperature_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_logit_bias_ok() -> Result<()> {
+	common_tests::common_test_chat_logit_bias_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_logprobs_ok() -> Result<()> {
+	common_tests::common_test_chat_logprobs_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_candidate_count_ok() -> Result<()> {
+	common_tests::common_test_chat_candidate_count_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_penalties_ok() -> Result<()> {
+	common_tests::common_test_chat_penalties_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_seed_ok() -> Result<()> {
+	common_tests::common_test_chat_seed_ok(MODEL, true).await
+}
+
 #[tokio::test]
 async fn test_chat_stop_sequences_ok() -> Result<()> {
 	common_tests::common_test_chat_stop_sequences_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_user_metadata_ok() -> Result<()> {
+	common_tests::common_test_chat_user_metadata_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_store_metadata_ok() -> Result<()> {
+	common_tests::common_test_chat_store_metadata_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_prompt_template_ok() -> Result<()> {
+	common_tests::common_test_chat_prompt_template_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_prediction_ok() -> Result<()> {
+	common_tests::common_test_chat_prediction_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_extra_body_ok() -> Result<()> {
+	common_tests::common_test_chat_extra_body_ok(MODEL, "max_tokens").await
+}
+
+#[tokio::test]
+async fn test_chat_extra_headers_ok() -> Result<()> {
+	common_tests::common_test_chat_extra_headers_ok(MODEL, "Authorization", "Bearer bad-key").await
+}
+
 // endregion: --- Chat
 
 // region:    --- Chat Implicit Cache
@@ -69,6 +145,11 @@ async fn test_chat_stream_capture_content_ok() -> Result<()> {
 	common_tests::common_test_chat_stream_capture_content_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_stream_finish_reason_ok() -> Result<()> {
+	common_tests::common_test_chat_stream_finish_reason_ok(MODEL).await
+}
+
 #[tokio::test]
 async fn test_chat_stream_capture_all_ok() -> Result<()> {
 	common_tests::common_test_chat_stream_capture_all_ok(MODEL, None).await
@@ -76,6 +157,119 @@ async fn test_chat_stream_capture_all_ok() -> Result<()> {
 
 // endregion: --- Chat Stream Tests
 
+// region:    --- Chat Prompt Shield
+
+#[tokio::test]
+async fn test_chat_prompt_shield_redact_ok() -> Result<()> {
+	common_tests::common_test_chat_prompt_shield_redact_ok(MODEL).await
+}
+
+// endregion: --- Chat Prompt Shield
+
+// region:    --- Chat AI Disclosure
+
+#[tokio::test]
+async fn test_chat_ai_disclosure_ok() -> Result<()> {
+	common_tests::common_test_chat_ai_disclosure_ok(MODEL).await
+}
+
+// endregion: --- Chat AI Disclosure
+
+// region:    --- Chat Auto Continue
+
+#[tokio::test]
+async fn test_chat_auto_continue_ok() -> Result<()> {
+	common_tests::common_test_chat_auto_continue_ok(MODEL).await
+}
+
+// endregion: --- Chat Auto Continue
+
+// region:    --- Chat RAG Citations
+
+#[tokio::test]
+async fn test_chat_rag_citations_ok() -> Result<()> {
+	common_tests::common_test_chat_rag_citations_ok(MODEL).await
+}
+
+// endregion: --- Chat RAG Citations
+
+// region:    --- Chat Refusal
+
+#[tokio::test]
+async fn test_chat_not_refusal_ok() -> Result<()> {
+	common_tests::common_test_chat_not_refusal_ok(MODEL).await
+}
+
+// endregion: --- Chat Refusal
+
+// region:    --- Chat Time Context
+
+#[tokio::test]
+async fn test_chat_time_context_ok() -> Result<()> {
+	common_tests::common_test_chat_time_context_ok(MODEL).await
+}
+
+// endregion: --- Chat Time Context
+
+// region:    --- Chat Regeneration
+
+#[tokio::test]
+async fn test_chat_regenerate_ok() -> Result<()> {
+	common_tests::common_test_chat_regenerate_ok(MODEL).await
+}
+
+// endregion: --- Chat Regeneration
+
+// region:    --- Chat Routing
+
+#[tokio::test]
+async fn test_chat_routed_ok() -> Result<()> {
+	common_tests::common_test_chat_routed_ok(MODEL, "gpt-4o").await
+}
+
+// endregion: --- Chat Routing
+
+// region:    --- Chat Capability Fallback
+
+#[tokio::test]
+async fn test_chat_capability_fallback_ok() -> Result<()> {
+	common_tests::common_test_chat_capability_fallback_ok("gpt-3.5-turbo", MODEL).await
+}
+
+// endregion: --- Chat Capability Fallback
+
+// region:    --- Chat Map-Reduce
+
+#[tokio::test]
+async fn test_chat_map_reduce_ok() -> Result<()> {
+	common_tests::common_test_chat_map_reduce_ok(MODEL).await
+}
+
+// endregion: --- Chat Map-Reduce
+
+// region:    --- Chat Quota
+
+#[tokio::test]
+async fn test_chat_quota_rpm_ok() -> Result<()> {
+	common_tests::common_test_chat_quota_rpm_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_quota_shared_store_ok() -> Result<()> {
+	common_tests::common_test_chat_quota_shared_store_ok(MODEL).await
+}
+
+// endregion: --- Chat Quota
+
+// region:    --- Chat Speculative
+
+#[tokio::test]
+async fn test_chat_speculative_ok() -> Result<()> {
+	common_tests::common_test_chat_speculative_ok(MODEL, "gpt-4o").await
+}
+
+// endregion: --- Chat Speculative
+
 // region:    --- Image Tests
 
 #[tokio::test]
@@ -88,6 +282,21 @@ async fn test_chat_image_b64_ok() -> Result<()> {
 	common_tests::common_test_chat_image_b64_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_document_b64_ok() -> Result<()> {
+	common_tests::common_test_chat_document_b64_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_audio_b64_ok() -> Result<()> {
+	common_tests::common_test_chat_audio_b64_ok("gpt-4o-audio-preview").await
+}
+
+#[tokio::test]
+async fn test_chat_image_output_ok() -> Result<()> {
+	common_tests::common_test_chat_image_output_ok(MODEL, Some(vec![Tool::image_generation(None)])).await
+}
+
 // endregion: --- Image Test
 
 // region:    --- Tool Tests
@@ -97,10 +306,36 @@ async fn test_tool_simple_ok() -> Result<()> {
 	common_tests::common_test_tool_simple_ok(MODEL, true).await
 }
 
+#[tokio::test]
+async fn test_tool_choice_ok() -> Result<()> {
+	common_tests::common_test_tool_choice_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_tool_no_parallel_calls_ok() -> Result<()> {
+	common_tests::common_test_tool_no_parallel_calls_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_tool_strict_ok() -> Result<()> {
+	common_tests::common_test_tool_strict_ok(MODEL).await
+}
+
 #[tokio::test]
 async fn test_tool_full_flow_ok() -> Result<()> {
 	common_tests::common_test_tool_full_flow_ok(MODEL, true).await
 }
+
+#[tokio::test]
+async fn test_tool_history_edit_ok() -> Result<()> {
+	common_tests::common_test_tool_history_edit_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_builtin_web_search_ok() -> Result<()> {
+	common_tests::common_test_chat_builtin_web_search_ok(MODEL).await
+}
+
 // endregion: --- Tool Tests
 
 // region:    --- Resolver Tests
@@ -110,8 +345,55 @@ async fn test_resolver_auth_ok() -> Result<()> {
 	common_tests::common_test_resolver_auth_ok(MODEL, AuthData::from_env("OPENAI_API_KEY")).await
 }
 
+#[tokio::test]
+async fn test_resolver_request_signer_ok() -> Result<()> {
+	common_tests::common_test_resolver_request_signer_ok(MODEL).await
+}
+
 // endregion: --- Resolver Tests
 
+// region:    --- Files
+
+#[tokio::test]
+async fn test_files_upload_list_delete_ok() -> Result<()> {
+	common_tests::common_test_files_upload_list_delete_ok(AdapterKind::OpenAI).await
+}
+
+#[tokio::test]
+async fn test_chat_file_search_ok() -> Result<()> {
+	common_tests::common_test_chat_file_search_ok(MODEL).await
+}
+
+// endregion: --- Files
+
+// region:    --- Fine-tuning
+
+#[tokio::test]
+async fn test_finetune_list_ok() -> Result<()> {
+	common_tests::common_test_finetune_list_ok(AdapterKind::OpenAI).await
+}
+
+// endregion: --- Fine-tuning
+
+// region:    --- Golden
+
+#[tokio::test]
+async fn test_golden_runner_ok() -> Result<()> {
+	common_tests::common_test_golden_runner_ok(MODEL).await
+}
+
+// endregion: --- Golden
+
+// region:    --- Sqlite Log
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn test_sqlite_log_ok() -> Result<()> {
+	common_tests::common_test_sqlite_log_ok(MODEL).await
+}
+
+// endregion: --- Sqlite Log
+
 // region:    --- List
 
 #[tokio::test]
@@ -120,3 +402,21 @@ async fn test_list_models() -> Result<()> {
 }
 
 // endregion: --- List
+
+// region:    --- Ping
+
+#[tokio::test]
+async fn test_ping_ok() -> Result<()> {
+	common_tests::common_test_ping_ok(AdapterKind::OpenAI).await
+}
+
+// endregion: --- Ping
+
+// region:    --- Model Info
+
+#[tokio::test]
+async fn test_model_info_ok() -> Result<()> {
+	common_tests::common_test_model_info_ok(MODEL, false).await
+}
+
+// endregion: --- Model Info
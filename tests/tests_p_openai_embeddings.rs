@@ -7,6 +7,7 @@ use genai::embed::{EmbedOptions, EmbedRequest};
 const MODEL: &str = "text-embedding-3-small";
 const MODEL_LARGE: &str = "text-embedding-3-large";
 const MODEL_NS: &str = "openai::text-embedding-3-small";
+const MODEL_ADA: &str = "text-embedding-ada-002";
 
 // region:    --- Single Embedding Tests
 
@@ -34,6 +35,11 @@ async fn test_embed_batch_simple_ok() -> Result<()> {
 	common_tests::common_test_embed_batch_simple_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_embed_batch_concurrent_ok() -> Result<()> {
+	common_tests::common_test_embed_batch_concurrent_ok(MODEL).await
+}
+
 #[tokio::test]
 async fn test_embed_batch_empty_should_fail() -> Result<()> {
 	common_tests::common_test_embed_empty_batch_should_fail(MODEL).await
@@ -41,6 +47,42 @@ async fn test_embed_batch_empty_should_fail() -> Result<()> {
 
 // endregion: --- Batch Embedding Tests
 
+// region:    --- Encoding Format Tests
+
+#[tokio::test]
+async fn test_embed_base64_encoding_ok() -> Result<()> {
+	common_tests::common_test_embed_base64_encoding_ok(MODEL).await
+}
+
+// endregion: --- Encoding Format Tests
+
+// region:    --- Dimensions Tests
+
+#[tokio::test]
+async fn test_embed_dimensions_not_supported_should_fail() -> Result<()> {
+	common_tests::common_test_embed_dimensions_not_supported_should_fail(MODEL_ADA).await
+}
+
+// endregion: --- Dimensions Tests
+
+// region:    --- Usage Tests
+
+#[tokio::test]
+async fn test_embed_billed_units_ok() -> Result<()> {
+	common_tests::common_test_embed_billed_units_ok(MODEL).await
+}
+
+// endregion: --- Usage Tests
+
+// region:    --- Normalize Tests
+
+#[tokio::test]
+async fn test_embed_normalize_ok() -> Result<()> {
+	common_tests::common_test_embed_normalize_ok(MODEL).await
+}
+
+// endregion: --- Normalize Tests
+
 // region:    --- EmbedRequest Tests
 
 #[tokio::test]
@@ -0,0 +1,20 @@
+mod support;
+
+use crate::support::{Result, common_tests};
+
+const MODEL: &str = "gpt-realtime";
+const MODEL_NS: &str = "openai::gpt-realtime";
+
+// region:    --- Realtime
+
+#[tokio::test]
+async fn test_realtime_text_ok() -> Result<()> {
+	common_tests::common_test_realtime_text_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_realtime_text_namespaced_ok() -> Result<()> {
+	common_tests::common_test_realtime_text_ok(MODEL_NS).await
+}
+
+// endregion: --- Realtime
@@ -31,6 +31,11 @@ async fn test_chat_multi_system_ok() -> Result<()> {
 	common_tests::common_test_chat_multi_system_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_developer_role_ok() -> Result<()> {
+	common_tests::common_test_chat_developer_role_ok(MODEL).await
+}
+
 #[tokio::test]
 async fn test_chat_json_mode_ok() -> Result<()> {
 	common_tests::common_test_chat_json_mode_ok(MODEL, Some(Check::USAGE)).await
@@ -52,6 +57,11 @@ async fn test_chat_stop_sequences_ok() -> Result<()> {
 	common_tests::common_test_chat_stop_sequences_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_reasoning_summary_ok() -> Result<()> {
+	common_tests::common_test_chat_reasoning_summary_ok(MODEL_FOR_THINKING).await
+}
+
 /// NOTE 2025-01-31  - Reasoning_content or <think> content not supported by OpenAI at this point
 ///                    So, disabled for now.
 // #[tokio::test]
@@ -0,0 +1,271 @@
+mod support;
+
+use crate::support::Result;
+use genai::ModelIden;
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatMessage, ChatRequest, Usage};
+use genai::trace::{Anonymizer, TraceFormat, TraceMessage, TraceRecord};
+use serde_json::json;
+use std::time::{Duration, UNIX_EPOCH};
+
+// region:    --- Anonymizer
+
+#[test]
+fn test_anonymizer_emails_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut anonymizer = Anonymizer::new();
+
+	// -- Exec
+	let text = anonymizer.anonymize_text("Contact alice.martin@example.com or bob@mail.example.org.");
+
+	// -- Check
+	assert_eq!(text, "Contact <EMAIL_1> or <EMAIL_2>.");
+
+	Ok(())
+}
+
+#[test]
+fn test_anonymizer_emails_not_matched_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut anonymizer = Anonymizer::new();
+	let text = "Ping @alice, see user@localhost and the @decorator.";
+
+	// -- Exec & Check
+	assert_eq!(anonymizer.anonymize_text(text), text);
+
+	Ok(())
+}
+
+#[test]
+fn test_anonymizer_phones_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut anonymizer = Anonymizer::new().with_detect_emails(false);
+
+	// -- Exec
+	let text = anonymizer.anonymize_text("Call +33612345678, (555) 123-4567, or 06 12 34 56 78 today.");
+
+	// -- Check
+	assert_eq!(text, "Call <PHONE_1>, <PHONE_2>, or <PHONE_3> today.");
+
+	Ok(())
+}
+
+#[test]
+fn test_anonymizer_phones_not_matched_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut anonymizer = Anonymizer::new();
+	let texts = [
+		"Created at 2024-01-15 10:30 (UTC).",
+		"Created at 15.01.2024 10:30.",
+		"Your order number is 123456789012.",
+		"See ticket ORD-2024-000123456 and #555-123-4567.",
+		"The timestamp was 1705312200.",
+		"Version 1.2.3 shipped.",
+	];
+
+	// -- Exec & Check
+	for text in texts {
+		assert_eq!(anonymizer.anonymize_text(text), text, "Should not be anonymized");
+	}
+
+	Ok(())
+}
+
+#[test]
+fn test_anonymizer_terms_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut anonymizer = Anonymizer::new()
+		.append_term("Alice Martin", "name")
+		.append_term("ACC-98765", "account");
+
+	// -- Exec
+	let text = anonymizer.anonymize_text("Alice Martin (ACC-98765) wrote to alice@example.com.");
+
+	// -- Check
+	assert_eq!(text, "<NAME_1> (<ACCOUNT_1>) wrote to <EMAIL_1>.");
+
+	Ok(())
+}
+
+#[test]
+fn test_anonymizer_placeholders_stable_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut anonymizer = Anonymizer::new();
+
+	// -- Exec
+	let first = anonymizer.anonymize_text("From bob@example.com to alice@example.com");
+	let second = anonymizer.anonymize_text("Reply to alice@example.com");
+
+	// -- Check
+	assert_eq!(first, "From <EMAIL_1> to <EMAIL_2>");
+	assert_eq!(second, "Reply to <EMAIL_2>");
+
+	Ok(())
+}
+
+// endregion: --- Anonymizer
+
+// region:    --- TraceFormat
+
+#[test]
+fn test_trace_format_open_inference_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let record = seed_trace_record();
+
+	// -- Exec
+	let span = record.to_json(TraceFormat::OpenInference);
+
+	// -- Check
+	assert_eq!(span["name"], "genai.chat");
+	assert_eq!(span["span_kind"], "LLM");
+	assert_eq!(span["context"]["trace_id"], "0123456789abcdef0123456789abcdef");
+	assert_eq!(span["context"]["span_id"], "0123456789abcdef");
+	assert_eq!(span["start_time"], "2024-05-08T10:20:30.123456Z");
+	assert_eq!(span["end_time"], "2024-05-08T10:20:31.500000Z");
+	assert_eq!(span["status_code"], "OK");
+	let attributes = &span["attributes"];
+	assert_eq!(attributes["openinference.span.kind"], "LLM");
+	assert_eq!(attributes["llm.provider"], "openai");
+	assert_eq!(attributes["llm.model_name"], "gpt-4o-mini");
+	assert_eq!(attributes["llm.input_messages.0.message.role"], "system");
+	assert_eq!(
+		attributes["llm.input_messages.1.message.content"],
+		"Why is the sky blue?"
+	);
+	assert_eq!(attributes["llm.output_messages.0.message.role"], "assistant");
+	assert_eq!(attributes["llm.token_count.prompt"], 12);
+	assert_eq!(attributes["llm.token_count.completion"], 30);
+	assert_eq!(attributes["llm.token_count.total"], 42);
+	assert_eq!(attributes["metadata.feature"], "faq");
+
+	Ok(())
+}
+
+#[test]
+fn test_trace_format_open_inference_short_id_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut record = seed_trace_record();
+	record.id = "run-42".to_string();
+
+	// -- Exec
+	let span = record.to_json(TraceFormat::OpenInference);
+
+	// -- Check
+	assert_eq!(span["context"]["trace_id"], "run42");
+	assert_eq!(span["context"]["span_id"], "run42");
+
+	Ok(())
+}
+
+#[test]
+fn test_trace_format_lang_smith_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let record = seed_trace_record();
+
+	// -- Exec
+	let run = record.to_json(TraceFormat::LangSmith);
+
+	// -- Check
+	assert_eq!(run["id"], "01234567-89ab-cdef-0123-456789abcdef");
+	assert_eq!(run["run_type"], "llm");
+	assert_eq!(run["start_time"], "2024-05-08T10:20:30.123456Z");
+	assert_eq!(run["end_time"], "2024-05-08T10:20:31.500000Z");
+	assert_eq!(
+		run["inputs"]["messages"][1],
+		json!({"role": "user", "content": "Why is the sky blue?"})
+	);
+	assert_eq!(run["outputs"]["choices"][0]["message"]["role"], "assistant");
+	assert_eq!(
+		run["outputs"]["usage_metadata"],
+		json!({"input_tokens": 12, "output_tokens": 30, "total_tokens": 42})
+	);
+	assert_eq!(run["error"], json!(null));
+	assert_eq!(run["extra"]["metadata"]["ls_provider"], "openai");
+	assert_eq!(run["extra"]["metadata"]["ls_model_name"], "gpt-4o-mini");
+	assert_eq!(run["extra"]["metadata"]["feature"], "faq");
+
+	Ok(())
+}
+
+#[test]
+fn test_trace_format_error_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let record = seed_trace_record().with_error("Rate limited");
+
+	// -- Exec
+	let span = record.to_json(TraceFormat::OpenInference);
+	let run = record.to_json(TraceFormat::LangSmith);
+
+	// -- Check
+	assert_eq!(span["status_code"], "ERROR");
+	assert_eq!(span["status_message"], "Rate limited");
+	assert_eq!(run["error"], "Rate limited");
+	assert_eq!(run["outputs"], json!(null));
+
+	Ok(())
+}
+
+#[test]
+fn test_trace_format_timestamp_leap_day_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let record = seed_trace_record().with_start_time(UNIX_EPOCH + Duration::from_secs(951_782_400));
+
+	// -- Exec
+	let run = record.to_json(TraceFormat::LangSmith);
+
+	// -- Check
+	assert_eq!(run["start_time"], "2000-02-29T00:00:00.000000Z");
+
+	Ok(())
+}
+
+#[test]
+fn test_trace_format_jsonl_line_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let record = seed_trace_record();
+
+	// -- Exec
+	let line = record.to_jsonl_line(TraceFormat::LangSmith);
+
+	// -- Check
+	assert!(line.ends_with('\n'), "Should end with a new line");
+	assert_eq!(line.matches('\n').count(), 1, "Should be a single line");
+	let run: serde_json::Value = serde_json::from_str(&line)?;
+	assert_eq!(run, record.to_json(TraceFormat::LangSmith));
+
+	Ok(())
+}
+
+// endregion: --- TraceFormat
+
+// region:    --- Support
+
+fn seed_trace_record() -> TraceRecord {
+	let model_iden = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+	let chat_req = ChatRequest::new(vec![
+		ChatMessage::system("Answer in one sentence"),
+		ChatMessage::user("Why is the sky blue?"),
+	]);
+	let start_time = UNIX_EPOCH + Duration::from_micros(1_715_163_630_123_456);
+
+	let mut record = TraceRecord::from_chat(model_iden, &chat_req)
+		.with_metadata(json!({"feature": "faq"}))
+		.with_start_time(start_time)
+		.with_end_time(start_time + Duration::from_micros(1_376_544));
+	record.id = "01234567-89ab-cdef-0123-456789abcdef".to_string();
+	record.output_messages = vec![TraceMessage {
+		role: "assistant".to_string(),
+		content: Some("Because of the Rayleigh scattering.".to_string()),
+		tool_calls: Vec::new(),
+		tool_call_id: None,
+	}];
+	record.usage = Some(Usage {
+		prompt_tokens: Some(12),
+		completion_tokens: Some(30),
+		total_tokens: Some(42),
+		..Default::default()
+	});
+	record
+}
+
+// endregion: --- Support